@@ -0,0 +1,16 @@
+# chiru 生成的 python 代码所使用的运行时, 与 rust 运行时的 chiru::runtime 相对应
+
+from .ast import AstContext, ErrorContext, RuleContext, TerminalContext, build_contexts, set_element_label
+from .error import ChiruError, ErrorKind
+from .error_listener import (
+    ConsoleErrorListener, DiagnosticErrorListener, ErrorListener, format_expected, report_error, sort_terminals,
+)
+from .error_strategy import (
+    BailErrorStrategy, DefaultErrorStrategy, ErrorStrategy, LL1Table, Recognizer, SingleTokenErrorStrategy,
+)
+from .lexer import Lexer, LexerRule, ModeAction, TokenIter
+from .ll1_analyzer import LL1Grammar, ll1_analyze, sync_from, table_from, terminals_from
+from .production import Production, ProductionItem
+from .token import Location, Position, Token
+from .token_stream import TokenStream
+from .vocabulary import NonTerminal, Terminal
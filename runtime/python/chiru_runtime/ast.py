@@ -0,0 +1,198 @@
+from typing import Callable, List, Optional, Sequence, Union
+
+from .error_listener import format_expected
+from .token import Location, Token
+from .vocabulary import NonTerminal, Terminal
+
+
+class TerminalContext:
+    def __init__(self, symbol: Token, element_label: Optional[str] = None):
+        self.symbol = symbol
+        # 在产生式中的标签, 如 op=PLUS
+        self.element_label = element_label
+
+    def get_text(self) -> str:
+        return self.symbol.text
+
+    def __str__(self) -> str:
+        return self.symbol.terminal.name
+
+    def to_dict(self) -> dict:
+        return {"token_name": self.symbol.terminal.name, "token_type": self.symbol.terminal.id, "text": self.symbol.text}
+
+
+class ErrorContext:
+    # 错误节点的种类, 多余的 token、出现在期望的符号位置上的错误 token 以及缺失的符号
+    REDUNDANT = "redundant"
+    MISTAKE = "mistake"
+    MISSING = "missing"
+
+    def __init__(self, kind: str, token: Optional[Token], expected: Sequence[Terminal], message: str, location: Location):
+        self.kind = kind
+        # 出错的 token, 缺失的符号没有 token
+        self.token = token
+        # 出错时期望的终结符集合
+        self.expected = list(expected)
+        # 可读的错误信息, 如 missing SEMI at '}'
+        self.message = message
+        # 错误的位置, 缺失的符号位于下一个 token 的起始位置, 长度为 0
+        self.location = location
+
+    def get_text(self) -> str:
+        return "<missing>" if self.token is None else self.token.text
+
+    # 多余的 token, 会被丢弃
+    @classmethod
+    def redundant(cls, symbol: Token, expected: Sequence[Terminal]) -> "ErrorContext":
+        return cls(cls.REDUNDANT, symbol, expected,
+                   f"extraneous input '{symbol.text}' expecting {format_expected(expected)}", symbol.location)
+
+    # 出现在期望的符号位置上的错误 token
+    @classmethod
+    def mistake(cls, symbol: Token, expected: Sequence[Terminal]) -> "ErrorContext":
+        return cls(cls.MISTAKE, symbol, expected,
+                   f"mismatched input '{symbol.text}' expecting {format_expected(expected)}", symbol.location)
+
+    # 在 next 之前缺失了期望的符号
+    @classmethod
+    def missing(cls, next: Token, expected: Sequence[Terminal]) -> "ErrorContext":
+        start = next.location.start
+        index = next.location.index_start
+        return cls(cls.MISSING, None, expected,
+                   f"missing {format_expected(expected)} at '{next.text}'", Location(start, start, index, index))
+
+    # 输出位置和错误信息, 行号和列号从 1 开始, 如 <3:14 missing SEMI at '}'>
+    def __str__(self) -> str:
+        return f"<{self.location.start.line + 1}:{self.location.start.char_position + 1} {self.message}>"
+
+    def to_dict(self) -> dict:
+        result = {
+            "error_type": self.kind, "message": self.message,
+            "expected": [terminal.name for terminal in self.expected],
+        }
+        if self.token is not None:
+            result.update(token_name=self.token.terminal.name, token_type=self.token.terminal.id, text=self.token.text)
+        return result
+
+
+# 语法树中的节点
+AstContext = Union[TerminalContext, "RuleContext", ErrorContext]
+
+
+class RuleContext:
+    def __init__(
+        self,
+        symbol: NonTerminal,
+        children: Optional[List[AstContext]] = None,
+        label: Optional[str] = None,
+        element_label: Optional[str] = None,
+    ):
+        # 非终结符 rule
+        self.symbol = symbol
+        self.children: List[AstContext] = children if children is not None else []
+        # 匹配到的备选分支的标签, 没有标签时为 None
+        self.label = label
+        # 在父节点的产生式中的标签, 如 lhs=expr
+        self.element_label = element_label
+
+    def get_children(self) -> List[AstContext]:
+        return self.children
+
+    def get_child_count(self) -> int:
+        return len(self.children)
+
+    def get_rule_index(self) -> int:
+        return self.symbol.id
+
+    def get_label(self) -> Optional[str]:
+        return self.label
+
+    def get_first_terminal(self) -> Optional[TerminalContext]:
+        if not self.children:
+            return None
+        first = self.children[0]
+        if isinstance(first, TerminalContext):
+            return first
+        if isinstance(first, RuleContext):
+            return first.get_first_terminal()
+        return None
+
+    def get_last_terminal(self) -> Optional[TerminalContext]:
+        if not self.children:
+            return None
+        last = self.children[-1]
+        if isinstance(last, TerminalContext):
+            return last
+        if isinstance(last, RuleContext):
+            return last.get_last_terminal()
+        return None
+
+    def get_start_token(self) -> Optional[Token]:
+        terminal = self.get_first_terminal()
+        return terminal.symbol if terminal is not None else None
+
+    def get_stop_token(self) -> Optional[Token]:
+        terminal = self.get_last_terminal()
+        return terminal.symbol if terminal is not None else None
+
+    def get_terminal(self, token_type: int, i: int) -> Optional[TerminalContext]:
+        return _nth(self.get_terminals(token_type), i)
+
+    def get_terminals(self, token_type: int) -> List[TerminalContext]:
+        return [child for child in self.children
+                if isinstance(child, TerminalContext) and child.symbol.terminal.id == token_type]
+
+    def get_error_node(self, i: int) -> Optional[ErrorContext]:
+        return _nth(self.get_error_nodes(), i)
+
+    def get_error_nodes(self) -> List[ErrorContext]:
+        return [child for child in self.children if isinstance(child, ErrorContext)]
+
+    def get_rule_context(self, rule_type: int, i: int) -> Optional["RuleContext"]:
+        return _nth(self.get_rule_contexts(rule_type), i)
+
+    def get_rule_contexts(self, rule_type: int) -> List["RuleContext"]:
+        return [child for child in self.children
+                if isinstance(child, RuleContext) and child.get_rule_index() == rule_type]
+
+    def get_labeled_rule_context(self, label: str, i: int) -> Optional["RuleContext"]:
+        return _nth(self.get_labeled_rule_contexts(label), i)
+
+    def get_labeled_rule_contexts(self, label: str) -> List["RuleContext"]:
+        return [child for child in self.children
+                if isinstance(child, RuleContext) and child.element_label == label]
+
+    def get_labeled_terminal(self, label: str, i: int) -> Optional[TerminalContext]:
+        return _nth(self.get_labeled_terminals(label), i)
+
+    def get_labeled_terminals(self, label: str) -> List[TerminalContext]:
+        return [child for child in self.children
+                if isinstance(child, TerminalContext) and child.element_label == label]
+
+    def __str__(self) -> str:
+        name = self.symbol.name if self.symbol.name is not None else self.symbol.id
+        return f"({name}" + "".join(f" {child}" for child in self.children) + ")"
+
+    def to_dict(self) -> dict:
+        return {
+            "rule_index": self.symbol.id, "rule_name": self.symbol.name,
+            "children": [child.to_dict() for child in self.children],
+        }
+
+
+def _nth(items: list, i: int):
+    return items[i] if 0 <= i < len(items) else None
+
+
+# 设置节点在产生式中的标签, 已经有标签的节点保持不变, 错误节点没有标签
+def set_element_label(ctx: AstContext, label: Optional[str]) -> None:
+    if isinstance(ctx, ErrorContext):
+        return
+    if ctx.element_label is None:
+        ctx.element_label = label
+
+
+# 自底向上地将分析得到的语法树转换为生成的 context 类型, factory 根据规则编号和标签创建对应的 context
+def build_contexts(ctx: RuleContext, factory: Callable[[RuleContext], RuleContext]) -> RuleContext:
+    children = [build_contexts(child, factory) if isinstance(child, RuleContext) else child for child in ctx.children]
+    return factory(RuleContext(ctx.symbol, children, ctx.label, ctx.element_label))
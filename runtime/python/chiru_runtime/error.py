@@ -0,0 +1,35 @@
+from enum import Enum
+from typing import Optional
+
+from .token import Location
+
+
+# 运行时产生的错误, 与 rust 运行时的 runtime::error::Error 相对应
+class ErrorKind(Enum):
+    # look_ahead 超出了范围
+    TOKEN_STREAM_OUT_OF_RANGE = "TokenStreamOutOfRange"
+    # 语法错误的位置和信息, 不进行错误恢复时抛出
+    SYNTAX_ERROR = "SyntaxError"
+    # 未知错误
+    UNKNOWN = "Unknown"
+
+
+class ChiruError(Exception):
+    def __init__(self, kind: ErrorKind, message: str, location: Optional[Location] = None):
+        super().__init__(message)
+        self.kind = kind
+        self.message = message
+        # 错误在输入中的位置, 没有位置信息时为 None
+        self.location = location
+
+    @classmethod
+    def token_stream_out_of_range(cls) -> "ChiruError":
+        return cls(ErrorKind.TOKEN_STREAM_OUT_OF_RANGE, "token stream out of range")
+
+    @classmethod
+    def syntax_error(cls, location: Location, message: str) -> "ChiruError":
+        return cls(ErrorKind.SYNTAX_ERROR, f"syntax error: {message}", location)
+
+    @classmethod
+    def unknown(cls) -> "ChiruError":
+        return cls(ErrorKind.UNKNOWN, "unknown error")
@@ -0,0 +1,61 @@
+import json
+import sys
+from typing import TYPE_CHECKING, Iterable, List, Sequence
+
+from .token import Location, Token
+from .vocabulary import NonTerminal, Terminal
+
+if TYPE_CHECKING:
+    from .ast import ErrorContext
+
+
+class ErrorListener:
+    # 语法分析时发现错误, 每产生一个 redundant 或 missing 错误节点都会调用一次
+    # rule 为出错时正在分析的非终结符, offending_token 为出错位置的 token,
+    # location 为错误的位置, expected 为此时期望的终结符集合, message 为可读的错误信息
+    def syntax_error(self, rule: NonTerminal, offending_token: Token, location: Location,
+                     expected: List[Terminal], message: str) -> None:
+        pass
+
+    # 词法分析时遇到无法匹配任何规则的字符, 该字符会被跳过
+    def lexer_error(self, text: str, location: Location) -> None:
+        pass
+
+
+# 将期望的终结符集合格式化为 {A, B} 的形式, 只有一个终结符时不加括号
+def format_expected(expected: Sequence[Terminal]) -> str:
+    names = [terminal.name for terminal in expected]
+    return names[0] if len(names) == 1 else "{" + ", ".join(names) + "}"
+
+
+# 终结符集合按照名称排序, 与 rust 运行时中 BTreeSet<Terminal> 的顺序一致
+def sort_terminals(terminals: Iterable[Terminal]) -> List[Terminal]:
+    result = {terminal.id: terminal for terminal in terminals}
+    return sorted(result.values(), key=lambda terminal: (terminal.name, terminal.id))
+
+
+# 在标准错误中以 line:col 的形式输出错误, 行号和列号从 1 开始
+class ConsoleErrorListener(ErrorListener):
+    def syntax_error(self, rule, offending_token, location, expected, message):
+        print(f"{location.start.line + 1}:{location.start.char_position + 1} {message}", file=sys.stderr)
+
+    def lexer_error(self, text, location):
+        print(f"{location.start.line + 1}:{location.start.char_position + 1} token recognition error at: "
+              f"{json.dumps(text, ensure_ascii=False)}", file=sys.stderr)
+
+
+# 输出更详细的信息, 包括出错的非终结符、token 和期望的终结符集合
+class DiagnosticErrorListener(ErrorListener):
+    def syntax_error(self, rule, offending_token, location, expected, message):
+        print(f"{location.start.line + 1}:{location.start.char_position + 1} {message} "
+              f"(rule: {rule}, token: {offending_token}, expected: {format_expected(expected)})", file=sys.stderr)
+
+    def lexer_error(self, text, location):
+        print(f"{location.start.line + 1}:{location.start.char_position + 1} token recognition error at: "
+              f"{json.dumps(text, ensure_ascii=False)} (index: {location.index_start}..{location.index_stop})", file=sys.stderr)
+
+
+# 通知所有监听器产生了一个错误节点, token 为出错位置的 token
+def report_error(listeners: Sequence[ErrorListener], rule: NonTerminal, token: Token, error: "ErrorContext") -> None:
+    for listener in listeners:
+        listener.syntax_error(rule, token, error.location, error.expected, error.message)
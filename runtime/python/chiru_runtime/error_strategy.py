@@ -0,0 +1,157 @@
+from dataclasses import dataclass
+from typing import Dict, List, Optional, Sequence, Set
+
+from .ast import AstContext, ErrorContext
+from .error import ChiruError
+from .error_listener import ErrorListener, report_error, sort_terminals
+from .production import ProductionItem
+from .token import Token
+from .token_stream import TokenStream
+from .vocabulary import NonTerminal, Terminal
+
+
+# 生成的 ll1 分析器提供的分析表
+@dataclass
+class LL1Table:
+    # 所有的终结符, 用于获取期望的终结符的名称
+    terminals: Dict[int, Terminal]
+    # 非终结符编号 -> 终结符编号 -> 产生式编号
+    table: Dict[int, Dict[int, int]]
+    # 同步集合, 非终结符编号 -> 终结符编号
+    sync: Dict[int, Set[int]]
+
+
+# 错误处理策略在恢复时可以使用的 ll1 分析器的信息
+class Recognizer:
+    def __init__(self, rule: NonTerminal, tables: LL1Table, error_listeners: Sequence[ErrorListener]):
+        # 出错时正在分析的非终结符
+        self.rule = rule
+        self.tables = tables
+        self.error_listeners = error_listeners
+
+    # 预测分析表中 (nonterminal, terminal) 对应的产生式编号
+    def predict(self, nonterminal: NonTerminal, terminal: Terminal) -> Optional[int]:
+        return self.tables.table.get(nonterminal.id, {}).get(terminal.id)
+
+    def is_sync(self, terminal: Terminal) -> bool:
+        return terminal.id in self.tables.sync.get(self.rule.id, ())
+
+    # 在分析当前非终结符时能够接受的所有终结符
+    def expected_terminals(self) -> List[Terminal]:
+        ids = self.tables.table.get(self.rule.id, {}).keys()
+        return sort_terminals(self.tables.terminals.get(id, Terminal(f"<{id}>", id)) for id in ids)
+
+    # 通知所有监听器, 并将错误节点添加到 errors 中
+    def report(self, token: Token, error: ErrorContext, errors: List[AstContext]) -> None:
+        report_error(self.error_listeners, self.rule, token, error)
+        errors.append(error)
+
+
+# ll1 分析出错时的处理策略, 恢复过程中产生的错误节点都添加到 errors 中
+class ErrorStrategy:
+    # 预测分析表中找不到 (rule, 下一个 token) 对应的产生式时调用
+    # 返回用于展开 rule 的产生式编号, 返回 None 表示整个 rule 都缺失了
+    def recover(self, recognizer: Recognizer, token_stream: TokenStream, errors: List[AstContext]) -> Optional[int]:
+        raise NotImplementedError
+
+    # 期望终结符 expected, 但是下一个 token 与之不匹配时调用, rest 为产生式中位于 expected 之后的部分
+    # 返回 True 表示恢复之后下一个 token 就是 expected, 返回 False 表示 expected 缺失了或者被错误的 token 代替了
+    def recover_inline(self, recognizer: Recognizer, expected: Terminal, rest: Sequence[ProductionItem],
+                       token_stream: TokenStream, errors: List[AstContext]) -> bool:
+        raise NotImplementedError
+
+
+# 遇到第一个错误就抛出 ChiruError, 不进行任何恢复
+class BailErrorStrategy(ErrorStrategy):
+    def recover(self, recognizer, token_stream, errors):
+        token = token_stream.peek_next_token()
+        error = ErrorContext.mistake(token, recognizer.expected_terminals())
+        report_error(recognizer.error_listeners, recognizer.rule, token, error)
+        raise ChiruError.syntax_error(error.location, error.message)
+
+    def recover_inline(self, recognizer, expected, rest, token_stream, errors):
+        token = token_stream.peek_next_token()
+        error = ErrorContext.mistake(token, [expected])
+        report_error(recognizer.error_listeners, recognizer.rule, token, error)
+        raise ChiruError.syntax_error(error.location, error.message)
+
+
+# 恐慌模式, 一直丢弃 token, 直到遇到能够接受的 token 或者同步集合中的 token 为止
+class DefaultErrorStrategy(ErrorStrategy):
+    def recover(self, recognizer, token_stream, errors):
+        expected = recognizer.expected_terminals()
+        while True:
+            token = token_stream.peek_next_token()
+            production_id = recognizer.predict(recognizer.rule, token.terminal)
+            if production_id is not None:
+                return production_id
+
+            if recognizer.is_sync(token.terminal) or token.terminal.id == 1:
+                # 同步 这里表示整个非终结符都缺失了
+                recognizer.report(token, ErrorContext.missing(token, expected), errors)
+                return None
+
+            # 丢弃，将其添加到 error node, 这里认为该 token 是多余的
+            recognizer.report(token, ErrorContext.redundant(token, expected), errors)
+            token_stream.consume()
+
+    def recover_inline(self, recognizer, expected, rest, token_stream, errors):
+        while True:
+            token = token_stream.peek_next_token()
+            if token.terminal.id == expected.id:
+                return True
+
+            if token.terminal.id == 1:
+                recognizer.report(token, ErrorContext.missing(token, [expected]), errors)
+                return False
+
+            recognizer.report(token, ErrorContext.redundant(token, [expected]), errors)
+            token_stream.consume()
+
+
+# 先尝试删除一个多余的 token、插入一个缺失的终结符或者替换一个错误的 token, 都不行时再使用恐慌模式
+# 生成的 ll1 分析器默认使用该策略
+class SingleTokenErrorStrategy(ErrorStrategy):
+    # 如果 expected 缺失, 下一个终结符是否能够出现在 expected 之后
+    @staticmethod
+    def _can_follow(recognizer: Recognizer, rest: Sequence[ProductionItem], terminal: Terminal) -> bool:
+        if not rest:
+            return recognizer.is_sync(terminal)
+        next = rest[0]
+        if isinstance(next, Terminal):
+            return next.id == terminal.id
+        return recognizer.predict(next, terminal) is not None
+
+    def recover(self, recognizer, token_stream, errors):
+        token = token_stream.peek_next_token()
+
+        # 删除下一个 token 之后就能够选择产生式
+        production_id = recognizer.predict(recognizer.rule, token_stream.look_ahead(2).terminal)
+        if production_id is not None:
+            recognizer.report(token, ErrorContext.redundant(token, recognizer.expected_terminals()), errors)
+            token_stream.consume()
+            return production_id
+
+        return DefaultErrorStrategy().recover(recognizer, token_stream, errors)
+
+    def recover_inline(self, recognizer, expected, rest, token_stream, errors):
+        token = token_stream.peek_next_token()
+
+        # 单个 token 删除: 下一个 token 是多余的
+        if token.terminal.id != 1 and token_stream.look_ahead(2).terminal.id == expected.id:
+            recognizer.report(token, ErrorContext.redundant(token, [expected]), errors)
+            token_stream.consume()
+            return True
+
+        # 单个 token 插入: 缺少了 expected
+        if self._can_follow(recognizer, rest, token.terminal):
+            recognizer.report(token, ErrorContext.missing(token, [expected]), errors)
+            return False
+
+        # 单个 token 替换: 下一个 token 出现在了 expected 的位置上
+        if token.terminal.id != 1 and self._can_follow(recognizer, rest, token_stream.look_ahead(2).terminal):
+            recognizer.report(token, ErrorContext.mistake(token, [expected]), errors)
+            token_stream.consume()
+            return False
+
+        return DefaultErrorStrategy().recover_inline(recognizer, expected, rest, token_stream, errors)
@@ -0,0 +1,160 @@
+from bisect import bisect_right
+from dataclasses import dataclass
+from typing import Iterator, List, Optional, Pattern, Sequence, Tuple
+
+from .error_listener import ConsoleErrorListener, ErrorListener
+from .token import Location, Position, Token
+from .vocabulary import Terminal
+
+
+# 词法模式的切换命令, 对应 @push_mode(X) @pop_mode @mode(X)
+@dataclass(frozen=True)
+class ModeAction:
+    # push 将模式压入模式栈, pop 弹出栈顶的模式, set 将栈顶的模式替换为指定的模式
+    kind: str
+    mode: int = 0
+
+
+# 生成的词法分析器中的每一条规则, 按照优先级排序
+@dataclass(frozen=True)
+class LexerRule:
+    rule: Pattern[str]
+    token_type: int
+    token_name: str
+    channel: int
+    skip: bool
+
+    # 该规则所属的词法模式, 默认模式为 0
+    mode: int = 0
+    # 匹配之后对模式栈进行的操作
+    mode_action: Optional[ModeAction] = None
+
+
+# lexer 不识别 start 和 stop，所有 start 和 stop 都交给 TokenStream 来添加
+class Lexer:
+    def __init__(self, input: str, rules: Sequence[LexerRule]):
+        self.input = input
+        self.rules = list(rules)
+        self.error_listeners: List[ErrorListener] = [ConsoleErrorListener()]
+
+    def remove_all_error_listeners(self) -> None:
+        self.error_listeners = []
+
+    def add_error_listener(self, listener: ErrorListener) -> None:
+        self.error_listeners.append(listener)
+
+    def iter(self) -> "TokenIter":
+        return TokenIter(self.input, self.rules, self.error_listeners)
+
+    def __iter__(self) -> Iterator[Token]:
+        return self.iter()
+
+    def get_all_tokens(self) -> List[Token]:
+        return list(self.iter())
+
+    def get_all_on_channel_tokens(self, channel: int) -> List[Token]:
+        return [token for token in self.get_all_tokens() if token.channel == channel]
+
+
+# 这里不管 start 和 stop，需要 TokenStream 自己处理
+class TokenIter:
+    def __init__(self, input: str, rules: Sequence[LexerRule], error_listeners: Sequence[ErrorListener]):
+        self.input = input
+        self.rules = rules
+        self.error_listeners = error_listeners
+
+        # 每一行起始字符的下标, 用于判断字符所在的 position
+        self.line_starts = [0] + [index + 1 for index, char in enumerate(input) if char == "\n"]
+        # 字符游标，当前处理到的文本字符下标
+        self.cursor = 0
+        # token 序号，表示当前扫描到了第几个 token
+        self.token_index = 1
+        # 模式栈, 只有栈顶模式中的规则参与匹配
+        self.modes = [0]
+
+    def get_current_position(self) -> Position:
+        return self._get_position(self.cursor)
+
+    def get_current_mode(self) -> int:
+        return self.modes[-1] if self.modes else 0
+
+    def reset(self) -> None:
+        self.cursor = 0
+        self.token_index = 1
+        self.modes = [0]
+
+    def _get_position(self, index: int) -> Position:
+        line = bisect_right(self.line_starts, index) - 1
+        return Position(line, index - self.line_starts[line])
+
+    # 在当前位置找到最长的匹配, 长度相同时选择优先级高的规则, 返回规则的下标和匹配的结束位置
+    def _longest_match(self) -> Optional[Tuple[int, int]]:
+        mode = self.get_current_mode()
+        result: Optional[Tuple[int, int]] = None
+        for index, rule in enumerate(self.rules):
+            if rule.mode != mode:
+                continue
+            matched = rule.rule.match(self.input, self.cursor)
+            if matched is None:
+                continue
+
+            # 规则按照优先级从高到低排列, 只有更长的匹配才会替换之前的结果
+            stop = matched.end()
+            if stop > self.cursor and (result is None or stop > result[1]):
+                result = (index, stop)
+        return result
+
+    # 这个函数只管匹配，不会识别到 start 和 stop。扫描到输入末尾时返回 None
+    # 匹配不上的字符会报告给错误监听器, 然后跳过该字符继续匹配
+    def lexer_match(self) -> Optional[Token]:
+        while True:
+            if self.cursor >= len(self.input):
+                return None
+
+            matched = self._longest_match()
+            if matched is None:
+                self._skip_unmatched_char()
+                continue
+            index, stop = matched
+            start = self.cursor
+            meta = self.rules[index]
+
+            location = Location(self._get_position(start), self._get_position(stop), start, stop)
+            token = Token(Terminal(meta.token_name, meta.token_type), self.input[start:stop], location, self.token_index, meta.channel)
+
+            self.cursor = stop
+
+            # 切换词法模式, 默认模式不会被弹出
+            action = meta.mode_action
+            if action is not None and action.kind == "push":
+                self.modes.append(action.mode)
+            elif action is not None and action.kind == "pop" and len(self.modes) > 1:
+                self.modes.pop()
+            elif action is not None and action.kind == "set":
+                self.modes[-1] = action.mode
+
+            # 如果需要跳过，则继续匹配下一个
+            if meta.skip:
+                continue
+
+            self.token_index += 1
+            return token
+
+    # 跳过 cursor 处的一个字符, 并将其位置报告给错误监听器
+    def _skip_unmatched_char(self) -> None:
+        start = self.cursor
+        stop = start + 1
+        location = Location(self._get_position(start), self._get_position(stop), start, stop)
+
+        for listener in self.error_listeners:
+            listener.lexer_error(self.input[start:stop], location)
+        self.cursor = stop
+
+    def __iter__(self) -> "TokenIter":
+        return self
+
+    def __next__(self) -> Token:
+        token = self.lexer_match()
+        if token is None:
+            raise StopIteration
+        return token
@@ -0,0 +1,133 @@
+from dataclasses import dataclass
+from typing import Dict, Iterable, List, Optional, Sequence, Set, Tuple
+
+from .ast import AstContext, RuleContext, TerminalContext, set_element_label
+from .error_listener import ErrorListener
+from .error_strategy import ErrorStrategy, LL1Table, Recognizer
+from .production import Production
+from .token_stream import TokenStream
+from .vocabulary import NonTerminal, Terminal
+
+
+# 生成的 ll1 分析器提供的全部信息
+@dataclass
+class LL1Grammar(LL1Table):
+    productions: Dict[int, Production]
+    # 消除左递归时引入的匿名非终结符编号 -> 所属非终结符编号
+    left_recursion_tails: Dict[int, int]
+
+
+# 从 rule_symbol 开始分析, 分析完毕之后必须到达输入末尾
+def ll1_analyze(
+    token_stream: TokenStream,
+    rule_symbol: NonTerminal,
+    grammar: LL1Grammar,
+    error_listeners: Sequence[ErrorListener],
+    error_strategy: ErrorStrategy,
+) -> RuleContext:
+    result = _analyze_rule(token_stream, rule_symbol, grammar, error_listeners, error_strategy)
+
+    # 剩余的 token 交给错误处理策略, 期望的是 _STOP
+    # 开始符号之后只能是 _STOP, 因此不使用同步集合, 错误处理策略会一直丢弃 token 直到输入末尾
+    if token_stream.peek_next_token().terminal.id != 1:
+        recognizer = Recognizer(rule_symbol, LL1Table(grammar.terminals, grammar.table, {}), error_listeners)
+        error_strategy.recover_inline(recognizer, Terminal("_STOP", 1), [], token_stream, result.children)
+    return result
+
+
+def _analyze_rule(
+    token_stream: TokenStream,
+    rule_symbol: NonTerminal,
+    grammar: LL1Grammar,
+    error_listeners: Sequence[ErrorListener],
+    error_strategy: ErrorStrategy,
+) -> RuleContext:
+    result = RuleContext(rule_symbol)
+    recognizer = Recognizer(rule_symbol, grammar, error_listeners)
+
+    # 获取 production_id, 找不到时交给错误处理策略
+    production_id = recognizer.predict(rule_symbol, token_stream.peek_next_token().terminal)
+    if production_id is None:
+        production_id = error_strategy.recover(recognizer, token_stream, result.children)
+        # 这里表示整个非终结符都缺失了
+        if production_id is None:
+            return result
+
+    production = grammar.productions[production_id]
+    result.label = production.label
+
+    for index, child in enumerate(production.right):
+        element_label = production.get_element_label(index)
+
+        if isinstance(child, Terminal):
+            if (token_stream.peek_next_token().terminal.id != child.id
+                    and not error_strategy.recover_inline(recognizer, child, production.right[index + 1:], token_stream, result.children)):
+                # 缺失或者被替换的符号已经由错误处理策略记录, 到达输入末尾时不再继续匹配
+                if token_stream.peek_next_token().terminal.id == 1:
+                    break
+                continue
+
+            # 匹配了, 消耗掉
+            result.children.append(TerminalContext(token_stream.consume(), element_label))
+        else:
+            t = _analyze_rule(token_stream, child, grammar, error_listeners, error_strategy)
+            # 消除左递归引入的非终结符先保留嵌套, 等到其所属的非终结符分析完毕之后再折叠
+            if child.name is not None or child.id in grammar.left_recursion_tails:
+                t.element_label = element_label
+                result.children.append(t)
+            else:
+                # 提取左公因子之后, 标签记录在匿名非终结符的产生式上
+                if result.label is None:
+                    result.label = t.label
+                for grandchild in t.children:
+                    set_element_label(grandchild, element_label)
+                    result.children.append(grandchild)
+
+    # 将属于当前非终结符的左递归尾部折叠为左结合的语法树
+    last = result.children[-1] if result.children else None
+    if isinstance(last, RuleContext) and grammar.left_recursion_tails.get(last.symbol.id) == rule_symbol.id:
+        result.children.pop()
+        result = _fold_left_recursion(result, last)
+    return result
+
+
+# 将消除左递归时引入的非终结符展开的结果折叠起来, A' 的每一次非空展开都会将之前的结果作为新的 A 节点的第一个孩子
+# 原产生式中左递归位置的标签记录在末尾的 A' 上, 折叠时转交给之前的结果
+def _fold_left_recursion(result: RuleContext, tail: RuleContext) -> RuleContext:
+    while True:
+        children: List[AstContext] = list(tail.children)
+        next: Optional[RuleContext] = None
+        if children and isinstance(children[-1], RuleContext) and children[-1].symbol.id == tail.symbol.id:
+            next = children.pop()
+
+        if children:
+            if result.symbol.name is not None:
+                result.element_label = None
+                if next is not None:
+                    result.element_label = next.element_label
+                    next.element_label = None
+                result = RuleContext(result.symbol, [result], tail.label)
+            result.children.extend(children)
+
+        if next is None:
+            return result
+        tail = next
+
+
+# 创建 LL1Grammar 时使用的辅助函数, 将生成代码中的列表转换为字典
+def table_from(entries: Iterable[Tuple[int, int, int]]) -> Dict[int, Dict[int, int]]:
+    result: Dict[int, Dict[int, int]] = {}
+    for nonterminal, terminal, production in entries:
+        result.setdefault(nonterminal, {})[terminal] = production
+    return result
+
+
+def sync_from(entries: Iterable[Tuple[int, int]]) -> Dict[int, Set[int]]:
+    result: Dict[int, Set[int]] = {}
+    for nonterminal, terminal in entries:
+        result.setdefault(nonterminal, set()).add(terminal)
+    return result
+
+
+def terminals_from(terminals: Iterable[Terminal]) -> Dict[int, Terminal]:
+    return {terminal.id: terminal for terminal in terminals}
@@ -0,0 +1,42 @@
+from typing import List, Optional, Sequence, Union
+
+from .vocabulary import NonTerminal, Terminal
+
+
+# 产生式右部的元素, 终结符或者非终结符
+ProductionItem = Union[Terminal, NonTerminal]
+
+
+class Production:
+    def __init__(
+        self,
+        id: int,
+        left: NonTerminal,
+        right: Sequence[ProductionItem],
+        label: Optional[str] = None,
+        element_labels: Sequence[Optional[str]] = (),
+    ):
+        # 产生式的编号
+        self.id = id
+        # 产生式左部
+        self.left = left
+        self.right: List[ProductionItem] = list(right)
+        # 备选分支的标签, 如 expr: expr PLUS expr # Add;
+        self.label = label
+        # 右部每个元素的标签, 如 lhs=expr, 和 right 一一对应
+        self.element_labels: List[Optional[str]] = list(element_labels)
+
+    # 获取右部第 index 个元素的标签
+    def get_element_label(self, index: int) -> Optional[str]:
+        return self.element_labels[index] if index < len(self.element_labels) else None
+
+    def __str__(self) -> str:
+        result = f"{self.id}: {self.left} ->"
+        if not self.right:
+            return result + " ε"
+        for index, item in enumerate(self.right):
+            label = self.get_element_label(index)
+            result += f" {item}" if label is None else f" {label}={item}"
+        if self.label is not None:
+            result += f" # {self.label}"
+        return result
@@ -0,0 +1,48 @@
+from dataclasses import dataclass
+
+from .vocabulary import Terminal
+
+
+# 行号和列号都从 0 开始
+@dataclass(frozen=True)
+class Position:
+    line: int
+    char_position: int
+
+    def __str__(self) -> str:
+        return f"line: {self.line}, position: {self.char_position}"
+
+
+# 用于描述 token 或者 error 的位置, 左闭右开
+# 与 rust 运行时不同, 下标和列号都按照字符而不是字节计算, 下标可以直接用于切片
+@dataclass(frozen=True)
+class Location:
+    start: Position
+    stop: Position
+    index_start: int
+    index_stop: int
+
+
+@dataclass(frozen=True)
+class Token:
+    # 名称和类型
+    terminal: Terminal
+    # token 的文本
+    text: str
+    # token 所在的位置
+    location: Location
+    # token 在token序列中的序号(所有频道一起排号)
+    token_index: int
+    # token 所在的频道
+    channel: int
+
+    # 快速创建 start token
+    @staticmethod
+    def start(channel: int) -> "Token":
+        pos = Position(0, 0)
+        return Token(Terminal("_START", 0), "_START", Location(pos, pos, 0, 0), 0, channel)
+
+    def __str__(self) -> str:
+        location = self.location
+        return (f"[@{self.token_index}, {location.index_start}:{location.index_stop}='{self.text}', "
+                f"<{self.terminal.name}>, <{self.terminal.id}>, start: <{location.start}>, stop: <{location.stop}>]")
@@ -0,0 +1,86 @@
+from collections import deque
+from typing import Deque, Optional
+
+from .error import ChiruError
+from .lexer import Lexer
+from .token import Location, Token
+from .vocabulary import Terminal
+
+
+# 词法分析的时候，直接丢弃掉 skip 的 token, 并且只保留属于该频道的 token
+class TokenStream:
+    def __init__(self, lexer: Lexer, channel: int = 0):
+        # 词法分析器
+        self.iter = lexer.iter()
+        self.channel = channel
+
+        # 当前 token，初始化为 _START
+        # 我们认为 start 和 stop 永远都和当前 stream 一样。
+        self._next_token: Optional[Token] = Token.start(channel)
+        # 上一个 token
+        self._previous_token: Optional[Token] = None
+        # 预查看 token 的缓冲队列
+        self._cached_tokens: Deque[Token] = deque()
+
+    # 消耗掉 next_token, 并返回它
+    def consume(self) -> Token:
+        # previous_token 是 stop, next_token 是 None, 表示已经消耗了 stop
+        token = self._next_token
+        if token is None:
+            raise ChiruError.token_stream_out_of_range()
+
+        self._previous_token = token
+        if self._cached_tokens:
+            # 如果缓存中还有token
+            self._next_token = self._cached_tokens.popleft()
+        elif token.terminal.id == 1:
+            self._next_token = None
+        else:
+            self._next_token = self._scan_next_token()
+        return token
+
+    def look_ahead(self, n: int) -> Token:
+        if n <= 0:
+            # 至少向前看一个 token
+            raise ChiruError.unknown()
+        if n == 1:
+            return self.peek_next_token()
+
+        # 第 n 个 token 位于缓冲队列中的第 n - 2 个位置
+        while len(self._cached_tokens) < n - 1:
+            # 已经扫描到了 stop, 之后的 token 都认为是 stop
+            last = self._cached_tokens[-1] if self._cached_tokens else self._next_token
+            if last is not None and last.terminal.id == 1:
+                return last
+
+            self._cached_tokens.append(self._scan_next_token())
+        return self._cached_tokens[n - 2]
+
+    # 从词法分析器中获取下一个属于该频道的 token, 扫描到输入末尾时返回 stop
+    def _scan_next_token(self) -> Token:
+        while True:
+            token = self.iter.lexer_match()
+            if token is None:
+                position = self.iter.get_current_position()
+                location = Location(position, position, self.iter.cursor, self.iter.cursor)
+                # 添加 stop token
+                return Token(Terminal("_STOP", 1), "_STOP", location, self.iter.token_index, self.channel)
+            if token.channel == self.channel:
+                return token
+
+    def peek_next_token(self) -> Token:
+        if self._next_token is None:
+            raise ChiruError.token_stream_out_of_range()
+        return self._next_token
+
+    def peek_previous_token(self) -> Token:
+        if self._previous_token is None:
+            raise ChiruError.token_stream_out_of_range()
+        return self._previous_token
+
+    # 重置 TokenStream
+    def reset(self) -> None:
+        self._cached_tokens.clear()
+        self.iter.reset()
+        self._next_token = Token.start(self.channel)
+        self._previous_token = None
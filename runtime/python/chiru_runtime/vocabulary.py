@@ -0,0 +1,22 @@
+from dataclasses import dataclass
+from typing import Optional
+
+
+# 终结符, 名称和编号, 编号 0 和 1 分别为 _START 和 _STOP
+@dataclass(frozen=True)
+class Terminal:
+    name: str
+    id: int
+
+    def __str__(self) -> str:
+        return self.name
+
+
+# 非终结符, 消除左递归和提取左公因子引入的非终结符没有名称
+@dataclass(frozen=True)
+class NonTerminal:
+    name: Optional[str]
+    id: int
+
+    def __str__(self) -> str:
+        return self.name if self.name is not None else f"<{self.id}>"
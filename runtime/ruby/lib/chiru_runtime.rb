@@ -0,0 +1,12 @@
+# chiru 生成的 ruby 代码所使用的运行时, 与 rust 运行时的 chiru::runtime 相对应
+
+require_relative "chiru_runtime/vocabulary"
+require_relative "chiru_runtime/token"
+require_relative "chiru_runtime/error"
+require_relative "chiru_runtime/production"
+require_relative "chiru_runtime/error_listener"
+require_relative "chiru_runtime/lexer"
+require_relative "chiru_runtime/token_stream"
+require_relative "chiru_runtime/ast"
+require_relative "chiru_runtime/error_strategy"
+require_relative "chiru_runtime/ll1_analyzer"
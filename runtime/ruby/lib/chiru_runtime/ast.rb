@@ -0,0 +1,193 @@
+module ChiruRuntime
+  class TerminalContext
+    # element_label 为在产生式中的标签, 如 op=PLUS
+    attr_reader :symbol
+    attr_accessor :element_label
+
+    def initialize(symbol, element_label = nil)
+      @symbol = symbol
+      @element_label = element_label
+    end
+
+    def get_text
+      symbol.text
+    end
+
+    def to_s
+      symbol.terminal.name
+    end
+
+    def to_h
+      { token_name: symbol.terminal.name, token_type: symbol.terminal.id, text: symbol.text }
+    end
+  end
+
+  # 错误节点, kind 为 :redundant(多余的 token), :mistake(出现在期望的符号位置上的错误 token) 或者 :missing(缺失的符号)
+  class ErrorContext
+    # token 为出错的 token, 缺失的符号没有 token
+    # expected 为出错时期望的终结符集合, message 为可读的错误信息, 如 missing SEMI at '}'
+    # location 为错误的位置, 缺失的符号位于下一个 token 的起始位置, 长度为 0
+    attr_reader :kind, :token, :expected, :message, :location
+
+    def initialize(kind, token, expected, message, location)
+      @kind = kind
+      @token = token
+      @expected = expected
+      @message = message
+      @location = location
+    end
+
+    def get_text
+      token.nil? ? "<missing>" : token.text
+    end
+
+    # 多余的 token, 会被丢弃
+    def self.redundant(symbol, expected)
+      new(:redundant, symbol, expected,
+          "extraneous input '#{symbol.text}' expecting #{ChiruRuntime.format_expected(expected)}", symbol.location)
+    end
+
+    # 出现在期望的符号位置上的错误 token
+    def self.mistake(symbol, expected)
+      new(:mistake, symbol, expected,
+          "mismatched input '#{symbol.text}' expecting #{ChiruRuntime.format_expected(expected)}", symbol.location)
+    end
+
+    # 在 next_token 之前缺失了期望的符号
+    def self.missing(next_token, expected)
+      start = next_token.location.start
+      index = next_token.location.index_start
+      new(:missing, nil, expected,
+          "missing #{ChiruRuntime.format_expected(expected)} at '#{next_token.text}'", Location.new(start, start, index, index))
+    end
+
+    # 输出位置和错误信息, 行号和列号从 1 开始, 如 <3:14 missing SEMI at '}'>
+    def to_s
+      "<#{location.start.line + 1}:#{location.start.char_position + 1} #{message}>"
+    end
+
+    def to_h
+      result = { error_type: kind.to_s, message: message, expected: expected.map(&:name) }
+      unless token.nil?
+        result.merge!(token_name: token.terminal.name, token_type: token.terminal.id, text: token.text)
+      end
+      result
+    end
+  end
+
+  class RuleContext
+    # symbol 为非终结符 rule, label 为匹配到的备选分支的标签, 没有标签时为 nil
+    # element_label 为在父节点的产生式中的标签, 如 lhs=expr
+    attr_reader :symbol
+    attr_accessor :children, :label, :element_label
+
+    def initialize(symbol, children = [], label = nil, element_label = nil)
+      @symbol = symbol
+      @children = children
+      @label = label
+      @element_label = element_label
+    end
+
+    def get_children
+      children
+    end
+
+    def get_child_count
+      children.length
+    end
+
+    def get_rule_index
+      symbol.id
+    end
+
+    def get_label
+      label
+    end
+
+    def get_first_terminal
+      first = children.first
+      return first if first.is_a?(TerminalContext)
+      return first.get_first_terminal if first.is_a?(RuleContext)
+
+      nil
+    end
+
+    def get_last_terminal
+      last = children.last
+      return last if last.is_a?(TerminalContext)
+      return last.get_last_terminal if last.is_a?(RuleContext)
+
+      nil
+    end
+
+    def get_start_token
+      get_first_terminal&.symbol
+    end
+
+    def get_stop_token
+      get_last_terminal&.symbol
+    end
+
+    def get_terminal(token_type, i)
+      get_terminals(token_type)[i]
+    end
+
+    def get_terminals(token_type)
+      children.select { |child| child.is_a?(TerminalContext) && child.symbol.terminal.id == token_type }
+    end
+
+    def get_error_node(i)
+      get_error_nodes[i]
+    end
+
+    def get_error_nodes
+      children.select { |child| child.is_a?(ErrorContext) }
+    end
+
+    def get_rule_context(rule_type, i)
+      get_rule_contexts(rule_type)[i]
+    end
+
+    def get_rule_contexts(rule_type)
+      children.select { |child| child.is_a?(RuleContext) && child.get_rule_index == rule_type }
+    end
+
+    def get_labeled_rule_context(label, i)
+      get_labeled_rule_contexts(label)[i]
+    end
+
+    def get_labeled_rule_contexts(label)
+      children.select { |child| child.is_a?(RuleContext) && child.element_label == label }
+    end
+
+    def get_labeled_terminal(label, i)
+      get_labeled_terminals(label)[i]
+    end
+
+    def get_labeled_terminals(label)
+      children.select { |child| child.is_a?(TerminalContext) && child.element_label == label }
+    end
+
+    def to_s
+      name = symbol.name.nil? ? symbol.id : symbol.name
+      "(#{name}" + children.map { |child| " #{child}" }.join + ")"
+    end
+
+    def to_h
+      { rule_index: symbol.id, rule_name: symbol.name, children: children.map(&:to_h) }
+    end
+  end
+
+  # 设置节点在产生式中的标签, 已经有标签的节点保持不变, 错误节点没有标签
+  def self.set_element_label(ctx, label)
+    return if ctx.is_a?(ErrorContext)
+
+    ctx.element_label ||= label
+  end
+
+  # 自底向上地将分析得到的语法树转换为生成的 context 类型, factory 根据规则编号和标签创建对应的 context
+  def self.build_contexts(ctx, factory)
+    children = ctx.children.map { |child| child.is_a?(RuleContext) ? build_contexts(child, factory) : child }
+    factory.call(RuleContext.new(ctx.symbol, children, ctx.label, ctx.element_label))
+  end
+end
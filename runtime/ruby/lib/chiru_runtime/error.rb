@@ -0,0 +1,26 @@
+module ChiruRuntime
+  # 运行时产生的错误, 与 rust 运行时的 runtime::error::Error 相对应
+  # kind 为 :token_stream_out_of_range(look_ahead 超出了范围), :syntax_error(不进行错误恢复时抛出) 或者 :unknown
+  class ChiruError < StandardError
+    attr_reader :kind, :location
+
+    def initialize(kind, message, location = nil)
+      super(message)
+      @kind = kind
+      # 错误在输入中的位置, 没有位置信息时为 nil
+      @location = location
+    end
+
+    def self.token_stream_out_of_range
+      new(:token_stream_out_of_range, "token stream out of range")
+    end
+
+    def self.syntax_error(location, message)
+      new(:syntax_error, "syntax error: #{message}", location)
+    end
+
+    def self.unknown
+      new(:unknown, "unknown error")
+    end
+  end
+end
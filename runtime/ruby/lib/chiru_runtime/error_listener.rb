@@ -0,0 +1,53 @@
+module ChiruRuntime
+  class ErrorListener
+    # 语法分析时发现错误, 每产生一个 redundant 或 missing 错误节点都会调用一次
+    # rule 为出错时正在分析的非终结符, offending_token 为出错位置的 token,
+    # location 为错误的位置, expected 为此时期望的终结符集合, message 为可读的错误信息
+    def syntax_error(rule, offending_token, location, expected, message); end
+
+    # 词法分析时遇到无法匹配任何规则的字符, 该字符会被跳过
+    def lexer_error(text, location); end
+  end
+
+  # 将期望的终结符集合格式化为 {A, B} 的形式, 只有一个终结符时不加括号
+  def self.format_expected(expected)
+    names = expected.map(&:name)
+    names.length == 1 ? names[0] : "{#{names.join(', ')}}"
+  end
+
+  # 终结符集合按照名称排序, 与 rust 运行时中 BTreeSet<Terminal> 的顺序一致
+  def self.sort_terminals(terminals)
+    terminals.uniq(&:id).sort_by { |terminal| [terminal.name, terminal.id] }
+  end
+
+  # 通知所有监听器产生了一个错误节点, token 为出错位置的 token
+  def self.report_error(listeners, rule, token, error)
+    listeners.each do |listener|
+      listener.syntax_error(rule, token, error.location, error.expected, error.message)
+    end
+  end
+
+  # 在标准错误中以 line:col 的形式输出错误, 行号和列号从 1 开始
+  class ConsoleErrorListener < ErrorListener
+    def syntax_error(_rule, _offending_token, location, _expected, message)
+      warn "#{location.start.line + 1}:#{location.start.char_position + 1} #{message}"
+    end
+
+    def lexer_error(text, location)
+      warn "#{location.start.line + 1}:#{location.start.char_position + 1} token recognition error at: #{text.inspect}"
+    end
+  end
+
+  # 输出更详细的信息, 包括出错的非终结符、token 和期望的终结符集合
+  class DiagnosticErrorListener < ErrorListener
+    def syntax_error(rule, offending_token, location, expected, message)
+      warn "#{location.start.line + 1}:#{location.start.char_position + 1} #{message} " \
+           "(rule: #{rule}, token: #{offending_token}, expected: #{ChiruRuntime.format_expected(expected)})"
+    end
+
+    def lexer_error(text, location)
+      warn "#{location.start.line + 1}:#{location.start.char_position + 1} token recognition error at: #{text.inspect} " \
+           "(index: #{location.index_start}..#{location.index_stop})"
+    end
+  end
+end
@@ -0,0 +1,159 @@
+module ChiruRuntime
+  # 错误处理策略在恢复时可以使用的 ll1 分析器的信息
+  class Recognizer
+    # rule 为出错时正在分析的非终结符, tables 为生成的 ll1 分析器提供的 LL1Grammar
+    attr_reader :rule, :tables, :error_listeners
+
+    def initialize(rule, tables, error_listeners)
+      @rule = rule
+      @tables = tables
+      @error_listeners = error_listeners
+    end
+
+    # 预测分析表中 (nonterminal, terminal) 对应的产生式编号
+    def predict(nonterminal, terminal)
+      tables.table.fetch(nonterminal.id, {})[terminal.id]
+    end
+
+    def sync?(terminal)
+      tables.sync.fetch(rule.id, []).include?(terminal.id)
+    end
+
+    # 在分析当前非终结符时能够接受的所有终结符
+    def expected_terminals
+      ids = tables.table.fetch(rule.id, {}).keys
+      ChiruRuntime.sort_terminals(ids.map { |id| tables.terminals.fetch(id) { Terminal.new("<#{id}>", id) } })
+    end
+
+    # 通知所有监听器, 并将错误节点添加到 errors 中
+    def report(token, error, errors)
+      ChiruRuntime.report_error(error_listeners, rule, token, error)
+      errors << error
+    end
+  end
+
+  # ll1 分析出错时的处理策略, 恢复过程中产生的错误节点都添加到 errors 中
+  class ErrorStrategy
+    # 预测分析表中找不到 (rule, 下一个 token) 对应的产生式时调用
+    # 返回用于展开 rule 的产生式编号, 返回 nil 表示整个 rule 都缺失了
+    def recover(recognizer, token_stream, errors)
+      raise NotImplementedError
+    end
+
+    # 期望终结符 expected, 但是下一个 token 与之不匹配时调用, rest 为产生式中位于 expected 之后的部分
+    # 返回 true 表示恢复之后下一个 token 就是 expected, 返回 false 表示 expected 缺失了或者被错误的 token 代替了
+    def recover_inline(recognizer, expected, rest, token_stream, errors)
+      raise NotImplementedError
+    end
+  end
+
+  # 遇到第一个错误就抛出 ChiruError, 不进行任何恢复
+  class BailErrorStrategy < ErrorStrategy
+    def recover(recognizer, token_stream, _errors)
+      token = token_stream.peek_next_token
+      error = ErrorContext.mistake(token, recognizer.expected_terminals)
+      ChiruRuntime.report_error(recognizer.error_listeners, recognizer.rule, token, error)
+      raise ChiruError.syntax_error(error.location, error.message)
+    end
+
+    def recover_inline(recognizer, expected, _rest, token_stream, _errors)
+      token = token_stream.peek_next_token
+      error = ErrorContext.mistake(token, [expected])
+      ChiruRuntime.report_error(recognizer.error_listeners, recognizer.rule, token, error)
+      raise ChiruError.syntax_error(error.location, error.message)
+    end
+  end
+
+  # 恐慌模式, 一直丢弃 token, 直到遇到能够接受的 token 或者同步集合中的 token 为止
+  class DefaultErrorStrategy < ErrorStrategy
+    def recover(recognizer, token_stream, errors)
+      expected = recognizer.expected_terminals
+      loop do
+        token = token_stream.peek_next_token
+        production_id = recognizer.predict(recognizer.rule, token.terminal)
+        return production_id unless production_id.nil?
+
+        if recognizer.sync?(token.terminal) || token.terminal.id == 1
+          # 同步 这里表示整个非终结符都缺失了
+          recognizer.report(token, ErrorContext.missing(token, expected), errors)
+          return nil
+        end
+
+        # 丢弃，将其添加到 error node, 这里认为该 token 是多余的
+        recognizer.report(token, ErrorContext.redundant(token, expected), errors)
+        token_stream.consume
+      end
+    end
+
+    def recover_inline(recognizer, expected, _rest, token_stream, errors)
+      loop do
+        token = token_stream.peek_next_token
+        return true if token.terminal.id == expected.id
+
+        if token.terminal.id == 1
+          recognizer.report(token, ErrorContext.missing(token, [expected]), errors)
+          return false
+        end
+
+        recognizer.report(token, ErrorContext.redundant(token, [expected]), errors)
+        token_stream.consume
+      end
+    end
+  end
+
+  # 先尝试删除一个多余的 token、插入一个缺失的终结符或者替换一个错误的 token, 都不行时再使用恐慌模式
+  # 生成的 ll1 分析器默认使用该策略
+  class SingleTokenErrorStrategy < ErrorStrategy
+    def recover(recognizer, token_stream, errors)
+      token = token_stream.peek_next_token
+
+      # 删除下一个 token 之后就能够选择产生式
+      production_id = recognizer.predict(recognizer.rule, token_stream.look_ahead(2).terminal)
+      unless production_id.nil?
+        recognizer.report(token, ErrorContext.redundant(token, recognizer.expected_terminals), errors)
+        token_stream.consume
+        return production_id
+      end
+
+      DefaultErrorStrategy.new.recover(recognizer, token_stream, errors)
+    end
+
+    def recover_inline(recognizer, expected, rest, token_stream, errors)
+      token = token_stream.peek_next_token
+
+      # 单个 token 删除: 下一个 token 是多余的
+      if token.terminal.id != 1 && token_stream.look_ahead(2).terminal.id == expected.id
+        recognizer.report(token, ErrorContext.redundant(token, [expected]), errors)
+        token_stream.consume
+        return true
+      end
+
+      # 单个 token 插入: 缺少了 expected
+      if can_follow?(recognizer, rest, token.terminal)
+        recognizer.report(token, ErrorContext.missing(token, [expected]), errors)
+        return false
+      end
+
+      # 单个 token 替换: 下一个 token 出现在了 expected 的位置上
+      if token.terminal.id != 1 && can_follow?(recognizer, rest, token_stream.look_ahead(2).terminal)
+        recognizer.report(token, ErrorContext.mistake(token, [expected]), errors)
+        token_stream.consume
+        return false
+      end
+
+      DefaultErrorStrategy.new.recover_inline(recognizer, expected, rest, token_stream, errors)
+    end
+
+    private
+
+    # 如果 expected 缺失, 下一个终结符是否能够出现在 expected 之后
+    def can_follow?(recognizer, rest, terminal)
+      return recognizer.sync?(terminal) if rest.empty?
+
+      following = rest.first
+      return following.id == terminal.id if following.is_a?(Terminal)
+
+      !recognizer.predict(following, terminal).nil?
+    end
+  end
+end
@@ -0,0 +1,163 @@
+module ChiruRuntime
+  # 词法模式的切换命令, 对应 @push_mode(X) @pop_mode @mode(X)
+  # kind 为 :push(将模式压入模式栈), :pop(弹出栈顶的模式) 或者 :set(将栈顶的模式替换为指定的模式)
+  ModeAction = Struct.new(:kind, :mode)
+
+  # 生成的词法分析器中的每一条规则, 按照优先级排序
+  # mode 为该规则所属的词法模式, 默认模式为 0, mode_action 为匹配之后对模式栈进行的操作
+  LexerRule = Struct.new(:rule, :token_type, :token_name, :channel, :skip, :mode, :mode_action, keyword_init: true)
+
+  # lexer 不识别 start 和 stop，所有 start 和 stop 都交给 TokenStream 来添加
+  class Lexer
+    include Enumerable
+
+    attr_reader :input, :rules
+    attr_accessor :error_listeners
+
+    def initialize(input, rules)
+      @input = input
+      @rules = rules
+      @error_listeners = [ConsoleErrorListener.new]
+      # 使用 \G 将匹配锚定在当前位置
+      @anchored_rules = rules.map { |rule| Regexp.new("\\G(?:#{rule.rule.source})", rule.rule.options) }
+    end
+
+    def remove_all_error_listeners
+      @error_listeners = []
+    end
+
+    def add_error_listener(listener)
+      @error_listeners << listener
+    end
+
+    def iter
+      TokenIter.new(input, rules, @anchored_rules, error_listeners)
+    end
+
+    def each(&block)
+      iter.each(&block)
+    end
+
+    def get_all_tokens
+      iter.to_a
+    end
+
+    def get_all_on_channel_tokens(channel)
+      get_all_tokens.select { |token| token.channel == channel }
+    end
+  end
+
+  # 这里不管 start 和 stop，需要 TokenStream 自己处理
+  class TokenIter
+    include Enumerable
+
+    # cursor 为字符游标，当前处理到的文本字符下标
+    # token_index 为 token 序号，表示当前扫描到了第几个 token
+    # modes 为模式栈, 只有栈顶模式中的规则参与匹配
+    attr_reader :input, :cursor, :token_index, :modes
+
+    def initialize(input, rules, anchored_rules, error_listeners)
+      @input = input
+      @rules = rules
+      @anchored_rules = anchored_rules
+      @error_listeners = error_listeners
+
+      # 每一行起始字符的下标, 用于判断字符所在的 position
+      @line_starts = [0]
+      input.each_char.with_index { |char, index| @line_starts << index + 1 if char == "\n" }
+      reset
+    end
+
+    def get_current_position
+      get_position(cursor)
+    end
+
+    def get_current_mode
+      modes.last || 0
+    end
+
+    def reset
+      @cursor = 0
+      @token_index = 1
+      @modes = [0]
+    end
+
+    # 这个函数只管匹配，不会识别到 start 和 stop。扫描到输入末尾时返回 nil
+    # 匹配不上的字符会报告给错误监听器, 然后跳过该字符继续匹配
+    def lexer_match
+      loop do
+        return nil if cursor >= input.length
+
+        matched = longest_match
+        if matched.nil?
+          skip_unmatched_char
+          next
+        end
+        index, stop = matched
+        start = cursor
+        meta = @rules[index]
+
+        location = Location.new(get_position(start), get_position(stop), start, stop)
+        token = Token.new(Terminal.new(meta.token_name, meta.token_type), input[start...stop], location, token_index, meta.channel)
+
+        @cursor = stop
+
+        # 切换词法模式, 默认模式不会被弹出
+        action = meta.mode_action
+        case action&.kind
+        when :push then modes.push(action.mode)
+        when :pop then modes.pop if modes.length > 1
+        when :set then modes[-1] = action.mode
+        end
+
+        # 如果需要跳过，则继续匹配下一个
+        next if meta.skip
+
+        @token_index += 1
+        return token
+      end
+    end
+
+    def each
+      return enum_for(:each) unless block_given?
+
+      while (token = lexer_match)
+        yield token
+      end
+    end
+
+    private
+
+    def get_position(index)
+      line = (@line_starts.bsearch_index { |start| start > index } || @line_starts.length) - 1
+      Position.new(line, index - @line_starts[line])
+    end
+
+    # 在当前位置找到最长的匹配, 长度相同时选择优先级高的规则, 返回规则的下标和匹配的结束位置
+    def longest_match
+      mode = get_current_mode
+      result = nil
+      @rules.each_with_index do |rule, index|
+        next if rule.mode != mode
+
+        matched = @anchored_rules[index].match(input, cursor)
+        next if matched.nil?
+
+        # 规则按照优先级从高到低排列, 只有更长的匹配才会替换之前的结果
+        stop = matched.end(0)
+        result = [index, stop] if stop > cursor && (result.nil? || stop > result[1])
+      end
+      result
+    end
+
+    # 跳过 cursor 处的一个字符, 并将其位置报告给错误监听器
+    def skip_unmatched_char
+      start = cursor
+      stop = start + 1
+      location = Location.new(get_position(start), get_position(stop), start, stop)
+
+      @error_listeners.each { |listener| listener.lexer_error(input[start...stop], location) }
+      @cursor = stop
+    end
+  end
+end
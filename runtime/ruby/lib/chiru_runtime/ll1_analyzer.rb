@@ -0,0 +1,127 @@
+module ChiruRuntime
+  # 生成的 ll1 分析器提供的全部信息
+  # terminals: 所有的终结符, 终结符编号 -> Terminal, 用于获取期望的终结符的名称
+  # table: 非终结符编号 -> 终结符编号 -> 产生式编号
+  # sync: 同步集合, 非终结符编号 -> 终结符编号的集合
+  # productions: 产生式编号 -> Production
+  # left_recursion_tails: 消除左递归时引入的匿名非终结符编号 -> 所属非终结符编号
+  LL1Grammar = Struct.new(:terminals, :table, :sync, :productions, :left_recursion_tails, keyword_init: true)
+
+  # 从 rule_symbol 开始分析, 分析完毕之后必须到达输入末尾
+  def self.ll1_analyze(token_stream, rule_symbol, grammar, error_listeners, error_strategy)
+    result = analyze_rule(token_stream, rule_symbol, grammar, error_listeners, error_strategy)
+
+    # 剩余的 token 交给错误处理策略, 期望的是 _STOP
+    # 开始符号之后只能是 _STOP, 因此不使用同步集合, 错误处理策略会一直丢弃 token 直到输入末尾
+    if token_stream.peek_next_token.terminal.id != 1
+      tables = grammar.dup.tap { |copy| copy.sync = {} }
+      recognizer = Recognizer.new(rule_symbol, tables, error_listeners)
+      error_strategy.recover_inline(recognizer, Terminal.new("_STOP", 1), [], token_stream, result.children)
+    end
+    result
+  end
+
+  def self.analyze_rule(token_stream, rule_symbol, grammar, error_listeners, error_strategy)
+    result = RuleContext.new(rule_symbol)
+    recognizer = Recognizer.new(rule_symbol, grammar, error_listeners)
+
+    # 获取 production_id, 找不到时交给错误处理策略
+    production_id = recognizer.predict(rule_symbol, token_stream.peek_next_token.terminal)
+    if production_id.nil?
+      production_id = error_strategy.recover(recognizer, token_stream, result.children)
+      # 这里表示整个非终结符都缺失了
+      return result if production_id.nil?
+    end
+
+    production = grammar.productions.fetch(production_id)
+    result.label = production.label
+
+    production.right.each_with_index do |child, index|
+      element_label = production.get_element_label(index)
+
+      if child.is_a?(Terminal)
+        if token_stream.peek_next_token.terminal.id != child.id &&
+           !error_strategy.recover_inline(recognizer, child, production.right[(index + 1)..], token_stream, result.children)
+          # 缺失或者被替换的符号已经由错误处理策略记录, 到达输入末尾时不再继续匹配
+          break if token_stream.peek_next_token.terminal.id == 1
+
+          next
+        end
+
+        # 匹配了, 消耗掉
+        result.children << TerminalContext.new(token_stream.consume, element_label)
+      else
+        t = analyze_rule(token_stream, child, grammar, error_listeners, error_strategy)
+        # 消除左递归引入的非终结符先保留嵌套, 等到其所属的非终结符分析完毕之后再折叠
+        if !child.name.nil? || grammar.left_recursion_tails.key?(child.id)
+          t.element_label = element_label
+          result.children << t
+        else
+          # 提取左公因子之后, 标签记录在匿名非终结符的产生式上
+          result.label ||= t.label
+          t.children.each do |grandchild|
+            set_element_label(grandchild, element_label)
+            result.children << grandchild
+          end
+        end
+      end
+    end
+
+    # 将属于当前非终结符的左递归尾部折叠为左结合的语法树
+    last = result.children.last
+    if last.is_a?(RuleContext) && grammar.left_recursion_tails[last.symbol.id] == rule_symbol.id
+      result.children.pop
+      result = fold_left_recursion(result, last)
+    end
+    result
+  end
+
+  # 将消除左递归时引入的非终结符展开的结果折叠起来, A' 的每一次非空展开都会将之前的结果作为新的 A 节点的第一个孩子
+  # 原产生式中左递归位置的标签记录在末尾的 A' 上, 折叠时转交给之前的结果
+  def self.fold_left_recursion(result, tail)
+    loop do
+      children = tail.children.dup
+      following = nil
+      if children.last.is_a?(RuleContext) && children.last.symbol.id == tail.symbol.id
+        following = children.pop
+      end
+
+      unless children.empty?
+        unless result.symbol.name.nil?
+          result.element_label = nil
+          unless following.nil?
+            result.element_label = following.element_label
+            following.element_label = nil
+          end
+          result = RuleContext.new(result.symbol, [result], tail.label)
+        end
+        result.children.concat(children)
+      end
+
+      return result if following.nil?
+
+      tail = following
+    end
+  end
+
+  # 创建 LL1Grammar 时使用的辅助函数, 将生成代码中的数组转换为 Hash
+  def self.table_from(entries)
+    result = {}
+    entries.each do |nonterminal, terminal, production|
+      (result[nonterminal] ||= {})[terminal] = production
+    end
+    result
+  end
+
+  def self.sync_from(entries)
+    result = {}
+    entries.each do |nonterminal, terminal|
+      (result[nonterminal] ||= []) << terminal
+    end
+    result
+  end
+
+  def self.terminals_from(terminals)
+    terminals.to_h { |terminal| [terminal.id, terminal] }
+  end
+end
@@ -0,0 +1,33 @@
+module ChiruRuntime
+  # 产生式, 右部的元素为终结符或者非终结符
+  class Production
+    # label 为备选分支的标签, 如 expr: expr PLUS expr # Add;
+    # element_labels 为右部每个元素的标签, 如 lhs=expr, 和 right 一一对应
+    attr_reader :id, :left, :right, :label, :element_labels
+
+    def initialize(id, left, right, label = nil, element_labels = [])
+      @id = id
+      @left = left
+      @right = right
+      @label = label
+      @element_labels = element_labels
+    end
+
+    # 获取右部第 index 个元素的标签
+    def get_element_label(index)
+      element_labels[index]
+    end
+
+    def to_s
+      result = "#{id}: #{left} ->"
+      return "#{result} ε" if right.empty?
+
+      right.each_with_index do |item, index|
+        label = get_element_label(index)
+        result += label.nil? ? " #{item}" : " #{label}=#{item}"
+      end
+      result += " # #{self.label}" unless self.label.nil?
+      result
+    end
+  end
+end
@@ -0,0 +1,26 @@
+module ChiruRuntime
+  # 行号和列号都从 0 开始
+  Position = Struct.new(:line, :char_position) do
+    def to_s
+      "line: #{line}, position: #{char_position}"
+    end
+  end
+
+  # 用于描述 token 或者 error 的位置, 左闭右开
+  # 与 rust 运行时不同, 下标和列号都按照字符而不是字节计算, 下标可以直接用于 String#[]
+  Location = Struct.new(:start, :stop, :index_start, :index_stop)
+
+  # terminal 为名称和类型, token_index 为 token 在token序列中的序号(所有频道一起排号)
+  Token = Struct.new(:terminal, :text, :location, :token_index, :channel) do
+    # 快速创建 start token
+    def self.start(channel)
+      pos = Position.new(0, 0)
+      new(Terminal.new("_START", 0), "_START", Location.new(pos, pos, 0, 0), 0, channel)
+    end
+
+    def to_s
+      "[@#{token_index}, #{location.index_start}:#{location.index_stop}='#{text}', " \
+        "<#{terminal.name}>, <#{terminal.id}>, start: <#{location.start}>, stop: <#{location.stop}>]"
+    end
+  end
+end
@@ -0,0 +1,91 @@
+module ChiruRuntime
+  # 词法分析的时候，直接丢弃掉 skip 的 token, 并且只保留属于该频道的 token
+  class TokenStream
+    # iter 为词法分析器
+    attr_reader :iter, :channel
+
+    def initialize(lexer, channel = 0)
+      @iter = lexer.iter
+      @channel = channel
+      reset_tokens
+    end
+
+    # 消耗掉 next_token, 并返回它
+    def consume
+      # previous_token 是 stop, next_token 是 nil, 表示已经消耗了 stop
+      token = @next_token
+      raise ChiruError.token_stream_out_of_range if token.nil?
+
+      @previous_token = token
+      @next_token = if !@cached_tokens.empty?
+                      # 如果缓存中还有token
+                      @cached_tokens.shift
+                    elsif token.terminal.id == 1
+                      nil
+                    else
+                      scan_next_token
+                    end
+      token
+    end
+
+    def look_ahead(n)
+      # 至少向前看一个 token
+      raise ChiruError.unknown if n <= 0
+      return peek_next_token if n == 1
+
+      # 第 n 个 token 位于缓冲队列中的第 n - 2 个位置
+      while @cached_tokens.length < n - 1
+        # 已经扫描到了 stop, 之后的 token 都认为是 stop
+        last = @cached_tokens.last || @next_token
+        return last if !last.nil? && last.terminal.id == 1
+
+        @cached_tokens << scan_next_token
+      end
+      @cached_tokens[n - 2]
+    end
+
+    def peek_next_token
+      raise ChiruError.token_stream_out_of_range if @next_token.nil?
+
+      @next_token
+    end
+
+    def peek_previous_token
+      raise ChiruError.token_stream_out_of_range if @previous_token.nil?
+
+      @previous_token
+    end
+
+    # 重置 TokenStream
+    def reset
+      iter.reset
+      reset_tokens
+    end
+
+    private
+
+    def reset_tokens
+      # 当前 token，初始化为 _START
+      # 我们认为 start 和 stop 永远都和当前 stream 一样。
+      @next_token = Token.start(channel)
+      # 上一个 token
+      @previous_token = nil
+      # 预查看 token 的缓冲队列
+      @cached_tokens = []
+    end
+
+    # 从词法分析器中获取下一个属于该频道的 token, 扫描到输入末尾时返回 stop
+    def scan_next_token
+      loop do
+        token = iter.lexer_match
+        if token.nil?
+          position = iter.get_current_position
+          location = Location.new(position, position, iter.cursor, iter.cursor)
+          # 添加 stop token
+          return Token.new(Terminal.new("_STOP", 1), "_STOP", location, iter.token_index, channel)
+        end
+        return token if token.channel == channel
+      end
+    end
+  end
+end
@@ -0,0 +1,15 @@
+module ChiruRuntime
+  # 终结符, 名称和编号, 编号 0 和 1 分别为 _START 和 _STOP
+  Terminal = Struct.new(:name, :id) do
+    def to_s
+      name
+    end
+  end
+
+  # 非终结符, 消除左递归和提取左公因子引入的非终结符没有名称
+  NonTerminal = Struct.new(:name, :id) do
+    def to_s
+      name.nil? ? "<#{id}>" : name
+    end
+  end
+end
@@ -0,0 +1,198 @@
+import { formatExpected } from "./error_listener";
+import { Location, Token } from "./token";
+import { NonTerminal, Terminal } from "./vocabulary";
+
+
+// 语法树中的节点
+export type AstContext = TerminalContext | RuleContext | ErrorContext;
+
+
+export class TerminalContext {
+  constructor(
+    public readonly symbol: Token,
+    // 在产生式中的标签, 如 op=PLUS
+    public elementLabel: string | null = null,
+  ) {}
+
+  getText(): string {
+    return this.symbol.text;
+  }
+
+  toString(): string {
+    return this.symbol.terminal.name;
+  }
+
+  toJSON(): object {
+    return { token_name: this.symbol.terminal.name, token_type: this.symbol.terminal.id, text: this.symbol.text };
+  }
+}
+
+
+// 错误节点的种类, 多余的 token、出现在期望的符号位置上的错误 token 以及缺失的符号
+export type ErrorSymbol =
+  | { kind: "redundant"; token: Token }
+  | { kind: "mistake"; token: Token }
+  | { kind: "missing" };
+
+
+export class ErrorContext {
+  constructor(
+    public readonly symbol: ErrorSymbol,
+    // 出错时期望的终结符集合
+    public readonly expected: Terminal[],
+    // 可读的错误信息, 如 missing SEMI at '}'
+    public readonly message: string,
+    // 错误的位置, 缺失的符号位于下一个 token 的起始位置, 长度为 0
+    public readonly location: Location,
+  ) {}
+
+  getText(): string {
+    return this.symbol.kind === "missing" ? "<missing>" : this.symbol.token.text;
+  }
+
+  // 多余的 token, 会被丢弃
+  static redundant(symbol: Token, expected: Terminal[]): ErrorContext {
+    return new ErrorContext({ kind: "redundant", token: symbol }, expected,
+      `extraneous input '${symbol.text}' expecting ${formatExpected(expected)}`, symbol.location);
+  }
+
+  // 出现在期望的符号位置上的错误 token
+  static mistake(symbol: Token, expected: Terminal[]): ErrorContext {
+    return new ErrorContext({ kind: "mistake", token: symbol }, expected,
+      `mismatched input '${symbol.text}' expecting ${formatExpected(expected)}`, symbol.location);
+  }
+
+  // 在 next 之前缺失了期望的符号
+  static missing(next: Token, expected: Terminal[]): ErrorContext {
+    const start = next.location.start;
+    const index = next.location.indexStart;
+    return new ErrorContext({ kind: "missing" }, expected,
+      `missing ${formatExpected(expected)} at '${next.text}'`, new Location(start, start, index, index));
+  }
+
+  // 输出位置和错误信息, 行号和列号从 1 开始, 如 <3:14 missing SEMI at '}'>
+  toString(): string {
+    return `<${this.location.start.line + 1}:${this.location.start.charPosition + 1} ${this.message}>`;
+  }
+
+  toJSON(): object {
+    const expected = this.expected.map((terminal) => terminal.name);
+    if (this.symbol.kind === "missing") {
+      return { error_type: "missing", message: this.message, expected, location: this.location };
+    }
+    const token = this.symbol.token;
+    return {
+      token_name: token.terminal.name, token_type: token.terminal.id, text: token.text,
+      error_type: this.symbol.kind, message: this.message, expected, location: this.location,
+    };
+  }
+}
+
+
+export class RuleContext {
+  constructor(
+    // 非终结符 rule
+    public readonly symbol: NonTerminal,
+    public children: AstContext[] = [],
+    // 匹配到的备选分支的标签, 没有标签时为 null
+    public label: string | null = null,
+    // 在父节点的产生式中的标签, 如 lhs=expr
+    public elementLabel: string | null = null,
+  ) {}
+
+  getChildren(): AstContext[] { return this.children; }
+
+  getChildCount(): number { return this.children.length; }
+
+  getRuleIndex(): number { return this.symbol.id; }
+
+  getLabel(): string | null { return this.label; }
+
+  getFirstTerminal(): TerminalContext | null {
+    const first = this.children[0];
+    if (first instanceof TerminalContext) { return first; }
+    if (first instanceof RuleContext) { return first.getFirstTerminal(); }
+    return null;
+  }
+
+  getLastTerminal(): TerminalContext | null {
+    const last = this.children[this.children.length - 1];
+    if (last instanceof TerminalContext) { return last; }
+    if (last instanceof RuleContext) { return last.getLastTerminal(); }
+    return null;
+  }
+
+  getStartToken(): Token | null {
+    return this.getFirstTerminal()?.symbol ?? null;
+  }
+
+  getStopToken(): Token | null {
+    return this.getLastTerminal()?.symbol ?? null;
+  }
+
+  getTerminal(tokenType: number, i: number): TerminalContext | null {
+    return this.getTerminals(tokenType)[i] ?? null;
+  }
+
+  getTerminals(tokenType: number): TerminalContext[] {
+    return this.children.filter((child): child is TerminalContext =>
+      child instanceof TerminalContext && child.symbol.terminal.id === tokenType);
+  }
+
+  getErrorNode(i: number): ErrorContext | null {
+    return this.getErrorNodes()[i] ?? null;
+  }
+
+  getErrorNodes(): ErrorContext[] {
+    return this.children.filter((child): child is ErrorContext => child instanceof ErrorContext);
+  }
+
+  getRuleContext(ruleType: number, i: number): RuleContext | null {
+    return this.getRuleContexts(ruleType)[i] ?? null;
+  }
+
+  getRuleContexts(ruleType: number): RuleContext[] {
+    return this.children.filter((child): child is RuleContext =>
+      child instanceof RuleContext && child.getRuleIndex() === ruleType);
+  }
+
+  getLabeledRuleContext(label: string, i: number): RuleContext | null {
+    return this.getLabeledRuleContexts(label)[i] ?? null;
+  }
+
+  getLabeledRuleContexts(label: string): RuleContext[] {
+    return this.children.filter((child): child is RuleContext =>
+      child instanceof RuleContext && child.elementLabel === label);
+  }
+
+  getLabeledTerminal(label: string, i: number): TerminalContext | null {
+    return this.getLabeledTerminals(label)[i] ?? null;
+  }
+
+  getLabeledTerminals(label: string): TerminalContext[] {
+    return this.children.filter((child): child is TerminalContext =>
+      child instanceof TerminalContext && child.elementLabel === label);
+  }
+
+  toString(): string {
+    let result = `(${this.symbol.name ?? this.symbol.id}`;
+    for (const child of this.children) {
+      result += ` ${child}`;
+    }
+    return result + ")";
+  }
+}
+
+
+// 设置节点在产生式中的标签, 已经有标签的节点保持不变, 错误节点没有标签
+export function setElementLabel(ctx: AstContext, label: string | null): void {
+  if (ctx instanceof ErrorContext) { return; }
+  ctx.elementLabel = ctx.elementLabel ?? label;
+}
+
+
+// 自底向上地将分析得到的语法树转换为生成的 context 类型, factory 根据规则编号和标签创建对应的 context
+export function buildContexts(ctx: RuleContext, factory: (ctx: RuleContext) => RuleContext): RuleContext {
+  const children = ctx.children.map((child) => child instanceof RuleContext ? buildContexts(child, factory) : child);
+  return factory(new RuleContext(ctx.symbol, children, ctx.label, ctx.elementLabel));
+}
@@ -0,0 +1,33 @@
+import { Location } from "./token";
+
+
+// 运行时产生的错误, 与 rust 运行时的 runtime::error::Error 相对应
+export type ErrorKind =
+  | "TokenStreamOutOfRange" // look_ahead 超出了范围
+  | "SyntaxError"           // 语法错误的位置和信息, 不进行错误恢复时抛出
+  | "Unknown";              // 未知错误
+
+
+export class ChiruError extends Error {
+  constructor(
+    public readonly kind: ErrorKind,
+    message: string,
+    // 错误在输入中的位置, 没有位置信息时为 null
+    public readonly location: Location | null = null,
+  ) {
+    super(message);
+    this.name = "ChiruError";
+  }
+
+  static tokenStreamOutOfRange(): ChiruError {
+    return new ChiruError("TokenStreamOutOfRange", "token stream out of range");
+  }
+
+  static syntaxError(location: Location, message: string): ChiruError {
+    return new ChiruError("SyntaxError", `syntax error: ${message}`, location);
+  }
+
+  static unknown(): ChiruError {
+    return new ChiruError("Unknown", "unknown error");
+  }
+}
@@ -0,0 +1,63 @@
+import type { ErrorContext } from "./ast";
+import { Location, Token } from "./token";
+import { NonTerminal, Terminal } from "./vocabulary";
+
+
+export interface ErrorListener {
+  // 语法分析时发现错误, 每产生一个 redundant 或 missing 错误节点都会调用一次
+  // rule 为出错时正在分析的非终结符, offendingToken 为出错位置的 token,
+  // location 为错误的位置, expected 为此时期望的终结符集合, message 为可读的错误信息
+  syntaxError?(rule: NonTerminal, offendingToken: Token, location: Location, expected: Terminal[], message: string): void;
+
+  // 词法分析时遇到无法匹配任何规则的字符, 该字符会被跳过
+  lexerError?(text: string, location: Location): void;
+}
+
+
+// 将期望的终结符集合格式化为 {A, B} 的形式, 只有一个终结符时不加括号
+export function formatExpected(expected: Terminal[]): string {
+  const names = expected.map((terminal) => terminal.name);
+  return names.length === 1 ? names[0] : `{${names.join(", ")}}`;
+}
+
+
+// 终结符集合按照名称排序, 与 rust 运行时中 BTreeSet<Terminal> 的顺序一致
+export function sortTerminals(terminals: Iterable<Terminal>): Terminal[] {
+  const result = new Map<number, Terminal>();
+  for (const terminal of terminals) {
+    result.set(terminal.id, terminal);
+  }
+  return [...result.values()].sort((a, b) => a.name < b.name ? -1 : a.name > b.name ? 1 : a.id - b.id);
+}
+
+
+// 在标准错误中以 line:col 的形式输出错误, 行号和列号从 1 开始
+export class ConsoleErrorListener implements ErrorListener {
+  syntaxError(_rule: NonTerminal, _offendingToken: Token, location: Location, _expected: Terminal[], message: string): void {
+    console.error(`${location.start.line + 1}:${location.start.charPosition + 1} ${message}`);
+  }
+
+  lexerError(text: string, location: Location): void {
+    console.error(`${location.start.line + 1}:${location.start.charPosition + 1} token recognition error at: ${JSON.stringify(text)}`);
+  }
+}
+
+
+// 输出更详细的信息, 包括出错的非终结符、token 和期望的终结符集合
+export class DiagnosticErrorListener implements ErrorListener {
+  syntaxError(rule: NonTerminal, offendingToken: Token, location: Location, expected: Terminal[], message: string): void {
+    console.error(`${location.start.line + 1}:${location.start.charPosition + 1} ${message} (rule: ${rule}, token: ${offendingToken}, expected: ${formatExpected(expected)})`);
+  }
+
+  lexerError(text: string, location: Location): void {
+    console.error(`${location.start.line + 1}:${location.start.charPosition + 1} token recognition error at: ${JSON.stringify(text)} (index: ${location.indexStart}..${location.indexStop})`);
+  }
+}
+
+
+// 通知所有监听器产生了一个错误节点, token 为出错位置的 token
+export function reportError(listeners: ErrorListener[], rule: NonTerminal, token: Token, error: ErrorContext): void {
+  for (const listener of listeners) {
+    listener.syntaxError?.(rule, token, error.location, error.expected, error.message);
+  }
+}
@@ -0,0 +1,177 @@
+import { AstContext, ErrorContext } from "./ast";
+import { ChiruError } from "./error";
+import { ErrorListener, reportError, sortTerminals } from "./error_listener";
+import { ProductionItem } from "./production";
+import { Token } from "./token";
+import { TokenStream } from "./token_stream";
+import { NonTerminal, Terminal } from "./vocabulary";
+
+
+// 生成的 ll1 分析器提供的分析表
+export interface LL1Table {
+  // 所有的终结符, 用于获取期望的终结符的名称
+  terminals: Map<number, Terminal>;
+  // 非终结符编号 -> 终结符编号 -> 产生式编号
+  table: Map<number, Map<number, number>>;
+  // 同步集合, 非终结符编号 -> 终结符编号
+  sync: Map<number, Set<number>>;
+}
+
+
+// 错误处理策略在恢复时可以使用的 ll1 分析器的信息
+export class Recognizer {
+  constructor(
+    // 出错时正在分析的非终结符
+    public readonly rule: NonTerminal,
+    public readonly tables: LL1Table,
+    public readonly errorListeners: ErrorListener[],
+  ) {}
+
+  // 预测分析表中 (nonterminal, terminal) 对应的产生式编号
+  predict(nonterminal: NonTerminal, terminal: Terminal): number | undefined {
+    return this.tables.table.get(nonterminal.id)?.get(terminal.id);
+  }
+
+  isSync(terminal: Terminal): boolean {
+    return this.tables.sync.get(this.rule.id)?.has(terminal.id) ?? false;
+  }
+
+  // 在分析当前非终结符时能够接受的所有终结符
+  expectedTerminals(): Terminal[] {
+    const ids = this.tables.table.get(this.rule.id)?.keys() ?? [];
+    const terminals: Terminal[] = [];
+    for (const id of ids) {
+      terminals.push(this.tables.terminals.get(id) ?? new Terminal(`<${id}>`, id));
+    }
+    return sortTerminals(terminals);
+  }
+
+  // 通知所有监听器, 并将错误节点添加到 errors 中
+  report(token: Token, error: ErrorContext, errors: AstContext[]): void {
+    reportError(this.errorListeners, this.rule, token, error);
+    errors.push(error);
+  }
+}
+
+
+// ll1 分析出错时的处理策略, 恢复过程中产生的错误节点都添加到 errors 中
+export interface ErrorStrategy {
+  // 预测分析表中找不到 (rule, 下一个 token) 对应的产生式时调用
+  // 返回用于展开 rule 的产生式编号, 返回 null 表示整个 rule 都缺失了
+  recover(recognizer: Recognizer, tokenStream: TokenStream, errors: AstContext[]): number | null;
+
+  // 期望终结符 expected, 但是下一个 token 与之不匹配时调用, rest 为产生式中位于 expected 之后的部分
+  // 返回 true 表示恢复之后下一个 token 就是 expected, 返回 false 表示 expected 缺失了或者被错误的 token 代替了
+  recoverInline(recognizer: Recognizer, expected: Terminal, rest: ProductionItem[], tokenStream: TokenStream, errors: AstContext[]): boolean;
+}
+
+
+// 遇到第一个错误就抛出 ChiruError, 不进行任何恢复
+export class BailErrorStrategy implements ErrorStrategy {
+  recover(recognizer: Recognizer, tokenStream: TokenStream): number | null {
+    const token = tokenStream.peekNextToken();
+    const error = ErrorContext.mistake(token, recognizer.expectedTerminals());
+    reportError(recognizer.errorListeners, recognizer.rule, token, error);
+    throw ChiruError.syntaxError(error.location, error.message);
+  }
+
+  recoverInline(recognizer: Recognizer, expected: Terminal, _rest: ProductionItem[], tokenStream: TokenStream): boolean {
+    const token = tokenStream.peekNextToken();
+    const error = ErrorContext.mistake(token, [expected]);
+    reportError(recognizer.errorListeners, recognizer.rule, token, error);
+    throw ChiruError.syntaxError(error.location, error.message);
+  }
+}
+
+
+// 恐慌模式, 一直丢弃 token, 直到遇到能够接受的 token 或者同步集合中的 token 为止
+export class DefaultErrorStrategy implements ErrorStrategy {
+  recover(recognizer: Recognizer, tokenStream: TokenStream, errors: AstContext[]): number | null {
+    const expected = recognizer.expectedTerminals();
+    for (;;) {
+      const token = tokenStream.peekNextToken();
+      const productionId = recognizer.predict(recognizer.rule, token.terminal);
+      if (productionId !== undefined) {
+        return productionId;
+      }
+
+      if (recognizer.isSync(token.terminal) || token.terminal.id === 1) {
+        // 同步 这里表示整个非终结符都缺失了
+        recognizer.report(token, ErrorContext.missing(token, expected), errors);
+        return null;
+      }
+
+      // 丢弃，将其添加到 error node, 这里认为该 token 是多余的
+      recognizer.report(token, ErrorContext.redundant(token, expected), errors);
+      tokenStream.consume();
+    }
+  }
+
+  recoverInline(recognizer: Recognizer, expected: Terminal, _rest: ProductionItem[], tokenStream: TokenStream, errors: AstContext[]): boolean {
+    for (;;) {
+      const token = tokenStream.peekNextToken();
+      if (token.terminal.id === expected.id) { return true; }
+
+      if (token.terminal.id === 1) {
+        recognizer.report(token, ErrorContext.missing(token, [expected]), errors);
+        return false;
+      }
+
+      recognizer.report(token, ErrorContext.redundant(token, [expected]), errors);
+      tokenStream.consume();
+    }
+  }
+}
+
+
+// 先尝试删除一个多余的 token、插入一个缺失的终结符或者替换一个错误的 token, 都不行时再使用恐慌模式
+// 生成的 ll1 分析器默认使用该策略
+export class SingleTokenErrorStrategy implements ErrorStrategy {
+  // 如果 expected 缺失, 下一个终结符是否能够出现在 expected 之后
+  private static canFollow(recognizer: Recognizer, rest: ProductionItem[], terminal: Terminal): boolean {
+    const next = rest[0];
+    if (next === undefined) { return recognizer.isSync(terminal); }
+    if (next instanceof Terminal) { return next.id === terminal.id; }
+    return recognizer.predict(next, terminal) !== undefined;
+  }
+
+  recover(recognizer: Recognizer, tokenStream: TokenStream, errors: AstContext[]): number | null {
+    const token = tokenStream.peekNextToken();
+
+    // 删除下一个 token 之后就能够选择产生式
+    const productionId = recognizer.predict(recognizer.rule, tokenStream.lookAhead(2).terminal);
+    if (productionId !== undefined) {
+      recognizer.report(token, ErrorContext.redundant(token, recognizer.expectedTerminals()), errors);
+      tokenStream.consume();
+      return productionId;
+    }
+
+    return new DefaultErrorStrategy().recover(recognizer, tokenStream, errors);
+  }
+
+  recoverInline(recognizer: Recognizer, expected: Terminal, rest: ProductionItem[], tokenStream: TokenStream, errors: AstContext[]): boolean {
+    const token = tokenStream.peekNextToken();
+
+    // 单个 token 删除: 下一个 token 是多余的
+    if (token.terminal.id !== 1 && tokenStream.lookAhead(2).terminal.id === expected.id) {
+      recognizer.report(token, ErrorContext.redundant(token, [expected]), errors);
+      tokenStream.consume();
+      return true;
+    }
+
+    // 单个 token 插入: 缺少了 expected
+    if (SingleTokenErrorStrategy.canFollow(recognizer, rest, token.terminal)) {
+      recognizer.report(token, ErrorContext.missing(token, [expected]), errors);
+      return false;
+    }
+
+    // 单个 token 替换: 下一个 token 出现在了 expected 的位置上
+    if (token.terminal.id !== 1 && SingleTokenErrorStrategy.canFollow(recognizer, rest, tokenStream.lookAhead(2).terminal)) {
+      recognizer.report(token, ErrorContext.mistake(token, [expected]), errors);
+      tokenStream.consume();
+      return false;
+    }
+
+    return new DefaultErrorStrategy().recoverInline(recognizer, expected, rest, tokenStream, errors);
+  }
+}
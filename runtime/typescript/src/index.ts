@@ -0,0 +1,12 @@
+// chiru 生成的 typescript 代码所使用的运行时, 与 rust 运行时的 chiru::runtime 相对应
+
+export * from "./ast";
+export * from "./error";
+export * from "./error_listener";
+export * from "./error_strategy";
+export * from "./lexer";
+export * from "./ll1_analyzer";
+export * from "./production";
+export * from "./token";
+export * from "./token_stream";
+export * from "./vocabulary";
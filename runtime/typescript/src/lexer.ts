@@ -0,0 +1,192 @@
+import { ConsoleErrorListener, ErrorListener } from "./error_listener";
+import { Location, Position, Token } from "./token";
+import { Terminal } from "./vocabulary";
+
+
+// 词法模式的切换命令, 对应 @push_mode(X) @pop_mode @mode(X)
+export type ModeAction =
+  | { kind: "push"; mode: number } // 将模式压入模式栈
+  | { kind: "pop" }                // 弹出栈顶的模式
+  | { kind: "set"; mode: number }; // 将栈顶的模式替换为指定的模式
+
+
+// 生成的词法分析器中的每一条规则, 按照优先级排序
+export interface LexerRule {
+  rule: RegExp;
+  tokenType: number;
+  tokenName: string;
+  channel: number;
+  skip: boolean;
+
+  // 该规则所属的词法模式, 默认模式为 0
+  mode: number;
+  // 匹配之后对模式栈进行的操作
+  modeAction: ModeAction | null;
+}
+
+
+// lexer 不识别 start 和 stop，所有 start 和 stop 都交给 TokenStream 来添加
+export class Lexer {
+  public errorListeners: ErrorListener[] = [new ConsoleErrorListener()];
+
+  // 带有 y 标志的正则表达式, 只在当前位置进行锚定匹配
+  private readonly stickyRules: RegExp[];
+
+  constructor(
+    public readonly input: string,
+    public readonly rules: LexerRule[],
+  ) {
+    this.stickyRules = rules.map((rule) => new RegExp(rule.rule.source, rule.rule.flags.replace(/[gy]/g, "") + "y"));
+  }
+
+  removeAllErrorListeners(): void {
+    this.errorListeners = [];
+  }
+
+  addErrorListener(listener: ErrorListener): void {
+    this.errorListeners.push(listener);
+  }
+
+  iter(): TokenIter {
+    return new TokenIter(this.input, this.rules, this.stickyRules, this.errorListeners);
+  }
+
+  getAllTokens(): Token[] {
+    return [...this.iter()];
+  }
+
+  getAllOnChannelTokens(channel: number): Token[] {
+    return this.getAllTokens().filter((token) => token.channel === channel);
+  }
+}
+
+
+// 这里不管 start 和 stop，需要 TokenStream 自己处理
+export class TokenIter implements Iterable<Token> {
+  // 每一行起始字符的下标, 用于判断字符所在的 position
+  private readonly lineStarts: number[] = [0];
+
+  // 字符游标，当前处理到的文本字符下标
+  public cursor = 0;
+  // token 序号，表示当前扫描到了第几个 token
+  public tokenIndex = 1;
+  // 模式栈, 只有栈顶模式中的规则参与匹配
+  public modes: number[] = [0];
+
+  constructor(
+    public readonly input: string,
+    private readonly rules: LexerRule[],
+    private readonly stickyRules: RegExp[],
+    private readonly errorListeners: ErrorListener[],
+  ) {
+    for (let index = 0; index < input.length; index++) {
+      if (input[index] === "\n") { this.lineStarts.push(index + 1); }
+    }
+  }
+
+  getCurrentPosition(): Position {
+    return this.getPosition(this.cursor);
+  }
+
+  getCurrentMode(): number {
+    return this.modes[this.modes.length - 1] ?? 0;
+  }
+
+  reset(): void {
+    this.cursor = 0;
+    this.tokenIndex = 1;
+    this.modes = [0];
+  }
+
+  private getPosition(index: number): Position {
+    // 二分查找 index 所在的行
+    let low = 0;
+    let high = this.lineStarts.length - 1;
+    while (low < high) {
+      const middle = (low + high + 1) >> 1;
+      if (this.lineStarts[middle] <= index) { low = middle; } else { high = middle - 1; }
+    }
+    return new Position(low, index - this.lineStarts[low]);
+  }
+
+  // 在当前位置找到最长的匹配, 长度相同时选择优先级高的规则, 返回规则的下标和匹配的结束位置
+  private longestMatch(): [number, number] | null {
+    const mode = this.getCurrentMode();
+    let result: [number, number] | null = null;
+    for (let index = 0; index < this.rules.length; index++) {
+      if (this.rules[index].mode !== mode) { continue; }
+      const regex = this.stickyRules[index];
+      regex.lastIndex = this.cursor;
+      const matched = regex.exec(this.input);
+      if (matched === null) { continue; }
+
+      // 规则按照优先级从高到低排列, 只有更长的匹配才会替换之前的结果
+      const stop = this.cursor + matched[0].length;
+      if (stop > this.cursor && (result === null || stop > result[1])) {
+        result = [index, stop];
+      }
+    }
+    return result;
+  }
+
+  // 这个函数只管匹配，不会识别到 start 和 stop。扫描到输入末尾时返回 null
+  // 匹配不上的字符会报告给错误监听器, 然后跳过该字符继续匹配
+  lexerMatch(): Token | null {
+    for (;;) {
+      if (this.cursor >= this.input.length) {
+        return null;
+      }
+
+      const matched = this.longestMatch();
+      if (matched === null) {
+        this.skipUnmatchedChar();
+        continue;
+      }
+      const [index, stop] = matched;
+      const start = this.cursor;
+      const meta = this.rules[index];
+
+      const location = new Location(this.getPosition(start), this.getPosition(stop), start, stop);
+      const token = new Token(new Terminal(meta.tokenName, meta.tokenType), this.input.slice(start, stop), location, this.tokenIndex, meta.channel);
+
+      this.cursor = stop;
+
+      // 切换词法模式, 默认模式不会被弹出
+      const action = meta.modeAction;
+      if (action?.kind === "push") {
+        this.modes.push(action.mode);
+      } else if (action?.kind === "pop" && this.modes.length > 1) {
+        this.modes.pop();
+      } else if (action?.kind === "set") {
+        this.modes[this.modes.length - 1] = action.mode;
+      }
+
+      // 如果需要跳过，则继续匹配下一个
+      if (meta.skip) { continue; }
+
+      this.tokenIndex += 1;
+      return token;
+    }
+  }
+
+  // 跳过 cursor 处的一个字符, 并将其位置报告给错误监听器
+  private skipUnmatchedChar(): void {
+    const start = this.cursor;
+    const stop = start + String.fromCodePoint(this.input.codePointAt(start) ?? 0).length;
+    const location = new Location(this.getPosition(start), this.getPosition(stop), start, stop);
+
+    for (const listener of this.errorListeners) {
+      listener.lexerError?.(this.input.slice(start, stop), location);
+    }
+    this.cursor = stop;
+  }
+
+  next(): IteratorResult<Token> {
+    const token = this.lexerMatch();
+    return token === null ? { done: true, value: undefined } : { done: false, value: token };
+  }
+
+  [Symbol.iterator](): Iterator<Token> {
+    return this;
+  }
+}
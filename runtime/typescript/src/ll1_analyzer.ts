@@ -0,0 +1,158 @@
+import { AstContext, RuleContext, TerminalContext, setElementLabel } from "./ast";
+import { ErrorListener } from "./error_listener";
+import { ErrorStrategy, LL1Table, Recognizer } from "./error_strategy";
+import { Production } from "./production";
+import { TokenStream } from "./token_stream";
+import { NonTerminal, Terminal } from "./vocabulary";
+
+
+// 生成的 ll1 分析器提供的全部信息
+export interface LL1Grammar extends LL1Table {
+  productions: Map<number, Production>;
+  // 消除左递归时引入的匿名非终结符编号 -> 所属非终结符编号
+  leftRecursionTails: Map<number, number>;
+}
+
+
+// 从 ruleSymbol 开始分析, 分析完毕之后必须到达输入末尾
+export function ll1Analyze(
+  tokenStream: TokenStream,
+  ruleSymbol: NonTerminal,
+  grammar: LL1Grammar,
+  errorListeners: ErrorListener[],
+  errorStrategy: ErrorStrategy,
+): RuleContext {
+  const result = analyzeRule(tokenStream, ruleSymbol, grammar, errorListeners, errorStrategy);
+
+  // 剩余的 token 交给错误处理策略, 期望的是 _STOP
+  // 开始符号之后只能是 _STOP, 因此不使用同步集合, 错误处理策略会一直丢弃 token 直到输入末尾
+  if (tokenStream.peekNextToken().terminal.id !== 1) {
+    const recognizer = new Recognizer(ruleSymbol, { terminals: grammar.terminals, table: grammar.table, sync: new Map() }, errorListeners);
+    errorStrategy.recoverInline(recognizer, new Terminal("_STOP", 1), [], tokenStream, result.children);
+  }
+  return result;
+}
+
+
+function analyzeRule(
+  tokenStream: TokenStream,
+  ruleSymbol: NonTerminal,
+  grammar: LL1Grammar,
+  errorListeners: ErrorListener[],
+  errorStrategy: ErrorStrategy,
+): RuleContext {
+  let result = new RuleContext(ruleSymbol);
+  const recognizer = new Recognizer(ruleSymbol, grammar, errorListeners);
+
+  // 获取 productionId, 找不到时交给错误处理策略
+  let productionId = recognizer.predict(ruleSymbol, tokenStream.peekNextToken().terminal) ?? null;
+  if (productionId === null) {
+    productionId = errorStrategy.recover(recognizer, tokenStream, result.children);
+    // 这里表示整个非终结符都缺失了
+    if (productionId === null) { return result; }
+  }
+
+  const production = grammar.productions.get(productionId);
+  if (production === undefined) {
+    throw new Error(`unknown production ${productionId}`);
+  }
+  result.label = production.label;
+
+  for (let index = 0; index < production.right.length; index++) {
+    const child = production.right[index];
+    const elementLabel = production.getElementLabel(index);
+
+    if (child instanceof Terminal) {
+      if (tokenStream.peekNextToken().terminal.id !== child.id
+        && !errorStrategy.recoverInline(recognizer, child, production.right.slice(index + 1), tokenStream, result.children)) {
+        // 缺失或者被替换的符号已经由错误处理策略记录, 到达输入末尾时不再继续匹配
+        if (tokenStream.peekNextToken().terminal.id === 1) { break; }
+        continue;
+      }
+
+      // 匹配了, 消耗掉
+      result.children.push(new TerminalContext(tokenStream.consume(), elementLabel));
+    } else {
+      const t = analyzeRule(tokenStream, child, grammar, errorListeners, errorStrategy);
+      // 消除左递归引入的非终结符先保留嵌套, 等到其所属的非终结符分析完毕之后再折叠
+      if (child.name !== null || grammar.leftRecursionTails.has(child.id)) {
+        t.elementLabel = elementLabel;
+        result.children.push(t);
+      } else {
+        // 提取左公因子之后, 标签记录在匿名非终结符的产生式上
+        result.label = result.label ?? t.label;
+        for (const grandchild of t.children) {
+          setElementLabel(grandchild, elementLabel);
+          result.children.push(grandchild);
+        }
+      }
+    }
+  }
+
+  // 将属于当前非终结符的左递归尾部折叠为左结合的语法树
+  const last = result.children[result.children.length - 1];
+  if (last instanceof RuleContext && grammar.leftRecursionTails.get(last.symbol.id) === ruleSymbol.id) {
+    result.children.pop();
+    result = foldLeftRecursion(result, last);
+  }
+  return result;
+}
+
+
+// 将消除左递归时引入的非终结符展开的结果折叠起来, A' 的每一次非空展开都会将之前的结果作为新的 A 节点的第一个孩子
+// 原产生式中左递归位置的标签记录在末尾的 A' 上, 折叠时转交给之前的结果
+function foldLeftRecursion(result: RuleContext, tail: RuleContext): RuleContext {
+  const children: AstContext[] = [...tail.children];
+  let next: RuleContext | null = null;
+  const last = children[children.length - 1];
+  if (last instanceof RuleContext && last.symbol.id === tail.symbol.id) {
+    children.pop();
+    next = last;
+  }
+
+  if (children.length > 0) {
+    if (result.symbol.name !== null) {
+      result.elementLabel = null;
+      if (next !== null) {
+        result.elementLabel = next.elementLabel;
+        next.elementLabel = null;
+      }
+      result = new RuleContext(result.symbol, [result], tail.label);
+    }
+    result.children.push(...children);
+  }
+
+  return next === null ? result : foldLeftRecursion(result, next);
+}
+
+
+// 创建 LL1Grammar 时使用的辅助函数, 将生成代码中的数组转换为 Map
+export function tableFrom(entries: [number, number, number][]): Map<number, Map<number, number>> {
+  const result = new Map<number, Map<number, number>>();
+  for (const [nonterminal, terminal, production] of entries) {
+    let row = result.get(nonterminal);
+    if (row === undefined) {
+      row = new Map<number, number>();
+      result.set(nonterminal, row);
+    }
+    row.set(terminal, production);
+  }
+  return result;
+}
+
+export function syncFrom(entries: [number, number][]): Map<number, Set<number>> {
+  const result = new Map<number, Set<number>>();
+  for (const [nonterminal, terminal] of entries) {
+    let row = result.get(nonterminal);
+    if (row === undefined) {
+      row = new Set<number>();
+      result.set(nonterminal, row);
+    }
+    row.add(terminal);
+  }
+  return result;
+}
+
+export function terminalsFrom(terminals: Terminal[]): Map<number, Terminal> {
+  return new Map(terminals.map((terminal) => [terminal.id, terminal]));
+}
@@ -0,0 +1,40 @@
+import { NonTerminal, Terminal } from "./vocabulary";
+
+
+// 产生式右部的元素, 终结符或者非终结符
+export type ProductionItem = Terminal | NonTerminal;
+
+
+export class Production {
+  constructor(
+    // 产生式的编号
+    public readonly id: number,
+    // 产生式左部
+    public readonly left: NonTerminal,
+    public readonly right: ProductionItem[],
+    // 备选分支的标签, 如 expr: expr PLUS expr # Add;
+    public readonly label: string | null = null,
+    // 右部每个元素的标签, 如 lhs=expr, 和 right 一一对应
+    public readonly elementLabels: (string | null)[] = [],
+  ) {}
+
+  // 获取右部第 index 个元素的标签
+  getElementLabel(index: number): string | null {
+    return this.elementLabels[index] ?? null;
+  }
+
+  toString(): string {
+    let result = `${this.id}: ${this.left} ->`;
+    if (this.right.length === 0) {
+      return result + " ε";
+    }
+    this.right.forEach((item, index) => {
+      const label = this.getElementLabel(index);
+      result += label === null ? ` ${item}` : ` ${label}=${item}`;
+    });
+    if (this.label !== null) {
+      result += ` # ${this.label}`;
+    }
+    return result;
+  }
+}
@@ -0,0 +1,53 @@
+import { Terminal } from "./vocabulary";
+
+
+// 行号和列号都从 0 开始
+export class Position {
+  constructor(
+    public readonly line: number,
+    public readonly charPosition: number,
+  ) {}
+
+  toString(): string {
+    return `line: ${this.line}, position: ${this.charPosition}`;
+  }
+}
+
+
+// 用于描述 token 或者 error 的位置, 左闭右开
+// 与 rust 运行时不同, 下标是输入字符串中 utf-16 编码单元的下标, 可以直接用于 input.slice
+export class Location {
+  constructor(
+    public readonly start: Position,
+    public readonly stop: Position,
+    public readonly indexStart: number,
+    public readonly indexStop: number,
+  ) {}
+}
+
+
+export class Token {
+  constructor(
+    // 名称和类型
+    public readonly terminal: Terminal,
+    // token 的文本
+    public readonly text: string,
+    // token 所在的位置
+    public readonly location: Location,
+    // token 在token序列中的序号(所有频道一起排号)
+    public readonly tokenIndex: number,
+    // token 所在的频道
+    public readonly channel: number,
+  ) {}
+
+  // 快速创建 start token
+  static start(channel: number): Token {
+    const pos = new Position(0, 0);
+    return new Token(new Terminal("_START", 0), "_START", new Location(pos, pos, 0, 0), 0, channel);
+  }
+
+  toString(): string {
+    const location = this.location;
+    return `[@${this.tokenIndex}, ${location.indexStart}:${location.indexStop}='${this.text}', <${this.terminal.name}>, <${this.terminal.id}>, start: <${location.start}>, stop: <${location.stop}>]`;
+  }
+}
@@ -0,0 +1,104 @@
+import { ChiruError } from "./error";
+import { Lexer, TokenIter } from "./lexer";
+import { Location, Token } from "./token";
+import { Terminal } from "./vocabulary";
+
+
+// 词法分析的时候，直接丢弃掉 skip 的 token, 并且只保留属于该频道的 token
+export class TokenStream {
+  // 词法分析器
+  public readonly iter: TokenIter;
+
+  // 当前 token，初始化为 _START
+  private nextToken: Token | null;
+
+  // 上一个 token
+  private previousToken: Token | null = null;
+
+  // 预查看 token 的缓冲队列
+  private cachedTokens: Token[] = [];
+
+  constructor(lexer: Lexer, public readonly channel: number = 0) {
+    this.iter = lexer.iter();
+    // 我们认为 start 和 stop 永远都和当前 stream 一样。
+    this.nextToken = Token.start(channel);
+  }
+
+  // 消耗掉 nextToken, 并返回它
+  consume(): Token {
+    // previousToken 是 stop, nextToken 是 null, 表示已经消耗了 stop
+    const token = this.nextToken;
+    if (token === null) {
+      throw ChiruError.tokenStreamOutOfRange();
+    }
+
+    this.previousToken = token;
+    if (this.cachedTokens.length > 0) {
+      // 如果缓存中还有token
+      this.nextToken = this.cachedTokens.shift() ?? null;
+    } else if (token.terminal.id === 1) {
+      this.nextToken = null;
+    } else {
+      this.nextToken = this.scanNextToken();
+    }
+    return token;
+  }
+
+  lookAhead(n: number): Token {
+    if (n <= 0) {
+      // 至少向前看一个 token
+      throw ChiruError.unknown();
+    }
+    if (n === 1) {
+      return this.peekNextToken();
+    }
+
+    // 第 n 个 token 位于缓冲队列中的第 n - 2 个位置
+    while (this.cachedTokens.length < n - 1) {
+      // 已经扫描到了 stop, 之后的 token 都认为是 stop
+      const last = this.cachedTokens[this.cachedTokens.length - 1] ?? this.nextToken;
+      if (last !== null && last.terminal.id === 1) { return last; }
+
+      this.cachedTokens.push(this.scanNextToken());
+    }
+    return this.cachedTokens[n - 2];
+  }
+
+  // 从词法分析器中获取下一个属于该频道的 token, 扫描到输入末尾时返回 stop
+  private scanNextToken(): Token {
+    for (;;) {
+      const token = this.iter.lexerMatch();
+      if (token === null) {
+        const position = this.iter.getCurrentPosition();
+        const location = new Location(position, position, this.iter.cursor, this.iter.cursor);
+        // 添加 stop token
+        return new Token(new Terminal("_STOP", 1), "_STOP", location, this.iter.tokenIndex, this.channel);
+      }
+      if (token.channel === this.channel) {
+        return token;
+      }
+    }
+  }
+
+  peekNextToken(): Token {
+    if (this.nextToken === null) {
+      throw ChiruError.tokenStreamOutOfRange();
+    }
+    return this.nextToken;
+  }
+
+  peekPreviousToken(): Token {
+    if (this.previousToken === null) {
+      throw ChiruError.tokenStreamOutOfRange();
+    }
+    return this.previousToken;
+  }
+
+  // 重置 TokenStream
+  reset(): void {
+    this.cachedTokens = [];
+    this.iter.reset();
+    this.nextToken = Token.start(this.channel);
+    this.previousToken = null;
+  }
+}
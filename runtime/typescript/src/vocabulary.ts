@@ -0,0 +1,24 @@
+// 终结符, 名称和编号, 编号 0 和 1 分别为 _START 和 _STOP
+export class Terminal {
+  constructor(
+    public readonly name: string,
+    public readonly id: number,
+  ) {}
+
+  toString(): string {
+    return this.name;
+  }
+}
+
+
+// 非终结符, 消除左递归和提取左公因子引入的非终结符没有名称
+export class NonTerminal {
+  constructor(
+    public readonly name: string | null,
+    public readonly id: number,
+  ) {}
+
+  toString(): string {
+    return this.name ?? `<${this.id}>`;
+  }
+}
@@ -2,6 +2,7 @@
 // 添加相关引用
 
 pub use regex;
+pub use regex_automata;
 pub use once_cell;
 pub use maplit;
 
@@ -1,14 +1,26 @@
 
 pub mod tool;
 use clap::Parser;
-use tool::cli::Cli;
+use tool::cli::{error_report::ReportedError, Cli};
 
 
 fn main() {
   
   let cli = Cli::parse();
   
-  cli.execute_command().unwrap();
+  if let Err(err) = cli.execute_command() {
+    // 带有位置信息的错误已经在源码片段上输出过了
+    if err.is::<ReportedError>() { std::process::exit(1); }
+
+    eprintln!("error: {}", err);
+    // 依次输出导致该错误的底层错误
+    let mut source = err.source();
+    while let Some(cause) = source {
+      eprintln!("  caused by: {}", cause);
+      source = cause.source();
+    }
+    std::process::exit(1);
+  }
 }
 
 
@@ -12,13 +12,12 @@ pub enum AstContext<'a> {
 }
 
 impl<'a> AstContext<'a> {
-  pub fn to_string(&self) -> String {
-    use AstContext::{Terminal, Rule, Error};
-
-    match &self {
-      Terminal(ctx) => ctx.to_string(),
-      Rule(ctx) => ctx.to_string(),
-      Error(ctx) => ctx.to_string(),
+  // 设置节点在产生式中的标签, 已经有标签的节点保持不变, 错误节点没有标签
+  pub fn set_element_label(&mut self, label: Option<&'a str>) {
+    match self {
+      AstContext::Terminal(ctx) => ctx.element_label = ctx.element_label.or(label),
+      AstContext::Rule(ctx) => ctx.element_label = ctx.element_label.or(label),
+      AstContext::Error(_) => {},
     }
   }
 }
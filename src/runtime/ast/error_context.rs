@@ -1,14 +1,21 @@
 
-use std::fmt::Display;
+use std::{collections::BTreeSet, fmt::Display};
 use serde::{Serialize, ser::SerializeStruct};
-use crate::runtime::token::Token;
+use crate::runtime::{error_strategy::error_listener::format_expected, location::Location, token::Token, vocabulary::Terminal};
 
 #[derive(Clone, Debug)]
 pub struct ErrorContext<'a> {
 
   pub symbol: ErrorSymbol<'a>,
 
-  // 考虑添加一个 error_message
+  // 出错时期望的终结符集合
+  pub expected: BTreeSet<Terminal<'a>>,
+
+  // 可读的错误信息, 如 missing SEMI at '}'
+  pub message: String,
+
+  // 错误的位置, 缺失的符号位于下一个 token 的起始位置, 长度为 0
+  pub location: Location,
 }
 
 
@@ -35,39 +42,44 @@ impl<'a> ErrorContext<'a> {
     }
   }
 
-  pub fn to_string(&self) -> String {
-    use ErrorSymbol::*;
-    match &self.symbol {
-      Redundant(symbol) | Mistake(symbol) => symbol.terminal.name.to_string(),
-      Missing => "missing".to_string(),
+  // 多余的 token, 会被丢弃
+  pub fn redundant(symbol: &Token<'a>, expected: BTreeSet<Terminal<'a>>) -> Self {
+    Self {
+      message: format!("extraneous input '{}' expecting {}", symbol.text, format_expected(&expected)),
+      location: symbol.location,
+      symbol: ErrorSymbol::Redundant(symbol.to_owned()),
+      expected,
     }
   }
 
-  pub fn redundant(symbol: &Token<'a>) -> Self {
+  // 出现在期望的符号位置上的错误 token
+  pub fn mistake(symbol: &Token<'a>, expected: BTreeSet<Terminal<'a>>) -> Self {
     Self {
-      symbol: ErrorSymbol::Redundant(symbol.to_owned())
+      message: format!("mismatched input '{}' expecting {}", symbol.text, format_expected(&expected)),
+      location: symbol.location,
+      symbol: ErrorSymbol::Mistake(symbol.to_owned()),
+      expected,
     }
   }
 
-  pub fn mistake(symbol: &Token<'a>) -> Self {
+  // 在 next 之前缺失了期望的符号
+  pub fn missing(next: &Token<'a>, expected: BTreeSet<Terminal<'a>>) -> Self {
+    let start = next.location.start;
+    let index = next.location.byte_index_start;
     Self {
-      symbol: ErrorSymbol::Mistake(symbol.to_owned())
+      message: format!("missing {} at '{}'", format_expected(&expected), next.text),
+      location: Location::new(start, start, index, index),
+      symbol: ErrorSymbol::Missing,
+      expected,
     }
   }
 
-  pub fn missing() -> Self {
-    Self { symbol: ErrorSymbol::Missing }
-  }
-
 }
 
+// 输出位置和错误信息, 行号和列号从 1 开始, 如 <3:14 missing SEMI at '}'>
 impl<'a> Display for ErrorContext<'a> {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    use ErrorSymbol::*;
-    match &self.symbol {
-      Redundant(symbol) | Mistake(symbol) => write!(f, "{}", symbol.terminal.name),
-      Missing => write!(f, "missing"),
-    }
+    write!(f, "<{}:{} {}>", self.location.start.line + 1, self.location.start.char_position + 1, self.message)
   }
 }
 
@@ -75,32 +87,35 @@ impl<'a> Serialize for ErrorContext<'a> {
   fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
   where
     S: serde::Serializer {
-    
+
     use ErrorSymbol::*;
+    let expected = self.expected.iter().map(|terminal| terminal.name).collect::<Vec<_>>();
     match &self.symbol {
       Redundant(symbol) | Mistake(symbol) => {
-        let mut state = serializer.serialize_struct("ErrorContext", 4)?;
+        let mut state = serializer.serialize_struct("ErrorContext", 7)?;
         state.serialize_field("token_name", &symbol.terminal.name)?;
         state.serialize_field("token_type", &symbol.terminal.id)?;
         state.serialize_field("text", &symbol.text)?;
-        
+
         let mut error_type = "redundant";
         if let Mistake(_) = self.symbol {
           error_type = "mistake";
         }
 
         state.serialize_field("error_type", error_type)?;
-        
-        // 考虑添加 error message
+        state.serialize_field("message", &self.message)?;
+        state.serialize_field("expected", &expected)?;
+        state.serialize_field("location", &self.location)?;
         state.end()
       },
       Missing => {
-        let mut state = serializer.serialize_struct("ErrorContext", 1)?;
+        let mut state = serializer.serialize_struct("ErrorContext", 4)?;
         state.serialize_field("error_type", "missing")?;
-        // 考虑添加 message
+        state.serialize_field("message", &self.message)?;
+        state.serialize_field("expected", &expected)?;
+        state.serialize_field("location", &self.location)?;
         state.end()
       },
     }
   }
 }
-
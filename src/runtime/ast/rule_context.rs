@@ -20,7 +20,11 @@ pub struct RuleContext<'a> {
   pub symbol: NonTerminal<'a>,
   pub children: Vec<AstContext<'a>>,
 
-  
+  // 匹配到的备选分支的标签, 没有标签时为 None
+  pub label: Option<&'a str>,
+
+  // 在父节点的产生式中的标签, 如 lhs=expr
+  pub element_label: Option<&'a str>,
 }
 
 impl<'a> ToRule<'a> for RuleContext<'a> {
@@ -37,13 +41,15 @@ impl<'a> ToRule<'a> for RuleContext<'a> {
 
 // 其他函数
 impl<'a> RuleContext<'a> {
-  pub fn get_children(&self) -> &[AstContext] { &self.children }
+  pub fn get_children(&self) -> &[AstContext<'a>] { &self.children }
 
   pub fn get_child_count(&self) -> usize { self.children.len() }
 
   pub fn get_rule_index(&self) -> usize { self.symbol.id }
 
-  pub fn get_first_terminal(&self) -> Option<&TerminalContext> { 
+  pub fn get_label(&self) -> Option<&'a str> { self.label }
+
+  pub fn get_first_terminal(&self) -> Option<&TerminalContext<'a>> { 
     match self.children.first()? {
       AstContext::Terminal(ctx) => Some(ctx),
       AstContext::Rule(ctx) => ctx.get_first_terminal(),
@@ -51,15 +57,15 @@ impl<'a> RuleContext<'a> {
     }
   }
 
-  pub fn get_last_terminal(&self) -> Option<&TerminalContext> { 
+  pub fn get_last_terminal(&self) -> Option<&TerminalContext<'a>> { 
     match self.children.last()? {
       AstContext::Terminal(ctx) => Some(ctx),
-      AstContext::Rule(ctx) => ctx.get_first_terminal(),
+      AstContext::Rule(ctx) => ctx.get_last_terminal(),
       AstContext::Error(_) => None,
     }
   }
 
-  pub fn get_start_token(&self) -> Option<Token> {
+  pub fn get_start_token(&self) -> Option<Token<'a>> {
     match self.children.first()? {
       AstContext::Terminal(ctx) => Some(ctx.symbol.clone()),
       AstContext::Rule(ctx) => ctx.get_start_token(),
@@ -67,21 +73,21 @@ impl<'a> RuleContext<'a> {
     }
   }
 
-  pub fn get_stop_token(&self) -> Option<Token> {
+  pub fn get_stop_token(&self) -> Option<Token<'a>> {
     match self.children.last()? {
       AstContext::Terminal(ctx) => Some(ctx.symbol.clone()),
-      AstContext::Rule(ctx) => ctx.get_start_token(),
+      AstContext::Rule(ctx) => ctx.get_stop_token(),
       AstContext::Error(_) => None,
     }
   }
 
-  pub fn get_terminal(&self, token_type: usize, i: usize) -> Option<&TerminalContext> {
+  pub fn get_terminal(&self, token_type: usize, i: usize) -> Option<&TerminalContext<'a>> {
     let tokens = self.get_terminals(token_type);
     let token = tokens.get(i)?;
     Some(token)
   }
 
-  pub fn get_terminals(&self, token_type: usize) -> Vec<&TerminalContext> { 
+  pub fn get_terminals(&self, token_type: usize) -> Vec<&TerminalContext<'a>> { 
     let mut result = Vec::new();
     for child in self.children.iter() {
       if let AstContext::Terminal(child) = child {
@@ -91,13 +97,13 @@ impl<'a> RuleContext<'a> {
     result
   }
 
-  pub fn get_errornode(&self, i: usize) -> Option<&ErrorContext> { 
+  pub fn get_errornode(&self, i: usize) -> Option<&ErrorContext<'a>> { 
     let errors = self.get_errornodes();
     let error = errors.get(i)?;
     Some(error)
   }
 
-  pub fn get_errornodes(&self) -> Vec<&ErrorContext> { 
+  pub fn get_errornodes(&self) -> Vec<&ErrorContext<'a>> { 
     let mut result = Vec::new();
     for child in self.children.iter() {
       if let AstContext::Error(child) = child {
@@ -108,13 +114,13 @@ impl<'a> RuleContext<'a> {
 
   }
 
-  pub fn get_rule_context(&self, rule_type: usize, index: usize) -> Option<&RuleContext> {  
+  pub fn get_rule_context(&self, rule_type: usize, index: usize) -> Option<&RuleContext<'a>> {  
     let rules = self.get_rule_contexts(rule_type);
     let rule = rules.get(index)?;
     Some(rule)
   }
 
-  pub fn get_rule_contexts(&self, rule_type: usize) -> Vec<&RuleContext> { 
+  pub fn get_rule_contexts(&self, rule_type: usize) -> Vec<&RuleContext<'a>> { 
     let mut result = Vec::new();
     for child in self.children.iter() {
       if let AstContext::Rule(child) = child {
@@ -124,6 +130,38 @@ impl<'a> RuleContext<'a> {
     result
   }
 
+  pub fn get_labeled_rule_context(&self, label: &str, index: usize) -> Option<&RuleContext<'a>> {
+    let rules = self.get_labeled_rule_contexts(label);
+    let rule = rules.get(index)?;
+    Some(rule)
+  }
+
+  pub fn get_labeled_rule_contexts(&self, label: &str) -> Vec<&RuleContext<'a>> {
+    let mut result = Vec::new();
+    for child in self.children.iter() {
+      if let AstContext::Rule(child) = child {
+        if child.element_label == Some(label) { result.push(child) }
+      }
+    }
+    result
+  }
+
+  pub fn get_labeled_terminal(&self, label: &str, index: usize) -> Option<&TerminalContext<'a>> {
+    let terminals = self.get_labeled_terminals(label);
+    let terminal = terminals.get(index)?;
+    Some(terminal)
+  }
+
+  pub fn get_labeled_terminals(&self, label: &str) -> Vec<&TerminalContext<'a>> {
+    let mut result = Vec::new();
+    for child in self.children.iter() {
+      if let AstContext::Terminal(child) = child {
+        if child.element_label == Some(label) { result.push(child) }
+      }
+    }
+    result
+  }
+
   // pub fn get_test<'a>(&self, input: &'a str) -> Option<&'a str> {
   //   if let Some(start) = self.get_start_token() {
   //     if let Some(stop) = self.get_stop_token() {
@@ -140,38 +178,18 @@ impl<'a> RuleContext<'a> {
 
 
 
-  pub fn to_string(&self) -> String {
-    let mut result;
-    if let Some(name) = self.symbol.name {
-      result = format!("({}", name);
-    } else {
-      result = self.symbol.id.to_string();
-    }
-    
-    
-    self.children.iter().for_each(|child| {
-      result += " ";
-      match child {
-        AstContext::Terminal(ctx) => result += &ctx.symbol.terminal.name,
-        AstContext::Rule(ctx) => result += &ctx.to_string(),
-        AstContext::Error(ctx) => result += &ctx.to_string(),
-      }
-    });
-
-    result += ")";
-    result
-  }
-
 }
 
 impl<'a> Display for RuleContext<'a> {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    let mut result = String::new();
-    for child in self.children.iter() {
-      result += &format!("{} ", child);
+    match self.symbol.name {
+      Some(name) => write!(f, "({}", name)?,
+      None => write!(f, "({}", self.symbol.id)?,
     }
 
-    let t = if let Some(name) = self.symbol.name { name.to_string() } else { self.symbol.id.to_string() };
-    write!(f, "{} ({})", t, result)
+    for child in self.children.iter() {
+      write!(f, " {}", child)?;
+    }
+    write!(f, ")")
   }
 }
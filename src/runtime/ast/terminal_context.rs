@@ -6,6 +6,9 @@ use crate::runtime::token::Token;
 #[derive(Clone, Debug)]
 pub struct TerminalContext<'a> {
   pub symbol: Token<'a>,
+
+  // 在产生式中的标签, 如 op=PLUS
+  pub element_label: Option<&'a str>,
 }
 
 impl<'a> TerminalContext<'a> {
@@ -13,12 +16,8 @@ impl<'a> TerminalContext<'a> {
     &self.symbol.text
   }
 
-  pub fn to_string(&self) -> String {
-    self.symbol.terminal.name.to_string()
-  }
-
   pub fn new(symbol: &Token<'a>) -> Self {
-    Self {  symbol: symbol.to_owned(), }
+    Self {  symbol: symbol.to_owned(), element_label: None, }
   }
 }
 
@@ -1,6 +1,4 @@
-
-
-use std::{error, fmt::Display};
+use std::{error, fmt::Display, io, sync::Arc};
 
 use super::location::Location;
 
@@ -10,12 +8,17 @@ pub enum Error {
 
 
   LexerScanOverflow, // 当词法分析器扫描到输入结束的时候，会产生该错误
-  LexerNoMatch(Location), // 所有 token 都匹配不上
-  LexerRecoverFail, // 尝试修复但还是失败
 
 
   TokenStreamOutOfRange, // look_ahead 超出了范围
 
+  ParserRecoverFail, // 语法分析时尝试修复但还是失败
+  SyntaxError(Location, String), // 语法错误的位置和信息, 不进行错误恢复时返回
+
+  GrammarError(Option<Location>, String), // 语法文件中的错误, 如引用了未定义的规则
+
+  Io(Arc<io::Error>), // 读写文件时发生的错误
+
   Unknown, // 未知错误
 }
 
@@ -24,36 +27,67 @@ impl Error {
     Self::TokenStreamOutOfRange
   }
 
-  pub fn lexer_no_match(location: Location) -> Self {
-    Self::LexerNoMatch(location)
-  }
-
   pub fn lexer_scan_overflow() -> Self {
     Self::LexerScanOverflow
   }
 
-}
+  pub fn parser_recover_fail() -> Self {
+    Self::ParserRecoverFail
+  }
 
-impl error::Error for Error {
-  fn source(&self) -> Option<&(dyn error::Error + 'static)> {
-    None
+  pub fn syntax_error(location: Location, message: &str) -> Self {
+    Self::SyntaxError(location, message.to_owned())
   }
 
-  fn description(&self) -> &str {
-    "description() is deprecated; use Display"
+  pub fn grammar_error(location: Option<Location>, message: &str) -> Self {
+    Self::GrammarError(location, message.to_owned())
   }
 
-  fn cause(&self) -> Option<&dyn error::Error> {
-    self.source()
+  // 错误在输入中的位置, 没有位置信息时返回 None
+  pub fn location(&self) -> Option<Location> {
+    match self {
+      Self::SyntaxError(location, _) => Some(*location),
+      Self::GrammarError(location, _) => *location,
+      _ => None,
+    }
   }
-}
 
-impl Display for Error {
-  fn fmt(&self, _f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    todo!()
+  // 不包含位置信息的错误描述
+  pub fn message(&self) -> String {
+    match self {
+      Self::LexerScanOverflow => "lexer reached the end of input".to_owned(),
+      Self::TokenStreamOutOfRange => "token stream out of range".to_owned(),
+      Self::ParserRecoverFail => "parser failed to recover from a syntax error".to_owned(),
+      Self::SyntaxError(_, message) => format!("syntax error: {}", message),
+      Self::GrammarError(_, message) => format!("grammar error: {}", message),
+      Self::Io(_) => "failed to read or write file".to_owned(),
+      Self::Unknown => "unknown error".to_owned(),
+    }
   }
-}
 
+}
 
+impl From<io::Error> for Error {
+  fn from(err: io::Error) -> Self {
+    Self::Io(Arc::new(err))
+  }
+}
 
+impl error::Error for Error {
+  fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+    match self {
+      Self::Io(err) => Some(err.as_ref()),
+      _ => None,
+    }
+  }
+}
 
+// 有位置信息时输出 line:col, 行号和列号从 1 开始
+impl Display for Error {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    if let Some(location) = self.location() {
+      write!(f, "{}:{}: ", location.start.line + 1, location.start.char_position + 1)?;
+    }
+    write!(f, "{}", self.message())
+  }
+}
@@ -1,7 +1,26 @@
+use std::collections::BTreeSet;
+
+use crate::runtime::{ast::error_context::ErrorContext, location::Location, token::Token, vocabulary::{NonTerminal, Terminal}};
 
 
 pub trait ErrorListener {
-  fn syntax_error(&self) {}
+  // 语法分析时发现错误, 每产生一个 redundant 或 missing 错误节点都会调用一次
+  // rule 为出错时正在分析的非终结符 (lalr 分析时为开始符号), offending_token 为出错位置的 token,
+  // location 为错误的位置, expected 为此时期望的终结符集合, message 为可读的错误信息
+  fn syntax_error(&self, _rule: NonTerminal<'_>, _offending_token: &Token<'_>, _location: &Location, _expected: &BTreeSet<Terminal<'_>>, _message: &str) {}
+
+  // 词法分析时遇到无法匹配任何规则的字符, 该字符会被跳过
+  fn lexer_error(&self, _text: &str, _location: &Location) {}
+}
+
+
+// 将期望的终结符集合格式化为 {A, B} 的形式, 只有一个终结符时不加括号
+pub fn format_expected(expected: &BTreeSet<Terminal<'_>>) -> String {
+  let names = expected.iter().map(|terminal| terminal.name).collect::<Vec<_>>();
+  match names.len() {
+    1 => names[0].to_owned(),
+    _ => format!("{{{}}}", names.join(", ")),
+  }
 }
 
 
@@ -11,20 +30,79 @@ impl ErrorListener for BaseErrorListener {
 }
 
 
+// 在标准错误中以 line:col 的形式输出错误, 行号和列号从 1 开始
 pub struct ConsoleErrorListener;
+impl Default for ConsoleErrorListener {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
 impl ConsoleErrorListener {
   pub fn new() -> Self { Self {} }
 }
 
 
 impl ErrorListener for ConsoleErrorListener {
+  fn syntax_error(&self, _rule: NonTerminal<'_>, _offending_token: &Token<'_>, location: &Location, _expected: &BTreeSet<Terminal<'_>>, message: &str) {
+    eprintln!("{}:{} {}", location.start.line + 1, location.start.char_position + 1, message);
+  }
+
+  fn lexer_error(&self, text: &str, location: &Location) {
+    eprintln!("{}:{} token recognition error at: {:?}", location.start.line + 1, location.start.char_position + 1, text);
+  }
 }
 
+
+// 输出更详细的信息, 包括出错的非终结符、token 和期望的终结符集合
 pub struct DiagnosticErrorListener;
 impl ErrorListener for DiagnosticErrorListener {
+  fn syntax_error(&self, rule: NonTerminal<'_>, offending_token: &Token<'_>, location: &Location, expected: &BTreeSet<Terminal<'_>>, message: &str) {
+    let rule = match rule.name {
+      Some(name) => name.to_owned(),
+      None => format!("<{}>", rule.id),
+    };
+    eprintln!("{}:{} {} (rule: {}, token: {}, expected: {})", location.start.line + 1, location.start.char_position + 1,
+      message, rule, offending_token, format_expected(expected));
+  }
+
+  fn lexer_error(&self, text: &str, location: &Location) {
+    eprintln!("{}:{} token recognition error at: {:?} (bytes: {}..{})", location.start.line + 1, location.start.char_position + 1,
+      text, location.byte_index_start, location.byte_index_stop);
+  }
+}
+
+
+// 将错误转发给多个监听器
+#[derive(Default)]
+pub struct ProxyErrorListener {
+  pub delegates: Vec<Box<dyn ErrorListener>>,
+}
+
+impl ProxyErrorListener {
+  pub fn new(delegates: Vec<Box<dyn ErrorListener>>) -> Self {
+    Self { delegates }
+  }
 }
 
-pub struct ProxyErrorListener ;
 impl ErrorListener for ProxyErrorListener {
+  fn syntax_error(&self, rule: NonTerminal<'_>, offending_token: &Token<'_>, location: &Location, expected: &BTreeSet<Terminal<'_>>, message: &str) {
+    for delegate in self.delegates.iter() {
+      delegate.syntax_error(rule, offending_token, location, expected, message);
+    }
+  }
+
+  fn lexer_error(&self, text: &str, location: &Location) {
+    for delegate in self.delegates.iter() {
+      delegate.lexer_error(text, location);
+    }
+  }
 }
 
+
+// 通知所有监听器产生了一个错误节点, token 为出错位置的 token
+pub fn report_error(listeners: &[Box<dyn ErrorListener>], rule: NonTerminal<'_>, token: &Token<'_>, error: &ErrorContext<'_>) {
+  for listener in listeners.iter() {
+    listener.syntax_error(rule, token, &error.location, &error.expected, &error.message);
+  }
+}
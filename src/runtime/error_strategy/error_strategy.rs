@@ -1,22 +1,167 @@
+use std::{collections::{BTreeMap, BTreeSet}, error::Error};
 
+use crate::runtime::{ast::{ast_context::AstContext, error_context::ErrorContext}, error, production::ProductionItem, token::Token, token_stream::TokenStream, vocabulary::{NonTerminal, Terminal}};
+
+use super::error_listener::{report_error, ErrorListener};
+
+
+
+// 错误处理策略在恢复时可以使用的 ll1 分析器的信息
+pub struct Recognizer<'r, 'a> {
+  // 出错时正在分析的非终结符
+  pub rule: NonTerminal<'a>,
+
+  pub table: &'r BTreeMap<(NonTerminal<'a>, Terminal<'a>), usize>,
+  pub sync: &'r BTreeSet<(NonTerminal<'a>, Terminal<'a>)>,
+  pub error_listeners: &'r [Box<dyn ErrorListener>],
+}
+
+impl<'r, 'a> Recognizer<'r, 'a> {
+  // 在分析当前非终结符时能够接受的所有终结符
+  pub fn expected_terminals(&self) -> BTreeSet<Terminal<'a>> {
+    self.table.keys().filter(|(nonterminal, _)| *nonterminal == self.rule).map(|(_, terminal)| *terminal).collect()
+  }
+
+  // 通知所有监听器, 并将错误节点添加到 errors 中
+  pub fn report(&self, token: &Token<'a>, error: ErrorContext<'a>, errors: &mut Vec<AstContext<'a>>) {
+    report_error(self.error_listeners, self.rule, token, &error);
+    errors.push(AstContext::Error(error));
+  }
+}
 
-pub trait ErrorStrategy {
-  fn reset(&self) {}
 
-  fn recover(&self) {}
 
-  fn sync(&self) {}
+// ll1 分析出错时的处理策略, 恢复过程中产生的错误节点都添加到 errors 中
+pub trait ErrorStrategy {
+  // 预测分析表中找不到 (rule, 下一个 token) 对应的产生式时调用
+  // 返回用于展开 rule 的产生式编号, 返回 None 表示整个 rule 都缺失了
+  fn recover<'a>(&self, recognizer: &Recognizer<'_, 'a>, token_stream: &mut TokenStream<'a>, errors: &mut Vec<AstContext<'a>>) -> Result<Option<usize>, Box<dyn Error>>;
 
-  fn report(&self) {}
+  // 期望终结符 expected, 但是下一个 token 与之不匹配时调用, rest 为产生式中位于 expected 之后的部分
+  // 返回 true 表示恢复之后下一个 token 就是 expected, 返回 false 表示 expected 缺失了或者被错误的 token 代替了
+  fn recover_inline<'a>(&self, recognizer: &Recognizer<'_, 'a>, expected: Terminal<'a>, rest: &[ProductionItem<'a>],
+    token_stream: &mut TokenStream<'a>, errors: &mut Vec<AstContext<'a>>) -> Result<bool, Box<dyn Error>>;
 }
 
 
+
+// 遇到第一个错误就返回 Err, 不进行任何恢复
 pub struct BailErrorSrategy;
-impl ErrorStrategy for BailErrorSrategy {}
+impl ErrorStrategy for BailErrorSrategy {
+  fn recover<'a>(&self, recognizer: &Recognizer<'_, 'a>, token_stream: &mut TokenStream<'a>, _errors: &mut Vec<AstContext<'a>>) -> Result<Option<usize>, Box<dyn Error>> {
+    let token = token_stream.peek_next_token()?;
+    let error = ErrorContext::mistake(&token, recognizer.expected_terminals());
+    report_error(recognizer.error_listeners, recognizer.rule, &token, &error);
+    Err(Box::new(error::Error::syntax_error(error.location, &error.message)))
+  }
+
+  fn recover_inline<'a>(&self, recognizer: &Recognizer<'_, 'a>, expected: Terminal<'a>, _rest: &[ProductionItem<'a>],
+    token_stream: &mut TokenStream<'a>, _errors: &mut Vec<AstContext<'a>>) -> Result<bool, Box<dyn Error>> {
+    let token = token_stream.peek_next_token()?;
+    let error = ErrorContext::mistake(&token, BTreeSet::from([expected]));
+    report_error(recognizer.error_listeners, recognizer.rule, &token, &error);
+    Err(Box::new(error::Error::syntax_error(error.location, &error.message)))
+  }
+}
 
 
 
+// 恐慌模式, 一直丢弃 token, 直到遇到能够接受的 token 或者同步集合中的 token 为止
 pub struct DefaultErrorStrategy;
-impl ErrorStrategy for DefaultErrorStrategy {}
+impl ErrorStrategy for DefaultErrorStrategy {
+  fn recover<'a>(&self, recognizer: &Recognizer<'_, 'a>, token_stream: &mut TokenStream<'a>, errors: &mut Vec<AstContext<'a>>) -> Result<Option<usize>, Box<dyn Error>> {
+    let expected = recognizer.expected_terminals();
+    loop {
+      let token = token_stream.peek_next_token()?;
+      if let Some(production_id) = recognizer.table.get(&(recognizer.rule, token.terminal)) {
+        return Ok(Some(*production_id));
+      }
+
+      if recognizer.sync.contains(&(recognizer.rule, token.terminal)) || token.terminal.id == 1 {
+        // 同步 这里表示整个非终结符都缺失了
+        recognizer.report(&token, ErrorContext::missing(&token, expected.clone()), errors);
+        return Ok(None);
+      }
+
+      // 丢弃，将其添加到 error node, 这里认为该 token 是多余的
+      recognizer.report(&token, ErrorContext::redundant(&token, expected.clone()), errors);
+      token_stream.consume()?;
+    }
+  }
 
+  fn recover_inline<'a>(&self, recognizer: &Recognizer<'_, 'a>, expected: Terminal<'a>, _rest: &[ProductionItem<'a>],
+    token_stream: &mut TokenStream<'a>, errors: &mut Vec<AstContext<'a>>) -> Result<bool, Box<dyn Error>> {
+    let expected_set = BTreeSet::from([expected]);
+    loop {
+      let token = token_stream.peek_next_token()?;
+      if token.terminal.id == expected.id { return Ok(true); }
 
+      if token.terminal.id == 1 {
+        recognizer.report(&token, ErrorContext::missing(&token, expected_set.clone()), errors);
+        return Ok(false);
+      }
+
+      recognizer.report(&token, ErrorContext::redundant(&token, expected_set.clone()), errors);
+      token_stream.consume()?;
+    }
+  }
+}
+
+
+
+// 先尝试删除一个多余的 token、插入一个缺失的终结符或者替换一个错误的 token, 都不行时再使用恐慌模式
+// 生成的 ll1 分析器默认使用该策略
+pub struct SingleTokenErrorStrategy;
+impl SingleTokenErrorStrategy {
+  // 如果 expected 缺失, 下一个终结符是否能够出现在 expected 之后
+  fn can_follow<'a>(recognizer: &Recognizer<'_, 'a>, rest: &[ProductionItem<'a>], terminal: Terminal<'a>) -> bool {
+    match rest.first() {
+      Some(ProductionItem::Terminal(next)) => *next == terminal,
+      Some(ProductionItem::NonTerminal(next)) => recognizer.table.contains_key(&(*next, terminal)),
+      None => recognizer.sync.contains(&(recognizer.rule, terminal)),
+    }
+  }
+}
+
+impl ErrorStrategy for SingleTokenErrorStrategy {
+  fn recover<'a>(&self, recognizer: &Recognizer<'_, 'a>, token_stream: &mut TokenStream<'a>, errors: &mut Vec<AstContext<'a>>) -> Result<Option<usize>, Box<dyn Error>> {
+    let token = token_stream.peek_next_token()?;
+
+    // 删除下一个 token 之后就能够选择产生式
+    if let Some(production_id) = recognizer.table.get(&(recognizer.rule, token_stream.look_ahead(2)?.terminal)) {
+      recognizer.report(&token, ErrorContext::redundant(&token, recognizer.expected_terminals()), errors);
+      token_stream.consume()?;
+      return Ok(Some(*production_id));
+    }
+
+    DefaultErrorStrategy.recover(recognizer, token_stream, errors)
+  }
+
+  fn recover_inline<'a>(&self, recognizer: &Recognizer<'_, 'a>, expected: Terminal<'a>, rest: &[ProductionItem<'a>],
+    token_stream: &mut TokenStream<'a>, errors: &mut Vec<AstContext<'a>>) -> Result<bool, Box<dyn Error>> {
+    let token = token_stream.peek_next_token()?;
+    let expected_set = BTreeSet::from([expected]);
+
+    // 单个 token 删除: 下一个 token 是多余的
+    if token.terminal.id != 1 && token_stream.look_ahead(2)?.terminal.id == expected.id {
+      recognizer.report(&token, ErrorContext::redundant(&token, expected_set.clone()), errors);
+      token_stream.consume()?;
+      return Ok(true);
+    }
+
+    // 单个 token 插入: 缺少了 expected
+    if Self::can_follow(recognizer, rest, token.terminal) {
+      recognizer.report(&token, ErrorContext::missing(&token, expected_set.clone()), errors);
+      return Ok(false);
+    }
+
+    // 单个 token 替换: 下一个 token 出现在了 expected 的位置上
+    if token.terminal.id != 1 && Self::can_follow(recognizer, rest, token_stream.look_ahead(2)?.terminal) {
+      recognizer.report(&token, ErrorContext::mistake(&token, expected_set.clone()), errors);
+      token_stream.consume()?;
+      return Ok(false);
+    }
+
+    DefaultErrorStrategy.recover_inline(recognizer, expected, rest, token_stream, errors)
+  }
+}
@@ -3,4 +3,5 @@
 
 
 pub mod error_listener;
+#[allow(clippy::module_inception)]
 pub mod error_strategy;
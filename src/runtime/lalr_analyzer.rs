@@ -0,0 +1,235 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::error::Error;
+
+use super::{ast::{ast_context::AstContext, error_context::ErrorContext, rule_context::RuleContext, terminal_context::TerminalContext}, error, error_strategy::error_listener::{report_error, ErrorListener}, production::Production, token_stream::TokenStream, vocabulary::{NonTerminal, Terminal}};
+
+
+
+// action 表中的元素
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ActionTableElement {
+  Shift(usize),  // 移入, 并转移到对应的状态
+  Reduce(usize), // 使用对应编号的产生式进行归约
+  Accept,        // 接受
+}
+
+
+
+pub fn lalr_analyze<'a>(
+  token_stream: &mut TokenStream<'a>,
+  rule_symbol: NonTerminal<'a>,
+  action_table: &BTreeMap<(usize, Terminal<'a>), ActionTableElement>,
+  goto_table: &BTreeMap<(usize, NonTerminal<'a>), usize>,
+  start_states: &BTreeMap<NonTerminal<'a>, usize>,
+  productions: &BTreeMap<usize, Production<'a>>,
+  error_listeners: &[Box<dyn ErrorListener>],
+) -> Result<RuleContext<'a>, Box<dyn Error>> {
+
+  // 每个命名非终结符都有一个对应的初始状态
+  let start_state = match start_states.get(&rule_symbol) {
+    Some(state) => *state,
+    None => return Err(Box::new(error::Error::Unknown)),
+  };
+
+  // 状态栈
+  let mut states: Vec<usize> = vec![start_state];
+
+  // 节点栈, 和状态栈中除栈底外的每个状态一一对应, 由于存在错误节点, 因此每个元素都是一个节点列表
+  let mut nodes: Vec<Vec<AstContext<'a>>> = Vec::new();
+
+  // 还没有移入的错误节点, 会跟随下一个移入的终结符一起入栈
+  let mut errors: Vec<AstContext<'a>> = Vec::new();
+
+  loop {
+    let state = *states.last().unwrap();
+    let token = token_stream.peek_next_token()?;
+
+    // 处于恢复状态时, 记录需要插入的缺失终结符
+    let mut missing: Option<Terminal<'a>> = None;
+    let action = match action_table.get(&(state, token.terminal)) {
+      Some(action) => *action,
+      None => {
+        if token.terminal.id != 1 {
+          // 丢弃，将其添加到 error node, 这里认为该 token 是多余的
+          let error = ErrorContext::redundant(&token, expected_terminals(state, action_table));
+          report_error(error_listeners, rule_symbol, &token, &error);
+          errors.push(AstContext::Error(error));
+          token_stream.consume()?;
+          continue;
+        }
+
+        // 已经到达输入末尾, 只能通过归约或者插入缺失的符号来恢复, 搜索的规模有上限, 因此不会死循环
+        match recover_at_stop(&states, token.terminal, action_table, goto_table, productions) {
+          Some((action, terminal)) => {
+            missing = Some(terminal);
+            action
+          },
+          None => return Err(Box::new(error::Error::parser_recover_fail())),
+        }
+      },
+    };
+
+    match action {
+      ActionTableElement::Shift(next_state) => {
+        if let Some(terminal) = missing {
+          // 报告实际插入的终结符, 与 ll1 分析中的 missing SEMI 一致
+          let error = ErrorContext::missing(&token, BTreeSet::from([terminal]));
+          report_error(error_listeners, rule_symbol, &token, &error);
+          errors.push(AstContext::Error(error));
+        } else {
+          errors.push(AstContext::Terminal(TerminalContext::new(&token)));
+          token_stream.consume()?;
+        }
+        nodes.push(std::mem::take(&mut errors));
+        states.push(next_state);
+      },
+      ActionTableElement::Reduce(production_id) => {
+        let production = productions.get(&production_id).unwrap();
+        let len = production.right.len();
+
+        states.truncate(states.len() - len);
+        let mut result = RuleContext { symbol: production.left, children: Vec::new(), label: production.label, element_label: None, };
+        for (index, mut group) in nodes.drain(nodes.len() - len..).enumerate() {
+          // 组中最后一个节点才是产生式右部对应的节点, 之前的都是错误节点
+          if let Some(node) = group.last_mut() {
+            node.set_element_label(production.get_element_label(index));
+          }
+
+          for child in group {
+            match child {
+              // 匿名非终结符直接展开, 其标签会传递给展开出来的节点
+              AstContext::Rule(ctx) if ctx.symbol.name.is_none() => {
+                for mut grandchild in ctx.children {
+                  grandchild.set_element_label(ctx.element_label);
+                  result.children.push(grandchild);
+                }
+              },
+              _ => result.children.push(child),
+            }
+          }
+        }
+
+        let state = *states.last().unwrap();
+        let next_state = match goto_table.get(&(state, production.left)) {
+          Some(next_state) => *next_state,
+          None => return Err(Box::new(error::Error::parser_recover_fail())),
+        };
+        states.push(next_state);
+        nodes.push(vec![AstContext::Rule(result)]);
+      },
+      ActionTableElement::Accept => {
+        // 此时节点栈中只剩下开始符号对应的节点, 以及它之前被丢弃的 token
+        let mut result = RuleContext { symbol: rule_symbol, children: Vec::new(), label: None, element_label: None, };
+        for child in nodes.into_iter().flatten().chain(errors) {
+          match child {
+            AstContext::Rule(ctx) if ctx.symbol == rule_symbol => {
+              result.label = ctx.label;
+              result.children.extend(ctx.children);
+            },
+            _ => result.children.push(child),
+          }
+        }
+        return Ok(result);
+      },
+    }
+  }
+}
+
+
+// 在某个状态下能够接受的所有终结符
+fn expected_terminals<'a>(state: usize, action_table: &BTreeMap<(usize, Terminal<'a>), ActionTableElement>) -> BTreeSet<Terminal<'a>> {
+  action_table.keys().filter(|(s, _)| *s == state).map(|(_, terminal)| *terminal).collect()
+}
+
+
+// 恢复时执行的第一个动作, 以及第一个缺失的终结符
+type Recovery<'a> = (ActionTableElement, Terminal<'a>);
+
+// 在输入末尾出错时, 搜索一个最短的缺失终结符序列, 使得插入这些终结符之后能够接受输入
+// 返回此时应该执行的第一个动作以及第一个缺失的终结符, 第一个动作可能是读入该终结符之前的归约
+fn recover_at_stop<'a>(
+  states: &[usize],
+  stop: Terminal<'a>,
+  action_table: &BTreeMap<(usize, Terminal<'a>), ActionTableElement>,
+  goto_table: &BTreeMap<(usize, NonTerminal<'a>), usize>,
+  productions: &BTreeMap<usize, Production<'a>>,
+) -> Option<Recovery<'a>> {
+  // 限制搜索的规模, 防止状态栈无限增长
+  const MAX_VISITED: usize = 4096;
+
+  let mut queue: VecDeque<(Vec<usize>, Option<Recovery<'a>>)> = VecDeque::from([(states.to_vec(), None)]);
+  let mut visited: BTreeSet<Vec<usize>> = BTreeSet::new();
+
+  while let Some((stack, first)) = queue.pop_front() {
+    if first.is_some() && accept_at_stop(stack.clone(), stop, action_table, goto_table, productions) {
+      return first;
+    }
+    if visited.len() >= MAX_VISITED || !visited.insert(stack.clone()) { continue; }
+
+    let state = *stack.last().unwrap();
+    for ((_, terminal), action) in action_table.iter().filter(|((s, terminal), _)| *s == state && *terminal != stop) {
+      if let Some(next_stack) = shift_terminal(stack.clone(), *terminal, action_table, goto_table, productions) {
+        queue.push_back((next_stack, first.or(Some((*action, *terminal)))));
+      }
+    }
+  }
+  None
+}
+
+// 模拟读入一个终结符, 先进行若干次归约, 然后移入该终结符, 返回移入之后的状态栈
+fn shift_terminal<'a>(
+  mut stack: Vec<usize>,
+  terminal: Terminal<'a>,
+  action_table: &BTreeMap<(usize, Terminal<'a>), ActionTableElement>,
+  goto_table: &BTreeMap<(usize, NonTerminal<'a>), usize>,
+  productions: &BTreeMap<usize, Production<'a>>,
+) -> Option<Vec<usize>> {
+  loop {
+    match action_table.get(&(*stack.last()?, terminal))? {
+      ActionTableElement::Shift(next_state) => {
+        stack.push(*next_state);
+        return Some(stack);
+      },
+      ActionTableElement::Reduce(production_id) => reduce_states(&mut stack, *production_id, goto_table, productions)?,
+      ActionTableElement::Accept => return None,
+    }
+  }
+}
+
+// 模拟在输入末尾进行归约, 判断是否能够接受
+fn accept_at_stop<'a>(
+  mut stack: Vec<usize>,
+  stop: Terminal<'a>,
+  action_table: &BTreeMap<(usize, Terminal<'a>), ActionTableElement>,
+  goto_table: &BTreeMap<(usize, NonTerminal<'a>), usize>,
+  productions: &BTreeMap<usize, Production<'a>>,
+) -> bool {
+  loop {
+    let state = match stack.last() {
+      Some(state) => *state,
+      None => return false,
+    };
+    match action_table.get(&(state, stop)) {
+      Some(ActionTableElement::Accept) => return true,
+      Some(ActionTableElement::Reduce(production_id)) => {
+        if reduce_states(&mut stack, *production_id, goto_table, productions).is_none() { return false; }
+      },
+      _ => return false,
+    }
+  }
+}
+
+// 只在状态栈上进行归约
+fn reduce_states<'a>(
+  stack: &mut Vec<usize>,
+  production_id: usize,
+  goto_table: &BTreeMap<(usize, NonTerminal<'a>), usize>,
+  productions: &BTreeMap<usize, Production<'a>>,
+) -> Option<()> {
+  let production = productions.get(&production_id)?;
+  if stack.len() <= production.right.len() { return None; }
+  stack.truncate(stack.len() - production.right.len());
+  let next_state = goto_table.get(&(*stack.last()?, production.left))?;
+  stack.push(*next_state);
+  Some(())
+}
@@ -8,39 +8,36 @@ use std::ops::Range;
 use super::error::Error;
 
 use super::error_strategy::error_listener::ErrorListener;
-use super::lexer_rule::LexerRule;
+use super::lexer_dfa::LexerDfa;
+use super::lexer_rule::{LexerRule, ModeAction};
 use super::location::Location;
 use super::position::Position;
 use super::token::Token;
 
 
 // lexer 都不识别 start 和 stop，所有 start 和 stop 都交给 tokenstream 来添加
-// pub trait Lexer {
-//   fn iter(&self) -> TokenIter ;
+pub trait Lexer {
+  fn iter(&self) -> TokenIter<'_>;
 
 
-//   fn get_all_on_channel_tokens(&self, channel: usize) -> Vec<Token> {
-//     self.iter().filter(|token| token.channel == channel).collect::<Vec<_>>()
-//   }
+  fn get_all_on_channel_tokens(&self, channel: usize) -> Vec<Token<'_>> {
+    self.iter().filter(|token| token.channel == channel).collect::<Vec<_>>()
+  }
 
-//   fn get_all_tokens(&self) -> Vec<Token> {
-//     self.iter().collect::<Vec<_>>()
-//   }
+  fn get_all_tokens(&self) -> Vec<Token<'_>> {
+    self.iter().collect::<Vec<_>>()
+  }
 
-//   fn scan_all_tokens_and_group_by_channel(&mut self) -> HashMap<usize, Vec<Token>> {
-//     let mut ret: HashMap<usize, Vec<Token>> = HashMap::new();
-//     for token in self.iter() {
-//       if ret.contains_key(&token.channel) {
-//         ret.get_mut(&token.channel).unwrap().push(token);
-//       } else {
-//         ret.insert(token.channel, vec![token]);
-//       }
-//     }
-//     ret
-//   }
+  fn scan_all_tokens_and_group_by_channel(&mut self) -> HashMap<usize, Vec<Token<'_>>> {
+    let mut ret: HashMap<usize, Vec<Token>> = HashMap::new();
+    for token in self.iter() {
+      ret.entry(token.channel).or_default().push(token);
+    }
+    ret
+  }
 
 
-// }
+}
 
 
 // 这里不管 start 和 stop，需要 Token_Stream 自己处理
@@ -55,12 +52,14 @@ pub struct TokenIter<'a> {
 
 
   pub rules: &'a [LexerRule],
+  pub dfa: &'a LexerDfa, // 由 rules 构造的自动机
   
 
 
   // 可变内容放入 Iter
   pub cursor: usize, // 字符游标，当前处理到的文本字符序号
   pub token_index: usize, // token 序号，表示当前扫描到了第几个 token
+  pub modes: Vec<usize>, // 模式栈, 只有栈顶模式中的规则参与匹配
 }
 
 
@@ -72,6 +71,10 @@ impl<'a> TokenIter<'a> {
     self.get_position_from_char_index(self.cursor)
   }
 
+  pub fn get_current_mode(&self) -> usize {
+    self.modes.last().copied().unwrap_or(0)
+  }
+
   fn get_position_from_char_index(&self, char_index: usize) -> Position {
     let line = self.ranges.binary_search_by(|range| {
       if range.end <= char_index {
@@ -91,84 +94,82 @@ impl<'a> TokenIter<'a> {
 
 
 
-  // 这个函数只管匹配，匹配不上就报一个 Error。且不会识别到 start 和 stop
-  pub fn lexer_match(&mut self) -> Result<Token, Error> {
-    if self.cursor >= self.input.len() {
-      return Err(Error::lexer_scan_overflow());
-    }
-    
-    // 找到 start 最小的 len 最长的匹配
-    let mut len: usize = 0;
-    let mut start = self.input.len();
-    let mut stop = start;
-
-    let mut meta: Option<LexerRule> = None;
-
-    for lexer_meta in self.rules.iter() {
-      // 为提高效率，可以检查是否匹配
-      if ! lexer_meta.rule.is_match_at(self.input, self.cursor) { continue; }
-
-      let result = lexer_meta.rule.find_at(self.input, self.cursor) ;
-      if let Some(result) = result {
-        if result.start() < start || result.start() == start && result.end() - result.start() > len {
-          meta = Some(lexer_meta.clone());
-          start = result.start();
-          stop = result.end();
-          len = result.end() - result.start();
-        }
+  // 这个函数只管匹配，不会识别到 start 和 stop。扫描到输入末尾时返回 LexerScanOverflow
+  // 匹配不上的字符会报告给错误监听器, 然后跳过该字符继续匹配
+  pub fn lexer_match(&mut self) -> Result<Token<'a>, Error> {
+    loop {
+      if self.cursor >= self.input.len() {
+        return Err(Error::lexer_scan_overflow());
       }
-    }
-
-    // 如果都不匹配，则报错
-    if let None = meta { 
-      let pos = self.get_position_from_char_index(self.cursor);
-      let location = Location::new(pos, pos, self.cursor, self.cursor);
-      return Err(Error::lexer_no_match(location)); 
-    }
 
-
-    // 如果不是在当前位置匹配，那么报告给错误监听器
-    if start != self.cursor {
-      for listener in self.error_listeners.iter() {
-        listener.syntax_error();
+      // 在当前位置进行锚定匹配, 找到最长的匹配, 长度相同时选择优先级高的规则
+      let (index, stop) = match self.dfa.longest_match(self.get_current_mode(), self.input, self.cursor) {
+        Some(result) => result,
+        None => {
+          self.skip_unmatched_char();
+          continue;
+        },
+      };
+      let start = self.cursor;
+      let meta = &self.rules[index];
+
+      // 将对应的文本找出来
+      let text = String::from(&self.input[start..stop]);
+
+
+      let location = Location::new(
+        self.get_position_from_char_index(start),
+        self.get_position_from_char_index(stop),
+        start, stop
+      );
+      let token = Token::new(meta.token_type, &meta.token_name, &text, location, self.token_index, meta.channel);
+
+      self.cursor = stop;
+
+      // 切换词法模式, 默认模式不会被弹出
+      match meta.mode_action {
+        Some(ModeAction::Push(mode)) => self.modes.push(mode),
+        Some(ModeAction::Pop) if self.modes.len() > 1 => { self.modes.pop(); },
+        Some(ModeAction::Set(mode)) => if let Some(last) = self.modes.last_mut() { *last = mode; },
+        _ => {},
       }
-      todo!()
-    }
 
-    // 将对应的文本找出来
-    let text = String::from(&self.input[start..stop]);
 
+      // 如果需要跳过，则继续匹配下一个
+      if meta.skip { continue; }
+
+      self.token_index += 1;
+      return Ok(token);
+    }
+  }
 
+  // 跳过 cursor 处的一个字符, 并将其位置报告给错误监听器
+  fn skip_unmatched_char(&mut self) {
+    let start = self.cursor;
+    let stop = start + self.input[start..].chars().next().map_or(1, |c| c.len_utf8());
     let location = Location::new(
       self.get_position_from_char_index(start),
       self.get_position_from_char_index(stop),
       start, stop
     );
-    let meta = meta.unwrap();
-    let token = Token::new(meta.token_type, &meta.token_name, &text, location, self.token_index, meta.channel);
-
-    self.cursor = stop;
-
 
-    // 如果需要跳过，则返回下一个
-    if meta.skip {
-      return self.lexer_match();
+    for listener in self.error_listeners.iter() {
+      listener.lexer_error(&self.input[start..stop], &location);
     }
-    
-    self.token_index += 1;
-    return Ok(token);
+    self.cursor = stop;
   }
 
 
   pub fn reset(&mut self) {
     self.cursor = 0;
     self.token_index = 1;
+    self.modes = vec![0];
   }
 
 
 
 
-  pub fn new(input: &'a str, rules: &'a [LexerRule], error_listeners: &'a [Box<dyn ErrorListener>]) -> Self {
+  pub fn new(input: &'a str, rules: &'a [LexerRule], dfa: &'a LexerDfa, error_listeners: &'a [Box<dyn ErrorListener>]) -> Self {
     let mut st = 0;
     let ranges = input.split("\n").map(|f| { 
       let ed = st + f.len() + 1; // +1 是为了补上 \n 换行符
@@ -178,21 +179,18 @@ impl<'a> TokenIter<'a> {
     }).collect::<Vec<_>>();
     
     Self {
-      input, rules, error_listeners, cursor: 0, token_index: 1, 
-      ranges,
+      input, rules, dfa, error_listeners, cursor: 0, token_index: 1, 
+      ranges, modes: vec![0],
     }
   }
 }
 
 
-// impl<'a> Iterator for TokenIter<'a> {
-//   type Item = Token<'a>;
+impl<'a> Iterator for TokenIter<'a> {
+  type Item = Token<'a>;
 
-//   fn next(&mut self) -> Option<Self::Item> {
-//     match self.lexer_match() {
-//       Ok(token) => Some(token), 
-//       Err(_) => None,
-//     }
-//   }
-// }
+  fn next(&mut self) -> Option<Self::Item> {
+    self.lexer_match().ok()
+  }
+}
 
@@ -0,0 +1,188 @@
+use std::{collections::BTreeMap, error::Error};
+
+use regex_automata::{dfa::{dense::{self, BuildError}, sparse, Automaton, StartKind}, Anchored, Input, MatchKind, PatternSet};
+
+use super::lexer_rule::LexerRule;
+
+
+// 将所有词法规则合并成的自动机, 每个词法模式一个
+// 可以由词法规则构造, 也可以从 to_bytes 序列化得到的字节中恢复, 生成的词法分析器使用后者, 运行时不需要再编译正则表达式
+#[derive(Debug, Clone)]
+pub struct LexerDfa {
+  modes: BTreeMap<usize, ModeDfa>,
+}
+
+
+#[derive(Debug, Clone)]
+struct ModeDfa {
+  // 包含该模式下所有规则的多模式自动机, 一次扫描就可以找出在当前位置能够匹配的所有规则
+  set: sparse::DFA<Vec<u8>>,
+
+  // 每个模式对应的规则在规则列表中的下标, 以及该规则单独的自动机, 用于求出该规则的匹配长度
+  // 单独的自动机使用 leftmost-first 语义, 因此 .*? 这样的非贪婪匹配和正则表达式中的含义相同
+  rules: Vec<(usize, sparse::DFA<Vec<u8>>)>,
+}
+
+
+impl LexerDfa {
+  // rules 中的顺序就是规则的优先级, 越靠前优先级越高
+  pub fn new(rules: &[LexerRule]) -> Result<Self, Box<dyn Error>> {
+    let mut groups: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    for (index, rule) in rules.iter().enumerate() {
+      groups.entry(rule.mode).or_default().push(index);
+    }
+
+    let mut modes = BTreeMap::new();
+    for (mode, indices) in groups {
+      // 先单独编译每个规则, 这样出错时可以指出是哪一个规则
+      let rule_dfas = indices.iter().map(|index| {
+        let rule = &rules[*index];
+        let dfa = Self::rule_dfa(&rule.pattern)
+          .map_err(|err| format!("lexer rule '{}' cannot be compiled into a DFA: {}", rule.token_name, err))?;
+        Ok((*index, dfa))
+      }).collect::<Result<Vec<_>, Box<dyn Error>>>()?;
+
+      // MatchKind::All 会报告所有能够匹配的模式, 而不是只报告第一个
+      let patterns = indices.iter().map(|index| rules[*index].pattern.as_str()).collect::<Vec<_>>();
+      let set = dense::Builder::new()
+        .configure(dense::Config::new().match_kind(MatchKind::All).start_kind(StartKind::Anchored))
+        .build_many(&patterns)?
+        .to_sparse()?;
+
+      modes.insert(mode, ModeDfa { set, rules: rule_dfas });
+    }
+
+    Ok(Self { modes })
+  }
+
+  // 检查正则表达式能否编译为词法分析使用的 DFA, 语法文件的语义检查使用它, 保证通过检查的规则之后不会编译失败
+  // 返回的错误信息包含 regex_automata 记录在 source 中的详细原因
+  pub fn check_pattern(pattern: &str) -> Result<(), String> {
+    Self::rule_dfa(pattern).map(|_| ())
+  }
+
+  // 单个规则的自动机
+  fn rule_dfa(pattern: &str) -> Result<sparse::DFA<Vec<u8>>, String> {
+    let error = |err: BuildError| {
+      let mut message = err.to_string();
+      let mut source = err.source();
+      while let Some(err) = source {
+        message += &format!(": {}", err);
+        source = err.source();
+      }
+      message
+    };
+    dense::Builder::new()
+      .configure(dense::Config::new().start_kind(StartKind::Anchored))
+      .build(pattern)
+      .map_err(error)?
+      .to_sparse()
+      .map_err(error)
+  }
+
+  // 在 cursor 处进行锚定匹配, 返回最长匹配的规则下标和匹配的结束位置, 长度相同时选择优先级高的规则, 不接受空匹配
+  pub fn longest_match(&self, mode: usize, input: &str, cursor: usize) -> Option<(usize, usize)> {
+    let dfa = self.modes.get(&mode)?;
+    let input = Input::new(input).range(cursor..).anchored(Anchored::Yes);
+
+    let mut candidates = PatternSet::new(dfa.set.pattern_len());
+    dfa.set.try_which_overlapping_matches(&input, &mut candidates).ok()?;
+
+    let mut result: Option<(usize, usize)> = None;
+    for pattern in candidates.iter() {
+      let (index, rule) = &dfa.rules[pattern.as_usize()];
+      let stop = match rule.try_search_fwd(&input) {
+        Ok(Some(m)) if m.offset() > cursor => m.offset(),
+        _ => continue,
+      };
+
+      // 候选规则按照优先级从高到低排列, 只有更长的匹配才会替换之前的结果
+      if result.is_none_or(|(_, end)| stop > end) {
+        result = Some((*index, stop));
+      }
+    }
+    result
+  }
+
+
+  /**
+   * 将自动机序列化为字节, 所有整数都是 4 字节小端序
+   *   模式数量, 然后对于每个模式:
+   *     模式编号, 规则数量, 多模式自动机的长度以及内容,
+   *     然后对于每个规则: 规则下标, 自动机的长度以及内容
+   * 自动机本身使用 regex_automata 的小端序格式, 因此只能在小端序的平台上恢复
+   */
+  pub fn to_bytes(&self) -> Vec<u8> {
+    fn push(result: &mut Vec<u8>, value: usize) {
+      result.extend_from_slice(&(value as u32).to_le_bytes());
+    }
+
+    let mut result = Vec::new();
+    push(&mut result, self.modes.len());
+    for (mode, dfa) in self.modes.iter() {
+      push(&mut result, *mode);
+      push(&mut result, dfa.rules.len());
+
+      let set = dfa.set.to_bytes_little_endian();
+      push(&mut result, set.len());
+      result.extend(set);
+
+      for (index, rule) in dfa.rules.iter() {
+        let rule = rule.to_bytes_little_endian();
+        push(&mut result, *index);
+        push(&mut result, rule.len());
+        result.extend(rule);
+      }
+    }
+    result
+  }
+
+  // 从 to_bytes 得到的字节中恢复自动机, 字节不完整、平台的字节序不同或者 regex_automata 的格式发生变化时返回错误
+  pub fn from_bytes(bytes: &[u8]) -> Result<Self, Box<dyn Error>> {
+    let mut reader = ByteReader { bytes };
+
+    let mut modes = BTreeMap::new();
+    for _ in 0..reader.read_usize()? {
+      let mode = reader.read_usize()?;
+      let rule_count = reader.read_usize()?;
+      let set = reader.read_dfa()?;
+      if set.pattern_len() != rule_count { return Err("malformed lexer DFA table".into()); }
+
+      let mut rules = Vec::new();
+      for _ in 0..rule_count {
+        let index = reader.read_usize()?;
+        rules.push((index, reader.read_dfa()?));
+      }
+      modes.insert(mode, ModeDfa { set, rules });
+    }
+
+    if !reader.bytes.is_empty() { return Err("malformed lexer DFA table".into()); }
+    Ok(Self { modes })
+  }
+}
+
+
+// 按顺序读取 to_bytes 写入的内容
+struct ByteReader<'a> {
+  bytes: &'a [u8],
+}
+
+impl<'a> ByteReader<'a> {
+  fn read(&mut self, len: usize) -> Result<&'a [u8], Box<dyn Error>> {
+    if self.bytes.len() < len { return Err("malformed lexer DFA table".into()); }
+    let (result, rest) = self.bytes.split_at(len);
+    self.bytes = rest;
+    Ok(result)
+  }
+
+  fn read_usize(&mut self) -> Result<usize, Box<dyn Error>> {
+    let bytes = self.read(4)?;
+    Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize)
+  }
+
+  fn read_dfa(&mut self) -> Result<sparse::DFA<Vec<u8>>, Box<dyn Error>> {
+    let len = self.read_usize()?;
+    let (dfa, _) = sparse::DFA::from_bytes(self.read(len)?)?;
+    Ok(dfa.to_owned())
+  }
+}
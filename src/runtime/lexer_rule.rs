@@ -1,15 +1,27 @@
-
-use regex::Regex;
-
 // 这个是给 runtime 用的
 #[derive(Debug, Clone)]
 pub struct LexerRule {
   pub token_type: usize,
   pub token_name: String,
-  pub rule: Regex,
+  // 正则表达式, 由 LexerDfa 编译为自动机
+  pub pattern: String,
 
   pub channel: usize,
   pub skip: bool,
+
+  // 该规则所属的词法模式, 默认模式为 0
+  pub mode: usize,
+  // 匹配之后对模式栈进行的操作
+  pub mode_action: Option<ModeAction>,
+}
+
+
+// 词法模式的切换命令, 对应 @push_mode(X) @pop_mode @mode(X)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ModeAction {
+  Push(usize), // 将模式压入模式栈
+  Pop,         // 弹出栈顶的模式
+  Set(usize),  // 将栈顶的模式替换为指定的模式
 }
 
 
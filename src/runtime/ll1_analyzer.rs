@@ -1,87 +1,136 @@
 use std::{collections::{BTreeMap, BTreeSet}, error::Error};
 
-use super::{ast::{ast_context::AstContext, error_context::ErrorContext, rule_context::RuleContext, terminal_context::TerminalContext}, error_strategy::error_listener::ErrorListener, production::{Production, ProductionItem}, token_stream::TokenStream, vocabulary::{NonTerminal, Terminal}};
+use super::{ast::{ast_context::AstContext, rule_context::RuleContext, terminal_context::TerminalContext}, error_strategy::{error_listener::ErrorListener, error_strategy::{ErrorStrategy, Recognizer}}, production::{Production, ProductionItem}, token_stream::TokenStream, vocabulary::{NonTerminal, Terminal}};
 
 
 
 
+// 从 rule_symbol 开始分析, 分析完毕之后必须到达输入末尾
+#[allow(clippy::too_many_arguments)]
 pub fn ll1_analyze<'a>(
-  token_stream: &'a mut TokenStream<'a>, 
+  token_stream: &mut TokenStream<'a>, 
   rule_symbol: NonTerminal<'a>, 
-  table:  &'a BTreeMap<(NonTerminal<'a>, Terminal<'a>), usize>,
-  productions: &'a BTreeMap<usize, Production<'a>>,
-  sync: &'a BTreeSet<(NonTerminal, Terminal)>,
-  error_listeners: &'a [Box<dyn ErrorListener>],
+  table: &BTreeMap<(NonTerminal<'a>, Terminal<'a>), usize>,
+  productions: &BTreeMap<usize, Production<'a>>,
+  sync: &BTreeSet<(NonTerminal<'a>, Terminal<'a>)>,
+  left_recursion_tails: &BTreeMap<NonTerminal<'a>, NonTerminal<'a>>,
+  error_listeners: &[Box<dyn ErrorListener>],
+  error_strategy: &dyn ErrorStrategy,
 ) -> Result<RuleContext<'a>, Box<dyn Error>> {
+  let mut result = analyze_rule(token_stream, rule_symbol, table, productions, sync, left_recursion_tails, error_listeners, error_strategy)?;
 
-  // 获取名称
-  let mut result = RuleContext { symbol: rule_symbol, children: Vec::new(), };
+  // 剩余的 token 交给错误处理策略, 期望的是 _STOP
+  // 开始符号之后只能是 _STOP, 因此不使用同步集合, 错误处理策略会一直丢弃 token 直到输入末尾
+  if token_stream.peek_next_token()?.terminal.id != 1 {
+    let recognizer = Recognizer { rule: rule_symbol, table, sync: &BTreeSet::new(), error_listeners };
+    error_strategy.recover_inline(&recognizer, Terminal::new("_STOP", 1), &[], token_stream, &mut result.children)?;
+  }
+  Ok(result)
+}
 
-  // 获取 production_id
-  let production_id = loop {
-    // 先查看一下下一个token是什么
-    let token = token_stream.peek_next_token()?;
 
-    if let Some(production_id) = table.get(&(rule_symbol, token.terminal)) {
-      break *production_id;
-    }
-    else if sync.contains(&(rule_symbol, token.terminal)) {
-      // 同步 这里表示整个非终结符都缺失了
-      result.children.push(AstContext::Error( ErrorContext::missing() ));
-      return Ok(result);
-    }
-    else {
-      // 丢弃，将其添加到 error node, 这里认为该 token 是多余的
-      // println!("redundant");
-      result.children.push(AstContext::Error( ErrorContext::redundant(&token)  ));
-      // 消耗掉该 token
-      token_stream.consume()?;
-    }
+#[allow(clippy::too_many_arguments)]
+fn analyze_rule<'a>(
+  token_stream: &mut TokenStream<'a>, 
+  rule_symbol: NonTerminal<'a>, 
+  table: &BTreeMap<(NonTerminal<'a>, Terminal<'a>), usize>,
+  productions: &BTreeMap<usize, Production<'a>>,
+  sync: &BTreeSet<(NonTerminal<'a>, Terminal<'a>)>,
+  left_recursion_tails: &BTreeMap<NonTerminal<'a>, NonTerminal<'a>>,
+  error_listeners: &[Box<dyn ErrorListener>],
+  error_strategy: &dyn ErrorStrategy,
+) -> Result<RuleContext<'a>, Box<dyn Error>> {
+
+  // 获取名称
+  let mut result = RuleContext { symbol: rule_symbol, children: Vec::new(), label: None, element_label: None, };
+
+  let recognizer = Recognizer { rule: rule_symbol, table, sync, error_listeners };
+
+  // 获取 production_id, 找不到时交给错误处理策略
+  let token = token_stream.peek_next_token()?;
+  let production_id = match table.get(&(rule_symbol, token.terminal)) {
+    Some(production_id) => *production_id,
+    None => match error_strategy.recover(&recognizer, token_stream, &mut result.children)? {
+      Some(production_id) => production_id,
+      // 这里表示整个非终结符都缺失了
+      None => return Ok(result),
+    },
   };
   
   let production = productions.get(&production_id).unwrap();
-  
+  result.label = production.label;
 
   
   
-  for child in production.right.iter() {
+  for (index, child) in production.right.iter().enumerate() {
+    let element_label = production.get_element_label(index);
 
     match child {
       ProductionItem::NonTerminal(nonterminal) => {
-        let t = ll1_analyze(token_stream, *nonterminal, table, productions,  sync, error_listeners)?;
-        if let Some(_) = nonterminal.name {
+        let mut t = analyze_rule(token_stream, *nonterminal, table, productions,  sync, left_recursion_tails, error_listeners, error_strategy)?;
+        // 消除左递归引入的非终结符先保留嵌套, 等到其所属的非终结符分析完毕之后再折叠
+        if nonterminal.name.is_some() || left_recursion_tails.contains_key(nonterminal) {
+          t.element_label = element_label;
           result.children.push(AstContext::Rule(t));
         }
         else {
-          result.children.extend(t.children);
+          // 提取左公因子之后, 标签记录在匿名非终结符的产生式上
+          result.label = result.label.or(t.label);
+          for mut grandchild in t.children {
+            grandchild.set_element_label(element_label);
+            result.children.push(grandchild);
+          }
         }
       },
       ProductionItem::Terminal(token_type) => {
-        let mut token = token_stream.peek_next_token()?;
-        while token_type.id != token.terminal.id && token.terminal.id != 1 {
-          result.children.push(AstContext::Error( ErrorContext::redundant(&token) ));
-          token_stream.consume()?; // 是在这里报的错
-          token = token_stream.peek_next_token()?;
+        if token_stream.peek_next_token()?.terminal.id != token_type.id
+          && !error_strategy.recover_inline(&recognizer, *token_type, &production.right[index+1..], token_stream, &mut result.children)? {
+          // 缺失或者被替换的符号已经由错误处理策略记录, 到达输入末尾时不再继续匹配
+          if token_stream.peek_next_token()?.terminal.id == 1 { break; }
+          continue;
         }
 
-        if token.terminal.id == 1 {
-          result.children.push(AstContext::Error(ErrorContext::missing()));
-          break;
-        }
-
-
-
+        let token = token_stream.peek_next_token()?;
         // 匹配了
-        result.children.push(AstContext::Terminal(TerminalContext { symbol: token.clone() }));
+        result.children.push(AstContext::Terminal(TerminalContext { symbol: token.clone(), element_label }));
         // 消耗掉
         token_stream.consume()?;
       },
     }
   }
+
+  // 将属于当前非终结符的左递归尾部折叠为左结合的语法树
+  if let Some(AstContext::Rule(ctx)) = result.children.last() {
+    if left_recursion_tails.get(&ctx.symbol) == Some(&rule_symbol) {
+      if let Some(AstContext::Rule(tail)) = result.children.pop() {
+        result = fold_left_recursion(result, tail);
+      }
+    }
+  }
   Ok(result)
 }
 
 
+// 将消除左递归时引入的非终结符展开的结果折叠起来, A' 的每一次非空展开都会将之前的结果作为新的 A 节点的第一个孩子
+// 原产生式中左递归位置的标签记录在末尾的 A' 上, 折叠时转交给之前的结果
+fn fold_left_recursion<'a>(mut result: RuleContext<'a>, tail: RuleContext<'a>) -> RuleContext<'a> {
+  let mut children = tail.children;
+  let mut next = match children.pop() {
+    Some(AstContext::Rule(ctx)) if ctx.symbol == tail.symbol => Some(ctx),
+    Some(child) => { children.push(child); None },
+    None => None,
+  };
 
+  if !children.is_empty() {
+    if result.symbol.name.is_some() {
+      result.element_label = next.as_mut().and_then(|next| next.element_label.take());
+      result = RuleContext { symbol: result.symbol, children: vec![AstContext::Rule(result)], label: tail.label, element_label: None, };
+    }
+    result.children.extend(children);
+  }
 
-
+  match next {
+    Some(next) => fold_left_recursion(result, next),
+    None => result,
+  }
+}
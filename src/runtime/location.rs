@@ -1,10 +1,12 @@
 
+use serde::Serialize;
+
 use super::position::Position;
 
 
 
 // 用于描述 token 或者 error 的位置
-#[derive(Clone, Debug, Default, Copy)]
+#[derive(Clone, Debug, Default, Copy, Serialize)]
 pub struct Location {
 
   // 起始位置和结束位置，左闭右开，行号从0开始编号
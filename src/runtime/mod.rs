@@ -10,7 +10,9 @@ pub mod vocabulary;
 pub mod error_strategy;
 pub mod production;
 pub mod lexer_rule;
+pub mod lexer_dfa;
 pub mod ll1_analyzer;
+pub mod lalr_analyzer;
 
 
 
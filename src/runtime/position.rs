@@ -1,8 +1,10 @@
 
 use std::fmt::Display;
 
+use serde::Serialize;
 
-#[derive(Clone, Debug, Copy, Default)]
+
+#[derive(Clone, Debug, Copy, Default, Serialize)]
 pub struct Position {
   pub line: usize, 
   pub char_position: usize,
@@ -13,10 +15,6 @@ impl Position {
     Self { line, char_position, }
   }
 
-  pub fn to_string(&self) -> String {
-    format!("line: {}, position: {}", self.line, self.char_position)
-  }
-
 }
 
 
@@ -14,6 +14,8 @@ pub struct Production<'a> {
   pub id: usize,                  // 添加一个产生式的编号
   pub left: NonTerminal<'a>,                // 产生式左部
   pub right: Vec<ProductionItem<'a>>,
+  pub label: Option<&'a str>,     // 备选分支的标签, 如 expr: expr PLUS expr # Add;
+  pub element_labels: Vec<Option<&'a str>>, // 右部每个元素的标签, 如 lhs=expr, 和 right 一一对应
 }
 
 impl<'a> Production<'a> {
@@ -22,13 +24,55 @@ impl<'a> Production<'a> {
       id,
       left,
       right: right.to_vec(),
+      label: None,
+      element_labels: vec![None; right.len()],
+    }
+  }
+
+  // 设置产生式所对应的备选分支的标签
+  pub fn with_label(mut self, label: Option<&'a str>) -> Self {
+    self.label = label;
+    self
+  }
+
+  // 设置右部元素的标签
+  pub fn with_element_labels(mut self, element_labels: &[Option<&'a str>]) -> Self {
+    self.element_labels = element_labels.to_vec();
+    self
+  }
+
+  // 获取右部第 index 个元素的标签
+  pub fn get_element_label(&self, index: usize) -> Option<&'a str> {
+    self.element_labels.get(index).copied().flatten()
+  }
+}
+
+impl<'a> Display for ProductionItem<'a> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      ProductionItem::NonTerminal(NonTerminal { name: Some(name), .. }) => write!(f, "{}", name),
+      ProductionItem::NonTerminal(NonTerminal { name: None, id }) => write!(f, "<{}>", id),
+      ProductionItem::Terminal(terminal) => write!(f, "{}", terminal.name),
     }
   }
 }
 
 impl<'a> Display for Production<'a> {
-  fn fmt(&self, _f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    todo!()
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}: {} ->", self.id, ProductionItem::NonTerminal(self.left))?;
+    if self.right.is_empty() {
+      return write!(f, " ε");
+    }
+    for (index, item) in self.right.iter().enumerate() {
+      match self.get_element_label(index) {
+        Some(label) => write!(f, " {}={}", label, item)?,
+        None => write!(f, " {}", item)?,
+      }
+    }
+    if let Some(label) = self.label {
+      write!(f, " # {}", label)?;
+    }
+    Ok(())
   }
 }
 
@@ -73,19 +73,6 @@ impl<'a> Token<'a> {
   }
 
 
-  pub fn to_string(&self) -> String {
-    format!("[@{}, {}:{}='{}', <{}>, <{}>, start: <{}>, stop: <{}>]", 
-      self.token_index, 
-      self.location.byte_index_start, 
-      self.location.byte_index_stop,
-      self.text,
-      self.terminal.name,
-      self.terminal.id,
-      self.location.start,
-      self.location.stop,
-    )
-  }
-
 }
 
 
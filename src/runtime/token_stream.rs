@@ -1,7 +1,7 @@
 
 use std::collections::VecDeque;
 
-use super::lexer::TokenIter;
+use super::lexer::{Lexer, TokenIter};
 use super::location::Location;
 use super::token::Token;
 use super::error::Error;
@@ -28,7 +28,7 @@ pub struct TokenStream<'a> {
 impl<'a> TokenStream<'a> {
 
   // 消耗掉 next_token, 并返回 next token 。
-  pub fn consume(&mut self) -> Result<Token, Error> {
+  pub fn consume(&mut self) -> Result<Token<'a>, Error> {
     // previous token 是 stop, next token 是 None, 表示已经消耗了 stop, 此时应该返回 None
 
     if let Some(token) = self.next_token.clone() {
@@ -36,7 +36,7 @@ impl<'a> TokenStream<'a> {
 
       // 更新 previous token
       self.previous_token = self.next_token.clone();
-      if self.cached_tokens.len() > 0 {
+      if !self.cached_tokens.is_empty() {
         // 如果缓存中还有token
         self.next_token = self.cached_tokens.pop_front();
       } else if token.terminal.id == 1 {
@@ -44,26 +44,7 @@ impl<'a> TokenStream<'a> {
         self.next_token = None;
       } else {
 
-        let next_token = loop {
-          let next_token = match self.iter.lexer_match() {
-            Ok(next_token) => next_token,
-
-            // 如果 lexer 扫描不到 token，则将 next_token 设置为 stop 。
-            Err(err) => { 
-              match err {
-                Error::LexerScanOverflow => {
-                  let location = Location::new(self.iter.get_current_position(), self.iter.get_current_position(), self.iter.cursor, self.iter.cursor);
-                  // 添加 stop token
-                  Token::new(1, "_STOP", "_STOP", location,self.iter.token_index, self.channel)
-                },
-                _ => { return Err(err); }
-              }
-            },
-          };
-          if next_token.channel == self.channel {
-            break next_token;
-          }
-        };
+        let next_token = self.scan_next_token()?;
         self.next_token = Some(next_token);
       }
       
@@ -74,43 +55,56 @@ impl<'a> TokenStream<'a> {
     }
   }
 
-  pub fn look_ahead(&mut self, n: usize) -> Result<Token, Error> {
-    if n <= 0 {
+  pub fn look_ahead(&mut self, n: usize) -> Result<Token<'a>, Error> {
+    if n == 0 {
       // 至少向前看一个 token
-      return Err(Error::Unknown);
+      Err(Error::Unknown)
     }
     else if n == 1 {
-      return self.peek_next_token();
+      self.peek_next_token()
     }
     else {
-      let n = n - 2;
-      if n >= self.cached_tokens.len() {
-        // 需要再获取 n - len + 1 个 token
-        for _ in 0..(n - self.cached_tokens.len() + 2) {
-          let next_token = loop {
-            let next_token = self.iter.lexer_match()?;
-            if next_token.channel == self.channel {
-              break next_token;
-            }
-          };
-
-          self.cached_tokens.push_back(next_token);
+      // 第 n 个 token 位于缓冲队列中的第 n - 2 个位置
+      while self.cached_tokens.len() < n - 1 {
+        // 已经扫描到了 stop, 之后的 token 都认为是 stop
+        if let Some(last) = self.cached_tokens.back().or(self.next_token.as_ref()) {
+          if last.terminal.id == 1 { return Ok(last.clone()); }
         }
-        
-      } 
-      
-      return Ok(self.cached_tokens[n].clone());
+
+        let next_token = self.scan_next_token()?;
+        self.cached_tokens.push_back(next_token);
+      }
+
+      Ok(self.cached_tokens[n - 2].clone())
+    }
+  }
+
+  // 从词法分析器中获取下一个属于该频道的 token, 扫描到输入末尾时返回 stop
+  fn scan_next_token(&mut self) -> Result<Token<'a>, Error> {
+    loop {
+      let next_token = match self.iter.lexer_match() {
+        Ok(next_token) => next_token,
+        Err(Error::LexerScanOverflow) => {
+          let location = Location::new(self.iter.get_current_position(), self.iter.get_current_position(), self.iter.cursor, self.iter.cursor);
+          // 添加 stop token
+          return Ok(Token::new(1, "_STOP", "_STOP", location, self.iter.token_index, self.channel));
+        },
+        Err(err) => return Err(err),
+      };
+      if next_token.channel == self.channel {
+        return Ok(next_token);
+      }
     }
   }
 
-  pub fn peek_next_token(&self) -> Result<Token, Error> {
+  pub fn peek_next_token(&self) -> Result<Token<'a>, Error> {
     match &self.next_token {
       Some(next_token) => Ok(next_token.clone()),
       None => Err(Error::token_stream_out_of_range()),
     }
   }
 
-  pub fn peek_previous_token(&self) -> Result<Token, Error> {
+  pub fn peek_previous_token(&self) -> Result<Token<'a>, Error> {
     match &self.previous_token {
       Some(previous_token) => Ok(previous_token.clone()),
       None => Err(Error::token_stream_out_of_range()),
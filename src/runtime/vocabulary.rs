@@ -45,6 +45,12 @@ pub struct Vocabulary<'a> {
   terminal_id_map: BTreeMap<usize, Terminal<'a>>,
 }
 
+impl<'a> Default for Vocabulary<'a> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
 impl<'a> Vocabulary<'a> {
   pub fn new() -> Self {
     Self {
@@ -118,7 +124,7 @@ impl<'a> Vocabulary<'a> {
   }
 
   pub fn get_all_named_nonterminals(&self) -> Vec<NonTerminal<'a>> {
-    let result = self.nonterminals.iter().filter(|item| item.name != None).cloned().collect();
+    let result = self.nonterminals.iter().filter(|item| item.name.is_some()).cloned().collect();
     result
   }
 
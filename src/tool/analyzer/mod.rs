@@ -1,8 +1,8 @@
-use std::{collections::{BTreeMap, HashMap, HashSet}, error::Error};
+use std::{collections::{BTreeMap, BTreeSet}, error::Error};
 
-use chiru::runtime::{ast::rule_context::RuleContext, error_strategy::error_listener::{ConsoleErrorListener, ErrorListener}, lexer::{Lexer, TokenIter}, lexer_rule::LexerRule, ll1_analyzer::ll1_analyze, production::Production, token_stream::TokenStream, vocabulary::{NonTerminal, Terminal}};
+use chiru::runtime::{ast::rule_context::RuleContext, error_strategy::{error_listener::{ConsoleErrorListener, ErrorListener}, error_strategy::{ErrorStrategy, SingleTokenErrorStrategy}}, lalr_analyzer::lalr_analyze, lexer::{Lexer, TokenIter}, lexer_dfa::LexerDfa, lexer_rule::LexerRule, ll1_analyzer::ll1_analyze, production::Production, token_stream::TokenStream, vocabulary::{NonTerminal, Terminal}};
 
-use super::grammar::Grammar;
+use super::{cli::Analyzer, grammar::{lexer_rule::strip_raw_literal, utils::LalrTable, Grammar}};
 
 
 
@@ -12,38 +12,43 @@ pub struct CommonLexer<'a> {
 
   // 只需要自定义词法分析规则即可
   pub rules: Vec<LexerRule>,
+  pub dfa: LexerDfa,
 }
 
 impl Lexer for CommonLexer<'_> {
-  fn iter(&self) -> chiru::runtime::lexer::TokenIter {
-    TokenIter::new(self.input, &self.rules, &self.error_listeners)
+  fn iter(&self) -> TokenIter<'_> {
+    TokenIter::new(self.input, &self.rules, &self.dfa, &self.error_listeners)
   }
 }
 
 
 impl<'a> CommonLexer<'a> {
-  pub fn from_grammar(grammar: &Grammar, input: &'a str) -> Self {
-    let mut rules = grammar.lexer_rule_map.values().map(|v| {
-      let re = regex::Regex::new(r####"(^r#*")|("#*$)"####).unwrap();
-      let c = re.replace_all(&v.regex, "");
-      let rule = regex::Regex::new(&c).unwrap();
-      return LexerRule {
+  // 正则表达式无法编译为 DFA 时返回错误, 如使用了 Unicode 单词边界 \b 的规则
+  pub fn from_grammar(grammar: &Grammar, input: &'a str) -> Result<Self, Box<dyn Error>> {
+    // 规则的顺序就是匹配的优先级
+    let mut lexer_rules = grammar.lexer_rule_map.values().collect::<Vec<_>>();
+    lexer_rules.sort_by_key(|rule| rule.priority);
+    let rules = lexer_rules.into_iter().map(|v| {
+      LexerRule {
         channel: v.channel,
         token_type: v.token_type,
         token_name: v.token_name.to_owned(),
         skip: v.skip,
-        rule
-      };
+        mode: v.mode,
+        mode_action: v.mode_action,
+        pattern: strip_raw_literal(&v.regex),
+      }
 
     }).collect::<Vec<_>>();
   
-    rules.sort_by(|a, b| a.token_type.cmp(&b.token_type));
+    let dfa = LexerDfa::new(&rules)?;
     
-    Self {
+    Ok(Self {
       input, 
       rules,
+      dfa,
       error_listeners: vec![Box::new(ConsoleErrorListener::new())],
-    }
+    })
   }
 }
 
@@ -51,63 +56,63 @@ impl<'a> CommonLexer<'a> {
 
 pub struct CommonParser<'a> {
   pub error_listeners: Vec<Box<dyn ErrorListener>>,
+  // 只用于 ll1 分析
+  pub error_strategy: Box<dyn ErrorStrategy>,
+  pub analyzer: Analyzer,
+  pub productions: BTreeMap<usize, Production<'a>>,
+
+  // ll1 分析需要的预测分析表和同步集合
   pub table: BTreeMap<(NonTerminal<'a>, Terminal<'a>), usize>,
-  pub productions: HashMap<usize, Production<'a>>,
-  pub nonterminals: HashMap<usize, String>,
-  pub sync: HashSet<(usize, usize)>,
+  pub sync: BTreeSet<(NonTerminal<'a>, Terminal<'a>)>,
+  pub left_recursion_tails: BTreeMap<NonTerminal<'a>, NonTerminal<'a>>,
+
+  // lalr 分析需要的 action 表和 goto 表
+  pub lalr_table: LalrTable<'a>,
 }
 
 impl<'a> CommonParser<'a> {
-  pub fn parse(&self, token_stream: &mut TokenStream, rule_index: usize) -> Result<RuleContext, Box<dyn Error>> {
-    if token_stream.peek_next_token()?.token_type == 0 {
+  pub fn parse(&self, token_stream: &mut TokenStream<'a>, rule_symbol: NonTerminal<'a>) -> Result<RuleContext<'a>, Box<dyn Error>> {
+    if token_stream.peek_next_token()?.terminal.id == 0 {
       token_stream.consume()?;
     }
 
-    ll1_analyze(token_stream, rule_index, &self.table, &self.productions, &self.nonterminals, &self.sync, &self.error_listeners)
+    match self.analyzer {
+      Analyzer::LL1 => ll1_analyze(token_stream, rule_symbol, &self.table, &self.productions, &self.sync, &self.left_recursion_tails, &self.error_listeners, self.error_strategy.as_ref()),
+      Analyzer::LALR => lalr_analyze(token_stream, rule_symbol, 
+        &self.lalr_table.action, &self.lalr_table.goto, &self.lalr_table.start_states, &self.productions, &self.error_listeners),
+    }
   }
 
 
 
-  pub fn from_grammar(grammar: &Grammar) -> Self {
-    let (first, first_set) = grammar.first_set();
-  
-    let follow = grammar.follow_set(&first);
-  
-    let table = grammar.ll1_table(&first_set, &follow);
-  
-
+  pub fn from_grammar(grammar: &Grammar<'a>, analyzer: Analyzer) -> Self {
     let productions = grammar.productions.clone();
-
-    let mut sync: HashSet<(NonTerminal, Terminal)> = HashSet::new();
-    // 根据 follow 集合来生成 sync
-    for (id, followers) in follow.iter() {
-      for x in followers.iter() {
-        sync.insert((*id, *x));
-      }
+    let mut table = BTreeMap::new();
+    let mut sync = BTreeSet::new();
+    let mut lalr_table = LalrTable { action: BTreeMap::new(), goto: BTreeMap::new(), start_states: BTreeMap::new(), conflicts: Vec::new() };
+
+    match analyzer {
+      Analyzer::LL1 => {
+        let (first, first_set) = grammar.first_set();
+        let follow = grammar.follow_set(&first);
+        (table, _) = grammar.ll1_table(&first_set, &follow);
+
+        // 根据 follow 集合来生成 sync
+        for (nonterminal, followers) in follow.iter() {
+          for terminal in followers.iter() {
+            sync.insert((*nonterminal, *terminal));
+          }
+        }
+      },
+      Analyzer::LALR => {
+        // 生成 action 和 goto 表格, 有了这两个表格, 就可以进行 lalr 分析了
+        lalr_table = grammar.lalr_table();
+      },
     }
 
-    let nonterminals = grammar.vocabulary.nonterminals.iter().map(|item| {
-      (item.id, item.name.clone())
-    }).collect();
-
-
-
-    // 生成 action 和 goto 表格, 有了这两个表格, 就可以进行 lalr 分析了
     Self {
-      table, productions, sync, nonterminals, error_listeners: vec![Box::new(ConsoleErrorListener::new())],
+      table, productions, sync, lalr_table, left_recursion_tails: grammar.left_recursion_tails.clone(), analyzer, error_listeners: vec![Box::new(ConsoleErrorListener::new())],
+      error_strategy: Box::new(SingleTokenErrorStrategy),
     }
   }
 }
-
-
-
-
-
-
-
-
-
-
-
-
-
@@ -0,0 +1,156 @@
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use ariadne::{sources, Label, Report, ReportKind, Source};
+use chiru::runtime::error;
+use chiru::runtime::error_strategy::error_listener::{format_expected, ErrorListener};
+use chiru::runtime::location::Location;
+use chiru::runtime::token::Token;
+use chiru::runtime::vocabulary::{NonTerminal, Terminal};
+
+use crate::tool::grammar::diagnostic::{Diagnostic, Severity, Span};
+
+
+// ariadne 使用字符下标, 而 location 记录的是字节下标, 缺失的符号长度为 0, 这里至少标出一个字符
+// 超出源码末尾的范围会导致 ariadne panic, 位于末尾的错误标在最后一个字符上, 源码为空时标出空的范围
+fn char_span(source: &str, location: &Location) -> std::ops::Range<usize> {
+  let len = source.chars().count();
+  let char_index = |index: usize| source[..index.min(source.len())].chars().count();
+  let start = char_index(location.byte_index_start).min(len.saturating_sub(1));
+  let stop = char_index(location.byte_index_stop).max(start + 1).min(len);
+  start..stop
+}
+
+// 在源码片段上标出错误的位置
+fn eprint_report(file_name: &str, source: &str, location: &Location, message: &str, label: &str) {
+  let span = char_span(source, location);
+  let report = Report::build(ReportKind::Error, file_name, span.start);
+  // 源码为空时没有可以标出的字符, 只输出错误信息和文件名
+  let report = if span.is_empty() { report.with_message(format!("{} in empty file '{}'", message, file_name)) }
+    else { report.with_message(message).with_label(Label::new((file_name, span)).with_message(label)) };
+  report
+    .finish()
+    .eprint((file_name, Source::from(source)))
+    .ok();
+}
+
+
+// 使用 ariadne 在源码片段上输出词法和语法错误
+pub struct AriadneErrorListener {
+  pub file_name: String,
+  pub source: String,
+}
+
+impl AriadneErrorListener {
+  pub fn new(file_name: &str, source: &str) -> Self {
+    Self { file_name: file_name.to_owned(), source: source.to_owned() }
+  }
+}
+
+impl ErrorListener for AriadneErrorListener {
+  fn syntax_error(&self, _rule: NonTerminal<'_>, _offending_token: &Token<'_>, location: &Location, expected: &BTreeSet<Terminal<'_>>, message: &str) {
+    let label = format!("expecting {}", format_expected(expected));
+    eprint_report(&self.file_name, &self.source, location, message, &label);
+  }
+
+  fn lexer_error(&self, text: &str, location: &Location) {
+    let message = format!("token recognition error at: {:?}", text);
+    eprint_report(&self.file_name, &self.source, location, &message, "no lexer rule matches here");
+  }
+}
+
+
+// 已经在源码片段上输出过的错误, main 中不再重复输出
+#[derive(Debug)]
+pub struct ReportedError(pub Box<dyn Error>);
+
+impl Display for ReportedError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    self.0.fmt(f)
+  }
+}
+
+impl Error for ReportedError {
+  fn source(&self) -> Option<&(dyn Error + 'static)> {
+    self.0.source()
+  }
+}
+
+
+// 带有位置信息的错误在源码片段上输出, 返回的错误由调用者继续传递
+pub fn render_error(file_name: &str, source: &str, err: Box<dyn Error>) -> Box<dyn Error> {
+  if let Some(runtime_error) = err.downcast_ref::<error::Error>() {
+    if let Some(location) = runtime_error.location() {
+      eprint_report(file_name, source, &location, &runtime_error.message(), "here");
+      return Box::new(ReportedError(err));
+    }
+  }
+  err
+}
+
+
+// 输出语义检查的结果, files 为语法文件的名称和内容, 下标即 Span 中的文件编号, 返回其中错误的数量
+pub fn report_diagnostics(files: &[(String, String)], diagnostics: &[Diagnostic]) -> usize {
+  let label_span = |span: &Span| {
+    let source = &files[span.file].1;
+    let char_index = |index: usize| source[..index.min(source.len())].chars().count();
+    (files[span.file].0.clone(), char_index(span.range.start)..char_index(span.range.end))
+  };
+
+  for diagnostic in diagnostics.iter() {
+    let kind = match diagnostic.severity {
+      Severity::Error => ReportKind::Error,
+      Severity::Warning => ReportKind::Warning,
+    };
+    let (file_name, span) = label_span(&diagnostic.span);
+    let mut report = Report::build(kind, file_name.clone(), span.start)
+      .with_message(&diagnostic.message)
+      .with_label(Label::new((file_name, span)));
+    if let Some((related, message)) = &diagnostic.related {
+      report = report.with_label(Label::new(label_span(related)).with_message(message));
+    }
+    report.finish().eprint(sources(files.iter().cloned())).ok();
+  }
+  diagnostics.iter().filter(|diagnostic| diagnostic.is_error()).count()
+}
+
+
+#[cfg(test)]
+mod tests {
+  use chiru::runtime::{error, location::Location};
+
+  use super::{char_span, eprint_report, render_error, ReportedError};
+
+  fn location(start: usize, stop: usize) -> Location {
+    Location { byte_index_start: start, byte_index_stop: stop, ..Default::default() }
+  }
+
+  #[test]
+  fn char_span_test() {
+    // 字节下标转换为字符下标, 长度为 0 的范围至少标出一个字符
+    assert_eq!(char_span("a: 你好;", &location(3, 9)), 3..5);
+    assert_eq!(char_span("a: 你好;", &location(9, 9)), 5..6);
+    // 位于末尾或者超出末尾的范围标在最后一个字符上
+    assert_eq!(char_span("a: b", &location(4, 4)), 3..4);
+    assert_eq!(char_span("a: b", &location(2, 10)), 2..4);
+    assert_eq!(char_span("你", &location(3, 3)), 0..1);
+    // 源码为空时没有可以标出的字符
+    assert_eq!(char_span("", &location(0, 0)), 0..0);
+
+    // 输出时不会 panic
+    eprint_report("empty.chiru", "", &location(0, 0), "missing GRAMMAR at '_STOP'", "here");
+    eprint_report("foo.chiru", "grammar", &location(7, 7), "missing TOKEN_REF at '_STOP'", "here");
+  }
+
+  #[test]
+  fn render_error_test() {
+    // 带有位置信息的错误输出之后标记为已经输出过了, 其余的错误原样返回
+    let err = render_error("foo.chiru", "a: b;", Box::new(error::Error::grammar_error(Some(location(3, 4)), "undefined rule 'b'")));
+    assert!(err.is::<ReportedError>());
+    assert_eq!(err.to_string(), error::Error::grammar_error(Some(location(3, 4)), "undefined rule 'b'").to_string());
+
+    let err = render_error("foo.chiru", "a: b;", Box::new(error::Error::grammar_error(None, "1 error(s) found")));
+    assert!(!err.is::<ReportedError>());
+  }
+}
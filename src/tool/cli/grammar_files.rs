@@ -0,0 +1,158 @@
+use std::error::Error;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use chiru::runtime::error;
+use chiru::runtime::lexer::Lexer;
+use chiru::runtime::location::Location;
+use chiru::runtime::token_stream::TokenStream;
+
+use crate::tool::syntaxis::{chiru_context::CompilationUnitContext, chiru_lexer::ChiruLexer, chiru_parser::ChiruParser};
+
+use super::error_report::{render_error, AriadneErrorListener};
+
+
+
+// 找出语法文件中所有的 import 语句导入的语法名称及其位置
+fn scan_imports(content: &str) -> Vec<(String, Location)> {
+  let mut lexer = ChiruLexer::new(content);
+  lexer.remove_all_error_listeners();
+
+  let mut result = Vec::new();
+  let mut in_import = false;
+  for token in lexer.iter().filter(|token| token.channel == 0) {
+    match token.terminal.id {
+      ChiruLexer::IMPORT => in_import = true,
+      ChiruLexer::SEMI => in_import = false,
+      ChiruLexer::TOKEN_REF | ChiruLexer::RULE_REF if in_import => result.push((token.text.to_owned(), token.location)),
+      _ => {},
+    }
+  }
+  result
+}
+
+
+fn read_file(path: &Path) -> Result<String, error::Error> {
+  let mut content = String::new();
+  File::open(path)?.read_to_string(&mut content)?;
+  Ok(content)
+}
+
+
+// 深度优先地读取 files[file_index] 导入的所有语法文件, stack 为正在读取的文件, 用于检查循环导入
+fn read_imports(dir: &Path, file_index: usize, stack: &mut Vec<usize>, files: &mut Vec<(String, String)>) -> Result<(), Box<dyn Error>> {
+  stack.push(file_index);
+  let (file_name, content) = files[file_index].clone();
+
+  for (name, location) in scan_imports(&content) {
+    let path = dir.join(format!("{}.chiru", name)).display().to_string();
+
+    match files.iter().position(|(imported, _)| *imported == path) {
+      // 正在读取的文件又被导入了
+      Some(index) if stack.contains(&index) => {
+        let cycle = stack.iter().skip_while(|file| **file != index).chain([&index])
+          .map(|file| files[*file].0.as_str()).collect::<Vec<_>>().join(" -> ");
+        let err = error::Error::grammar_error(Some(location), &format!("cyclic import: {}", cycle));
+        return Err(render_error(&file_name, &content, Box::new(err)));
+      },
+      // 已经被其他文件导入过了
+      Some(_) => {},
+      None => {
+        let imported = read_file(Path::new(&path)).map_err(|err| {
+          let message = format!("cannot import grammar '{}' from '{}': {}", name, path, err.source().map_or(err.to_string(), |source| source.to_string()));
+          render_error(&file_name, &content, Box::new(error::Error::grammar_error(Some(location), &message)))
+        })?;
+        files.push((path, imported));
+        read_imports(dir, files.len() - 1, stack, files)?;
+      },
+    }
+  }
+
+  stack.pop();
+  Ok(())
+}
+
+
+// 读取输入文件以及它直接或间接导入的所有语法文件, 返回文件名和内容, 第一个为输入文件
+// 导入的文件与输入文件位于同一目录下, 每个文件只读取一次
+pub fn read_grammar_files(input: &Path) -> Result<Vec<(String, String)>, Box<dyn Error>> {
+  let mut files = vec![(input.display().to_string(), read_file(input)?)];
+  let dir = input.parent().unwrap_or(Path::new(""));
+  read_imports(dir, 0, &mut Vec::new(), &mut files)?;
+  Ok(files)
+}
+
+
+// 每个语法文件一个词法分析器, 词法错误使用 ariadne 输出
+pub fn grammar_lexers(files: &[(String, String)]) -> Vec<ChiruLexer<'_>> {
+  files.iter().map(|(file_name, content)| {
+    let mut lexer = ChiruLexer::new(content);
+    lexer.remove_all_error_listeners();
+    lexer.add_error_listener(Box::new(AriadneErrorListener::new(file_name, content)));
+    lexer
+  }).collect()
+}
+
+
+// 解析所有语法文件, 语法树的顺序与 files 相同
+pub fn parse_grammar_files<'a>(files: &[(String, String)], lexers: &'a [ChiruLexer<'a>]) -> Result<Vec<Box<dyn CompilationUnitContext<'a> + 'a>>, Box<dyn Error>> {
+  let mut result = Vec::new();
+  for ((file_name, content), lexer) in files.iter().zip(lexers.iter()) {
+    let mut tokens = TokenStream::new(lexer, 0);
+    let mut parser = ChiruParser::new();
+    parser.error_listeners = vec![Box::new(AriadneErrorListener::new(file_name, content))];
+    result.push(parser.compilation_unit(&mut tokens).map_err(|err| render_error(file_name, content, err))?);
+  }
+  Ok(result)
+}
+
+
+#[cfg(test)]
+mod tests {
+  use crate::tool::{cli::{tests::grammar_dir, Analyzer}, grammar::{tests::parse, Grammar}, syntaxis::chiru_context::CompilationUnitContext};
+
+  use super::{grammar_lexers, parse_grammar_files, read_grammar_files};
+
+  #[test]
+  fn import_test() {
+    let dir = grammar_dir("import", &[
+      ("Main.chiru", "parser grammar Main;\nimport Tokens, Exprs;\nstmt: expr SEMI;\n"),
+      ("Exprs.chiru", "parser grammar Exprs;\nimport Tokens;\nexpr: NUM (PLUS NUM)*;\n"),
+      ("Tokens.chiru", "lexer grammar Tokens;\nNUM: r###\"[0-9]+\"###;\nPLUS: \"+\";\nSEMI: \";\";\n@ignore\nWS: r###\" +\"###;\n"),
+    ]);
+
+    // 深度优先地读取, 被多个文件导入的 Tokens 只读取一次
+    let files = read_grammar_files(&dir.join("Main.chiru")).unwrap();
+    let names = files.iter().map(|(file_name, _)| file_name.clone()).collect::<Vec<_>>();
+    let expected = ["Main.chiru", "Tokens.chiru", "Exprs.chiru"].map(|name| dir.join(name).display().to_string());
+    assert_eq!(names, expected);
+
+    // 导入的规则合并到输入文件的文法中
+    let lexers = grammar_lexers(&files);
+    let asts = parse_grammar_files(&files, &lexers).unwrap();
+    let asts = asts.iter().map(|ast| ast.as_ref() as &dyn CompilationUnitContext).collect::<Vec<_>>();
+    let grammar = Grammar::from_asts(&asts, &Default::default()).unwrap();
+    assert_eq!(grammar.name, "Main");
+    assert_eq!(parse(&grammar, Analyzer::LALR, "stmt", "1 + 2;"), "(stmt (expr NUM PLUS NUM) SEMI)");
+  }
+
+  #[test]
+  fn cyclic_import_test() {
+    let dir = grammar_dir("cyclic-import", &[
+      ("A.chiru", "grammar A;\nimport B;\na: b;\n"),
+      ("B.chiru", "parser grammar B;\nimport C;\nb: c;\n"),
+      ("C.chiru", "parser grammar C;\n\nimport B;\nc: X;\nX: \"x\";\n"),
+    ]);
+
+    let err = read_grammar_files(&dir.join("A.chiru")).unwrap_err();
+    let b = dir.join("B.chiru").display().to_string();
+    let c = dir.join("C.chiru").display().to_string();
+    assert_eq!(err.to_string(), format!("3:8: grammar error: cyclic import: {} -> {} -> {}", b, c, b));
+
+    // 导入不存在的文件
+    let dir = grammar_dir("missing-import", &[("A.chiru", "grammar A;\nimport Missing;\na: X;\nX: \"x\";\n")]);
+    let err = read_grammar_files(&dir.join("A.chiru")).unwrap_err();
+    assert!(err.to_string().starts_with("2:8: grammar error: cannot import grammar 'Missing' from "));
+  }
+}
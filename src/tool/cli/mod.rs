@@ -1,8 +1,13 @@
+pub mod error_report;
+pub mod grammar_files;
+
+use std::collections::BTreeMap;
 use std::error::Error;
 use std::fs::OpenOptions;
 use std::io::Write;
 use std::path::PathBuf;
 
+use ariadne::{Label, Report, ReportKind, Source};
 use chiru::runtime::ast::rule_context::RuleContext;
 use chiru::runtime::ast::rule_context::ToRule;
 use chiru::runtime::lexer::Lexer;
@@ -17,7 +22,11 @@ use chiru::runtime::token_stream::TokenStream;
 use super::analyzer::CommonLexer;
 use super::analyzer::CommonParser;
 use super::{code_generator::language::Language, gui::ast_drawer::ASTDrawer};
-use super::{syntaxis::{chiru_lexer::ChiruLexer, chiru_parser::ChiruParser}, grammar::Grammar, code_generator::CodeGenerator};
+use chiru::runtime::error;
+use error_report::{render_error, report_diagnostics, AriadneErrorListener};
+use grammar_files::{grammar_lexers, parse_grammar_files, read_grammar_files};
+use super::syntaxis::chiru_context::CompilationUnitContext;
+use super::{grammar::{Grammar, conflict::ConflictKind, token_vocab::parse_token_vocab}, code_generator::CodeGenerator};
 
 
 
@@ -43,24 +52,38 @@ pub struct Cli {
   #[arg(short, long, value_name = "PACKAGE")]
   pub package_name: Option<String>,
 
-  #[arg(long, default_value_t = true)]
+  // 默认生成 visitor、listener 和 walker, --no-xxx 可以关闭, 同时出现时以后出现的为准
+  #[arg(long, default_value_t = true, overrides_with = "no_visitor")]
   pub visitor: bool,
 
-  #[arg(long, default_value_t = true)]
+  #[arg(long, default_value_t = true, overrides_with = "no_listener")]
   pub listener: bool,
 
-  #[arg(long, default_value_t = true)]
+  // walker 依赖 listener, 不生成 listener 时也不会生成 walker
+  #[arg(long, default_value_t = true, overrides_with = "no_walker")]
   pub walker: bool,
 
-  #[arg(long, default_value_t = false)]
+  #[arg(long, default_value_t = false, overrides_with = "visitor")]
   pub no_visitor: bool,
 
-  #[arg(long, default_value_t = false)]
+  #[arg(long, default_value_t = false, overrides_with = "listener")]
   pub no_listener: bool,
 
-  #[arg(long, default_value_t = false)]
+  #[arg(long, default_value_t = false, overrides_with = "walker")]
   pub no_walker: bool,
 
+  // 文法存在冲突时不生成代码
+  #[arg(long, default_value_t = false)]
+  pub strict: bool,
+
+  // 从之前生成的 .tokens 文件中读取 token 的编号, 使得修改语法后编号保持不变, 或者与其他语法共享编号
+  #[arg(long, value_name = "FILE")]
+  pub token_vocab: Option<PathBuf>,
+
+  // 使用该目录中同名的 .tera 文件覆盖内置的模板, 如 header.tera、lexer.tera, 可用的变量见 src/tool/templates/README.md
+  #[arg(long, value_name = "DIR")]
+  pub template_dir: Option<PathBuf>,
+
 
 
 
@@ -103,22 +126,23 @@ pub enum Analyzer {
 impl Cli {
   fn generate_code(&self) -> Result<(), Box<dyn Error>> {
     
-    let mut input_file = File::open(&self.input)?;
-    let mut content = String::new();
-    input_file.read_to_string(&mut content)?;
-
-
-    let lexer = ChiruLexer::new(&content);
-    let mut tokens = TokenStream::new(&lexer, 0);
-    let parser = ChiruParser::new();
-    let ast = parser.compilation_unit(&mut tokens)?;
+    let files = read_grammar_files(&self.input)?;
+    let (file_name, content) = &files[0];
+    let lexers = grammar_lexers(&files);
+    let asts = parse_grammar_files(&files, &lexers)?;
+    let asts = asts.iter().map(|ast| ast.as_rule() as &dyn CompilationUnitContext).collect::<Vec<_>>();
+    self.validate_grammar(&asts, &files)?;
+    let token_vocab = self.read_token_vocab()?;
 
 
-    let grammar = Grammar::from_ast(ast.as_ref())?;
-
+    let mut grammar = Grammar::from_asts(&asts, &token_vocab).map_err(|err| render_error(file_name, content, err))?;
+    self.transform_grammar(&mut grammar, &files)?;
 
+    let conflicts = self.report_conflicts(&grammar, content);
+    if self.strict && conflicts > 0 {
+      return Err(format!("{} conflict(s) found in grammar '{}'", conflicts, grammar.name).into());
+    }
 
-    
 
     let base_dir: PathBuf;
     if let Some(p) = &self.output {
@@ -129,13 +153,19 @@ impl Cli {
     
 
     let version = Cli::command().render_version();
-    let code_generator = CodeGenerator::new(
-      &grammar, ast.as_ref(), 
+    let mut code_generator = CodeGenerator::new(
+      &grammar, &asts, 
       &base_dir, &self.input, 
       self.language,
       self.package_name.clone(), 
       &version, self.analyzer,
-    );
+    )?;
+    code_generator.toggle_visitor_generation(self.visitor && !self.no_visitor);
+    code_generator.toggle_listener_generation(self.listener && !self.no_listener);
+    code_generator.toggle_walker_generation(self.walker && !self.no_walker);
+    if let Some(template_dir) = &self.template_dir {
+      code_generator.load_templates(template_dir)?;
+    }
     code_generator.generate()?;
     Ok(())
   }
@@ -145,32 +175,113 @@ impl Cli {
 
 
 
-  // 打印 tokens
-  fn dump_tokens(&self) -> Result<(), Box<dyn Error>> {
-    let grammar = self.get_grammar()?;
+  // 构造文法之前进行语义检查, 存在错误时不再继续
+  fn validate_grammar(&self, asts: &[&dyn CompilationUnitContext], files: &[(String, String)]) -> Result<(), Box<dyn Error>> {
+    let diagnostics = Grammar::validate(asts)?;
+    let errors = report_diagnostics(files, &diagnostics);
+    if errors > 0 {
+      return Err(Box::new(error::Error::grammar_error(None, &format!("{} error(s) found in grammar file '{}'", errors, files[0].0))));
+    }
+    Ok(())
+  }
 
+  // ll1 分析需要先消除左递归并提取左公因子, 不支持经过其他规则的间接左递归
+  fn transform_grammar(&self, grammar: &mut Grammar, files: &[(String, String)]) -> Result<(), Box<dyn Error>> {
+    if self.analyzer == Analyzer::LL1 {
+      let errors = report_diagnostics(files, &grammar.indirect_left_recursion());
+      if errors > 0 {
+        return Err(Box::new(error::Error::grammar_error(None, &format!("{} error(s) found in grammar file '{}'", errors, files[0].0))));
+      }
+      grammar.eliminate_left_recursion();
+      grammar.left_factor();
+    }
+    Ok(())
+  }
+
+  // 使用 ariadne 输出分析表中的冲突, 返回冲突的数量
+  fn report_conflicts(&self, grammar: &Grammar, content: &str) -> usize {
+    let conflicts = match self.analyzer {
+      Analyzer::LL1 => {
+        let (first, first_set) = grammar.first_set();
+        let follow = grammar.follow_set(&first);
+        grammar.ll1_table(&first_set, &follow).1
+      },
+      Analyzer::LALR => grammar.lalr_table().conflicts,
+    };
+
+    let file_name = self.input.display().to_string();
+    let kind = if self.strict { ReportKind::Error } else { ReportKind::Warning };
+    // ariadne 使用字符下标, 而产生式记录的是字节下标
+    let char_span = |production_id: &usize| {
+      grammar.production_spans.get(production_id)
+        .map(|span| content[..span.start].chars().count()..content[..span.end].chars().count())
+    };
+
+    for conflict in conflicts.iter() {
+      let chosen = char_span(&conflict.chosen);
+      let rejected = char_span(&conflict.rejected);
+      let offset = chosen.as_ref().or(rejected.as_ref()).map_or(0, |span| span.start);
+
+      let mut report = Report::build(kind, file_name.as_str(), offset)
+        .with_message(conflict.to_string());
+      let (chosen_message, rejected_message) = match conflict.kind {
+        ConflictKind::ShiftReduce => (format!("shift in production {} (kept)", conflict.chosen), format!("reduce by production {} (discarded)", conflict.rejected)),
+        ConflictKind::ReduceReduce => (format!("reduce by production {} (kept)", conflict.chosen), format!("reduce by production {} (discarded)", conflict.rejected)),
+        _ => (format!("production {} (kept)", conflict.chosen), format!("production {} (discarded)", conflict.rejected)),
+      };
+      if let Some(span) = chosen {
+        report = report.with_label(Label::new((file_name.as_str(), span)).with_message(chosen_message));
+      }
+      if let Some(span) = rejected {
+        report = report.with_label(Label::new((file_name.as_str(), span)).with_message(rejected_message));
+      }
+      report.finish().eprint((file_name.as_str(), Source::from(content))).ok();
+    }
+    conflicts.len()
+  }
 
-    // content.clear();
+  // 从 test_file 或 stdin 中读取测试文件
+  fn read_test_file(&self) -> Result<String, Box<dyn Error>> {
     let mut content = String::new();
-    // 然后从 test_file 中或 stdin 中读取测试文件
     if let Some(test_file) = &self.test_file {
-      let mut file = File::open(test_file)?;
-      file.read_to_string(&mut content)?;
+      let mut file = File::open(test_file).map_err(error::Error::from)?;
+      file.read_to_string(&mut content).map_err(error::Error::from)?;
     }
     else {
-      std::io::stdin().read_to_string(&mut content)?;
+      std::io::stdin().read_to_string(&mut content).map_err(error::Error::from)?;
     }
+    Ok(content)
+  }
+
+  fn test_file_name(&self) -> String {
+    match &self.test_file {
+      Some(test_file) => test_file.display().to_string(),
+      None => "<stdin>".to_owned(),
+    }
+  }
 
+  // 测试文件的词法错误和语法错误都使用 ariadne 输出
+  fn test_lexer<'a>(&self, grammar: &Grammar, content: &'a str) -> Result<CommonLexer<'a>, Box<dyn Error>> {
+    let mut lexer = CommonLexer::from_grammar(grammar, content)?;
+    lexer.error_listeners = vec![Box::new(AriadneErrorListener::new(&self.test_file_name(), content))];
+    Ok(lexer)
+  }
 
-    let lexer = CommonLexer::from_grammar(&grammar, &content);
+  // 打印 tokens
+  fn dump_tokens(&self) -> Result<(), Box<dyn Error>> {
+    let grammar = self.get_grammar()?;
+    let content = self.read_test_file()?;
+
+
+    let lexer = self.test_lexer(&grammar, &content)?;
 
     if let Some(output) = &self.output {
       let mut file;
       if output.is_dir() {
         let output = output.join("tokens.txt");
-        file = OpenOptions::new().write(true).create(true).open(output)?;
+        file = OpenOptions::new().write(true).create(true).truncate(true).open(output)?;
       } else {
-        file = OpenOptions::new().write(true).create(true).open(output)?
+        file = OpenOptions::new().write(true).create(true).truncate(true).open(output)?
       }
       for token in lexer.iter() {
         file.write_all(token.to_string().as_bytes())?
@@ -189,8 +300,10 @@ impl Cli {
 
   fn draw_gui(&self) -> Result<(), Box<dyn Error>> {
     let grammar = self.get_grammar()?;
+    let content = self.read_test_file()?;
+    let lexer = self.test_lexer(&grammar, &content)?;
 
-    let ast = self.parse_ast(&grammar)?;
+    let ast = self.parse_ast(&grammar, &lexer)?;
 
     // 输出 ast 到文件
     let mut file;
@@ -198,15 +311,15 @@ impl Cli {
       
       if output.is_dir() {
         let output = output.join("ast.html");
-        file = OpenOptions::new().write(true).create(true).open(output)?;
+        file = OpenOptions::new().write(true).create(true).truncate(true).open(output)?;
       } else {
-        file = OpenOptions::new().write(true).create(true).open(output)?
+        file = OpenOptions::new().write(true).create(true).truncate(true).open(output)?
       }
 
     } else {
       // 创建一个默认文件
       let path = env::current_dir()?.join("ast.html");
-      file = OpenOptions::new().write(true).create(true).open(path)?;
+      file = OpenOptions::new().write(true).create(true).truncate(true).open(path)?;
     }
 
 
@@ -215,64 +328,58 @@ impl Cli {
     Ok(())
   }
 
-  fn parse_ast(&self, grammar: &Grammar) -> Result<RuleContext, Box<dyn Error>> {
-    let mut content = String::new();
-
-    
-    // 然后从 test_file 中或 stdin 中读取测试文件
-    if let Some(test_file) = &self.test_file {
-      let mut file = File::open(test_file)?;
-      file.read_to_string(&mut content)?;
-    }
-    else {
-      std::io::stdin().read_to_string(&mut content)?;
-    }
-
-    // 获取开始符号
-    let start_rule_id;
-    if let Some(start_rule) = &self.start_rule {
-      start_rule_id = grammar.vocabulary.get_nonterminal_by_name(start_rule).unwrap_or(NonTerminal::new(None, 0)).id;
-    } else { start_rule_id = 0; }
-
-    let lexer = CommonLexer::from_grammar(&grammar, &content);
-    let mut tokens = TokenStream::new(&lexer, 0);
-    let parser = CommonParser::from_grammar(&grammar);
-    let ast = parser.parse(&mut tokens, start_rule_id)?;
+  fn parse_ast<'a>(&self, grammar: &Grammar<'a>, lexer: &'a CommonLexer) -> Result<RuleContext<'a>, Box<dyn Error>> {
+    // 获取开始符号, 默认为编号为 0 的非终结符
+    let start_rule = match &self.start_rule {
+      Some(start_rule) => grammar.vocabulary.get_nonterminal_by_name(start_rule),
+      None => grammar.vocabulary.get_nonterminal_by_id(0),
+    };
+    let start_rule = start_rule.unwrap_or(NonTerminal::new(None, 0));
+
+    let mut tokens = TokenStream::new(lexer, 0);
+    let mut parser = CommonParser::from_grammar(grammar, self.analyzer);
+    let file_name = self.test_file_name();
+    parser.error_listeners = vec![Box::new(AriadneErrorListener::new(&file_name, lexer.input))];
+    let ast = parser.parse(&mut tokens, start_rule).map_err(|err| render_error(&file_name, lexer.input, err))?;
     Ok(ast)
   }
 
   fn dump_ast(&self) -> Result<(), Box<dyn Error>> {
     let grammar = self.get_grammar()?;
+    let content = self.read_test_file()?;
+    let lexer = self.test_lexer(&grammar, &content)?;
 
-    let ast = self.parse_ast(&grammar)?;
+    let ast = self.parse_ast(&grammar, &lexer)?;
 
     if let Some(output) = &self.output {
       let mut file;
       if output.is_dir() {
         let output = output.join("string-ast.txt");
-        file = OpenOptions::new().write(true).create(true).open(output)?;
+        file = OpenOptions::new().write(true).create(true).truncate(true).open(output)?;
       } else {
-        file = OpenOptions::new().write(true).create(true).open(output)?;
+        file = OpenOptions::new().write(true).create(true).truncate(true).open(output)?;
       }
       file.write_all(ast.to_string().as_bytes())?;
     } else {
-      println!("{}", ast.to_string());
+      println!("{}", ast);
     }
     Ok(())
   }
 
   fn dump_json_ast(&self) -> Result<(), Box<dyn Error>> {
     let grammar = self.get_grammar()?;
+    let content = self.read_test_file()?;
+    let lexer = self.test_lexer(&grammar, &content)?;
 
-    let ast = self.parse_ast(&grammar)?;
+    let ast = self.parse_ast(&grammar, &lexer)?;
 
     if let Some(output) = &self.output {
       let mut file;
       if output.is_dir() {
         let output = output.join("ast.json");
-        file = OpenOptions::new().write(true).create(true).open(output)?;
+        file = OpenOptions::new().write(true).create(true).truncate(true).open(output)?;
       } else {
-        file = OpenOptions::new().write(true).create(true).open(output)?;
+        file = OpenOptions::new().write(true).create(true).truncate(true).open(output)?;
       }
       file.write_all(serde_json::to_string(ast.as_rule())?.as_bytes())?;
     } else {
@@ -282,22 +389,32 @@ impl Cli {
   }
 
 
-  fn get_grammar(&self) -> Result<Grammar, Box<dyn Error>> {
-    let mut input_file = File::open(&self.input)?;
-    let mut content = String::new();
-    input_file.read_to_string(&mut content)?;
+  // 没有指定 --token-vocab 时按照定义的顺序编号
+  fn read_token_vocab(&self) -> Result<BTreeMap<String, usize>, Box<dyn Error>> {
+    let Some(path) = &self.token_vocab else { return Ok(BTreeMap::new()) };
+    let content = std::fs::read_to_string(path).map_err(error::Error::from)?;
+    parse_token_vocab(&content)
+  }
+
+
+  fn get_grammar(&self) -> Result<Grammar<'static>, Box<dyn Error>> {
+    let files = read_grammar_files(&self.input)?;
+    let (file_name, content) = &files[0];
 
 
-    let ast;
     let grammar;
 
     {
-      let lexer = ChiruLexer::new(&content);
-      let mut tokens = TokenStream::new(&lexer, 0);
-      let parser = ChiruParser::new();
-      ast = parser.compilation_unit(&mut tokens)?;
-      grammar = Grammar::from_ast(ast.as_ref())?;
+      let lexers = grammar_lexers(&files);
+      let asts = parse_grammar_files(&files, &lexers)?;
+      let asts = asts.iter().map(|ast| ast.as_rule() as &dyn CompilationUnitContext).collect::<Vec<_>>();
+      self.validate_grammar(&asts, &files)?;
+      let token_vocab = self.read_token_vocab()?;
+      grammar = Grammar::from_asts(&asts, &token_vocab).map_err(|err| render_error(file_name, content, err))?;
     }
+
+    let mut grammar = grammar;
+    self.transform_grammar(&mut grammar, &files)?;
     Ok(grammar)
   }
 
@@ -341,3 +458,349 @@ impl Cli {
 
 
 
+
+
+#[cfg(test)]
+mod tests {
+  use std::{fs, io::{self, ErrorKind, Write}, ops::Deref, path::{Path, PathBuf}, process::Command};
+
+  use clap::Parser;
+
+  use super::Cli;
+
+  // 测试使用的临时目录, 离开作用域时删除, 断言失败或提前返回时也不会留下文件
+  pub(super) struct TempDir(PathBuf);
+
+  impl Deref for TempDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+      &self.0
+    }
+  }
+
+  impl Drop for TempDir {
+    fn drop(&mut self) {
+      let _ = fs::remove_dir_all(&self.0);
+    }
+  }
+
+  // 为每个测试创建一个临时目录, 写入 files 中的语法文件, 生成的代码位于其中的 out 目录
+  pub(super) fn grammar_dir(name: &str, files: &[(&str, &str)]) -> TempDir {
+    let dir = std::env::temp_dir().join(format!("chiru-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("out")).unwrap();
+    for (file_name, content) in files.iter() {
+      fs::write(dir.join(file_name), content).unwrap();
+    }
+    TempDir(dir)
+  }
+
+  // 只包含一个语法文件 Amb.chiru 的临时目录
+  fn amb_dir(name: &str, grammar: &str) -> TempDir {
+    grammar_dir(name, &[("Amb.chiru", grammar)])
+  }
+
+  fn run(dir: &Path, args: &[&str]) -> Result<(), String> {
+    let input = dir.join("Amb.chiru").display().to_string();
+    let output = dir.join("out").display().to_string();
+    let cli = Cli::try_parse_from(["chiru", "-i", &input, "-o", &output].iter().chain(args)).unwrap();
+    cli.execute_command().map_err(|err| err.to_string())
+  }
+
+  // 各个目标语言的生成测试共用的文法, STRING 用于检查正则表达式中引号和反斜杠的转义
+  const EXPR: &str = r####"
+    grammar Amb;
+    expr: expr (PLUS | MINUS) term | term;
+    term: NUMBER | STRING;
+    @left(PLUS, MINUS);
+    PLUS: r###"\+"###;
+    MINUS: r###"-"###;
+    NUMBER: r###"[0-9]+"###;
+    STRING: r###""[^"\\]*""###;
+    @ignore
+    WHITE_SPACE: r###"[ \r\n\t]+"###;
+  "####;
+
+  // 输出目录中生成的文件名, 按名称排序
+  fn generated_files(dir: &Path) -> Vec<String> {
+    let mut files: Vec<String> = fs::read_dir(dir.join("out")).unwrap()
+      .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+      .collect();
+    files.sort();
+    files
+  }
+
+  // 在临时目录中运行外部程序, envs 中的路径相对于仓库根目录, 没有安装对应的程序时跳过
+  // 跳过的提示直接写入 stderr, 不经过测试框架的输出捕获, 运行 cargo test 时可以看到哪些检查没有执行
+  fn execute(dir: &Path, program: &str, args: &[&str], envs: &[(&str, &str)]) -> Option<(String, String)> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let envs = envs.iter().map(|(name, path)| (*name, root.join(path)));
+    let output = match Command::new(program).args(args).current_dir(dir).envs(envs).output() {
+      Ok(output) => output,
+      Err(err) if err.kind() == ErrorKind::NotFound => {
+        let _ = writeln!(io::stderr(), "warning: '{}' is not installed, skip checking the generated code with it", program);
+        return None;
+      },
+      Err(err) => panic!("{}", err),
+    };
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(output.status.success(), "{}{}", stdout, stderr);
+    Some((stdout, stderr))
+  }
+
+  #[test]
+  fn strict_conflict_test() {
+    let dir = amb_dir("strict", r#"
+      grammar Amb;
+      s: a A;
+      a: A?;
+      A: "a";
+    "#);
+
+    // 提取左公因子之后依然存在 first/follow 冲突, 存在冲突时只给出警告, 依然生成代码
+    assert_eq!(run(&dir, &["--analyzer", "ll1"]), Ok(()));
+    assert!(dir.join("out/amb_parser.rs").exists());
+
+    // --strict 时返回错误, main 以非零状态退出, 并且不生成代码
+    fs::remove_file(dir.join("out/amb_parser.rs")).unwrap();
+    assert_eq!(run(&dir, &["--analyzer", "ll1", "--strict"]), Err("1 conflict(s) found in grammar 'Amb'".to_owned()));
+    assert!(!dir.join("out/amb_parser.rs").exists());
+  }
+
+  #[test]
+  fn indirect_left_recursion_test() {
+    let dir = amb_dir("indirect", r#"
+      grammar Amb;
+      a: b | Y;
+      b: a Z | W;
+      Y: "y";
+      Z: "z";
+      W: "w";
+    "#);
+
+    // ll1 分析不支持间接左递归, 报告错误并且不生成代码, lalr 分析不受影响
+    let input = dir.join("Amb.chiru").display().to_string();
+    assert_eq!(run(&dir, &["--analyzer", "ll1"]), Err(format!("grammar error: 1 error(s) found in grammar file '{}'", input)));
+    assert!(generated_files(&dir).is_empty());
+    assert_eq!(run(&dir, &["--analyzer", "lalr"]), Ok(()));
+  }
+
+  #[test]
+  fn word_boundary_test() {
+    let dir = amb_dir("word-boundary", r####"
+      grammar Amb;
+      s: WORD+;
+      WORD: r###"\b[a-z]+\b"###;
+      @ignore
+      WS: r###"[ ]+"###;
+    "####);
+    fs::write(dir.join("input.txt"), "ab cd").unwrap();
+    let test_file = dir.join("input.txt").display().to_string();
+
+    // 词法分析使用的 DFA 不支持 Unicode 单词边界, 在语义检查时报告错误而不是 panic
+    let err = run(&dir, &["--tokens", "--test-file", &test_file]).unwrap_err();
+    assert_eq!(err, format!("grammar error: 1 error(s) found in grammar file '{}'", dir.join("Amb.chiru").display()));
+
+    // ASCII 单词边界可以正常使用
+    fs::write(dir.join("Amb.chiru"), fs::read_to_string(dir.join("Amb.chiru")).unwrap().replace(r"\b", r"(?-u:\b)")).unwrap();
+    assert_eq!(run(&dir, &["--tokens", "--test-file", &test_file]), Ok(()));
+    assert_eq!(fs::read_to_string(dir.join("out/tokens.txt")).unwrap().matches("WORD").count(), 2);
+  }
+
+  #[test]
+  fn generation_flags_test() {
+    let dir = amb_dir("flags", EXPR);
+
+    // walker 依赖 listener, 不生成 listener 时也不生成 walker, mod.rs 中只声明实际生成的文件
+    assert_eq!(run(&dir, &["--analyzer", "ll1", "--no-visitor", "--no-listener"]), Ok(()));
+    assert_eq!(generated_files(&dir), ["Amb.tokens", "amb_context.rs", "amb_lexer.rs", "amb_parser.rs", "mod.rs"]);
+    let module = fs::read_to_string(dir.join("out/mod.rs")).unwrap();
+    assert!(module.ends_with("pub mod amb_lexer;\npub mod amb_parser;\npub mod amb_context;\n"));
+
+    // context 中也不能引用没有生成的 visitor 和 listener
+    let context = fs::read_to_string(dir.join("out/amb_context.rs")).unwrap();
+    assert!(!context.contains("amb_visitor") && !context.contains("amb_listener"));
+
+    // 只关闭 visitor 时依然生成 listener 和 walker
+    fs::remove_dir_all(dir.join("out")).unwrap();
+    fs::create_dir(dir.join("out")).unwrap();
+    assert_eq!(run(&dir, &["--analyzer", "ll1", "--no-visitor"]), Ok(()));
+    assert_eq!(generated_files(&dir), [
+      "Amb.tokens", "amb_context.rs", "amb_lexer.rs", "amb_listener.rs", "amb_parser.rs", "amb_walker.rs", "mod.rs",
+    ]);
+  }
+
+  #[test]
+  fn missing_output_dir_test() {
+    let dir = amb_dir("missing-output", EXPR);
+
+    // 输出目录不存在时无法写入文件, 返回错误而不是只打印一条信息
+    fs::remove_dir(dir.join("out")).unwrap();
+    let err = run(&dir, &["--analyzer", "ll1"]).unwrap_err();
+    assert!(err.starts_with(&format!("fail to write file '{}'", dir.join("out/amb_lexer.rs").display())), "{}", err);
+  }
+
+  #[test]
+  fn template_dir_test() {
+    let dir = amb_dir("template-dir", EXPR);
+    let templates = dir.join("templates");
+    let template_dir = templates.display().to_string();
+
+    // 目录不存在
+    assert_eq!(
+      run(&dir, &["--analyzer", "ll1", "--template-dir", &template_dir]),
+      Err(format!("template directory '{}' does not exist", template_dir))
+    );
+
+    // 只覆盖 header, 所有生成的文件都使用新的文件头, 其余模板保持不变
+    fs::create_dir(&templates).unwrap();
+    fs::write(templates.join("header.tera"), "// Copyright {{ grammar_file_name }}\n").unwrap();
+    assert_eq!(run(&dir, &["--analyzer", "ll1", "--template-dir", &template_dir]), Ok(()));
+    let header = format!("// Copyright {}\n", dir.join("Amb.chiru").display());
+    for file in ["amb_lexer.rs", "amb_parser.rs", "mod.rs"] {
+      assert!(fs::read_to_string(dir.join("out").join(file)).unwrap().contains(&header), "{}", file);
+    }
+    fs::remove_dir_all(dir.join("out")).unwrap();
+    fs::create_dir(dir.join("out")).unwrap();
+
+    // 文件名必须是 target 已有的模板名称, 不生成任何代码
+    fs::write(templates.join("lexers.tera"), "").unwrap();
+    assert_eq!(
+      run(&dir, &["--analyzer", "ll1", "--template-dir", &template_dir]),
+      Err(format!(
+        "unknown template '{}', the Rust target only uses the templates: context, header, lalr_parser, lexer, listener, parser, visitor, vocabulary, walker",
+        templates.join("lexers.tera").display()
+      ))
+    );
+    assert!(generated_files(&dir).is_empty());
+    fs::remove_file(templates.join("lexers.tera")).unwrap();
+
+    // tera 的解析错误记录在 source 中, 需要一并输出
+    fs::write(templates.join("lexer.tera"), "{% if %}").unwrap();
+    let err = run(&dir, &["--analyzer", "ll1", "--template-dir", &template_dir]).unwrap_err();
+    assert!(err.starts_with(&format!("fail to load templates from '{}': ", template_dir)), "{}", err);
+    assert!(err.contains("Failed to parse") && err.contains("lexer"), "{}", err);
+    assert!(generated_files(&dir).is_empty());
+  }
+
+  #[test]
+  fn typescript_target_test() {
+    let dir = amb_dir("typescript", EXPR);
+
+    // typescript 运行时只实现了 ll1 分析
+    assert_eq!(run(&dir, &["-l", "typescript"]), Err("the TypeScript target only supports the LL(1) analyzer, use --analyzer ll1".to_owned()));
+
+    assert_eq!(run(&dir, &["-l", "typescript", "--analyzer", "ll1"]), Ok(()));
+    assert_eq!(generated_files(&dir), [
+      "Amb.tokens", "amb_context.ts", "amb_lexer.ts", "amb_listener.ts",
+      "amb_parser.ts", "amb_visitor.ts", "amb_walker.ts", "index.ts",
+    ]);
+
+    // 正则表达式写入 js 字符串, 引号和反斜杠需要转义
+    let lexer = fs::read_to_string(dir.join("out/amb_lexer.ts")).unwrap();
+    assert!(lexer.contains(r#"import { Lexer, LexerRule } from "chiru-runtime";"#));
+    assert!(lexer.contains("export class AmbLexer extends Lexer"));
+    assert!(lexer.contains(r#"rule: new RegExp("\\+", ""),"#));
+    assert!(lexer.contains(r#"rule: new RegExp("\"[^\"\\\\]*\"", ""),"#));
+    assert!(lexer.contains("static readonly STRING = 5;"));
+
+    let parser = fs::read_to_string(dir.join("out/amb_parser.ts")).unwrap();
+    assert!(parser.contains("export class AmbParser"));
+    assert!(parser.contains("expr(tokenStream: TokenStream): ExprContext"));
+    assert!(parser.contains("term(tokenStream: TokenStream): TermContext"));
+    assert!(parser.contains("ll1Analyze(tokenStream, nonterminal(rule), GRAMMAR, this.errorListeners, this.errorStrategy)"));
+
+    let index = fs::read_to_string(dir.join("out/index.ts")).unwrap();
+    for module in ["amb_lexer", "amb_parser", "amb_context", "amb_listener", "amb_visitor", "amb_walker"] {
+      assert!(index.contains(&format!("export * from \"./{}\";", module)));
+    }
+
+    // 用 tsc 对生成的代码做类型检查, chiru-runtime 映射到仓库中运行时库的源码
+    let runtime = Path::new(env!("CARGO_MANIFEST_DIR")).join("runtime/typescript/src").display().to_string();
+    let tsconfig = format!(r#"{{
+  "compilerOptions": {{ "target": "ES2020", "module": "commonjs", "strict": true, "noEmit": true, "paths": {{ "chiru-runtime": [{:?}] }} }},
+  "include": ["out"]
+}}"#, runtime);
+    fs::write(dir.join("tsconfig.json"), tsconfig).unwrap();
+    if let Some((stdout, _)) = execute(&dir, "tsc", &["-p", "tsconfig.json"], &[]) {
+      assert_eq!(stdout, "");
+    }
+  }
+
+  #[test]
+  fn python_target_test() {
+    let dir = amb_dir("python", EXPR);
+
+    // python 运行时只实现了 ll1 分析
+    assert_eq!(run(&dir, &["-l", "python"]), Err("the Python target only supports the LL(1) analyzer, use --analyzer ll1".to_owned()));
+
+    assert_eq!(run(&dir, &["-l", "python", "--analyzer", "ll1"]), Ok(()));
+    assert_eq!(generated_files(&dir), [
+      "Amb.tokens", "__init__.py", "amb_context.py", "amb_lexer.py", "amb_listener.py",
+      "amb_parser.py", "amb_visitor.py", "amb_walker.py",
+    ]);
+
+    let lexer = fs::read_to_string(dir.join("out/amb_lexer.py")).unwrap();
+    assert!(lexer.contains("class AmbLexer(Lexer):"));
+    assert!(lexer.contains(r#"rule=re.compile("\\+"),"#));
+    assert!(lexer.contains(r#"rule=re.compile("\"[^\"\\\\]*\""),"#));
+
+    // 输出目录 out 本身是一个 python 包, 解析一个正确的输入和一个多出数字的输入, 错误由 ConsoleErrorListener 输出到 stderr
+    let script = r#"
+from chiru_runtime import TokenStream
+from out import AmbLexer, AmbParser
+
+print(AmbParser().expr(TokenStream(AmbLexer('1 + "a" - 3'))))
+print(AmbParser().expr(TokenStream(AmbLexer('1 + 2 3'))))
+"#;
+    if let Some((stdout, stderr)) = execute(&dir, "python3", &["-c", script], &[("PYTHONPATH", "runtime/python")]) {
+      assert_eq!(stdout, "(expr (expr (expr (term NUMBER)) PLUS (term STRING)) MINUS (term NUMBER))\n(expr (expr (expr (term NUMBER)) PLUS (term NUMBER)) <1:7 extraneous input '3' expecting {MINUS, PLUS, _STOP}>)\n");
+      assert_eq!(stderr, "1:7 extraneous input '3' expecting {MINUS, PLUS, _STOP}\n");
+    }
+  }
+
+  #[test]
+  fn ruby_target_test() {
+    let dir = amb_dir("ruby", EXPR);
+
+    // ruby 运行时只实现了 ll1 分析
+    assert_eq!(run(&dir, &["-l", "ruby"]), Err("the Ruby target only supports the LL(1) analyzer, use --analyzer ll1".to_owned()));
+
+    assert_eq!(run(&dir, &["-l", "ruby", "--analyzer", "ll1"]), Ok(()));
+    assert_eq!(generated_files(&dir), [
+      "Amb.tokens", "amb.rb", "amb_context.rb", "amb_lexer.rb", "amb_listener.rb",
+      "amb_parser.rb", "amb_visitor.rb", "amb_walker.rb",
+    ]);
+
+    // 正则表达式写入单引号字符串, 只有反斜杠需要转义
+    let lexer = fs::read_to_string(dir.join("out/amb_lexer.rb")).unwrap();
+    assert!(lexer.contains("class AmbLexer < ChiruRuntime::Lexer"));
+    assert!(lexer.contains(r#"rule: Regexp.new('\\+'),"#));
+    assert!(lexer.contains(r#"rule: Regexp.new('"[^"\\\\]*"'),"#));
+
+    // 先用 ruby -c 检查运行时库和生成的文件的语法, 没有安装 ruby 时只检查一次就跳过
+    let runtime = Path::new(env!("CARGO_MANIFEST_DIR")).join("runtime/ruby/lib");
+    let mut files = vec![runtime.join("chiru_runtime.rb")];
+    files.extend(fs::read_dir(runtime.join("chiru_runtime")).unwrap().map(|entry| entry.unwrap().path()));
+    files.extend(generated_files(&dir).iter().filter(|file| file.ends_with(".rb")).map(|file| dir.join("out").join(file)));
+    for file in files {
+      let file = file.display().to_string();
+      let Some((stdout, _)) = execute(&dir, "ruby", &["-c", &file], &[]) else { return };
+      assert_eq!(stdout, "Syntax OK\n", "{}", file);
+    }
+
+    // amb.rb 引入其余生成的文件, 解析一个正确的输入和一个多出数字的输入, 错误由 ConsoleErrorListener 输出到 stderr
+    let script = r#"
+require "amb"
+
+puts AmbParser.new.expr(ChiruRuntime::TokenStream.new(AmbLexer.new('1 + "a" - 3')))
+puts AmbParser.new.expr(ChiruRuntime::TokenStream.new(AmbLexer.new('1 + 2 3')))
+"#;
+    if let Some((stdout, stderr)) = execute(&dir, "ruby", &["-I", "out", "-e", script], &[("RUBYLIB", "runtime/ruby/lib")]) {
+      assert_eq!(stdout, "(expr (expr (expr (term NUMBER)) PLUS (term STRING)) MINUS (term NUMBER))\n(expr (expr (expr (term NUMBER)) PLUS (term NUMBER)) <1:7 extraneous input '3' expecting {MINUS, PLUS, _STOP}>)\n");
+      assert_eq!(stderr, "1:7 extraneous input '3' expecting {MINUS, PLUS, _STOP}\n");
+    }
+  }
+}
@@ -4,6 +4,7 @@ use clap::ValueEnum;
 pub enum Language {
   Rust, 
 
+  #[value(name = "typescript", alias = "ts")]
   TypeScript,
 
   Ruby, 
@@ -5,21 +5,21 @@ pub mod language;
 pub mod name_case;
 
 
-use std::{path::Path, error::Error, collections::HashSet};
+use std::{collections::{BTreeSet, HashSet}, path::Path, error::Error};
 
 use crate::tool::{grammar::Grammar, syntaxis::chiru_context::CompilationUnitContext};
 
 
-use self::{target::{Target, rust_target::RustTarget}, name_case::{WriteFileData, LexerCase, LexerGenData, NameCaseWithId, ParserGenData, ContextCase, ContextGenData, VisitorOrListenerGenData, WalkerGenData}, language::Language};
+use self::{target::{Target, registry::TargetRegistry}, name_case::{ElementLabelCase, NameCase, WriteFileData, LexerCase, LexerGenData, NameCaseWithId, ParserGenData, ContextCase, ContextGenData, VisitorOrListenerGenData, VocabularyGenData, WalkerGenData}, language::Language};
 
-use super::{cli::Analyzer, visitor::context_visitor::ContextVisitor};
+use super::{cli::Analyzer, visitor::context_visitor::{ContextVisitor, ElementLabels}};
 
 
 pub struct CodeGenerator<'a> {
   // 解析出来的语法
   grammar: &'a Grammar<'a>,
-  // 还需要 ast ，持有引用
-  ast: &'a dyn CompilationUnitContext,
+  // 还需要 ast ，持有引用, 第一个为输入文件, 其余为导入的文件
+  asts: &'a [&'a dyn CompilationUnitContext<'a>],
   
   // 输出路径
   output_dir: &'a Path,
@@ -33,8 +33,7 @@ pub struct CodeGenerator<'a> {
   #[allow(unused)]
   version: String,
 
-  // 考虑加入 analyze
-  #[allow(unused)]
+  // 生成的语法分析器所使用的分析方法
   analyzer: Analyzer,
 
   // 以及 target
@@ -61,17 +60,19 @@ pub struct CodeGenerator<'a> {
 }
 
 impl<'a> CodeGenerator<'a> {
+  #[allow(clippy::too_many_arguments)]
   pub fn new(
-    grammar: &'a Grammar, ast: &'a dyn CompilationUnitContext,
-    output_dir: &'a Path, input_file: &'a Path, _language: Language, package_name: Option<String>,
+    grammar: &'a Grammar, asts: &'a [&'a dyn CompilationUnitContext<'a>],
+    output_dir: &'a Path, input_file: &'a Path, language: Language, package_name: Option<String>,
     version: &str, analyzer: Analyzer,
-  ) -> Self {
+  ) -> Result<Self, Box<dyn Error>> {
+    let target = TargetRegistry::default().create(language)?;
 
-    Self {
-      grammar, ast, output_dir,package_name, version: version.to_owned(),target: Box::new(RustTarget::new()),
+    Ok(Self {
+      grammar, asts, output_dir,package_name, version: version.to_owned(), target,
       input_file, analyzer,
       lexer: true, parser: true, context: true, listener: true, visitor: true, walker: true,
-    }
+    })
   }
 
 
@@ -82,9 +83,8 @@ impl<'a> CodeGenerator<'a> {
   pub fn toggle_visitor_generation(&mut self, flag: bool) { self.visitor = flag; }
   pub fn toggle_walker_generation(&mut self, flag: bool) { self.walker = flag; }
 
-
-  // 先生成一个 Vocabulary
-  // let x = self.generate_vocabulary();
+  // 使用目录中的 .tera 文件覆盖 target 内置的模板
+  pub fn load_templates(&mut self, dir: &Path) -> Result<(), Box<dyn Error>> { self.target.load_templates(dir) }
 
 
   // 直接写文件即可
@@ -94,142 +94,135 @@ impl<'a> CodeGenerator<'a> {
 
 
     
-    let grammar_file_name = match self.input_file.as_os_str().to_str() {
-      Some(name) => name,
-      None => "<unknown>",
-    };
+    let grammar_file_name = self.input_file.as_os_str().to_str().unwrap_or("<unknown>");
     // 定义一些常用变量
-    let nonterminals: Vec<NameCaseWithId> = self.grammar.vocabulary.named_nonterminals.iter().map(|(k, v)| {
-      NameCaseWithId::new(v, *k)
+    let nonterminals: Vec<NameCaseWithId> = self.grammar.vocabulary.get_all_named_nonterminals().iter().map(|nonterminal| {
+      NameCaseWithId::new(nonterminal.name.unwrap(), nonterminal.id)
+    }).collect::<Vec<_>>();
+    let terminals = self.grammar.vocabulary.get_all_terminals().iter().map(|terminal| {
+      NameCaseWithId::new(terminal.name, terminal.id)
     }).collect::<Vec<_>>();
-    let terminals = self.grammar.vocabulary.terminals.iter().map(|(id, t)| {
-      NameCaseWithId::new(&t, *id)
+
+    // 首先解析 ast 获取每个规则以及每个标签的 context 信息
+    let mut visitor = ContextVisitor::new(
+      self.grammar.vocabulary.get_all_named_nonterminals().iter().map(|nonterminal| (nonterminal.name.unwrap().to_owned(), nonterminal.id)).collect(),
+      self.grammar.vocabulary.get_all_terminals().iter().map(|terminal| (terminal.name.to_owned(), terminal.id)).collect(),
+    );
+    for ast in self.asts.iter() {
+      ast.accept(&mut visitor)?;
+    }
+
+    // 备选分支的标签, 按照所属非终结符和名称排列
+    let mut label_names = visitor.label_table.iter().map(|(label, (rule_id, _, _))| {
+      NameCaseWithId::new(label, *rule_id)
     }).collect::<Vec<_>>();
+    label_names.sort_by(|a, b| (a.id, &a.origin_case).cmp(&(b.id, &b.origin_case)));
+
     let lexer: Option<String> = if self.lexer {
-      let mut lexer_rules = self.grammar.lexer_rule_map.values().map(|v| {
-        LexerCase::new(&v.token_name, v.token_type, &v.regex, v.channel, v.skip)
+      // 这里一定要按照优先级排序
+      let mut lexer_rules = self.grammar.lexer_rule_map.values().collect::<Vec<_>>();
+      lexer_rules.sort_by_key(|rule| rule.priority);
+      let lexer_rules = lexer_rules.into_iter().map(|v| {
+        LexerCase::new(&v.token_name, v.token_type, &v.regex, v.channel, v.skip, v.mode, v.mode_action)
       }).collect::<Vec<_>>();
-    
-      // 这里一定要排序
-      lexer_rules.sort_by(|a, b| a.token_type.cmp(&b.token_type));
-      let data = LexerGenData::new(self.grammar, self.ast, grammar_file_name, &self.version, self.package_name.as_deref(), &self.grammar.name, &lexer_rules);
+      let modes = self.grammar.lexer_modes.iter().filter(|(_, id)| **id != 0).map(|(name, id)| NameCaseWithId::new(name, *id)).collect::<Vec<_>>();
+      let data = LexerGenData::new(self.grammar, self.asts[0], grammar_file_name, &self.version, self.package_name.as_deref(), &self.grammar.name, &lexer_rules, &modes);
       
       
       Some(self.target.generate_lexer(&data)?)
     } else { None };
 
     let parser: Option<String> = if self.parser {
-
-      let (first, first_set) = self.grammar.first_set();
-  
-      let follow = self.grammar.follow_set(&first);
-    
-      let table = self.grammar.ll1_table(&first_set, &follow);
-
-      let mut sync: HashSet<(usize, usize)> = HashSet::new();
-      // 根据 follow 集合来生成 sync
-      for (id, followers) in follow.iter() {
-        for x in followers.iter() {
-          sync.insert((*id, *x));
-        }
-      }
-
       let data = ParserGenData::new(
-        self.grammar, self.ast, grammar_file_name, &self.version, self.package_name.as_deref(), 
-        &self.grammar.name, &nonterminals, &table, &terminals, &sync
+        self.grammar, self.asts[0], grammar_file_name, &self.version, self.package_name.as_deref(), 
+        &self.grammar.name, &nonterminals, &terminals, self.analyzer,
       );
       Some(self.target.generate_parser(&data)?)
     } else { None };
 
     let context: Option<String> = if self.context {
+      let table = &visitor.table;
+      let label_table = &visitor.label_table;
 
-      let terminals = self.grammar.vocabulary.get_all_terminals_map();
-  
-      let nonterminals = self.grammar.vocabulary.get_all_named_nonterminals_map();
-  
-  
-  
-  
-      // 首先解析 ast 获取 table
-      let mut visitor = ContextVisitor::new(nonterminals, terminals);
-      self.ast.accept(&mut visitor)?;
-    
-      let table = visitor.table;
-      let nonterminals = self.grammar.vocabulary.get_all_named_nonterminals();
-    
-      nonterminals.iter().for_each(|x| {
-        if ! table.contains_key(x) {
-          println!("{} {}", x, self.grammar.vocabulary.get_nonterminal_name_by_id(*x).unwrap())
-        }
-      });
-    
-    
-    
-      let ctx_list = nonterminals.iter()
-        .map(|id| { 
-          let c = table.get(id).unwrap().clone();
-
-          let rule_name = self.grammar.vocabulary.get_nonterminal_name_by_id(*id).unwrap();
-          let terminal_list = c.0.iter().map(|id| {
-            let name = self.grammar.vocabulary.get_terminal_name_by_id(*id).unwrap();
-            NameCaseWithId::new(&name, *id)
-          }).collect::<Vec<_>>();
-        
-          let terminal = c.1.iter().map(|id| {
-            let name = self.grammar.vocabulary.get_terminal_name_by_id(*id).unwrap();
-            NameCaseWithId::new(&name, *id)
-          }).collect::<Vec<_>>();
-        
-          let nonterminal_list = c.2.iter().map(|id| {
-            let name = self.grammar.vocabulary.get_nonterminal_name_by_id(*id).unwrap();
-            NameCaseWithId::new(&name, *id)
-          }).collect::<Vec<_>>();
-        
-          let nonterminal = c.3.iter().map(|id| {
-            let name = self.grammar.vocabulary.get_nonterminal_name_by_id(*id).unwrap();
-            NameCaseWithId::new(&name, *id)
-          }).collect::<Vec<_>>();
-
-          ContextCase::new(&rule_name, terminal_list, terminal, nonterminal_list, nonterminal)
-          // self.ctx_gen( *id, c, grammar) 
+      // 将编号集合转换为名称, 按照编号排序, 保证每次生成的代码相同
+      let terminal_cases = |ids: &HashSet<usize>| ids.iter().collect::<BTreeSet<_>>().into_iter().map(|id| {
+        let name = self.grammar.vocabulary.get_terminal_by_id(*id).unwrap().name;
+        NameCaseWithId::new(name, *id)
+      }).collect::<Vec<_>>();
+      let nonterminal_cases = |ids: &HashSet<usize>| ids.iter().collect::<BTreeSet<_>>().into_iter().map(|id| {
+        let name = self.grammar.vocabulary.get_nonterminal_by_id(*id).unwrap().name.unwrap();
+        NameCaseWithId::new(name, *id)
+      }).collect::<Vec<_>>();
+
+      let element_label_cases = |labels: &ElementLabels| {
+        let mut result = labels.iter().map(|(label, (id, list))| {
+          let nonterminal = id.map(|id| {
+            let name = self.grammar.vocabulary.get_nonterminal_by_id(id).unwrap().name.unwrap();
+            NameCaseWithId::new(name, id)
+          });
+          ElementLabelCase::new(label, nonterminal, *list)
+        }).collect::<Vec<_>>();
+        result.sort_by(|a, b| a.origin_case.cmp(&b.origin_case));
+        result
+      };
+
+      let mut ctx_list = self.grammar.vocabulary.get_all_named_nonterminals().iter()
+        .map(|nonterminal| { 
+          let c = table.get(&nonterminal.id).unwrap();
+          let labels = label_names.iter().filter(|label| label.id == nonterminal.id)
+            .map(|label| NameCase::new(&label.origin_case)).collect::<Vec<_>>();
+          let element_labels = element_label_cases(visitor.element_label_table.get(&nonterminal.id).unwrap());
+
+          ContextCase::new(nonterminal.name.unwrap(), terminal_cases(&c.0), terminal_cases(&c.1), nonterminal_cases(&c.2), nonterminal_cases(&c.3), labels, element_labels)
         }).collect::<Vec<ContextCase>>();
 
+      // 每个标签都有一个对应的 context
+      for label in label_names.iter() {
+        let (_, c, element_labels) = label_table.get(&label.origin_case).unwrap();
+        ctx_list.push(ContextCase::new(&label.origin_case, terminal_cases(&c.0), terminal_cases(&c.1), nonterminal_cases(&c.2), nonterminal_cases(&c.3), vec![], element_label_cases(element_labels)));
+      }
+
+      // 没有生成 visitor 或 listener 时, context 中不能引用它们
       let data = ContextGenData::new(
-        self.grammar, self.ast, grammar_file_name, &self.version, self.package_name.as_deref(), &self.grammar.name, &ctx_list
+        self.grammar, self.asts[0], grammar_file_name, &self.version, self.package_name.as_deref(), &self.grammar.name, &ctx_list,
+        self.visitor, self.listener,
       );
       Some(self.target.generate_context(&data)?)
     } else { None };
 
     let listener: Option<String> = if self.listener {
-      let data = VisitorOrListenerGenData::new(self.grammar, self.ast, grammar_file_name, &self.version, self.package_name.as_deref(), &self.grammar.name, &nonterminals);
+      let data = VisitorOrListenerGenData::new(self.grammar, self.asts[0], grammar_file_name, &self.version, self.package_name.as_deref(), &self.grammar.name, &nonterminals, &label_names);
 
       Some(self.target.generate_listener(&data)?)
     } else { None };
 
     let visitor: Option<String> = if self.visitor {
-      let data = VisitorOrListenerGenData::new(self.grammar, self.ast, grammar_file_name, &self.version, self.package_name.as_deref(), &self.grammar.name, &nonterminals);
+      let data = VisitorOrListenerGenData::new(self.grammar, self.asts[0], grammar_file_name, &self.version, self.package_name.as_deref(), &self.grammar.name, &nonterminals, &label_names);
 
       Some(self.target.generate_visitor(&data)?)
     } else { None };
 
-    let walker: Option<String> = if self.walker {
-      let data = WalkerGenData::new(self.grammar, self.ast, grammar_file_name, &self.version, self.package_name.as_deref(), &self.grammar.name);
+    // walker 依赖 listener, 不生成 listener 时也不生成 walker
+    let walker: Option<String> = if self.walker && self.listener {
+      let data = WalkerGenData::new(self.grammar, self.asts[0], grammar_file_name, &self.version, self.package_name.as_deref(), &self.grammar.name);
       Some(self.target.generate_walker(&data)?)
     } else { None };
 
+    // token 的编号, 可以通过 --token-vocab 传给其他语法, 使得编号保持一致
+    let vocabulary = self.target.generate_vocabulary(&VocabularyGenData::new(self.grammar))?;
+
 
     // write file
     let data = WriteFileData::new(
-      self.grammar, self.ast, 
+      self.grammar, self.asts[0], 
       grammar_file_name, 
       &self.version, 
       self.package_name.as_deref(),
-      &self.grammar.name, &self.output_dir, 
+      &self.grammar.name, self.output_dir, 
       
-      lexer, parser, context, visitor, listener, walker);
+      lexer, parser, context, visitor, listener, walker, Some(vocabulary));
 
-    self.target.write_file(&data);
-    Ok(())
+    self.target.write_file(&data)
   }
 
 }
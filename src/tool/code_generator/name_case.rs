@@ -1,6 +1,8 @@
-use std::{collections::{HashMap, HashSet}, path::Path};
+use std::{collections::{BTreeMap, BTreeSet}, path::Path};
 
-use crate::tool::{grammar::Grammar, syntaxis::chiru_context::CompilationUnitContext};
+use chiru::runtime::{lalr_analyzer::ActionTableElement, lexer_rule::ModeAction};
+
+use crate::tool::{cli::Analyzer, grammar::Grammar, syntaxis::chiru_context::CompilationUnitContext};
 
 
 
@@ -55,7 +57,7 @@ impl NameCase {
         return true;
       }
     }
-    return false;
+    false
   }
 
   pub fn new(name: &str) -> Self {
@@ -115,13 +117,13 @@ impl NameCase {
 
 
     for s in slice.iter() {
-      pascal_case += &Self::uppercase(&s, 0);
+      pascal_case += &Self::uppercase(s, 0);
       snake_case += s;
       snake_case += "_";
     }
     
     let camel_case = Self::lowercase(&pascal_case, 0);
-    if snake_case.len() > 0 {
+    if !snake_case.is_empty() {
       snake_case = snake_case[..snake_case.len()-1].to_owned();
     }
 
@@ -199,11 +201,25 @@ pub struct LexerCase {
   pub channel: usize,
 
   pub skip: bool,
+
+  // 所属的词法模式
+  pub mode: usize,
+
+  // 模式切换命令, 至多有一个生效
+  pub push_mode: Option<usize>,
+  pub pop_mode: bool,
+  pub set_mode: Option<usize>,
 }
 
 impl LexerCase {
-  pub fn new(token_name: &str, token_type: usize, regex: &str, channel: usize, skip: bool) -> Self {
+  pub fn new(token_name: &str, token_type: usize, regex: &str, channel: usize, skip: bool, mode: usize, mode_action: Option<ModeAction>) -> Self {
     let case = NameCase::new(token_name);
+    let (push_mode, pop_mode, set_mode) = match mode_action {
+      Some(ModeAction::Push(mode)) => (Some(mode), false, None),
+      Some(ModeAction::Pop) => (None, true, None),
+      Some(ModeAction::Set(mode)) => (None, false, Some(mode)),
+      None => (None, false, None),
+    };
     
     Self {
       screaming_snake_case: case.screaming_snake_case,
@@ -213,7 +229,8 @@ impl LexerCase {
       pascal_case: case.pascal_case,
       token_type,
       regex: regex.to_owned(),
-      channel, skip,
+      channel, skip, mode,
+      push_mode, pop_mode, set_mode,
     }
   }
 }
@@ -243,11 +260,18 @@ pub struct ContextCase {
   pub terminal: Vec<NameCaseWithId>,
   pub nonterminal_list: Vec<NameCaseWithId>,
   pub nonterminal: Vec<NameCaseWithId>,
+
+  // 规则中备选分支的标签, 用于派发到对应的 visit_<label> 方法
+  pub labels: Vec<NameCase>,
+
+  // 元素的标签, 如 lhs=expr
+  pub element_labels: Vec<ElementLabelCase>,
 }
 impl ContextCase {
+  #[allow(clippy::too_many_arguments)]
   pub fn new(
     ctx_name: &str, terminal_list: Vec<NameCaseWithId>, terminal: Vec<NameCaseWithId>,nonterminal_list: Vec<NameCaseWithId>,
-    nonterminal: Vec<NameCaseWithId>,
+    nonterminal: Vec<NameCaseWithId>, labels: Vec<NameCase>, element_labels: Vec<ElementLabelCase>,
   ) -> Self {
     let case = NameCase::new(ctx_name);
     Self {
@@ -256,7 +280,46 @@ impl ContextCase {
       pascal_case: case.pascal_case,
       camel_case: case.camel_case,
       snake_case: case.snake_case,
-      terminal, terminal_list, nonterminal, nonterminal_list,
+      terminal, terminal_list, nonterminal, nonterminal_list, labels, element_labels,
+    }
+  }
+}
+
+
+#[derive(serde::Serialize, Clone)]
+pub struct ElementLabelCase {
+  pub screaming_snake_case: String,
+
+  // 每个单词的首字母大写，单词直接连接在一起，没有分隔符
+  pub pascal_case: String,
+
+  // 第一个单词的首字母小写，后续单词的首字母大写，
+  // 单词直接连接在一起，没有分隔符
+  // 如 firstName
+  pub camel_case: String,
+
+  // 全小写, 用下划线连接
+  pub snake_case: String,
+
+  pub origin_case: String,
+
+  // 标签指向的非终结符, 指向终结符时为 None
+  pub nonterminal: Option<NameCaseWithId>,
+
+  // 使用 += 的标签会生成返回列表的方法
+  pub list: bool,
+}
+
+impl ElementLabelCase {
+  pub fn new(label: &str, nonterminal: Option<NameCaseWithId>, list: bool) -> Self {
+    let case = NameCase::new(label);
+    Self {
+      origin_case: case.origin_case,
+      screaming_snake_case: case.screaming_snake_case,
+      pascal_case: case.pascal_case,
+      camel_case: case.camel_case,
+      snake_case: case.snake_case,
+      nonterminal, list,
     }
   }
 }
@@ -273,23 +336,26 @@ pub struct VisitorOrListenerGenData<'a> {
 
   pub rule_names: Vec<NameCaseWithId>,
 
+  // 备选分支的标签, id 为标签所属的非终结符的编号
+  pub label_names: Vec<NameCaseWithId>,
+
   pub grammar: &'a Grammar<'a>,
-  pub ast: &'a dyn CompilationUnitContext,
+  pub ast: &'a dyn CompilationUnitContext<'a>,
 }
 
 impl<'a> VisitorOrListenerGenData<'a> {
-  pub fn new(grammar: &'a Grammar, ast: &'a dyn CompilationUnitContext, grammar_file_name: &str, version: &str, package_name: Option<&str>, grammar_name: &str, rule_names: &[NameCaseWithId]) -> Self {
-    let package_name: Option<NameCase> = if let Some(name) = package_name {
-      Some(NameCase::new(name))
-    } else { None };
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(grammar: &'a Grammar, ast: &'a dyn CompilationUnitContext<'a>, grammar_file_name: &str, version: &str, package_name: Option<&str>, grammar_name: &str, rule_names: &[NameCaseWithId], label_names: &[NameCaseWithId]) -> Self {
+    let package_name: Option<NameCase> = package_name.map(NameCase::new);
     let grammar_name = NameCase::new(grammar_name);
-    let rule_names = rule_names.iter().cloned().collect::<Vec<_>>();
+    let rule_names = rule_names.to_vec();
+    let label_names = label_names.to_vec();
     
     Self {
       grammar_file_name: grammar_file_name.to_owned(),
       version: version.to_owned(),
       package_name, grammar_name,
-      rule_names, grammar, ast,
+      rule_names, label_names, grammar, ast,
     }
   }
 }
@@ -302,22 +368,25 @@ pub struct ContextGenData<'a> {
 
   pub context_list: Vec<ContextCase>,
 
+  // 是否同时生成了 visitor 和 listener
+  pub visitor: bool,
+  pub listener: bool,
+
   pub grammar: &'a Grammar<'a>,
-  pub ast: &'a dyn CompilationUnitContext,
+  pub ast: &'a dyn CompilationUnitContext<'a>,
 }
 
 impl<'a> ContextGenData<'a>  {
-  pub fn new(grammar: &'a Grammar, ast: &'a dyn CompilationUnitContext,grammar_file_name: &str, version: &str, package_name: Option<&str>, grammar_name: &str, context_list: &[ContextCase]) -> Self {
-    let package_name: Option<NameCase> = if let Some(name) = package_name {
-      Some(NameCase::new(name))
-    } else { None };
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(grammar: &'a Grammar, ast: &'a dyn CompilationUnitContext<'a>,grammar_file_name: &str, version: &str, package_name: Option<&str>, grammar_name: &str, context_list: &[ContextCase], visitor: bool, listener: bool) -> Self {
+    let package_name: Option<NameCase> = package_name.map(NameCase::new);
     let grammar_name = NameCase::new(grammar_name);
-    let context_list = context_list.iter().cloned().collect::<Vec<_>>();
+    let context_list = context_list.to_vec();
     Self {
       grammar_file_name: grammar_file_name.to_owned(),
       version: version.to_owned(),
       package_name, grammar_name,
-      context_list, grammar, ast,
+      context_list, visitor, listener, grammar, ast,
     }
   }
 }
@@ -329,14 +398,12 @@ pub struct WalkerGenData<'a> {
   pub grammar_name: NameCase,
 
   pub grammar: &'a Grammar<'a>,
-  pub ast: &'a dyn CompilationUnitContext,
+  pub ast: &'a dyn CompilationUnitContext<'a>,
 }
 
 impl<'a> WalkerGenData<'a> {
-  pub fn new(grammar: &'a Grammar, ast: &'a dyn CompilationUnitContext,grammar_file_name: &str, version: &str, package_name: Option<&str>, grammar_name: &str) -> Self {
-    let package_name: Option<NameCase> = if let Some(name) = package_name {
-      Some(NameCase::new(name))
-    } else { None };
+  pub fn new(grammar: &'a Grammar, ast: &'a dyn CompilationUnitContext<'a>,grammar_file_name: &str, version: &str, package_name: Option<&str>, grammar_name: &str) -> Self {
+    let package_name: Option<NameCase> = package_name.map(NameCase::new);
     let grammar_name = NameCase::new(grammar_name);
     
     Self {
@@ -356,24 +423,27 @@ pub struct LexerGenData<'a> {
 
 
   pub lexer_rule_list: Vec<LexerCase>,
+
+  // 除默认模式之外的词法模式的名称和编号
+  pub mode_list: Vec<NameCaseWithId>,
   pub grammar: &'a Grammar<'a>,
-  pub ast: &'a dyn CompilationUnitContext,
+  pub ast: &'a dyn CompilationUnitContext<'a>,
   
 }
 
 impl<'a> LexerGenData<'a> {
-  pub fn new(grammar: &'a Grammar, ast: &'a dyn CompilationUnitContext, grammar_file_name: &str, version: &str, package_name: Option<&str>, grammar_name: &str, lexer_rule_list: &[LexerCase]) -> Self {
-    let package_name: Option<NameCase> = if let Some(name) = package_name {
-      Some(NameCase::new(name))
-    } else { None };
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(grammar: &'a Grammar, ast: &'a dyn CompilationUnitContext<'a>, grammar_file_name: &str, version: &str, package_name: Option<&str>, grammar_name: &str, lexer_rule_list: &[LexerCase], mode_list: &[NameCaseWithId]) -> Self {
+    let package_name: Option<NameCase> = package_name.map(NameCase::new);
     let grammar_name = NameCase::new(grammar_name);
-    let lexer_rule_list = lexer_rule_list.iter().cloned().collect::<Vec<_>>();
+    let lexer_rule_list = lexer_rule_list.to_vec();
+    let mode_list = mode_list.to_vec();
 
     
     Self {
       grammar_file_name: grammar_file_name.to_owned(),
       version: version.to_owned(),
-      package_name, grammar_name, lexer_rule_list,
+      package_name, grammar_name, lexer_rule_list, mode_list,
       grammar, ast,
     }
   }
@@ -385,64 +455,101 @@ pub struct ParserGenData<'a> {
   pub package_name: Option<NameCase>,
   pub grammar_name: NameCase,
 
-
-  pub table: HashMap<(usize, usize), usize>,
+  pub analyzer: Analyzer,
   pub rule_names: Vec<NameCaseWithId>,
   pub terminal_names: Vec<NameCaseWithId>,
-  pub sync_list: HashSet<(usize, usize)>,
+  pub unnamed_nonterminals: Vec<usize>,
+
+  // ll1 分析表和同步集合, 使用 lalr 分析时为空
+  pub table: BTreeMap<(usize, usize), usize>,
+  pub sync_list: BTreeSet<(usize, usize)>,
+  pub left_recursion_tails: Vec<(usize, usize)>,
+
+  // lalr 分析所需的 action 表、goto 表和初始状态, 使用 ll1 分析时为空
+  pub action_table: BTreeMap<(usize, usize), ActionTableElement>,
+  pub goto_table: BTreeMap<(usize, usize), usize>,
+  pub start_states: BTreeMap<usize, usize>,
 
   pub grammar: &'a Grammar<'a>,
-  pub ast: &'a dyn CompilationUnitContext,
+  pub ast: &'a dyn CompilationUnitContext<'a>,
 }
 
 impl<'a> ParserGenData<'a> {
-  pub fn new(grammar: &'a Grammar, ast: &'a dyn CompilationUnitContext, 
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(grammar: &'a Grammar, ast: &'a dyn CompilationUnitContext<'a>, 
     grammar_file_name: &str, version: &str, 
     package_name: Option<&str>, grammar_name: &str, 
-    rule_names: &[NameCaseWithId],
-    table: &HashMap<(usize, usize), usize>, terminal_names: &[NameCaseWithId], sync_list: &HashSet<(usize, usize)>
+    rule_names: &[NameCaseWithId], terminal_names: &[NameCaseWithId], analyzer: Analyzer,
   ) -> Self {
-    let package_name: Option<NameCase> = if let Some(name) = package_name {
-      Some(NameCase::new(name))
-    } else { None };
+    let package_name: Option<NameCase> = package_name.map(NameCase::new);
     let grammar_name = NameCase::new(grammar_name);
-    let rule_names = rule_names.iter().cloned().collect::<Vec<_>>();
-    let terminal_names = terminal_names.iter().cloned().collect::<Vec<_>>();
+    let rule_names = rule_names.to_vec();
+    let terminal_names = terminal_names.to_vec();
+    let unnamed_nonterminals = grammar.vocabulary.get_all_nonterminals().iter()
+      .filter(|nonterminal| nonterminal.name.is_none()).map(|nonterminal| nonterminal.id).collect::<Vec<_>>();
+
+    let mut table = BTreeMap::new();
+    let mut sync_list = BTreeSet::new();
+    let mut left_recursion_tails = Vec::new();
+    let mut action_table = BTreeMap::new();
+    let mut goto_table = BTreeMap::new();
+    let mut start_states = BTreeMap::new();
+    match analyzer {
+      Analyzer::LL1 => {
+        let (first, first_set) = grammar.first_set();
+        let follow = grammar.follow_set(&first);
+        table = grammar.ll1_table(&first_set, &follow).0.iter()
+          .map(|((nonterminal, terminal), production_id)| ((nonterminal.id, terminal.id), *production_id)).collect();
+
+        // 根据 follow 集合来生成 sync
+        for (nonterminal, followers) in follow.iter() {
+          for terminal in followers.iter() {
+            sync_list.insert((nonterminal.id, terminal.id));
+          }
+        }
+        left_recursion_tails = grammar.left_recursion_tails.iter().map(|(tail, nonterminal)| (tail.id, nonterminal.id)).collect();
+      },
+      Analyzer::LALR => {
+        let lalr_table = grammar.lalr_table();
+        action_table = lalr_table.action.iter().map(|((state, terminal), action)| ((*state, terminal.id), *action)).collect();
+        goto_table = lalr_table.goto.iter().map(|((state, nonterminal), next_state)| ((*state, nonterminal.id), *next_state)).collect();
+        start_states = lalr_table.start_states.iter().map(|(nonterminal, state)| (nonterminal.id, *state)).collect();
+      },
+    }
 
     Self {
       grammar_file_name: grammar_file_name.to_owned(),
       version: version.to_owned(),
-      package_name, grammar_name,
-      rule_names, table: table.clone(), sync_list: sync_list.clone(),terminal_names,
+      package_name, grammar_name, analyzer,
+      rule_names, terminal_names, unnamed_nonterminals,
+      table, sync_list, left_recursion_tails, action_table, goto_table, start_states,
       grammar, ast,
     }
   }
 }
 
+// .tokens 文件中的每一项, 名称和编号, 字符串字面量以字面量本身作为名称
 pub struct VocabularyGenData {
-  pub rule_names: Vec<NameCaseWithId>,
-  pub terminal_names: Vec<NameCaseWithId>,
-  pub unnamed_nonterminal_ids: Vec<usize>
+  pub token_list: Vec<(String, usize)>,
 }
 
-// impl<'a> VocabularyGenData {
-//   pub fn new(grammar: &'a Grammar) -> Self {
-//     let terminal_names: Vec<_> = grammar.vocabulary.terminals.iter().map(|(id, name)| NameCaseWithId::new(name, *id)).collect();
-//     let rule_names: Vec<NameCaseWithId> = grammar.vocabulary.named_nonterminals.iter().map(|(k, v)| {
-//       NameCaseWithId::new(v, *k)
-//     }).collect::<Vec<_>>();
+impl VocabularyGenData {
+  pub fn new(grammar: &Grammar) -> Self {
+    let mut token_list = Vec::new();
+    for (key, rule) in grammar.lexer_rule_map.iter() {
+      token_list.push((rule.token_name.to_owned(), rule.token_type));
+      if *key != rule.token_name { token_list.push((key.to_owned(), rule.token_type)); }
+    }
+    // 按照编号排序, 字面量的生成名称排在字面量之前
+    token_list.sort_by(|a, b| (a.1, &a.0).cmp(&(b.1, &b.0)));
 
-//     let unnamed_nonterminal_ids = grammar.vocabulary.get_all_nonterminals();
-    
-//     Self {
-//       terminal_names, rule_names, unnamed_nonterminal_ids
-//     }
-//   }
-// }
+    Self { token_list }
+  }
+}
 
 pub struct WriteFileData<'a> {
   pub grammar: &'a Grammar<'a>,
-  pub ast: &'a dyn CompilationUnitContext,
+  pub ast: &'a dyn CompilationUnitContext<'a>,
 
   pub grammar_file_name: String, 
   pub version: String, 
@@ -455,26 +562,26 @@ pub struct WriteFileData<'a> {
   pub visitor: Option<String>,
   pub listener: Option<String>,
   pub walker: Option<String>,
+  pub vocabulary: Option<String>,
 
   pub output_dir: &'a Path,
 }
 
 impl<'a> WriteFileData<'a> {
+  #[allow(clippy::too_many_arguments)]
   pub fn new(
-    grammar: &'a Grammar, ast: &'a dyn CompilationUnitContext, 
+    grammar: &'a Grammar, ast: &'a dyn CompilationUnitContext<'a>, 
     grammar_file_name: &str, version: &str, 
     package_name: Option<&str>, grammar_name: &str,
     output_dir: &'a Path,
-    lexer: Option<String>, parser: Option<String>, context: Option<String>, visitor: Option<String>, listener: Option<String>, walker: Option<String>, 
+    lexer: Option<String>, parser: Option<String>, context: Option<String>, visitor: Option<String>, listener: Option<String>, walker: Option<String>, vocabulary: Option<String>,
   ) -> Self {
-    let package_name: Option<NameCase> = if let Some(name) = package_name {
-      Some(NameCase::new(name))
-    } else { None };
+    let package_name: Option<NameCase> = package_name.map(NameCase::new);
     let grammar_name = NameCase::new(grammar_name);
 
     Self {
       grammar, ast, grammar_file_name: grammar_file_name.to_owned(),version: version.to_owned(),package_name, grammar_name,
-      lexer, parser, context, visitor, listener, walker, output_dir,
+      lexer, parser, context, visitor, listener, walker, vocabulary, output_dir,
     }
   }
 }
@@ -0,0 +1,93 @@
+pub mod rust_target;
+pub mod typescript_target;
+pub mod python_target;
+pub mod ruby_target;
+pub mod registry;
+
+
+
+
+use std::{error::Error, fs::{self, File}, io::Write, path::Path};
+
+use tera::Tera;
+
+use super::{language::Language, name_case::{ContextGenData, LexerGenData, ParserGenData, VisitorOrListenerGenData, VocabularyGenData, WalkerGenData, WriteFileData}};
+
+
+
+pub trait Target {
+  fn get_language(&self) -> Language;
+
+  fn get_reserved_words(&self) -> &[&str];
+
+  // 内置的模板, 通过 include_str! 嵌入
+  fn get_template_mut(&mut self) -> &mut Tera;
+
+  // 使用 dir 中同名的 .tera 文件覆盖内置的模板, 如 lexer.tera 覆盖名为 lexer 的模板, 没有覆盖的模板保持不变
+  // 文件名必须是该 target 已有的模板名称, 并且模板能够正确解析
+  fn load_templates(&mut self, dir: &Path) -> Result<(), Box<dyn Error>> {
+    let language = self.get_language();
+    let template = self.get_template_mut();
+    let mut names = template.get_template_names().map(|name| name.to_owned()).collect::<Vec<_>>();
+    names.sort();
+
+    if !dir.is_dir() {
+      return Err(format!("template directory '{}' does not exist", dir.display()).into());
+    }
+    let mut paths = fs::read_dir(dir)?.map(|entry| entry.map(|entry| entry.path())).collect::<Result<Vec<_>, _>>()?;
+    paths.sort();
+
+    let mut overrides = vec![];
+    for path in paths.iter().filter(|path| path.extension().is_some_and(|ext| ext == "tera")) {
+      let name = path.file_stem().and_then(|name| name.to_str()).unwrap_or_default();
+      if !names.iter().any(|item| item == name) {
+        return Err(format!(
+          "unknown template '{}', the {:?} target only uses the templates: {}", path.display(), language, names.join(", ")
+        ).into());
+      }
+      overrides.push((name.to_owned(), fs::read_to_string(path)?));
+    }
+
+    // tera 的错误信息记录在 source 中
+    template.add_raw_templates(overrides).map_err(|err| {
+      let mut message = format!("fail to load templates from '{}'", dir.display());
+      let mut source: Option<&dyn Error> = Some(&err);
+      while let Some(err) = source {
+        message += &format!(": {}", err);
+        source = err.source();
+      }
+      message
+    })?;
+    Ok(())
+  }
+
+
+
+  fn generate_visitor(&self, data: &VisitorOrListenerGenData) -> Result<String, Box<dyn Error>>;
+
+  fn generate_listener(&self, data: &VisitorOrListenerGenData) -> Result<String, Box<dyn Error>>;
+
+  fn generate_walker(&self, data: &WalkerGenData) -> Result<String, Box<dyn Error>>;
+
+  fn generate_context(&self, data: &ContextGenData) -> Result<String, Box<dyn Error>>;
+
+  fn generate_parser(&self, data: &ParserGenData) -> Result<String, Box<dyn Error>>;
+
+  fn generate_lexer(&self, data: &LexerGenData) -> Result<String, Box<dyn Error>>;
+
+  fn generate_vocabulary(&self, data: &VocabularyGenData) -> Result<String, Box<dyn Error>>;
+
+  // 将生成的代码写入输出目录, 输出目录不存在或者无法写入时返回错误
+  fn write_file(&self, data: &WriteFileData) -> Result<(), Box<dyn Error>>;
+
+
+}
+
+// 写入一个生成的文件, 各个 target 共用
+pub fn write(path: &Path, content: &str) -> Result<(), Box<dyn Error>> {
+  File::create(path)
+    .and_then(|mut file| file.write_all(content.as_bytes()))
+    .map_err(|err| format!("fail to write file '{}': {}", path.display(), err))?;
+  println!("'{}' generated", path.display());
+  Ok(())
+}
@@ -0,0 +1,289 @@
+
+use std::{error::Error, path::Path};
+
+use chiru::runtime::production::{Production, ProductionItem};
+use tera::{Tera, Context};
+
+use crate::tool::cli::Analyzer;
+use crate::tool::grammar::lexer_rule::strip_raw_literal;
+use crate::tool::code_generator::{language::Language, name_case::{ContextGenData, LexerGenData, NameCaseWithId, ParserGenData, VisitorOrListenerGenData, VocabularyGenData, WalkerGenData, WriteFileData}};
+use super::{Target, write};
+
+
+
+// 生成的 python 代码依赖 chiru_runtime 包, 位于仓库的 runtime/python 目录
+pub struct PythonTarget {
+  pub template: Tera,
+  pub reserved_words: Vec<&'static str>
+}
+
+impl Default for PythonTarget {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl PythonTarget {
+  pub fn new() -> Self {
+    let mut template = Tera::default();
+    template.add_raw_template("lexer", include_str!("../../templates/target/python/lexer.tera")).unwrap();
+    template.add_raw_template("parser", include_str!("../../templates/target/python/parser.tera")).unwrap();
+    template.add_raw_template("context", include_str!("../../templates/target/python/context.tera")).unwrap();
+    template.add_raw_template("listener", include_str!("../../templates/target/python/listener.tera")).unwrap();
+    template.add_raw_template("visitor", include_str!("../../templates/target/python/visitor.tera")).unwrap();
+    template.add_raw_template("walker", include_str!("../../templates/target/python/walker.tera")).unwrap();
+    template.add_raw_template("header", include_str!("../../templates/target/python/header.tera")).unwrap();
+    template.add_raw_template("vocabulary", include_str!("../../templates/target/python/vocabulary.tera")).unwrap();
+    template.autoescape_on(vec![]);
+
+    let reserved_words = vec! {
+      // 关键字
+      "False", "None", "True", "and", "as", "assert", "async", "await",
+      "break", "class", "continue", "def", "del", "elif", "else", "except",
+      "finally", "for", "from", "global", "if", "import", "in", "is",
+      "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try",
+      "while", "with", "yield",
+
+      // 运行时 RuleContext 的属性以及生成的 context 中的方法
+      "symbol", "children", "label", "element_label", "accept", "enter", "exit",
+    };
+    Self { template, reserved_words }
+  }
+
+  // 与保留字冲突的名称在末尾加上下划线, 如 lambda_
+  fn escape_name(&self, name: &str) -> String {
+    if self.reserved_words.contains(&name) { format!("{}_", name) } else { name.to_owned() }
+  }
+
+  fn escape_names(&self, names: &[NameCaseWithId]) -> Vec<NameCaseWithId> {
+    names.iter().cloned().map(|mut name| {
+      name.snake_case = self.escape_name(&name.snake_case);
+      name
+    }).collect()
+  }
+
+
+  fn production_generate(&self, production: &Production) -> String {
+    let right = production.right.iter().map(|item| match item {
+      ProductionItem::NonTerminal(nonterminal) => format!("nonterminal({})", nonterminal.id),
+      ProductionItem::Terminal(terminal) => format!("terminal({})", terminal.id),
+    }).collect::<Vec<_>>().join(", ");
+
+    let mut result = format!("Production({}, nonterminal({}), [{}]", production.id, production.left.id, right);
+    let has_element_labels = production.element_labels.iter().any(|label| label.is_some());
+    if production.label.is_some() || has_element_labels {
+      match production.label {
+        Some(label) => result += &format!(", \"{}\"", label),
+        None => result += ", None",
+      }
+    }
+    if has_element_labels {
+      let labels = production.element_labels.iter().map(|label| match label {
+        Some(label) => format!("\"{}\"", label),
+        None => String::from("None"),
+      }).collect::<Vec<_>>().join(", ");
+      result += &format!(", [{}]", labels);
+    }
+    result + ")"
+  }
+
+  // 将 r#"..."# 形式的 rust 正则表达式转换为调用 re.compile 的表达式
+  // python 的 re 模块不支持 \x{..} 和 \p{..}, \x{..} 转换为 \U........, \z 转换为 \Z
+  fn regex_generate(&self, token_name: &str, regex: &str) -> Result<String, Box<dyn Error>> {
+    let pattern = strip_raw_literal(regex);
+
+    let prefix = regex::Regex::new(r"^\(\?([a-zA-Z]+)\)").unwrap();
+    if let Some(captures) = prefix.captures(&pattern) {
+      if let Some(flag) = captures[1].chars().find(|flag| !"imsux".contains(*flag)) {
+        return Err(format!("the flag '{}' used by token '{}' is not supported by the Python target", flag, token_name).into());
+      }
+    }
+
+    let mut result = String::new();
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+      if c != '\\' { result.push(c); continue; }
+
+      match chars.next() {
+        Some('x') if chars.peek() == Some(&'{') => {
+          chars.next();
+          let hex = chars.by_ref().take_while(|c| *c != '}').collect::<String>();
+          let code = u32::from_str_radix(&hex, 16)
+            .map_err(|_| format!("invalid escape '\\x{{{}}}' used by token '{}'", hex, token_name))?;
+          result += &format!("\\U{:08x}", code);
+        },
+        Some(class @ ('p' | 'P')) => {
+          return Err(format!("the unicode class '\\{}' used by token '{}' is not supported by the Python target", class, token_name).into());
+        },
+        Some('z') => result += "\\Z",
+        Some(c) => { result.push('\\'); result.push(c); },
+        None => result.push('\\'),
+      }
+    }
+
+    // json 字符串同时也是合法的 python 字符串
+    Ok(format!("re.compile({})", serde_json::to_string(&result)?))
+  }
+
+}
+
+
+impl Target for PythonTarget {
+  fn get_language(&self) -> Language {
+    Language::Python
+  }
+
+  fn get_reserved_words(&self) -> &[&str] {
+    &self.reserved_words
+  }
+
+  fn get_template_mut(&mut self) -> &mut Tera {
+    &mut self.template
+  }
+
+
+  fn generate_visitor(&self, data: &VisitorOrListenerGenData) -> Result<String, Box<dyn Error>> {
+    let mut context = Context::new();
+    context.insert("grammar_file_name", &data.grammar_file_name);
+    context.insert("version", &data.version);
+    context.insert("grammar_name", &data.grammar_name);
+    context.insert("package_name", &data.package_name);
+
+    context.insert("nonterminals", &data.rule_names);
+    context.insert("label_names", &data.label_names);
+    let result = self.template.render("visitor", &context)?;
+    Ok(result)
+  }
+
+  fn generate_listener(&self, data: &VisitorOrListenerGenData) -> Result<String, Box<dyn Error>> {
+    let mut context = Context::new();
+    context.insert("grammar_file_name", &data.grammar_file_name);
+    context.insert("version", &data.version);
+    context.insert("grammar_name", &data.grammar_name);
+    context.insert("package_name", &data.package_name);
+
+    context.insert("rule_names", &data.rule_names);
+    context.insert("label_names", &data.label_names);
+    let result = self.template.render("listener", &context)?;
+    Ok(result)
+  }
+
+  fn generate_walker(&self, data: &WalkerGenData) -> Result<String, Box<dyn Error>> {
+    let mut context = Context::new();
+    context.insert("grammar_file_name", &data.grammar_file_name);
+    context.insert("version", &data.version);
+    context.insert("grammar_name", &data.grammar_name);
+    context.insert("package_name", &data.package_name);
+
+    let result = self.template.render("walker", &context)?;
+    Ok(result)
+  }
+
+  fn generate_context(&self, data: &ContextGenData) -> Result<String, Box<dyn Error>> {
+    // context 中的方法以终结符、非终结符和元素标签命名, 需要避开保留字, 带有 _list 后缀的方法不会冲突
+    let context_list = data.context_list.iter().cloned().map(|mut ctx| {
+      ctx.terminal = self.escape_names(&ctx.terminal);
+      ctx.nonterminal = self.escape_names(&ctx.nonterminal);
+      for label in ctx.element_labels.iter_mut() {
+        label.snake_case = self.escape_name(&label.snake_case);
+      }
+      ctx
+    }).collect::<Vec<_>>();
+
+    let mut context = Context::new();
+    context.insert("grammar_file_name", &data.grammar_file_name);
+    context.insert("version", &data.version);
+    context.insert("grammar_name", &data.grammar_name);
+    context.insert("package_name", &data.package_name);
+
+    context.insert("context_list", &context_list);
+    context.insert("visitor", &data.visitor);
+    context.insert("listener", &data.listener);
+
+    let result = self.template.render("context", &context)?;
+    Ok(result)
+  }
+
+  fn generate_parser(&self, data: &ParserGenData) -> Result<String, Box<dyn Error>> {
+    // python 运行时只实现了 ll1 分析
+    if data.analyzer != Analyzer::LL1 {
+      return Err("the Python target only supports the LL(1) analyzer, use --analyzer ll1".into());
+    }
+
+    let productions = data.grammar.productions.iter().map(|(id, production)| {
+      (*id, self.production_generate(production))
+    }).collect::<Vec<_>>();
+    let table = data.table.iter().map(|((k1, k2), k3)| (*k1, *k2, *k3)).collect::<Vec<_>>();
+    let sync_list = data.sync_list.iter().cloned().collect::<Vec<_>>();
+
+    let mut context = Context::new();
+    context.insert("grammar_file_name", &data.grammar_file_name);
+    context.insert("version", &data.version);
+    context.insert("grammar_name", &data.grammar_name);
+    context.insert("package_name", &data.package_name);
+
+    context.insert("productions", &productions);
+    context.insert("rule_names", &self.escape_names(&data.rule_names));
+    context.insert("terminal_names", &data.terminal_names);
+    context.insert("unnamed_nonterminals", &data.unnamed_nonterminals);
+    context.insert("table", &table);
+    context.insert("sync_list", &sync_list);
+    context.insert("left_recursion_tails", &data.left_recursion_tails);
+
+    let result = self.template.render("parser", &context)?;
+    Ok(result)
+  }
+
+  fn generate_lexer(&self, data: &LexerGenData) -> Result<String, Box<dyn Error>> {
+    let mut lexer_rule_list = data.lexer_rule_list.clone();
+    for lexer_rule in lexer_rule_list.iter_mut() {
+      lexer_rule.regex = self.regex_generate(&lexer_rule.token_name, &lexer_rule.regex)?;
+    }
+
+    let mut context = Context::new();
+    context.insert("grammar_file_name", &data.grammar_file_name);
+    context.insert("version", &data.version);
+    context.insert("grammar_name", &data.grammar_name);
+    context.insert("package_name", &data.package_name);
+    context.insert("lexer_rule_list", &lexer_rule_list);
+    context.insert("mode_list", &data.mode_list);
+
+    let result = self.template.render("lexer", &context)?;
+    Ok(result)
+  }
+
+  fn generate_vocabulary(&self, data: &VocabularyGenData) -> Result<String, Box<dyn Error>> {
+    let mut context = Context::new();
+    context.insert("token_list", &data.token_list);
+
+    let result = self.template.render("vocabulary", &context)?;
+    Ok(result)
+  }
+
+  fn write_file(&self, data: &WriteFileData) -> Result<(), Box<dyn Error>> {
+    // 输出目录作为一个 python 包, __init__.py 导入所有生成的模块
+    let mut context = Context::new();
+    context.insert("grammar_file_name", &data.grammar_file_name);
+    context.insert("version", &data.version);
+    let mut init_str = self.template.render("header", &context)?;
+
+    let files = [
+      ("lexer", &data.lexer), ("parser", &data.parser), ("context", &data.context),
+      ("listener", &data.listener), ("visitor", &data.visitor), ("walker", &data.walker),
+    ];
+    for (kind, content) in files {
+      if let Some(content) = content {
+        let module = format!("{}_{}", data.grammar_name.snake_case, kind);
+        init_str += &format!("from .{} import *\n", module);
+        write(&Path::new(data.output_dir).join(format!("{}.py", module)), content)?;
+      }
+    }
+
+    // .tokens 文件不是源代码, 不需要导入
+    if let Some(vocabulary) = &data.vocabulary {
+      write(&Path::new(data.output_dir).join(format!("{}.tokens", data.grammar_name.pascal_case)), vocabulary)?;
+    }
+
+    write(&Path::new(data.output_dir).join("__init__.py"), &init_str)
+  }
+}
@@ -0,0 +1,68 @@
+use std::{collections::BTreeMap, error::Error};
+
+use crate::tool::code_generator::language::Language;
+
+use super::{Target, rust_target::RustTarget, typescript_target::TypeScriptTarget, python_target::PythonTarget, ruby_target::RubyTarget};
+
+
+// 创建 target 的函数, 只有真正需要生成代码时才会加载对应的模板
+pub type TargetFactory = fn() -> Box<dyn Target>;
+
+// 语言到 target 的映射, 添加新的 target 之后在 default 中注册即可
+pub struct TargetRegistry {
+  factories: BTreeMap<Language, TargetFactory>,
+}
+
+impl Default for TargetRegistry {
+  fn default() -> Self {
+    let mut registry = Self::new();
+    registry.register(Language::Rust, || Box::new(RustTarget::new()));
+    registry.register(Language::TypeScript, || Box::new(TypeScriptTarget::new()));
+    registry.register(Language::Python, || Box::new(PythonTarget::new()));
+    registry.register(Language::Ruby, || Box::new(RubyTarget::new()));
+    registry
+  }
+}
+
+impl TargetRegistry {
+  // 一个空的注册表
+  pub fn new() -> Self {
+    Self { factories: BTreeMap::new() }
+  }
+
+  // 已经注册过的语言会被覆盖
+  pub fn register(&mut self, language: Language, factory: TargetFactory) {
+    self.factories.insert(language, factory);
+  }
+
+  pub fn create(&self, language: Language) -> Result<Box<dyn Target>, Box<dyn Error>> {
+    match self.factories.get(&language) {
+      Some(factory) => Ok(factory()),
+      None => Err(format!("no code generation target is registered for language '{:?}'", language).into()),
+    }
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use crate::tool::code_generator::language::Language;
+
+  use super::TargetRegistry;
+
+  #[test]
+  fn registry_test() {
+    // 默认的注册表包含所有内置的 target
+    let registry = TargetRegistry::default();
+    for language in [Language::Rust, Language::TypeScript, Language::Python, Language::Ruby] {
+      assert_eq!(registry.create(language).unwrap().get_language(), language);
+    }
+
+    // 没有注册的语言返回错误, 而不是退回到 rust
+    let registry = TargetRegistry::new();
+    assert_eq!(
+      registry.create(Language::Python).err().unwrap().to_string(),
+      "no code generation target is registered for language 'Python'"
+    );
+  }
+}
@@ -0,0 +1,326 @@
+
+use std::{error::Error, path::Path};
+
+use chiru::runtime::production::{Production, ProductionItem};
+use tera::{Tera, Context};
+
+use crate::tool::cli::Analyzer;
+use crate::tool::grammar::lexer_rule::strip_raw_literal;
+use crate::tool::code_generator::{language::Language, name_case::{ContextGenData, LexerGenData, NameCaseWithId, ParserGenData, VisitorOrListenerGenData, VocabularyGenData, WalkerGenData, WriteFileData}};
+use super::{Target, write};
+
+
+
+// 生成的 ruby 代码依赖 chiru_runtime gem, 位于仓库的 runtime/ruby 目录
+pub struct RubyTarget {
+  pub template: Tera,
+  pub reserved_words: Vec<&'static str>
+}
+
+impl Default for RubyTarget {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl RubyTarget {
+  pub fn new() -> Self {
+    let mut template = Tera::default();
+    template.add_raw_template("lexer", include_str!("../../templates/target/ruby/lexer.tera")).unwrap();
+    template.add_raw_template("parser", include_str!("../../templates/target/ruby/parser.tera")).unwrap();
+    template.add_raw_template("context", include_str!("../../templates/target/ruby/context.tera")).unwrap();
+    template.add_raw_template("listener", include_str!("../../templates/target/ruby/listener.tera")).unwrap();
+    template.add_raw_template("visitor", include_str!("../../templates/target/ruby/visitor.tera")).unwrap();
+    template.add_raw_template("walker", include_str!("../../templates/target/ruby/walker.tera")).unwrap();
+    template.add_raw_template("header", include_str!("../../templates/target/ruby/header.tera")).unwrap();
+    template.add_raw_template("vocabulary", include_str!("../../templates/target/ruby/vocabulary.tera")).unwrap();
+    template.autoescape_on(vec![]);
+
+    let reserved_words = vec! {
+      // 关键字
+      "BEGIN", "END", "alias", "and", "begin", "break", "case", "class",
+      "def", "defined?", "do", "else", "elsif", "end", "ensure", "false",
+      "for", "if", "in", "module", "next", "nil", "not", "or", "redo",
+      "rescue", "retry", "return", "self", "super", "then", "true", "undef",
+      "unless", "until", "when", "while", "yield", "__FILE__", "__LINE__",
+      "__ENCODING__",
+
+      // 所有对象都有的方法
+      "clone", "display", "dup", "freeze", "hash", "inspect", "method",
+      "methods", "object_id", "send", "tap", "to_s",
+
+      // 运行时 RuleContext 的属性以及生成的 context 中的方法
+      "symbol", "children", "label", "element_label", "accept", "enter", "exit", "to_h",
+    };
+    Self { template, reserved_words }
+  }
+
+  // 与保留字冲突的名称在末尾加上下划线, 如 class_
+  fn escape_name(&self, name: &str) -> String {
+    if self.reserved_words.contains(&name) { format!("{}_", name) } else { name.to_owned() }
+  }
+
+  fn escape_names(&self, names: &[NameCaseWithId]) -> Vec<NameCaseWithId> {
+    names.iter().cloned().map(|mut name| {
+      name.snake_case = self.escape_name(&name.snake_case);
+      name
+    }).collect()
+  }
+
+
+  fn production_generate(&self, production: &Production) -> String {
+    let right = production.right.iter().map(|item| match item {
+      ProductionItem::NonTerminal(nonterminal) => format!("nonterminal({})", nonterminal.id),
+      ProductionItem::Terminal(terminal) => format!("terminal({})", terminal.id),
+    }).collect::<Vec<_>>().join(", ");
+
+    let mut result = format!("ChiruRuntime::Production.new({}, nonterminal({}), [{}]", production.id, production.left.id, right);
+    let has_element_labels = production.element_labels.iter().any(|label| label.is_some());
+    if production.label.is_some() || has_element_labels {
+      match production.label {
+        Some(label) => result += &format!(", \"{}\"", label),
+        None => result += ", nil",
+      }
+    }
+    if has_element_labels {
+      let labels = production.element_labels.iter().map(|label| match label {
+        Some(label) => format!("\"{}\"", label),
+        None => String::from("nil"),
+      }).collect::<Vec<_>>().join(", ");
+      result += &format!(", [{}]", labels);
+    }
+    result + ")"
+  }
+
+  // 将 r#"..."# 形式的 rust 正则表达式转换为构造 Regexp 的表达式
+  // ruby 中 ^ 和 $ 总是匹配行首和行尾, 没有 m 标志时需要转换为 \A 和 \z, rust 的 s 标志对应 ruby 的 MULTILINE
+  fn regex_generate(&self, token_name: &str, regex: &str) -> Result<String, Box<dyn Error>> {
+    let mut pattern = strip_raw_literal(regex);
+    let mut options = vec![];
+    let mut multi_line = false;
+
+    let prefix = regex::Regex::new(r"^\(\?([a-zA-Z]+)\)").unwrap();
+    while let Some(captures) = prefix.captures(&pattern.clone()) {
+      for flag in captures[1].chars() {
+        let option = match flag {
+          'i' => "Regexp::IGNORECASE",
+          's' => "Regexp::MULTILINE",
+          'x' => "Regexp::EXTENDED",
+          'm' => { multi_line = true; continue },
+          'u' => continue,
+          _ => return Err(format!("the flag '{}' used by token '{}' is not supported by the Ruby target", flag, token_name).into()),
+        };
+        if !options.contains(&option) { options.push(option); }
+      }
+      pattern = pattern[captures[0].len()..].to_owned();
+    }
+
+    let mut result = String::new();
+    let mut chars = pattern.chars().peekable();
+    // 是否位于字符类 [...] 中, 以及字符类中是否已经出现了字符
+    let mut in_class = false;
+    let mut class_empty = false;
+    while let Some(c) = chars.next() {
+      match c {
+        '\\' => match chars.next() {
+          Some('x') if chars.peek() == Some(&'{') => result += "\\u",
+          Some(class @ ('p' | 'P')) if chars.peek() != Some(&'{') => {
+            let name = chars.next().unwrap_or_default();
+            result += &format!("\\{}{{{}}}", class, name);
+          },
+          Some(c) => { result.push('\\'); result.push(c); },
+          None => result.push('\\'),
+        },
+        '[' if !in_class => {
+          in_class = true;
+          class_empty = true;
+          result.push('[');
+          if chars.peek() == Some(&'^') { chars.next(); result.push('^'); }
+          continue;
+        },
+        ']' if in_class && !class_empty => { in_class = false; result.push(']'); },
+        '^' if !in_class && !multi_line => result += "\\A",
+        '$' if !in_class && !multi_line => result += "\\z",
+        '(' if !in_class && pattern_starts_with(&mut chars, "?P<") => {
+          chars.next();
+          chars.next();
+          result += "(?";
+        },
+        c => result.push(c),
+      }
+      class_empty = false;
+    }
+
+    let source = result.replace('\\', "\\\\").replace('\'', "\\'");
+    if options.is_empty() {
+      Ok(format!("Regexp.new('{}')", source))
+    } else {
+      Ok(format!("Regexp.new('{}', {})", source, options.join(" | ")))
+    }
+  }
+
+}
+
+// 接下来的字符是否为 prefix, 不消耗字符
+fn pattern_starts_with(chars: &mut std::iter::Peekable<std::str::Chars>, prefix: &str) -> bool {
+  chars.clone().take(prefix.chars().count()).eq(prefix.chars())
+}
+
+
+impl Target for RubyTarget {
+  fn get_language(&self) -> Language {
+    Language::Ruby
+  }
+
+  fn get_reserved_words(&self) -> &[&str] {
+    &self.reserved_words
+  }
+
+  fn get_template_mut(&mut self) -> &mut Tera {
+    &mut self.template
+  }
+
+
+  fn generate_visitor(&self, data: &VisitorOrListenerGenData) -> Result<String, Box<dyn Error>> {
+    let mut context = Context::new();
+    context.insert("grammar_file_name", &data.grammar_file_name);
+    context.insert("version", &data.version);
+    context.insert("grammar_name", &data.grammar_name);
+    context.insert("package_name", &data.package_name);
+
+    context.insert("nonterminals", &data.rule_names);
+    context.insert("label_names", &data.label_names);
+    let result = self.template.render("visitor", &context)?;
+    Ok(result)
+  }
+
+  fn generate_listener(&self, data: &VisitorOrListenerGenData) -> Result<String, Box<dyn Error>> {
+    let mut context = Context::new();
+    context.insert("grammar_file_name", &data.grammar_file_name);
+    context.insert("version", &data.version);
+    context.insert("grammar_name", &data.grammar_name);
+    context.insert("package_name", &data.package_name);
+
+    context.insert("rule_names", &data.rule_names);
+    context.insert("label_names", &data.label_names);
+    let result = self.template.render("listener", &context)?;
+    Ok(result)
+  }
+
+  fn generate_walker(&self, data: &WalkerGenData) -> Result<String, Box<dyn Error>> {
+    let mut context = Context::new();
+    context.insert("grammar_file_name", &data.grammar_file_name);
+    context.insert("version", &data.version);
+    context.insert("grammar_name", &data.grammar_name);
+    context.insert("package_name", &data.package_name);
+
+    let result = self.template.render("walker", &context)?;
+    Ok(result)
+  }
+
+  fn generate_context(&self, data: &ContextGenData) -> Result<String, Box<dyn Error>> {
+    // context 中的方法以终结符、非终结符和元素标签命名, 需要避开保留字, 带有 _list 后缀的方法不会冲突
+    let context_list = data.context_list.iter().cloned().map(|mut ctx| {
+      ctx.terminal = self.escape_names(&ctx.terminal);
+      ctx.nonterminal = self.escape_names(&ctx.nonterminal);
+      for label in ctx.element_labels.iter_mut() {
+        label.snake_case = self.escape_name(&label.snake_case);
+      }
+      ctx
+    }).collect::<Vec<_>>();
+
+    let mut context = Context::new();
+    context.insert("grammar_file_name", &data.grammar_file_name);
+    context.insert("version", &data.version);
+    context.insert("grammar_name", &data.grammar_name);
+    context.insert("package_name", &data.package_name);
+
+    context.insert("context_list", &context_list);
+
+    let result = self.template.render("context", &context)?;
+    Ok(result)
+  }
+
+  fn generate_parser(&self, data: &ParserGenData) -> Result<String, Box<dyn Error>> {
+    // ruby 运行时只实现了 ll1 分析
+    if data.analyzer != Analyzer::LL1 {
+      return Err("the Ruby target only supports the LL(1) analyzer, use --analyzer ll1".into());
+    }
+
+    let productions = data.grammar.productions.iter().map(|(id, production)| {
+      (*id, self.production_generate(production))
+    }).collect::<Vec<_>>();
+    let table = data.table.iter().map(|((k1, k2), k3)| (*k1, *k2, *k3)).collect::<Vec<_>>();
+    let sync_list = data.sync_list.iter().cloned().collect::<Vec<_>>();
+
+    let mut context = Context::new();
+    context.insert("grammar_file_name", &data.grammar_file_name);
+    context.insert("version", &data.version);
+    context.insert("grammar_name", &data.grammar_name);
+    context.insert("package_name", &data.package_name);
+
+    context.insert("productions", &productions);
+    context.insert("rule_names", &self.escape_names(&data.rule_names));
+    context.insert("terminal_names", &data.terminal_names);
+    context.insert("unnamed_nonterminals", &data.unnamed_nonterminals);
+    context.insert("table", &table);
+    context.insert("sync_list", &sync_list);
+    context.insert("left_recursion_tails", &data.left_recursion_tails);
+
+    let result = self.template.render("parser", &context)?;
+    Ok(result)
+  }
+
+  fn generate_lexer(&self, data: &LexerGenData) -> Result<String, Box<dyn Error>> {
+    let mut lexer_rule_list = data.lexer_rule_list.clone();
+    for lexer_rule in lexer_rule_list.iter_mut() {
+      lexer_rule.regex = self.regex_generate(&lexer_rule.token_name, &lexer_rule.regex)?;
+    }
+
+    let mut context = Context::new();
+    context.insert("grammar_file_name", &data.grammar_file_name);
+    context.insert("version", &data.version);
+    context.insert("grammar_name", &data.grammar_name);
+    context.insert("package_name", &data.package_name);
+    context.insert("lexer_rule_list", &lexer_rule_list);
+    context.insert("mode_list", &data.mode_list);
+
+    let result = self.template.render("lexer", &context)?;
+    Ok(result)
+  }
+
+  fn generate_vocabulary(&self, data: &VocabularyGenData) -> Result<String, Box<dyn Error>> {
+    let mut context = Context::new();
+    context.insert("token_list", &data.token_list);
+
+    let result = self.template.render("vocabulary", &context)?;
+    Ok(result)
+  }
+
+  fn write_file(&self, data: &WriteFileData) -> Result<(), Box<dyn Error>> {
+    // 生成一个与语法同名的入口文件, 加载所有生成的文件
+    let mut context = Context::new();
+    context.insert("grammar_file_name", &data.grammar_file_name);
+    context.insert("version", &data.version);
+    let mut entry_str = self.template.render("header", &context)?;
+
+    let files = [
+      ("lexer", &data.lexer), ("parser", &data.parser), ("context", &data.context),
+      ("listener", &data.listener), ("visitor", &data.visitor), ("walker", &data.walker),
+    ];
+    for (kind, content) in files {
+      if let Some(content) = content {
+        let file_name = format!("{}_{}", data.grammar_name.snake_case, kind);
+        entry_str += &format!("require_relative \"{}\"\n", file_name);
+        write(&Path::new(data.output_dir).join(format!("{}.rb", file_name)), content)?;
+      }
+    }
+
+    // .tokens 文件不是源代码, 不需要加载
+    if let Some(vocabulary) = &data.vocabulary {
+      write(&Path::new(data.output_dir).join(format!("{}.tokens", data.grammar_name.pascal_case)), vocabulary)?;
+    }
+
+    write(&Path::new(data.output_dir).join(format!("{}.rb", data.grammar_name.snake_case)), &entry_str)
+  }
+}
@@ -0,0 +1,298 @@
+
+use std::{error::Error, path::Path};
+
+use chiru::runtime::{lalr_analyzer::ActionTableElement, lexer_dfa::LexerDfa, lexer_rule::LexerRule, production::{Production, ProductionItem}};
+use tera::{Tera, Context};
+
+use crate::tool::cli::Analyzer;
+use crate::tool::grammar::lexer_rule::strip_raw_literal;
+use crate::tool::code_generator::{language::Language, name_case::{ContextGenData, LexerCase, LexerGenData, ParserGenData, VisitorOrListenerGenData, VocabularyGenData, WalkerGenData, WriteFileData}};
+use super::{Target, write};
+
+
+
+pub struct RustTarget {
+  pub template: Tera,
+  pub reserved_words: Vec<&'static str>
+}
+
+impl Default for RustTarget {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl RustTarget {
+  pub fn new() -> Self {
+    let mut template = Tera::default();
+    // 添加
+    template.add_raw_template("lexer", include_str!("../../templates/target/rust/lexer.tera")).unwrap();
+    template.add_raw_template("parser", include_str!("../../templates/target/rust/parser.tera")).unwrap();
+    template.add_raw_template("lalr_parser", include_str!("../../templates/target/rust/lalr_parser.tera")).unwrap();
+    template.add_raw_template("context", include_str!("../../templates/target/rust/context.tera")).unwrap();
+    template.add_raw_template("listener", include_str!("../../templates/target/rust/listener.tera")).unwrap();
+    template.add_raw_template("visitor", include_str!("../../templates/target/rust/visitor.tera")).unwrap();
+    template.add_raw_template("walker", include_str!("../../templates/target/rust/walker.tera")).unwrap();
+    template.add_raw_template("header", include_str!("../../templates/target/rust/header.tera")).unwrap();
+    template.add_raw_template("vocabulary", include_str!("../../templates/target/rust/vocabulary.tera")).unwrap();
+    template.autoescape_on(vec![]);
+
+    let template = template;
+
+    let reserved_words = vec! {
+      // 严格关键字
+      "as", "break", "const", "continue", "crate", "else", "enum",
+      "extern", "false", "fn", "for", "if", "impl", "in", "let",
+      "loop", "match", "mod", "move", "mut", "pub", "ref", "return",
+      "self", "Self", "static", "struct", "super", "trait", "true",
+      "type", "unsafe", "use", "where", "while", "async", "await",
+      "dyn",
+
+      // 保留关键字
+      "abstract", "become", "box", "do", "final", "macro", "override",
+      "priv", "typeof", "unsized", "virtual", "yield", "try", "union",
+      "'static"
+
+    };
+    Self { template, reserved_words }
+
+
+  }
+
+
+
+
+  fn production_generate(&self, production: &Production) -> String {
+
+    let mut result = String::from("[");
+    for item in production.right.iter() {
+      match item {
+        ProductionItem::NonTerminal(nonterminal) => {
+          result += &format!("ProductionItem::NonTerminal(nonterminal({})),", nonterminal.id);
+        },
+        ProductionItem::Terminal(terminal) => {
+          result += &format!("ProductionItem::Terminal(terminal({})),", terminal.id);
+        },
+      }
+    }
+    result += "]";
+    
+    let mut result = format!("Production::new({}, nonterminal({}), &{})", production.id, production.left.id, result);
+    if let Some(label) = production.label {
+      result += &format!(".with_label(Some(\"{}\"))", label);
+    }
+    if production.element_labels.iter().any(|label| label.is_some()) {
+      let labels = production.element_labels.iter().map(|label| match label {
+        Some(label) => format!("Some(\"{}\"),", label),
+        None => String::from("None,"),
+      }).collect::<String>();
+      result += &format!(".with_element_labels(&[{}])", labels);
+    }
+    result
+  }
+
+  fn action_generate(&self, action: &ActionTableElement) -> String {
+    match action {
+      ActionTableElement::Shift(state) => format!("Shift({})", state),
+      ActionTableElement::Reduce(production_id) => format!("Reduce({})", production_id),
+      ActionTableElement::Accept => String::from("Accept"),
+    }
+  }
+
+  // 将所有词法规则编译为自动机, 并将序列化得到的字节写成字节串字面量, 每行 64 个字节
+  fn dfa_table_generate(&self, lexer_rule_list: &[LexerCase]) -> Result<String, Box<dyn Error>> {
+    let rules = lexer_rule_list.iter().map(|rule| LexerRule {
+      token_type: rule.token_type,
+      token_name: rule.token_name.clone(),
+      pattern: strip_raw_literal(&rule.regex),
+      channel: rule.channel,
+      skip: rule.skip,
+      mode: rule.mode,
+      mode_action: None,
+    }).collect::<Vec<_>>();
+    let bytes = LexerDfa::new(&rules)?.to_bytes();
+
+    // 行尾的 \ 会跳过换行以及下一行开头的空白, 因此空格也需要转义; \0 后面紧跟数字时看起来像八进制, 改用 \x00
+    let mut result = String::from("b\"");
+    for (index, byte) in bytes.iter().enumerate() {
+      if index % 64 == 0 { result += "\\\n  "; }
+      match byte {
+        0 if !bytes.get(index + 1).is_some_and(u8::is_ascii_digit) => result += "\\0",
+        b'"' | b'\\' | b' ' => result += &format!("\\x{:02x}", byte),
+        0x21..=0x7e => result.push(*byte as char),
+        _ => result += &format!("\\x{:02x}", byte),
+      }
+    }
+    result += "\"";
+    Ok(result)
+  }
+  
+}
+
+
+impl Target for RustTarget {
+  fn get_language(&self) -> Language {
+    Language::Rust
+  }
+
+  fn get_reserved_words(&self) -> &[&str] {
+    &self.reserved_words
+  }
+
+  fn get_template_mut(&mut self) -> &mut Tera {
+    &mut self.template
+  }
+
+
+
+  fn generate_visitor(&self, data: &VisitorOrListenerGenData) -> Result<String, Box<dyn Error>> {
+    let mut context = Context::new();
+
+    context.insert("grammar_file_name", &data.grammar_file_name);
+    context.insert("version", &data.version);
+    context.insert("grammar_name", &data.grammar_name);
+    context.insert("package_name", &data.package_name);
+
+    context.insert("nonterminals", &data.rule_names);
+    context.insert("label_names", &data.label_names);
+    let result = self.template.render("visitor", &context)?;
+    Ok(result)
+  }
+
+  fn generate_listener(&self, data: &VisitorOrListenerGenData) -> Result<String, Box<dyn Error>> {
+    let mut context = Context::new();
+    context.insert("grammar_file_name", &data.grammar_file_name);
+    context.insert("version", &data.version);
+    context.insert("grammar_name", &data.grammar_name);
+    context.insert("package_name", &data.package_name);
+
+
+    context.insert("rule_names", &data.rule_names);
+    context.insert("label_names", &data.label_names);
+    let result = self.template.render("listener", &context)?;
+    Ok(result)
+  }
+
+  fn generate_walker(&self, data: &WalkerGenData) -> Result<String, Box<dyn Error>> {
+    let mut context = Context::new();
+    context.insert("grammar_file_name", &data.grammar_file_name);
+    context.insert("version", &data.version);
+    context.insert("grammar_name", &data.grammar_name);
+    context.insert("package_name", &data.package_name);
+
+    let result =  self.template.render("walker", &context)?;
+    Ok(result)
+  }
+
+  fn generate_context(&self, data: &ContextGenData) -> Result<String, Box<dyn Error>> {
+    let mut context = Context::new();
+    context.insert("grammar_file_name", &data.grammar_file_name);
+    context.insert("version", &data.version);
+    context.insert("grammar_name", &data.grammar_name);
+    context.insert("package_name", &data.package_name);
+
+    context.insert("context_list", &data.context_list);
+    context.insert("visitor", &data.visitor);
+    context.insert("listener", &data.listener);
+  
+    let result = self.template.render("context", &context)?;
+    Ok(result)
+  }
+
+  fn generate_parser(&self, data: &ParserGenData) -> Result<String, Box<dyn Error>> {
+    let productions = data.grammar.productions.iter().map(|(id, production)| {
+      (*id, self.production_generate(production))
+    }).collect::<Vec<_>>();
+    let mut context = Context::new();
+
+
+    context.insert("grammar_file_name", &data.grammar_file_name);
+    context.insert("version", &data.version);
+    context.insert("grammar_name", &data.grammar_name);
+    context.insert("package_name", &data.package_name);
+
+    context.insert("productions", &productions);
+    context.insert("rule_names", &data.rule_names);
+    context.insert("terminal_names", &data.terminal_names);
+    context.insert("unnamed_nonterminals", &data.unnamed_nonterminals);
+
+    let result = match data.analyzer {
+      Analyzer::LL1 => {
+        let table = data.table.iter().map(|((k1, k2), k3)| (*k1, *k2, *k3)).collect::<Vec<_>>();
+        let sync_list = data.sync_list.iter().cloned().collect::<Vec<_>>();
+        context.insert("table", &table);
+        context.insert("sync_list", &sync_list);
+        context.insert("left_recursion_tails", &data.left_recursion_tails);
+        self.template.render("parser", &context)?
+      },
+      Analyzer::LALR => {
+        let action_table = data.action_table.iter()
+          .map(|((state, terminal), action)| (*state, *terminal, self.action_generate(action))).collect::<Vec<_>>();
+        let goto_table = data.goto_table.iter().map(|((k1, k2), k3)| (*k1, *k2, *k3)).collect::<Vec<_>>();
+        let start_states = data.start_states.iter().map(|(k1, k2)| (*k1, *k2)).collect::<Vec<_>>();
+        context.insert("action_table", &action_table);
+        context.insert("goto_table", &goto_table);
+        context.insert("start_states", &start_states);
+        self.template.render("lalr_parser", &context)?
+      },
+    };
+  
+    
+    Ok(result)
+  }
+
+  fn generate_lexer(&self, data: &LexerGenData) -> Result<String, Box<dyn Error>> {
+    let mut context = Context::new();
+
+    context.insert("grammar_file_name", &data.grammar_file_name);
+    context.insert("version", &data.version);
+    context.insert("grammar_name", &data.grammar_name);
+    context.insert("package_name", &data.package_name);
+    context.insert("lexer_rule_list", &data.lexer_rule_list);
+    context.insert("mode_list", &data.mode_list);
+    context.insert("dfa_table", &self.dfa_table_generate(&data.lexer_rule_list)?);
+
+    let result = self.template.render("lexer", &context)?;
+    Ok(result)
+  }
+
+  fn generate_vocabulary(&self, data: &VocabularyGenData) -> Result<String, Box<dyn Error>> {
+    let mut context = Context::new();
+    context.insert("token_list", &data.token_list);
+
+    let result = self.template.render("vocabulary", &context)?;
+    Ok(result)
+  }
+
+  fn write_file(&self, data: &WriteFileData) -> Result<(), Box<dyn Error>> {
+    // rust 需要一个 mod.rs 文件, 只声明实际生成的文件
+
+    // 首先生成文件头
+    let mut context = Context::new();
+    context.insert("grammar_file_name", &data.grammar_file_name);
+    context.insert("version", &data.version);
+    let mut mod_str = self.template.render("header", &context)?;
+
+    let files = [
+      ("lexer", &data.lexer), ("parser", &data.parser), ("context", &data.context),
+      ("listener", &data.listener), ("visitor", &data.visitor), ("walker", &data.walker),
+    ];
+    for (kind, content) in files {
+      if let Some(content) = content {
+        let module = format!("{}_{}", data.grammar_name.snake_case, kind);
+        mod_str += &format!("pub mod {};\n", module);
+        write(&Path::new(data.output_dir).join(format!("{}.rs", module)), content)?;
+      }
+    }
+
+    // .tokens 文件不是源代码, 不需要在 mod.rs 中声明
+    if let Some(vocabulary) = &data.vocabulary {
+      write(&Path::new(data.output_dir).join(format!("{}.tokens", data.grammar_name.pascal_case)), vocabulary)?;
+    }
+
+    write(&Path::new(data.output_dir).join("mod.rs"), &mod_str)
+  }
+
+}
+
+
@@ -0,0 +1,255 @@
+
+use std::{error::Error, path::Path};
+
+use chiru::runtime::production::{Production, ProductionItem};
+use tera::{Tera, Context};
+
+use crate::tool::cli::Analyzer;
+use crate::tool::grammar::lexer_rule::strip_raw_literal;
+use crate::tool::code_generator::{language::Language, name_case::{ContextGenData, LexerGenData, ParserGenData, VisitorOrListenerGenData, VocabularyGenData, WalkerGenData, WriteFileData}};
+use super::{Target, write};
+
+
+
+// 生成的 typescript 代码依赖 chiru-runtime 包, 位于仓库的 runtime/typescript 目录
+pub struct TypeScriptTarget {
+  pub template: Tera,
+  pub reserved_words: Vec<&'static str>
+}
+
+impl Default for TypeScriptTarget {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl TypeScriptTarget {
+  pub fn new() -> Self {
+    let mut template = Tera::default();
+    template.add_raw_template("lexer", include_str!("../../templates/target/typescript/lexer.tera")).unwrap();
+    template.add_raw_template("parser", include_str!("../../templates/target/typescript/parser.tera")).unwrap();
+    template.add_raw_template("context", include_str!("../../templates/target/typescript/context.tera")).unwrap();
+    template.add_raw_template("listener", include_str!("../../templates/target/typescript/listener.tera")).unwrap();
+    template.add_raw_template("visitor", include_str!("../../templates/target/typescript/visitor.tera")).unwrap();
+    template.add_raw_template("walker", include_str!("../../templates/target/typescript/walker.tera")).unwrap();
+    template.add_raw_template("header", include_str!("../../templates/target/typescript/header.tera")).unwrap();
+    template.add_raw_template("vocabulary", include_str!("../../templates/target/typescript/vocabulary.tera")).unwrap();
+    template.autoescape_on(vec![]);
+
+    let reserved_words = vec! {
+      // 保留字
+      "break", "case", "catch", "class", "const", "continue", "debugger",
+      "default", "delete", "do", "else", "enum", "export", "extends",
+      "false", "finally", "for", "function", "if", "import", "in",
+      "instanceof", "new", "null", "return", "super", "switch", "this",
+      "throw", "true", "try", "typeof", "var", "void", "while", "with",
+
+      // 严格模式下的保留字
+      "implements", "interface", "let", "package", "private", "protected",
+      "public", "static", "yield", "await",
+    };
+    Self { template, reserved_words }
+  }
+
+
+  fn production_generate(&self, production: &Production) -> String {
+    let right = production.right.iter().map(|item| match item {
+      ProductionItem::NonTerminal(nonterminal) => format!("nonterminal({})", nonterminal.id),
+      ProductionItem::Terminal(terminal) => format!("terminal({})", terminal.id),
+    }).collect::<Vec<_>>().join(", ");
+
+    let mut result = format!("new Production({}, nonterminal({}), [{}]", production.id, production.left.id, right);
+    let has_element_labels = production.element_labels.iter().any(|label| label.is_some());
+    if production.label.is_some() || has_element_labels {
+      match production.label {
+        Some(label) => result += &format!(", \"{}\"", label),
+        None => result += ", null",
+      }
+    }
+    if has_element_labels {
+      let labels = production.element_labels.iter().map(|label| match label {
+        Some(label) => format!("\"{}\"", label),
+        None => String::from("null"),
+      }).collect::<Vec<_>>().join(", ");
+      result += &format!(", [{}]", labels);
+    }
+    result + ")"
+  }
+
+  // 将 r#"..."# 形式的 rust 正则表达式转换为构造 RegExp 的表达式
+  // 开头的 (?s) 等标志转换为 RegExp 的标志, 使用 \x{..} 或 \p{..} 的正则表达式需要 u 标志
+  fn regex_generate(&self, token_name: &str, regex: &str) -> Result<String, Box<dyn Error>> {
+    let mut pattern = strip_raw_literal(regex);
+    let mut flags = String::new();
+
+    let prefix = regex::Regex::new(r"^\(\?([a-zA-Z]+)\)").unwrap();
+    while let Some(captures) = prefix.captures(&pattern.clone()) {
+      for flag in captures[1].chars() {
+        match flag {
+          'i' | 'm' | 's' => if !flags.contains(flag) { flags.push(flag) },
+          _ => return Err(format!("the flag '{}' used by token '{}' is not supported by the TypeScript target", flag, token_name).into()),
+        }
+      }
+      pattern = pattern[captures[0].len()..].to_owned();
+    }
+
+    if pattern.contains("\\x{") || pattern.contains("\\p{") || pattern.contains("\\P{") {
+      pattern = pattern.replace("\\x{", "\\u{");
+      flags.push('u');
+    }
+
+    Ok(format!("new RegExp({}, \"{}\")", serde_json::to_string(&pattern)?, flags))
+  }
+
+}
+
+
+impl Target for TypeScriptTarget {
+  fn get_language(&self) -> Language {
+    Language::TypeScript
+  }
+
+  fn get_reserved_words(&self) -> &[&str] {
+    &self.reserved_words
+  }
+
+  fn get_template_mut(&mut self) -> &mut Tera {
+    &mut self.template
+  }
+
+
+  fn generate_visitor(&self, data: &VisitorOrListenerGenData) -> Result<String, Box<dyn Error>> {
+    let mut context = Context::new();
+    context.insert("grammar_file_name", &data.grammar_file_name);
+    context.insert("version", &data.version);
+    context.insert("grammar_name", &data.grammar_name);
+    context.insert("package_name", &data.package_name);
+
+    context.insert("nonterminals", &data.rule_names);
+    context.insert("label_names", &data.label_names);
+    let result = self.template.render("visitor", &context)?;
+    Ok(result)
+  }
+
+  fn generate_listener(&self, data: &VisitorOrListenerGenData) -> Result<String, Box<dyn Error>> {
+    let mut context = Context::new();
+    context.insert("grammar_file_name", &data.grammar_file_name);
+    context.insert("version", &data.version);
+    context.insert("grammar_name", &data.grammar_name);
+    context.insert("package_name", &data.package_name);
+
+    context.insert("rule_names", &data.rule_names);
+    context.insert("label_names", &data.label_names);
+    let result = self.template.render("listener", &context)?;
+    Ok(result)
+  }
+
+  fn generate_walker(&self, data: &WalkerGenData) -> Result<String, Box<dyn Error>> {
+    let mut context = Context::new();
+    context.insert("grammar_file_name", &data.grammar_file_name);
+    context.insert("version", &data.version);
+    context.insert("grammar_name", &data.grammar_name);
+    context.insert("package_name", &data.package_name);
+
+    let result = self.template.render("walker", &context)?;
+    Ok(result)
+  }
+
+  fn generate_context(&self, data: &ContextGenData) -> Result<String, Box<dyn Error>> {
+    let mut context = Context::new();
+    context.insert("grammar_file_name", &data.grammar_file_name);
+    context.insert("version", &data.version);
+    context.insert("grammar_name", &data.grammar_name);
+    context.insert("package_name", &data.package_name);
+
+    context.insert("context_list", &data.context_list);
+    context.insert("visitor", &data.visitor);
+    context.insert("listener", &data.listener);
+
+    let result = self.template.render("context", &context)?;
+    Ok(result)
+  }
+
+  fn generate_parser(&self, data: &ParserGenData) -> Result<String, Box<dyn Error>> {
+    // typescript 运行时只实现了 ll1 分析
+    if data.analyzer != Analyzer::LL1 {
+      return Err("the TypeScript target only supports the LL(1) analyzer, use --analyzer ll1".into());
+    }
+
+    let productions = data.grammar.productions.iter().map(|(id, production)| {
+      (*id, self.production_generate(production))
+    }).collect::<Vec<_>>();
+    let table = data.table.iter().map(|((k1, k2), k3)| (*k1, *k2, *k3)).collect::<Vec<_>>();
+    let sync_list = data.sync_list.iter().cloned().collect::<Vec<_>>();
+
+    let mut context = Context::new();
+    context.insert("grammar_file_name", &data.grammar_file_name);
+    context.insert("version", &data.version);
+    context.insert("grammar_name", &data.grammar_name);
+    context.insert("package_name", &data.package_name);
+
+    context.insert("productions", &productions);
+    context.insert("rule_names", &data.rule_names);
+    context.insert("terminal_names", &data.terminal_names);
+    context.insert("unnamed_nonterminals", &data.unnamed_nonterminals);
+    context.insert("table", &table);
+    context.insert("sync_list", &sync_list);
+    context.insert("left_recursion_tails", &data.left_recursion_tails);
+
+    let result = self.template.render("parser", &context)?;
+    Ok(result)
+  }
+
+  fn generate_lexer(&self, data: &LexerGenData) -> Result<String, Box<dyn Error>> {
+    let mut lexer_rule_list = data.lexer_rule_list.clone();
+    for lexer_rule in lexer_rule_list.iter_mut() {
+      lexer_rule.regex = self.regex_generate(&lexer_rule.token_name, &lexer_rule.regex)?;
+    }
+
+    let mut context = Context::new();
+    context.insert("grammar_file_name", &data.grammar_file_name);
+    context.insert("version", &data.version);
+    context.insert("grammar_name", &data.grammar_name);
+    context.insert("package_name", &data.package_name);
+    context.insert("lexer_rule_list", &lexer_rule_list);
+    context.insert("mode_list", &data.mode_list);
+
+    let result = self.template.render("lexer", &context)?;
+    Ok(result)
+  }
+
+  fn generate_vocabulary(&self, data: &VocabularyGenData) -> Result<String, Box<dyn Error>> {
+    let mut context = Context::new();
+    context.insert("token_list", &data.token_list);
+
+    let result = self.template.render("vocabulary", &context)?;
+    Ok(result)
+  }
+
+  fn write_file(&self, data: &WriteFileData) -> Result<(), Box<dyn Error>> {
+    // 生成一个 index.ts, 导出所有生成的文件
+    let mut context = Context::new();
+    context.insert("grammar_file_name", &data.grammar_file_name);
+    context.insert("version", &data.version);
+    let mut index_str = self.template.render("header", &context)?;
+
+    let files = [
+      ("lexer", &data.lexer), ("parser", &data.parser), ("context", &data.context),
+      ("listener", &data.listener), ("visitor", &data.visitor), ("walker", &data.walker),
+    ];
+    for (kind, content) in files {
+      if let Some(content) = content {
+        let module = format!("{}_{}", data.grammar_name.snake_case, kind);
+        index_str += &format!("export * from \"./{}\";\n", module);
+        write(&Path::new(data.output_dir).join(format!("{}.ts", module)), content)?;
+      }
+    }
+
+    // .tokens 文件不是源代码, 不需要导出
+    if let Some(vocabulary) = &data.vocabulary {
+      write(&Path::new(data.output_dir).join(format!("{}.tokens", data.grammar_name.pascal_case)), vocabulary)?;
+    }
+
+    write(&Path::new(data.output_dir).join("index.ts"), &index_str)
+  }
+}
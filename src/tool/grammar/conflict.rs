@@ -0,0 +1,60 @@
+use std::{error::Error, fmt::Display};
+
+use chiru::runtime::vocabulary::{NonTerminal, Terminal};
+
+
+
+// 冲突的种类, 前两种出现在 ll1 分析表中, 后两种出现在 lalr 分析表中
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ConflictKind {
+  FirstFirst,
+  FirstFollow,
+  ShiftReduce,
+  ReduceReduce,
+}
+
+impl Display for ConflictKind {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      ConflictKind::FirstFirst => write!(f, "FIRST/FIRST"),
+      ConflictKind::FirstFollow => write!(f, "FIRST/FOLLOW"),
+      ConflictKind::ShiftReduce => write!(f, "shift/reduce"),
+      ConflictKind::ReduceReduce => write!(f, "reduce/reduce"),
+    }
+  }
+}
+
+
+// 构造分析表时发现的冲突
+// 对于 shift/reduce 冲突, chosen 为移入项目所在的产生式, rejected 为被舍弃的归约产生式
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GrammarConflict<'a> {
+  pub kind: ConflictKind,
+
+  // 发生冲突的非终结符以及展望符
+  pub nonterminal: NonTerminal<'a>,
+  pub terminal: Terminal<'a>,
+
+  // 分析表中保留的产生式和被舍弃的产生式
+  pub chosen: usize,
+  pub rejected: usize,
+}
+
+impl<'a> GrammarConflict<'a> {
+  pub fn new(kind: ConflictKind, nonterminal: NonTerminal<'a>, terminal: Terminal<'a>, chosen: usize, rejected: usize) -> Self {
+    Self { kind, nonterminal, terminal, chosen, rejected }
+  }
+}
+
+impl<'a> Display for GrammarConflict<'a> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let name = match self.nonterminal.name {
+      Some(name) => name.to_owned(),
+      None => format!("<{}>", self.nonterminal.id),
+    };
+    write!(f, "{} conflict in rule '{}' on '{}' between production {} and production {}",
+      self.kind, name, self.terminal.name, self.chosen, self.rejected)
+  }
+}
+
+impl<'a> Error for GrammarConflict<'a> {}
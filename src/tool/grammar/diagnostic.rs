@@ -0,0 +1,72 @@
+use std::{fmt::Display, ops::Range};
+
+
+
+// 诊断信息的严重程度, 存在 Error 时不会生成代码
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+  Error,
+  Warning,
+}
+
+impl Display for Severity {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Severity::Error => write!(f, "error"),
+      Severity::Warning => write!(f, "warning"),
+    }
+  }
+}
+
+
+// 语法文件中的一段字节范围, file 为语法文件的编号, 输入文件为 0, 导入的文件按照导入的顺序编号
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Span {
+  pub file: usize,
+  pub range: Range<usize>,
+}
+
+impl Span {
+  pub fn new(file: usize, range: Range<usize>) -> Self {
+    Self { file, range }
+  }
+}
+
+
+// 语义检查时发现的问题
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+  pub severity: Severity,
+  pub message: String,
+
+  // 问题在语法文件中的位置
+  pub span: Span,
+
+  // 与之相关的另一处位置及说明, 如重复定义时第一次定义的位置
+  pub related: Option<(Span, String)>,
+}
+
+impl Diagnostic {
+  pub fn error(message: &str, span: Span) -> Self {
+    Self { severity: Severity::Error, message: message.to_owned(), span, related: None }
+  }
+
+  pub fn warning(message: &str, span: Span) -> Self {
+    Self { severity: Severity::Warning, message: message.to_owned(), span, related: None }
+  }
+
+  pub fn with_related(mut self, span: Span, message: &str) -> Self {
+    self.related = Some((span, message.to_owned()));
+    self
+  }
+
+  pub fn is_error(&self) -> bool {
+    self.severity == Severity::Error
+  }
+}
+
+impl Display for Diagnostic {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}: {}", self.severity, self.message)
+  }
+}
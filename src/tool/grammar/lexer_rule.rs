@@ -1,3 +1,6 @@
+use chiru::runtime::lexer_rule::ModeAction;
+
+
 #[derive(Debug, Clone)]
 pub struct LexerRule {
   pub token_type: usize,
@@ -6,6 +9,13 @@ pub struct LexerRule {
 
   pub channel: usize,
   pub skip: bool,
+
+  // 所属的词法模式以及匹配之后的模式切换命令
+  pub mode: usize,
+  pub mode_action: Option<ModeAction>,
+
+  // 匹配长度相同时的优先级, 即定义的顺序, 越小越优先, 重新编号之后 token_type 不再反映定义的顺序
+  pub priority: usize,
 }
 
 
@@ -13,8 +23,24 @@ impl LexerRule {
   pub fn new(token_type: usize, token_name: &str, regex: &str, channel: usize, skip: bool) -> Self {
     Self {
       channel, skip, token_type,
+      mode: 0, mode_action: None,
+      priority: token_type,
       regex: regex.to_owned(),
       token_name: token_name.to_owned(),
     }
   }
 }
+
+
+// 去掉 r#"..."# 形式的正则表达式两端的标记, 得到正则表达式本身
+pub fn strip_raw_literal(text: &str) -> String {
+  let re = regex::Regex::new(r####"(^r#*")|("#*$)"####).unwrap();
+  re.replace_all(text, "").to_string()
+}
+
+// 将正则表达式写成 r#"..."# 的形式, 选择足够多的 #, 使得正则表达式中不会出现结束标记
+pub fn raw_literal(regex: &str) -> String {
+  let mut hashes = String::from("#");
+  while regex.contains(&format!("\"{}", hashes)) { hashes.push('#'); }
+  format!("r{}\"{}\"{}", hashes, regex, hashes)
+}
@@ -1,14 +1,23 @@
-pub mod vocabulary;
 pub mod lexer_rule;
-// pub mod utils;
-
-use std::{collections::{BTreeMap, BTreeSet}, error::Error, fmt::Display};
+pub mod utils;
+pub mod conflict;
+pub mod precedence;
+pub mod transform;
+pub mod diagnostic;
+pub mod token_vocab;
+
+use std::{collections::{BTreeMap, BTreeSet}, error::Error, fmt::Display, ops::Range};
 use chiru::runtime::vocabulary::{NonTerminal, Terminal};
 use chiru::runtime::{production::{Production, ProductionItem}, vocabulary::Vocabulary};
+use conflict::{ConflictKind, GrammarConflict};
+use diagnostic::Diagnostic;
+use token_vocab::apply_token_vocab;
 use lexer_rule::LexerRule;
+use precedence::Precedence;
 use maplit::btreeset;
+use utils::LalrTable;
 
-use crate::tool::visitor::{string_literal_to_token_visitor::StringLiteralToTokenVisitor, lexer_rule_visitor::LexerRuleVisitor, parser_rule_visitor::ParserRuleVisitor, grammar_visitor::GrammarVisitor};
+use crate::tool::visitor::{string_literal_to_token_visitor::StringLiteralToTokenVisitor, lexer_rule_visitor::LexerRuleVisitor, parser_rule_visitor::ParserRuleVisitor, grammar_visitor::GrammarVisitor, validation_visitor::ValidationVisitor};
 
 
 use super::syntaxis::chiru_context::CompilationUnitContext;
@@ -29,6 +38,18 @@ pub struct Grammar<'a> {
 
   // 词法分析规则
   pub lexer_rule_map: BTreeMap<String, LexerRule>,
+
+  // 词法模式的名称和编号, 不包括编号为 0 的默认模式
+  pub lexer_modes: BTreeMap<String, usize>,
+
+  // 终结符的优先级和结合性, 用于解决 lalr 分析表中的移入归约冲突
+  pub precedences: BTreeMap<Terminal<'a>, Precedence>,
+
+  // 每个产生式在语法文件中对应的字节范围, 用于报告冲突
+  pub production_spans: BTreeMap<usize, Range<usize>>,
+
+  // 消除左递归时引入的匿名非终结符以及它所属的非终结符, 分析时需要将其折叠为左结合的语法树
+  pub left_recursion_tails: BTreeMap<NonTerminal<'a>, NonTerminal<'a>>,
 }
 
 // 定义一个存放 first、follow 集合的数据结构, follow 集一定不会包含 epsilon, first 集合的元素为终结符
@@ -40,13 +61,6 @@ pub struct FirstCollection<'a> {
 
 
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub enum ActionTableElement {
-  Shift(usize),
-  Reduce(usize)
-}
-
-
 impl<'a> Grammar<'a> {
   pub fn new(name: &str) -> Self {    
     Self {
@@ -54,22 +68,53 @@ impl<'a> Grammar<'a> {
       vocabulary: Vocabulary::new(),
       productions: BTreeMap::new(),
       lexer_rule_map: BTreeMap::new(),
+      lexer_modes: BTreeMap::new(),
+      precedences: BTreeMap::new(),
+      production_spans: BTreeMap::new(),
+      left_recursion_tails: BTreeMap::new(),
+    }
+  }
+
+  // 在构造文法之前检查语法文件, 返回所有的错误和警告, asts 中第一个为输入文件, 其余为导入的文件
+  pub fn validate(asts: &[&dyn CompilationUnitContext]) -> Result<Vec<Diagnostic>, Box<dyn Error>> {
+    let mut visitor = ValidationVisitor::new();
+    for (file, ast) in asts.iter().enumerate() {
+      visitor.current_file = file;
+      ast.accept(&mut visitor)?;
     }
+    Ok(visitor.finish())
   }
 
-  pub fn from_ast(ast: &dyn CompilationUnitContext) -> Result<Self, Box<dyn Error>> {
+  // asts 中第一个为输入文件, 其余为导入的文件, 文法的名称和开始符号都来自输入文件
+  // 所有文件中的规则合并到同一个文法中, 同一个规则或 token 只能在一个文件中定义
+  // token_vocab 为从 .tokens 文件中读取的 token 编号, 为空时按照定义的顺序编号
+  pub fn from_asts(asts: &[&dyn CompilationUnitContext], token_vocab: &BTreeMap<String, usize>) -> Result<Grammar<'static>, Box<dyn Error>> {
     let mut visitor = StringLiteralToTokenVisitor::new(2);
-    ast.accept(&mut visitor)?;
+    for ast in asts.iter() { ast.accept(&mut visitor)?; }
+    visitor.assign_token_names();
     
     let mut lexer_visitor = LexerRuleVisitor::new(visitor.next_token_id, visitor.lexer_rule_map);
-    ast.accept(&mut lexer_visitor)?;
+    for ast in asts.iter() { ast.accept(&mut lexer_visitor)?; }
+    lexer_visitor.expand_references()?;
+    apply_token_vocab(&mut lexer_visitor.lexer_rule_map, token_vocab)?;
 
     let mut parser_visitor = ParserRuleVisitor::new();
-    ast.accept(&mut parser_visitor)?;
+    for ast in asts.iter() { ast.accept(&mut parser_visitor)?; }
 
     let mut grammar_visitor = GrammarVisitor::new("<no name>", &parser_visitor.parser_rule_map, &lexer_visitor.lexer_rule_map);
-    ast.accept(&mut grammar_visitor)?;
-    Ok(grammar_visitor.grammar)
+    let (main, imports) = asts.split_first().ok_or("no grammar file")?;
+    main.accept(&mut grammar_visitor)?;
+
+    // 产生式的字节范围只对输入文件有意义
+    let production_count = grammar_visitor.next_production_id;
+    for rules in imports.iter().filter_map(|ast| ast.rules()) {
+      rules.accept(&mut grammar_visitor)?;
+    }
+
+    let mut grammar = grammar_visitor.grammar;
+    grammar.production_spans.retain(|production_id, _| *production_id < production_count);
+    grammar.lexer_modes = lexer_visitor.mode_map.into_iter().collect();
+    Ok(grammar)
   }
   
 
@@ -77,7 +122,7 @@ impl<'a> Grammar<'a> {
 
 
   // 根据非终结符的first集合求一个串的first集合, 传入参数为非终结符的first集合, 返回结果为串的first集合
-  fn get_firstset_for_string(slice: &[ProductionItem], first_set: &BTreeMap<NonTerminal, FirstCollection<'a>>) -> FirstCollection<'a> {
+  fn get_firstset_for_string(slice: &[ProductionItem<'a>], first_set: &BTreeMap<NonTerminal<'a>, FirstCollection<'a>>) -> FirstCollection<'a> {
     // 初始化返回结果
     let mut result: FirstCollection = FirstCollection { allow_epsilon: true, set: BTreeSet::new(), };
     
@@ -85,7 +130,7 @@ impl<'a> Grammar<'a> {
       match item {
         ProductionItem::NonTerminal(nontermimal) => {
           // 如果是非终结符, 先获取到非终结符的first集合.
-          let c = first_set.get(&nontermimal).unwrap();
+          let c = first_set.get(nontermimal).unwrap();
           // 将非终结符的first集合添加到串的first集合中.
           for item in c.set.iter() { result.set.insert(*item) ; }
           if !c.allow_epsilon {
@@ -96,7 +141,7 @@ impl<'a> Grammar<'a> {
         },
         ProductionItem::Terminal(terminal) => {
           result.allow_epsilon = false;
-          result.set.insert(terminal.clone());
+          result.set.insert(*terminal);
           break;
         },
       }
@@ -106,7 +151,7 @@ impl<'a> Grammar<'a> {
   }
 
   // 求非 epsilon 产生式的 first 集, production: 待求产生式, result: 求得的结果, firstset: 非终结符的first集合(不断更新)
-  fn get_firstset_for_production(production: &Production, result: &mut FirstCollection, first_set: &BTreeMap<NonTerminal, FirstCollection>) -> bool {
+  fn get_firstset_for_production(production: &Production<'a>, result: &mut FirstCollection<'a>, first_set: &BTreeMap<NonTerminal<'a>, FirstCollection<'a>>) -> bool {
     let mut modified = false; // 标识 result 是否被修改
 
     // 首先判断是否可以为 epsilon 
@@ -144,7 +189,7 @@ impl<'a> Grammar<'a> {
           }
         },
         ProductionItem::Terminal(token_type) => {
-          modified = result.set.insert(token_type.clone()) || modified;
+          modified = result.set.insert(*token_type) || modified;
           
           // 遇到终结符就退出
           break;
@@ -159,7 +204,7 @@ impl<'a> Grammar<'a> {
 
 
   // 返回值 (非终结符的first集合, 产生式的 first 集合)
-  pub fn first_set(&self) -> (BTreeMap<NonTerminal, FirstCollection>, BTreeMap<usize, FirstCollection>) {
+  pub fn first_set(&self) -> (BTreeMap<NonTerminal<'a>, FirstCollection<'a>>, BTreeMap<usize, FirstCollection<'a>>) {
     // 求 first 集合
     
     // result 为非终结符的 first 集合
@@ -210,7 +255,7 @@ impl<'a> Grammar<'a> {
   }
 
   // follow 集合不可能包含 ε 返回每个非终结符的 follow 集合
-  pub fn follow_set(&self, first_set: &BTreeMap<NonTerminal, FirstCollection>) -> BTreeMap<NonTerminal, BTreeSet<Terminal>> {
+  pub fn follow_set(&self, first_set: &BTreeMap<NonTerminal<'a>, FirstCollection<'a>>) -> BTreeMap<NonTerminal<'a>, BTreeSet<Terminal<'a>>> {
     // 求 follow 集合
     let mut result = BTreeMap::new();
 
@@ -253,47 +298,65 @@ impl<'a> Grammar<'a> {
   }
 
   // 构造预测分析表 这里注意传入的 first 集合是产生式的 first 集合  预测分析表 (非终结符, 终结符) -> 产生式
-  pub fn ll1_table(&self, first_set: &BTreeMap<usize, FirstCollection>, follow_set: &BTreeMap<NonTerminal, BTreeSet<Terminal>>) 
-    -> BTreeMap<(NonTerminal, Terminal), usize> {
-    let mut result: BTreeMap<(NonTerminal, Terminal), usize> = BTreeMap::new();
+  // 同时返回构造过程中发现的冲突, 发生冲突时通过 first 集合填入的产生式优先于通过 follow 集合填入的产生式, 否则保留编号较小的产生式
+  pub fn ll1_table(&self, first_set: &BTreeMap<usize, FirstCollection<'a>>, follow_set: &BTreeMap<NonTerminal<'a>, BTreeSet<Terminal<'a>>>) 
+    -> (BTreeMap<(NonTerminal<'a>, Terminal<'a>), usize>, Vec<GrammarConflict<'a>>) {
+    let mut result: BTreeMap<(NonTerminal<'a>, Terminal<'a>), usize> = BTreeMap::new();
+    let mut conflicts: Vec<GrammarConflict<'a>> = Vec::new();
     let productions = self.productions.values().cloned().collect::<Vec<_>>();
 
 
+    // 通过 follow 集合填入的表项
+    let mut from_follow: BTreeSet<(NonTerminal<'a>, Terminal<'a>)> = BTreeSet::new();
+
+    // 多个产生式填入同一个表项时, 通过 first 集合填入的产生式优先, 这样可选的部分总是尽可能多地匹配输入,
+    // 除此之外保留先填入的, 也就是编号较小的产生式
     for production in productions.iter() {
       let first = first_set.get(&production.id).unwrap();
       let rule = production.left;
       
       // 将 first 集合中的所有元素
       for token_type in first.set.iter() {
-        if let Some(p) = result.insert((rule, *token_type), production.id) {
-          // 这表示不是 ll1 文法
-          println!("不是 ll1 文法 {:?}, {}, {}", production, p, rule.id);
+        let key = (rule, *token_type);
+        match result.get(&key) {
+          None => { result.insert(key, production.id); },
+          // 这表示不是 ll1 文法, 如果之前的产生式是通过 follow 集合填入的, 那么就是 first/follow 冲突
+          Some(p) if from_follow.remove(&key) => {
+            conflicts.push(GrammarConflict::new(ConflictKind::FirstFollow, rule, *token_type, production.id, *p));
+            result.insert(key, production.id);
+          },
+          Some(p) => conflicts.push(GrammarConflict::new(ConflictKind::FirstFirst, rule, *token_type, *p, production.id)),
         }
       }
 
       if first.allow_epsilon {
         let follow = follow_set.get(&rule).unwrap();
         for terminal in follow.iter() {
-          if let Some(p) = result.insert((rule, *terminal), production.id) {
-            println!("不是 ll1 文法 {:?}, {:?}, {}", production, p, rule.id);
+          let key = (rule, *terminal);
+          match result.get(&key) {
+            None => {
+              result.insert(key, production.id);
+              from_follow.insert(key);
+            },
+            // 同一个产生式的 first 集合和 follow 集合有交集, 表项没有歧义, 冲突会出现在它推导出的非终结符中
+            Some(p) if *p == production.id => {},
+            // 之前的产生式也是通过 follow 集合填入的, 说明两个产生式都能推导出 epsilon, 属于 first/first 冲突
+            Some(p) if from_follow.contains(&key) => conflicts.push(GrammarConflict::new(ConflictKind::FirstFirst, rule, *terminal, *p, production.id)),
+            Some(p) => conflicts.push(GrammarConflict::new(ConflictKind::FirstFollow, rule, *terminal, *p, production.id)),
           }
         }
       }
     }
-    result
+    (result, conflicts)
 
   }
 
 
 
 
-  pub fn action_table(&self) -> BTreeMap<(usize, usize), ActionTableElement> {
-    todo!()
-  }
-
-  // 获取 goto 表 (状态id, 非终结符id) -> 状态id
-  pub fn goto_table(&self) -> BTreeMap<(usize, usize), usize> {
-    todo!()
+  // 构造 lalr 分析表, 包括 action 表 (状态id, 终结符) -> 动作, goto 表 (状态id, 非终结符) -> 状态id, 以及每个命名非终结符的初始状态
+  pub fn lalr_table(&self) -> LalrTable<'a> {
+    utils::lalr_table(self)
   }
 
 }
@@ -308,10 +371,134 @@ impl<'a> Display for Grammar<'a> {
    */
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     for production in self.productions.values() {
-      write!(f, "{}\n", production);
+      writeln!(f, "{}", production)?;
     }
     Ok(())
   }
 }
 
 
+
+
+#[cfg(test)]
+pub mod tests {
+  use std::{collections::{BTreeMap, BTreeSet}, error::Error, ops::Range};
+
+  use chiru::runtime::{error_strategy::error_listener::ErrorListener, location::Location, token::Token, token_stream::TokenStream, vocabulary::{NonTerminal, Terminal}};
+
+  use crate::tool::{analyzer::{CommonLexer, CommonParser}, cli::Analyzer, syntaxis::{chiru_context::CompilationUnitContext, chiru_lexer::ChiruLexer, chiru_parser::ChiruParser}};
+  use super::{conflict::ConflictKind, Grammar};
+
+  // 语法文件中不应该存在任何错误
+  struct PanicErrorListener;
+  impl ErrorListener for PanicErrorListener {
+    fn syntax_error(&self, _rule: NonTerminal<'_>, _offending_token: &Token<'_>, location: &Location, _expected: &BTreeSet<Terminal<'_>>, message: &str) {
+      panic!("{}:{} {}", location.start.line + 1, location.start.char_position + 1, message);
+    }
+
+    fn lexer_error(&self, text: &str, location: &Location) {
+      panic!("{}:{} token recognition error at: {:?}", location.start.line + 1, location.start.char_position + 1, text);
+    }
+  }
+
+  // 从语法文件的内容构造文法, 供各个模块的测试使用
+  pub fn grammar(source: &str) -> Grammar<'static> {
+    grammar_from(&[source], &Default::default()).unwrap()
+  }
+
+  // 第一个为输入文件, 其余为导入的语法文件, token_vocab 为 .tokens 文件中记录的编号
+  pub fn grammar_from(sources: &[&str], token_vocab: &BTreeMap<String, usize>) -> Result<Grammar<'static>, Box<dyn Error>> {
+    with_asts(sources, |asts| Grammar::from_asts(asts, token_vocab))
+  }
+
+  // 语义检查的结果, 每条诊断信息附带其在语法文件中的字节范围
+  pub fn diagnostics(source: &str) -> Vec<(String, Range<usize>)> {
+    with_asts(&[source], |asts| Grammar::validate(asts).unwrap())
+      .into_iter().map(|diagnostic| (diagnostic.to_string(), diagnostic.span.range)).collect()
+  }
+
+  // 解析所有的语法文件, 将语法树交给 f
+  fn with_asts<T>(sources: &[&str], f: impl FnOnce(&[&dyn CompilationUnitContext]) -> T) -> T {
+    let lexers = sources.iter().map(|source| {
+      let mut lexer = ChiruLexer::new(source);
+      lexer.remove_all_error_listeners();
+      lexer.add_error_listener(Box::new(PanicErrorListener));
+      lexer
+    }).collect::<Vec<_>>();
+    let asts = lexers.iter().map(|lexer| {
+      let mut tokens = TokenStream::new(lexer, 0);
+      let mut parser = ChiruParser::new();
+      parser.error_listeners = vec![Box::new(PanicErrorListener)];
+      parser.compilation_unit(&mut tokens).unwrap()
+    }).collect::<Vec<_>>();
+    let asts = asts.iter().map(|ast| ast.as_ref() as &dyn CompilationUnitContext).collect::<Vec<_>>();
+    f(&asts)
+  }
+
+  // 使用文法分析输入, 返回语法树的字符串形式
+  pub fn parse(grammar: &Grammar, analyzer: Analyzer, rule: &str, input: &str) -> String {
+    let lexer = CommonLexer::from_grammar(grammar, input).unwrap();
+    let mut tokens = TokenStream::new(&lexer, 0);
+    let parser = CommonParser::from_grammar(grammar, analyzer);
+    let rule = grammar.vocabulary.get_nonterminal_by_name(rule).unwrap();
+    parser.parse(&mut tokens, rule).unwrap().to_string()
+  }
+
+  #[test]
+  fn ll1_conflict_test() {
+    let grammar = grammar(r#"
+      grammar Amb;
+      s: A B | A C;
+      t: a A;
+      a: A?;
+      u: v A;
+      v: B? | C?;
+      A: "a";
+      B: "b";
+      C: "c";
+    "#);
+    let (first, first_set) = grammar.first_set();
+    let follow = grammar.follow_set(&first);
+    let (table, conflicts) = grammar.ll1_table(&first_set, &follow);
+
+    let conflicts = conflicts.iter()
+      .map(|conflict| (conflict.kind, conflict.nonterminal.id, conflict.terminal.name, conflict.chosen, conflict.rejected))
+      .collect::<Vec<_>>();
+    assert_eq!(conflicts, vec![
+      (ConflictKind::FirstFirst, 0, "A", 0, 1),
+      // 可选的部分优先匹配输入
+      (ConflictKind::FirstFollow, 5, "A", 4, 3),
+      // 两个备选分支都能推导出 epsilon
+      (ConflictKind::FirstFirst, 4, "A", 9, 12),
+      (ConflictKind::FirstFirst, 4, "_STOP", 9, 12),
+    ]);
+
+    // 表中保留的是 chosen
+    let a = grammar.vocabulary.get_terminal_by_name("A").unwrap();
+    let optional = grammar.productions[&4].left;
+    assert_eq!(table[&(grammar.productions[&0].left, a)], 0);
+    assert_eq!(table[&(optional, a)], 4);
+  }
+
+  #[test]
+  fn epsilon_test() {
+    // epsilon 和 ε 都表示空的备选分支, 而不是对名为 epsilon 的规则的引用
+    let grammar = grammar(r#"
+      grammar Empty;
+      s: A b c;
+      b: epsilon | B;
+      c: C | ε;
+      epsilons: A;
+      A: "a";
+      B: "b";
+      C: "c";
+    "#);
+    assert!(grammar.vocabulary.get_nonterminal_by_name("epsilon").is_none());
+    assert!(grammar.vocabulary.get_nonterminal_by_name("epsilons").is_some());
+
+    for analyzer in [Analyzer::LL1, Analyzer::LALR] {
+      assert_eq!(parse(&grammar, analyzer, "s", "a"), "(s A (b) (c))");
+      assert_eq!(parse(&grammar, analyzer, "s", "abc"), "(s A (b B) (c C))");
+    }
+  }
+}
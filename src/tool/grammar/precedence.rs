@@ -0,0 +1,147 @@
+use std::collections::BTreeMap;
+use chiru::runtime::{production::{Production, ProductionItem}, vocabulary::{NonTerminal, Terminal}};
+
+
+// 终结符的结合性
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Associativity {
+  Left,
+  Right,
+}
+
+// 终结符的优先级, level 越大优先级越高
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Precedence {
+  pub level: usize,
+  pub associativity: Associativity,
+}
+
+impl Precedence {
+  pub fn new(level: usize, associativity: Associativity) -> Self {
+    Self { level, associativity }
+  }
+}
+
+
+// 产生式的优先级为其右部最后一个声明了优先级的终结符的优先级
+// 匿名非终结符的每个产生式都只有一个终结符, 并且这些终结符的优先级相同时 (如 op=(PLUS | MINUS)), 将其看作具有该优先级的终结符
+pub fn production_precedence<'a>(production: &Production<'a>, productions: &BTreeMap<usize, Production<'a>>, precedences: &BTreeMap<Terminal<'a>, Precedence>) -> Option<Precedence> {
+  production.right.iter().rev().find_map(|item| match item {
+    ProductionItem::Terminal(terminal) => precedences.get(terminal).cloned(),
+    ProductionItem::NonTerminal(nonterminal) if nonterminal.name.is_none() => block_precedence(*nonterminal, productions, precedences),
+    ProductionItem::NonTerminal(_) => None,
+  })
+}
+
+// 由单个终结符组成的匿名块的优先级, 块中的终结符没有声明优先级或者优先级不同时返回 None
+fn block_precedence<'a>(nonterminal: NonTerminal<'a>, productions: &BTreeMap<usize, Production<'a>>, precedences: &BTreeMap<Terminal<'a>, Precedence>) -> Option<Precedence> {
+  let mut result: Option<Precedence> = None;
+  for production in productions.values().filter(|production| production.left == nonterminal) {
+    let precedence = match production.right.as_slice() {
+      [ProductionItem::Terminal(terminal)] => *precedences.get(terminal)?,
+      _ => return None,
+    };
+    if result.is_some_and(|result| result != precedence) { return None; }
+    result = Some(precedence);
+  }
+  result
+}
+
+
+#[cfg(test)]
+mod tests {
+  use crate::tool::cli::Analyzer;
+  use crate::tool::grammar::tests::{diagnostics, grammar, parse};
+
+  #[test]
+  fn block_precedence_test() {
+    let grammar = grammar(r####"
+      grammar Expr;
+      stat: ID ASSIGN expr SEMI;
+      expr: expr op=(PLUS | MINUS) expr | expr op=(STAR | SLASH) expr | expr POW expr | NUM;
+
+      @left(PLUS, MINUS);
+      @left(STAR, SLASH);
+      @right(POW);
+
+      ID: r###"[a-z]+"###;
+      NUM: r###"[0-9]+"###;
+      ASSIGN: "=";
+      SEMI: ";";
+      PLUS: "+";
+      MINUS: "-";
+      STAR: "*";
+      SLASH: "/";
+      POW: "^";
+      @ignore
+      WS: r###"[ ]+"###;
+    "####);
+
+    // 分组中的运算符同样使用声明的优先级, 不产生冲突
+    assert!(grammar.lalr_table().conflicts.is_empty());
+    assert_eq!(parse(&grammar, Analyzer::LALR, "stat", "a = 1 + 2 * 3 - 4;"),
+      "(stat ID ASSIGN (expr (expr (expr NUM) PLUS (expr (expr NUM) STAR (expr NUM))) MINUS (expr NUM)) SEMI)");
+    assert_eq!(parse(&grammar, Analyzer::LALR, "stat", "a = 1 / 2 ^ 3 ^ 4;"),
+      "(stat ID ASSIGN (expr (expr NUM) SLASH (expr (expr NUM) POW (expr (expr NUM) POW (expr NUM)))) SEMI)");
+  }
+
+  #[test]
+  fn mixed_block_precedence_test() {
+    // 分组中的运算符优先级不同时, 无法确定产生式的优先级, 冲突会被报告出来
+    let grammar = grammar(r####"
+      grammar Expr;
+      expr: expr (PLUS | STAR) expr | NUM;
+
+      @left(PLUS);
+      @left(STAR);
+
+      NUM: r###"[0-9]+"###;
+      PLUS: "+";
+      STAR: "*";
+    "####);
+    assert!(!grammar.lalr_table().conflicts.is_empty());
+  }
+
+  #[test]
+  fn literal_precedence_test() {
+    // 直接写在语法规则中的运算符也可以声明优先级, 可以与 token 名称混合使用
+    let grammar = grammar(r####"
+      grammar Expr;
+      expr: expr "+" expr | expr MINUS expr | expr "*" expr | expr "^" expr | NUM;
+
+      @left("+", MINUS);
+      @left("*");
+      @right("^");
+
+      NUM: r###"[0-9]+"###;
+      MINUS: "-";
+    "####);
+    assert!(grammar.lalr_table().conflicts.is_empty());
+    assert_eq!(parse(&grammar, Analyzer::LALR, "expr", "1-2+3*4^5^6"),
+      "(expr (expr (expr NUM) MINUS (expr NUM)) PLUS (expr (expr NUM) STAR (expr (expr NUM) CARET (expr (expr NUM) CARET (expr NUM)))))");
+  }
+
+  #[test]
+  fn literal_precedence_diagnostics_test() {
+    let source = r####"
+      grammar Expr;
+      expr: expr "+" expr | NUM;
+      @left("+", "-", "");
+      @channel(HIDDEN)
+      NUM: r###"[0-9]+"###;
+      @channel("HIDDEN")
+      WS: r###"[ ]+"###;
+    "####;
+    let span = |text: &str| {
+      let start = source.find(text).unwrap();
+      start..start + text.len()
+    };
+
+    // 没有在语法规则中使用的字面量不会生成 token, 词法规则的注解不能使用字符串字面量
+    assert_eq!(diagnostics(source), [
+      ("error: string literal \"-\" in precedence declaration is not used in any parser rule".to_owned(), span("\"-\"")),
+      ("error: empty string literal is not allowed".to_owned(), span("\"\"")),
+      ("error: annotation 'channel' does not accept string literals".to_owned(), span("\"HIDDEN\"")),
+    ]);
+  }
+}
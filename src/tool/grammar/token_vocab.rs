@@ -0,0 +1,167 @@
+use std::{collections::{BTreeMap, HashMap}, error::Error};
+
+use chiru::runtime::error;
+
+use super::lexer_rule::LexerRule;
+
+
+
+/**
+ * .tokens 文件记录了每个 token 的编号, 每行一个, 如
+ * NUM=2
+ * "+"=3
+ * 字符串字面量对应的 token 以转义之后的字面量 (包括引号) 作为名称, 同时还会输出其生成的名称 PLUS=3
+ */
+pub fn parse_token_vocab(content: &str) -> Result<BTreeMap<String, usize>, Box<dyn Error>> {
+  let mut result = BTreeMap::new();
+  for (line_number, line) in content.lines().enumerate() {
+    let line = line.trim();
+    if line.is_empty() { continue; }
+
+    // 字面量中可能包含 =, 因此以最后一个 = 分隔
+    let id = line.rsplit_once('=').and_then(|(name, id)| Some((name, id.trim().parse::<usize>().ok()?)));
+    match id {
+      Some((name, id)) if id > 1 => { result.insert(name.trim().to_owned(), id); },
+      _ => {
+        let message = format!("invalid token vocabulary entry at line {}: '{}'", line_number + 1, line);
+        return Err(Box::new(error::Error::grammar_error(None, &message)));
+      },
+    }
+  }
+  Ok(result)
+}
+
+
+// 按照 vocab 中记录的编号重新为 token 编号, vocab 中没有的 token 按照优先级依次使用之后的编号
+// lexer_rule_map 的键为 token 的名称或者字符串字面量, 与 .tokens 文件中的名称一致
+pub fn apply_token_vocab(lexer_rule_map: &mut HashMap<String, LexerRule>, vocab: &BTreeMap<String, usize>) -> Result<(), Box<dyn Error>> {
+  if vocab.is_empty() { return Ok(()); }
+
+  let mut keys = lexer_rule_map.keys().cloned().collect::<Vec<_>>();
+  keys.sort_by_key(|key| lexer_rule_map[key].priority);
+
+  let mut used: HashMap<usize, &str> = HashMap::new();
+  let mut next_token_id = vocab.values().max().map_or(2, |id| id + 1);
+  let mut assigned = Vec::new();
+  for key in keys.iter() {
+    let token_type = match vocab.get(key) {
+      Some(id) => *id,
+      None => { next_token_id += 1; next_token_id - 1 },
+    };
+    if let Some(other) = used.insert(token_type, key) {
+      let message = format!("token id {} is assigned to both {} and {}", token_type, other, key);
+      return Err(Box::new(error::Error::grammar_error(None, &message)));
+    }
+    assigned.push((key.clone(), token_type));
+  }
+
+  for (key, token_type) in assigned {
+    let rule = lexer_rule_map.get_mut(&key).unwrap();
+    // 无法使用可读名称的字面量, 其名称包含编号, 需要一起修改
+    if key != rule.token_name && rule.token_name == format!("_T_{}", rule.token_type) {
+      rule.token_name = format!("_T_{}", token_type);
+    }
+    rule.token_type = token_type;
+  }
+  Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+  use std::collections::BTreeMap;
+
+  use chiru::runtime::lexer::Lexer;
+
+  use crate::tool::{analyzer::CommonLexer, code_generator::name_case::VocabularyGenData, grammar::{tests::grammar_from, Grammar}};
+
+  use super::parse_token_vocab;
+
+  // 生成的 .tokens 文件的内容
+  fn tokens_file(grammar: &Grammar) -> String {
+    VocabularyGenData::new(grammar).token_list.iter().map(|(name, id)| format!("{}={}\n", name, id)).collect()
+  }
+
+  fn token_names(grammar: &Grammar, input: &str) -> Vec<String> {
+    CommonLexer::from_grammar(grammar, input).unwrap().iter().map(|token| token.terminal.name.to_owned()).collect()
+  }
+
+  #[test]
+  fn stable_token_id_test() {
+    let old = grammar_from(&[r####"
+      grammar Calc;
+      expr: ID ("+" | "=>") ID;
+      ID: r###"[a-z]+"###;
+      @ignore
+      WS: r###" +"###;
+    "####], &BTreeMap::new()).unwrap();
+    let vocab = parse_token_vocab(&tokens_file(&old)).unwrap();
+    assert_eq!(vocab.len(), 6);
+
+    // 使用 .tokens 重新生成时编号不变
+    let again = grammar_from(&[r####"
+      grammar Calc;
+      expr: ID ("+" | "=>") ID;
+      ID: r###"[a-z]+"###;
+      @ignore
+      WS: r###" +"###;
+    "####], &vocab).unwrap();
+    assert_eq!(tokens_file(&again), tokens_file(&old));
+
+    // 新增的 token 排在已有的编号之后, 已有的 token 编号不变
+    let new = grammar_from(&[r####"
+      grammar Calc;
+      expr: ID ("-" | "+" | "=>") ID | KW;
+      KW: "if";
+      ID: r###"[a-z]+"###;
+      @ignore
+      WS: r###" +"###;
+    "####], &vocab).unwrap();
+    let new_vocab = parse_token_vocab(&tokens_file(&new)).unwrap();
+    for (name, id) in vocab.iter() {
+      assert_eq!(new_vocab[name], *id, "{}", name);
+    }
+    let max = vocab.values().max().unwrap();
+    assert!(new_vocab["KW"] > *max && new_vocab["\"-\""] > *max);
+
+    // 匹配长度相同时仍然按照定义的顺序选择, 而不是编号
+    assert_eq!(token_names(&new, "if x"), vec!["KW", "ID"]);
+  }
+
+  #[test]
+  fn imported_priority_test() {
+    // 输入文件中的规则优先于导入的规则, 即使导入的规则编号更小
+    let main = r####"
+      grammar Main;
+      import Words;
+      s: (KW | ID)*;
+      KW: "if";
+    "####;
+    let words = r####"
+      lexer grammar Words;
+      ID: r###"[a-z]+"###;
+      @ignore
+      WS: r###" +"###;
+    "####;
+    let vocab = BTreeMap::from([("ID".to_owned(), 2), ("WS".to_owned(), 3), ("KW".to_owned(), 4)]);
+    let grammar = grammar_from(&[main, words], &vocab).unwrap();
+    assert_eq!(grammar.lexer_rule_map["KW"].token_type, 4);
+    assert_eq!(token_names(&grammar, "if iff"), vec!["KW", "ID"]);
+  }
+
+  #[test]
+  fn malformed_token_vocab_test() {
+    assert_eq!(parse_token_vocab("A=2\n\n\"=\"=3\n").unwrap(), BTreeMap::from([("A".to_owned(), 2), ("\"=\"".to_owned(), 3)]));
+
+    // 格式错误的行返回错误而不是 panic, 0 和 1 保留给 _START 和 _STOP
+    for (content, line) in [("A=2\nB\n", "2: 'B'"), ("A=x", "1: 'A=x'"), ("A=1", "1: 'A=1'"), ("A=-3", "1: 'A=-3'"), ("A=99999999999999999999999", "1: 'A=99999999999999999999999'")] {
+      let err = parse_token_vocab(content).unwrap_err();
+      assert_eq!(err.to_string(), format!("grammar error: invalid token vocabulary entry at line {}", line));
+    }
+
+    // 两个 token 使用了相同的编号
+    let vocab = parse_token_vocab("A=2\nB=2\n").unwrap();
+    let err = grammar_from(&["grammar T;\ns: A B;\nA: \"a\";\nB: \"b\";\n"], &vocab).err().unwrap();
+    assert_eq!(err.to_string(), "grammar error: token id 2 is assigned to both A and B");
+  }
+}
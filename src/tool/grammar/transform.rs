@@ -0,0 +1,425 @@
+// 对文法进行等价变换, 使其能够用于 ll1 分析
+
+use std::collections::{BTreeMap, BTreeSet};
+use chiru::runtime::{production::{Production, ProductionItem}, vocabulary::NonTerminal};
+
+use super::{diagnostic::{Diagnostic, Span}, Grammar};
+
+
+impl<'a> Grammar<'a> {
+
+  /**
+   * 消除直接左递归和间接左递归
+   *   A -> A α1 | A α2 | β1 | β2
+   * 改写为
+   *   A -> β1 A' | β2 A'
+   *   A' -> α1 A' | α2 A' | ε
+   * 其中 A' 为匿名非终结符, 并在 left_recursion_tails 中记录 A' -> A, 运行时会将 A' 的每一次展开折叠为一个左结合的 A 节点。
+   * 改写后的产生式保留原来的标签, 折叠出的 A 节点使用 A' 产生式上的标签。
+   * A -> A α 中第一个 A 的元素标签记录在 A' -> α A' 末尾的 A' 上, 折叠时再转交给之前的结果。
+   * 只会代入匿名非终结符, 如 A -> (A α | β) γ 中的括号, 它们本来就会被展开到 A 中。
+   * 经过命名非终结符的间接左递归需要先通过 indirect_left_recursion 报告错误, 否则代入之后这一层节点会从语法树中消失。
+   */
+  pub fn eliminate_left_recursion(&mut self) {
+    let mut nonterminals = self.vocabulary.get_all_nonterminals();
+    nonterminals.sort_by_key(|nonterminal| nonterminal.id);
+
+    for nonterminal in nonterminals.iter() {
+      // 将 A -> B γ 中能够左推导出 A 的匿名非终结符 B 替换为 B 的所有产生式, 直到不能替换为止
+      // 匿名非终结符只会出现在一个规则中, 不会形成只由匿名非终结符组成的环, 因此不需要按照编号的顺序代入
+      let mut modified = true;
+      while modified {
+        modified = false;
+        let reachable = nonterminals.iter()
+          .filter(|other| other.name.is_none() && *other != nonterminal && self.left_reachable(**other).contains(nonterminal))
+          .cloned().collect::<BTreeSet<_>>();
+
+        let productions = self.productions_of(*nonterminal);
+        for production in productions.iter() {
+          if let Some(ProductionItem::NonTerminal(first)) = production.right.first() {
+            if reachable.contains(first) {
+              self.substitute(production, *first);
+              modified = true;
+            }
+          }
+        }
+      }
+
+      self.eliminate_direct_left_recursion(*nonterminal);
+    }
+  }
+
+  // 经过另一个命名非终结符的左递归, 如 A -> B γ, B -> A δ, 无法在保留 B 节点的同时转换为 ll1 文法
+  // 每组相互左递归的非终结符只报告一次, 位置为其中编号最小的非终结符的第一个左递归产生式
+  pub fn indirect_left_recursion(&self) -> Vec<Diagnostic> {
+    let mut nonterminals = self.vocabulary.get_all_named_nonterminals();
+    nonterminals.sort_by_key(|nonterminal| nonterminal.id);
+
+    let mut result = vec![];
+    for nonterminal in nonterminals.iter() {
+      let reachable = self.left_reachable(*nonterminal);
+      let through = nonterminals.iter()
+        .find(|other| other.id != nonterminal.id && reachable.contains(*other) && self.left_reachable(**other).contains(nonterminal));
+      let Some(through) = through else { continue };
+      if through.id < nonterminal.id { continue; }
+
+      let span = self.productions_of(*nonterminal).iter()
+        .find(|production| match production.right.first() {
+          Some(ProductionItem::NonTerminal(first)) => first == through || self.left_reachable(*first).contains(through),
+          _ => false,
+        })
+        .and_then(|production| self.production_spans.get(&production.id).cloned())
+        .unwrap_or(0..0);
+      let message = format!(
+        "rule '{}' is indirectly left-recursive through rule '{}', which the LL(1) analyzer does not support; rewrite it as direct left recursion or use --analyzer lalr",
+        nonterminal.name.unwrap(), through.name.unwrap()
+      );
+      result.push(Diagnostic::error(&message, Span::new(0, span)));
+    }
+    result
+  }
+
+  /**
+   * 提取左公因子
+   *   A -> α β1 | α β2 | γ
+   * 改写为
+   *   A -> α A' | γ
+   *   A' -> β1 | β2
+   * 其中 A' 为匿名非终结符, 分析时会被展开到 A 中, 因此用户看到的语法树的形状不变。
+   * 公共前缀 α 中的元素标签只有在各个产生式中都相同时才会保留。
+   */
+  pub fn left_factor(&mut self) {
+    let mut stack = self.vocabulary.get_all_nonterminals();
+
+    while let Some(nonterminal) = stack.pop() {
+      // 按照产生式右部的第一个符号分组
+      let mut groups: BTreeMap<ProductionItem, Vec<Production>> = BTreeMap::new();
+      for production in self.productions_of(nonterminal) {
+        if let Some(first) = production.right.first() {
+          groups.entry(*first).or_default().push(production);
+        }
+      }
+
+      for group in groups.into_values().filter(|group| group.len() > 1) {
+        // 求最长公共前缀的长度
+        let mut len = 1;
+        while group.iter().all(|production| production.right.len() > len && production.right[len] == group[0].right[len]) {
+          len += 1;
+        }
+
+        let helper = NonTerminal::new(None, self.next_nonterminal_id());
+        self.vocabulary.add_unnamed_nonterminal(helper.id);
+
+        // A' -> β, 沿用原来的产生式编号
+        for production in group.iter() {
+          self.productions.insert(production.id, Production::new(production.id, helper, &production.right[len..])
+            .with_label(production.label).with_element_labels(&production.element_labels[len..]));
+        }
+
+        // A -> α A', 位置为整个分组的范围
+        let mut right = group[0].right[..len].to_vec();
+        right.push(ProductionItem::NonTerminal(helper));
+        let mut element_labels = (0..len).map(|index| {
+          let label = group[0].element_labels[index];
+          if group.iter().all(|production| production.element_labels[index] == label) { label } else { None }
+        }).collect::<Vec<_>>();
+        element_labels.push(None);
+        let production_id = self.next_production_id();
+        self.productions.insert(production_id, Production::new(production_id, nonterminal, &right).with_element_labels(&element_labels));
+
+        let spans = group.iter().filter_map(|production| self.production_spans.get(&production.id)).collect::<Vec<_>>();
+        if let (Some(start), Some(end)) = (spans.iter().map(|span| span.start).min(), spans.iter().map(|span| span.end).max()) {
+          self.production_spans.insert(production_id, start..end);
+        }
+
+        // 新的非终结符可能仍然需要提取左公因子
+        stack.push(helper);
+      }
+    }
+  }
+
+  // 消除直接左递归
+  fn eliminate_direct_left_recursion(&mut self, nonterminal: NonTerminal<'a>) {
+    let productions = self.productions_of(nonterminal);
+    let first_item = ProductionItem::NonTerminal(nonterminal);
+
+    // A -> A 这样的产生式没有意义, 直接删除
+    for production in productions.iter().filter(|production| production.right == [first_item]) {
+      self.productions.remove(&production.id);
+      self.production_spans.remove(&production.id);
+    }
+
+    let (recursive, others): (Vec<_>, Vec<_>) = productions.into_iter()
+      .filter(|production| production.right != [first_item])
+      .partition(|production| production.right.first() == Some(&first_item));
+    if recursive.is_empty() { return; }
+
+    let tail = NonTerminal::new(None, self.next_nonterminal_id());
+    self.vocabulary.add_unnamed_nonterminal(tail.id);
+    self.left_recursion_tails.insert(tail, nonterminal);
+
+    // A -> β A', 沿用原来的产生式编号
+    for production in others.iter() {
+      let mut right = production.right.clone();
+      right.push(ProductionItem::NonTerminal(tail));
+      let mut element_labels = production.element_labels.clone();
+      element_labels.push(None);
+      self.productions.insert(production.id, Production::new(production.id, nonterminal, &right)
+        .with_label(production.label).with_element_labels(&element_labels));
+    }
+
+    // A' -> α A', 左递归位置的标签移动到末尾
+    for production in recursive.iter() {
+      let mut right = production.right[1..].to_vec();
+      right.push(ProductionItem::NonTerminal(tail));
+      let mut element_labels = production.element_labels[1..].to_vec();
+      element_labels.push(production.element_labels[0]);
+      self.productions.insert(production.id, Production::new(production.id, tail, &right)
+        .with_label(production.label).with_element_labels(&element_labels));
+    }
+
+    // A' -> ε, 位置使用第一个左递归产生式的位置
+    let production_id = self.next_production_id();
+    self.productions.insert(production_id, Production::new(production_id, tail, &[]));
+    if let Some(span) = self.production_spans.get(&recursive[0].id).cloned() {
+      self.production_spans.insert(production_id, span);
+    }
+  }
+
+  // 将产生式 A -> B γ 中开头的 B 替换为 B 的所有产生式
+  fn substitute(&mut self, production: &Production<'a>, first: NonTerminal<'a>) {
+    let span = self.production_spans.get(&production.id).cloned();
+    self.productions.remove(&production.id);
+
+    for (i, replacement) in self.productions_of(first).iter().enumerate() {
+      let mut right = replacement.right.clone();
+      right.extend_from_slice(&production.right[1..]);
+      let mut element_labels = replacement.element_labels.clone();
+      element_labels.extend_from_slice(&production.element_labels[1..]);
+
+      // 第一条产生式沿用原来的编号
+      let production_id = if i == 0 { production.id } else { self.next_production_id() };
+      self.productions.insert(production_id, Production::new(production_id, production.left, &right)
+        .with_label(production.label).with_element_labels(&element_labels));
+      if let Some(span) = &span {
+        self.production_spans.insert(production_id, span.clone());
+      }
+    }
+  }
+
+  // 从 nonterminal 出发, 只经过产生式的第一个符号能够到达的所有非终结符
+  fn left_reachable(&self, nonterminal: NonTerminal<'a>) -> BTreeSet<NonTerminal<'a>> {
+    let mut result = BTreeSet::new();
+    let mut stack = vec![nonterminal];
+    while let Some(current) = stack.pop() {
+      for production in self.productions.values().filter(|production| production.left == current) {
+        if let Some(ProductionItem::NonTerminal(first)) = production.right.first() {
+          if result.insert(*first) { stack.push(*first); }
+        }
+      }
+    }
+    result
+  }
+
+  fn productions_of(&self, nonterminal: NonTerminal<'a>) -> Vec<Production<'a>> {
+    self.productions.values().filter(|production| production.left == nonterminal).cloned().collect()
+  }
+
+  fn next_production_id(&self) -> usize {
+    self.productions.keys().max().map_or(0, |id| id + 1)
+  }
+
+  fn next_nonterminal_id(&self) -> usize {
+    self.vocabulary.nonterminals.iter().map(|nonterminal| nonterminal.id).max().map_or(0, |id| id + 1)
+  }
+}
+
+
+#[cfg(test)]
+mod tests {
+  use std::collections::BTreeSet;
+
+  use chiru::runtime::{production::ProductionItem, token_stream::TokenStream};
+
+  use crate::tool::{analyzer::{CommonLexer, CommonParser}, cli::Analyzer};
+  use crate::tool::grammar::{diagnostic::Span, tests::{grammar, parse}, Grammar};
+
+  // 没有任何产生式以自身开头
+  fn assert_no_direct_left_recursion(grammar: &Grammar) {
+    for production in grammar.productions.values() {
+      assert_ne!(production.right.first(), Some(&ProductionItem::NonTerminal(production.left)), "{}", production);
+    }
+  }
+
+  #[test]
+  fn direct_left_recursion_test() {
+    let mut grammar = grammar(r####"
+      grammar Expr;
+      expr: expr PLUS term | expr MINUS term | term;
+      term: NUM;
+      PLUS: "+";
+      MINUS: "-";
+      NUM: r###"[0-9]+"###;
+      @ignore
+      WS: r###"[ ]+"###;
+    "####);
+    grammar.eliminate_left_recursion();
+    assert_no_direct_left_recursion(&grammar);
+
+    let expr = grammar.vocabulary.get_nonterminal_by_name("expr").unwrap();
+    assert_eq!(grammar.left_recursion_tails.values().collect::<Vec<_>>(), vec![&expr]);
+
+    // 折叠为左结合的语法树
+    assert_eq!(parse(&grammar, Analyzer::LL1, "expr", "1 - 2 + 3"),
+      "(expr (expr (expr (term NUM)) MINUS (term NUM)) PLUS (term NUM))");
+    assert_eq!(parse(&grammar, Analyzer::LL1, "expr", "1"), "(expr (term NUM))");
+  }
+
+  #[test]
+  fn indirect_left_recursion_test() {
+    let source = r####"
+      grammar Indirect;
+      a: b | Y;
+      b: a Z | W;
+      c: d;
+      d: c X | a;
+      Y: "y";
+      Z: "z";
+      W: "w";
+      X: "x";
+    "####;
+    let mut grammar = grammar(source);
+
+    // 代入 b 之后 b 节点会从语法树中消失, 生成的 BContext 也就无法访问, 因此作为错误报告, 每组只报告一次
+    let diagnostics = grammar.indirect_left_recursion();
+    let messages = diagnostics.iter().map(|diagnostic| diagnostic.to_string()).collect::<Vec<_>>();
+    assert_eq!(messages, [
+      "error: rule 'a' is indirectly left-recursive through rule 'b', which the LL(1) analyzer does not support; rewrite it as direct left recursion or use --analyzer lalr",
+      "error: rule 'c' is indirectly left-recursive through rule 'd', which the LL(1) analyzer does not support; rewrite it as direct left recursion or use --analyzer lalr",
+    ]);
+    let start = source.find("a: b").unwrap() + 3;
+    assert_eq!(diagnostics[0].span, Span::new(0, start..start + 1));
+
+    // 不会代入命名非终结符
+    let productions = grammar.productions.clone();
+    grammar.eliminate_left_recursion();
+    assert_eq!(grammar.productions, productions);
+    assert!(grammar.left_recursion_tails.is_empty());
+  }
+
+  #[test]
+  fn anonymous_left_recursion_test() {
+    let mut grammar = grammar(r####"
+      grammar Expr;
+      expr: (expr PLUS | MINUS) NUM | NUM;
+      PLUS: "+";
+      MINUS: "-";
+      NUM: r###"[0-9]+"###;
+    "####);
+
+    // 括号对应的匿名非终结符本来就会被展开, 代入之后语法树的形状和 lalr 分析的结果相同
+    assert!(grammar.indirect_left_recursion().is_empty());
+    let lalr = parse(&grammar, Analyzer::LALR, "expr", "-1+2+3");
+    grammar.eliminate_left_recursion();
+    grammar.left_factor();
+    assert_no_direct_left_recursion(&grammar);
+    assert_eq!(parse(&grammar, Analyzer::LL1, "expr", "-1+2+3"), lalr);
+    assert_eq!(lalr, "(expr (expr (expr MINUS NUM) PLUS NUM) PLUS NUM)");
+
+    // 通过生成的访问器使用的接口访问子节点, ExprContext::expr() 和 ExprContext::plus()
+    let expr = grammar.vocabulary.get_nonterminal_by_name("expr").unwrap();
+    let plus = grammar.vocabulary.get_terminal_by_name("PLUS").unwrap();
+    let lexer = CommonLexer::from_grammar(&grammar, "-1+2+3").unwrap();
+    let mut tokens = TokenStream::new(&lexer, 0);
+    let ast = CommonParser::from_grammar(&grammar, Analyzer::LL1).parse(&mut tokens, expr).unwrap();
+    let lhs = ast.get_rule_context(expr.id, 0).unwrap();
+    assert_eq!(lhs.to_string(), "(expr (expr MINUS NUM) PLUS NUM)");
+    assert_eq!(ast.get_terminal(plus.id, 0).unwrap().symbol.text, "+");
+    assert_eq!(lhs.get_rule_context(expr.id, 0).unwrap().to_string(), "(expr MINUS NUM)");
+  }
+
+  #[test]
+  fn left_recursion_label_test() {
+    let mut grammar = grammar(r####"
+      grammar Expr;
+      expr: lhs=expr op=PLUS rhs=NUM # Add | NUM # Single;
+      PLUS: "+";
+      NUM: r###"[0-9]+"###;
+    "####);
+    grammar.eliminate_left_recursion();
+
+    // 标签和元素标签在折叠后依然存在
+    let lexer = CommonLexer::from_grammar(&grammar, "1+2+3").unwrap();
+    let mut tokens = TokenStream::new(&lexer, 0);
+    let parser = CommonParser::from_grammar(&grammar, Analyzer::LL1);
+    let expr = grammar.vocabulary.get_nonterminal_by_name("expr").unwrap();
+    let ast = parser.parse(&mut tokens, expr).unwrap();
+    assert_eq!(ast.get_label(), Some("Add"));
+    let lhs = ast.get_labeled_rule_context("lhs", 0).unwrap();
+    assert_eq!(lhs.to_string(), "(expr (expr NUM) PLUS NUM)");
+    assert_eq!(lhs.get_label(), Some("Add"));
+    assert_eq!(lhs.get_labeled_rule_context("lhs", 0).unwrap().get_label(), Some("Single"));
+    assert_eq!(ast.get_labeled_terminal("rhs", 0).unwrap().symbol.text, "3");
+  }
+
+  #[test]
+  fn without_left_recursion_test() {
+    let source = r####"
+      grammar Expr;
+      expr: term PLUS expr | term;
+      term: NUM;
+      PLUS: "+";
+      NUM: r###"[0-9]+"###;
+    "####;
+    let mut transformed = grammar(source);
+    transformed.eliminate_left_recursion();
+    assert_eq!(transformed.productions, grammar(source).productions);
+    assert!(transformed.left_recursion_tails.is_empty());
+  }
+
+  #[test]
+  fn left_factor_test() {
+    let mut grammar = grammar(r####"
+      grammar Stmt;
+      stmt: ID ASSIGN NUM | ID LPAREN RPAREN | ID LPAREN NUM RPAREN | NUM;
+      ID: r###"[a-z]+"###;
+      NUM: r###"[0-9]+"###;
+      ASSIGN: "=";
+      LPAREN: "(";
+      RPAREN: ")";
+    "####);
+    grammar.left_factor();
+
+    // 每个非终结符的产生式的第一个符号各不相同
+    for nonterminal in grammar.vocabulary.get_all_nonterminals() {
+      let firsts = grammar.productions_of(nonterminal).iter().filter_map(|production| production.right.first().cloned()).collect::<Vec<_>>();
+      assert_eq!(firsts.iter().collect::<BTreeSet<_>>().len(), firsts.len());
+    }
+
+    let (first, first_set) = grammar.first_set();
+    let follow = grammar.follow_set(&first);
+    assert!(grammar.ll1_table(&first_set, &follow).1.is_empty());
+
+    // 提取出的匿名非终结符会被展开, 语法树的形状不变
+    assert_eq!(parse(&grammar, Analyzer::LL1, "stmt", "x=1"), "(stmt ID ASSIGN NUM)");
+    assert_eq!(parse(&grammar, Analyzer::LL1, "stmt", "f()"), "(stmt ID LPAREN RPAREN)");
+    assert_eq!(parse(&grammar, Analyzer::LL1, "stmt", "f(1)"), "(stmt ID LPAREN NUM RPAREN)");
+    assert_eq!(parse(&grammar, Analyzer::LL1, "stmt", "1"), "(stmt NUM)");
+  }
+
+  #[test]
+  fn already_ll1_test() {
+    let source = r####"
+      grammar Stmt;
+      stmt: ID ASSIGN expr | NUM;
+      expr: ID | NUM;
+      ID: r###"[a-z]+"###;
+      NUM: r###"[0-9]+"###;
+      ASSIGN: "=";
+    "####;
+    let mut transformed = grammar(source);
+    transformed.eliminate_left_recursion();
+    transformed.left_factor();
+    assert_eq!(transformed.productions, grammar(source).productions);
+  }
+}
@@ -1,178 +1,51 @@
 // 构造分析相关的函数都写在这里, 这个模块不需要暴露出去
 
-use std::{collections::{BTreeSet, HashMap, BTreeMap}, vec};
-use chiru::runtime::{production::{Production, ProductionItem}, vocabulary::{NonTerminal, Terminal}};
-use maplit::btreeset;
-use super::{Grammar, ActionTableElement};
+use std::collections::{BTreeMap, BTreeSet};
+use chiru::runtime::{lalr_analyzer::ActionTableElement, production::{Production, ProductionItem}, vocabulary::{NonTerminal, Terminal}};
+use super::{conflict::{ConflictKind, GrammarConflict}, precedence::{production_precedence, Associativity, Precedence}, FirstCollection, Grammar};
 
 
-pub struct Collection {
-  pub allow_epsilon: bool,
-  pub set: BTreeSet<usize>,
-}
-
-// 项目
-#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Copy)]
-pub struct Item<'a> {
-  pub production: &'a Production<'a>,
-  pub dot: usize,
-  // 增加一个字段 state, 表示所属的项目集闭包的编号
-  pub state: usize,
-}
-
-#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Copy)]
-pub struct ItemWithLookAhead<'a> {
-  pub production: &'a Production<'a>,
+// 项目, 使用产生式的编号和点的位置来表示
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Debug)]
+pub struct Item {
+  pub production: usize,
   pub dot: usize,
-  pub look_ahead: usize,
-}
-
-// 求 first(βa)
-pub fn first(beta: &[ProductionItem], a: usize, first_set_of_nonterminals: &BTreeMap<usize, Collection>) -> BTreeSet<usize> {
-  let mut result: Collection = Collection { allow_epsilon: true, set: BTreeSet::new(), };
-  for item in beta.iter() {
-    match item {
-      ProductionItem::NonTerminal(rule_id) => {
-        let c = first_set_of_nonterminals.get(&rule_id.id).unwrap();
-        for item in c.set.iter() { result.set.insert(*item) ; }
-        if !c.allow_epsilon {
-          result.allow_epsilon = false;
-          break;
-        }
-      },
-      ProductionItem::Terminal(token_type) => {
-        result.allow_epsilon = false;
-        result.set.insert(token_type.id);
-        break;
-      },
-    }
-  }
-
-  if result.allow_epsilon { result.set.insert(a); }
-
-  result.set
 }
 
-pub fn get_first_set_for_non_epsilon_rule(production: &Production, result: &mut Collection, first_set: &BTreeMap<usize, Collection>) -> bool {
-  
-  let mut modified = false; // 标识 result 是否被修改
-
-  // 首先判断是否可以为 epsilon 
-  let mut allow_epsilon = true;
-  for item in production.right.iter() {
-    match item {
-      ProductionItem::NonTerminal(id) => {
-        let set = first_set.get(&id.id).unwrap();
-        if !set.allow_epsilon {
-          allow_epsilon = false;
-          break;
-        }
-      },
-      ProductionItem::Terminal(_) => {
-        allow_epsilon = false;
-        break;
-      },
-    }
-  }
-
-  if result.allow_epsilon != allow_epsilon {
-    modified = true; // 标记为已经修改
-    result.allow_epsilon = allow_epsilon;
-  }
-
-  for item in production.right.iter() {
-    match item {
-      ProductionItem::NonTerminal(rule_id) => {
-        let c = first_set.get(&rule_id.id).unwrap();
-        for item in c.set.iter() { modified = result.set.insert(*item) || modified; }
-
-        if ! c.allow_epsilon {
-          break;
-        }
-      },
-      ProductionItem::Terminal(token_type) => {
-        modified = result.set.insert(token_type.id) || modified;
-        
-        // 遇到终结符就退出
-        break;
-      },
-    }
-  }
-
-
-  modified
+// lalr 分析表, 包括 action 表、goto 表以及每个命名非终结符对应的初始状态, 以及构造过程中发现的冲突
+pub struct LalrTable<'a> {
+  pub action: BTreeMap<(usize, Terminal<'a>), ActionTableElement>,
+  pub goto: BTreeMap<(usize, NonTerminal<'a>), usize>,
+  pub start_states: BTreeMap<NonTerminal<'a>, usize>,
+  pub conflicts: Vec<GrammarConflict<'a>>,
 }
 
-pub fn first_set_for_nonterminal_and_production(grammar: &Grammar) -> (BTreeMap<usize, Collection>, BTreeMap<usize, Collection>) {
-  let mut /*非终结符的first集合 */ result:BTreeMap<usize, Collection> = BTreeMap::new();
-  for nonterminal in grammar.vocabulary.get_all_nonterminals().iter() {
-    result.insert(*nonterminal, Collection { allow_epsilon: false, set: BTreeSet::new() });
-  }
-  let mut modified = true;
-  let mut /*产生式的first集合 */ cache: BTreeMap<usize, Collection> = BTreeMap::new();
-  
-  for production in grammar.productions.values() {
-    cache.insert(production.id, Collection { allow_epsilon: false, set: BTreeSet::new() });
-  }
-  
-  while modified {
-    modified = false;
-    for production in grammar.productions.values() {
-      let t = cache.get_mut(&production.id).unwrap();
-      modified = get_first_set_for_non_epsilon_rule(production, t, &result);
-      
-      let r = result.get_mut(&production.left.id).unwrap();
-      if t.allow_epsilon && ! r.allow_epsilon {
-        r.allow_epsilon = t.allow_epsilon;
-        modified = true;
-      }
 
-      for item in t.set.iter() { modified = r.set.insert(*item) || modified }
-    }
-  }
-  (result, cache)
-}
+// 求项目集闭包, 返回结果包括项目集本身
+pub fn closure(item_set: &BTreeSet<Item>, productions: &BTreeMap<usize, Production>) -> BTreeSet<Item> {
+  let mut result = item_set.clone();
+  let mut stack = item_set.iter().cloned().collect::<Vec<_>>();
 
+  while let Some(item) = stack.pop() {
+    let production = productions.get(&item.production).unwrap();
+    if item.dot >= production.right.len() { continue; }
 
-// 求项目集闭包
-pub fn closure<'a>(item_set: BTreeSet<Item<'a>>, productions: &'a HashMap<usize, Production>) -> BTreeSet<Item<'a>> {
-  let mut result_set = item_set.to_owned();
-  let mut j = item_set.to_owned();
-  loop {
-    let mut k: BTreeSet<Item> = BTreeSet::new();
-    
-    // 将本轮循环产生的项目都放到了 k 中
-    for item in j.iter() {
-      if item.dot >= item.production.right.len() { continue; }
-      use ProductionItem::*;
-      match item.production.right[item.dot] {
-        Terminal(_) => continue,
-        NonTerminal(rule_id) => {
-          for production in productions.values().filter(|production| {
-            production.left == rule_id
-          }) {
-            let item = Item { production, dot: 0, state: 0 };
-            if ! result_set.contains(&item) {
-              k.insert(item);
-            }
-          }
-        },
+    if let ProductionItem::NonTerminal(nonterminal) = production.right[item.dot] {
+      for production in productions.values().filter(|production| production.left == nonterminal) {
+        let item = Item { production: production.id, dot: 0 };
+        if result.insert(item) { stack.push(item); }
       }
     }
-
-    let before = result_set.len();
-    result_set.extend(k.clone());
-    if result_set.len() <= before {
-      break;
-    }
-    j = k;
-
   }
-  result_set
+  result
 }
 
 #[allow(unused_doc_comments)]
-pub fn closure_with_look_ahead<'a>(item_set: BTreeSet<ItemWithLookAhead<'a>>, productions: &'a HashMap<usize, Production>, first_set_of_nonterminals: &BTreeMap<usize, Collection>) -> BTreeSet<ItemWithLookAhead<'a>> {
+pub fn closure_with_look_ahead<'a>(
+  item_set: &BTreeSet<(Item, Terminal<'a>)>,
+  productions: &BTreeMap<usize, Production<'a>>,
+  first_set: &BTreeMap<NonTerminal<'a>, FirstCollection<'a>>,
+) -> BTreeSet<(Item, Terminal<'a>)> {
   /**
    * closure(I):
    *   repeat
@@ -183,137 +56,96 @@ pub fn closure_with_look_ahead<'a>(item_set: BTreeSet<ItemWithLookAhead<'a>>, pr
    *   until 不能加入更多的项
    *   return I
    */
-  let mut result_set = item_set.to_owned();
-  let mut j = item_set.clone();
-  loop {
-    let mut k: BTreeSet<ItemWithLookAhead> = BTreeSet::new();
-    for /*[A->α·Bβ, a] */ item in j.iter() {
-      if item.dot >= item.production.right.len() { continue; }
-      else if let ProductionItem::NonTerminal(rule_id) = item.production.right[item.dot] {
-        for /*B -> γ */ production in productions.values().filter(|production| {
-          production.left == rule_id
-        }) {
-          for b in first(&item.production.right[item.dot+1..], item.look_ahead, first_set_of_nonterminals) {
-            let item = ItemWithLookAhead { production, dot:0, look_ahead: b };
-            if ! result_set.contains(&item) {
-              k.insert(item);
-            }
-          }
+  let mut result = item_set.clone();
+  let mut stack = item_set.iter().cloned().collect::<Vec<_>>();
+
+  while let Some((item, look_ahead)) = stack.pop() {
+    let production = productions.get(&item.production).unwrap();
+    if item.dot >= production.right.len() { continue; }
+
+    if let ProductionItem::NonTerminal(nonterminal) = production.right[item.dot] {
+      let mut first = Grammar::get_firstset_for_string(&production.right[item.dot+1..], first_set);
+      if first.allow_epsilon { first.set.insert(look_ahead); }
+
+      for production in productions.values().filter(|production| production.left == nonterminal) {
+        for b in first.set.iter() {
+          let item = (Item { production: production.id, dot: 0 }, *b);
+          if result.insert(item) { stack.push(item); }
         }
       }
     }
-    
-    let before = result_set.len();
-    result_set.extend(k.clone());
-    if result_set.len() <= before { break; }
-    j = k;
   }
-  result_set
+  result
 }
 
-// goto 转换函数
-pub fn goto<'a>(item_set: BTreeSet<Item<'a>>, x: ProductionItem, productions: &'a HashMap<usize, Production>) -> BTreeSet<Item<'a>> {
-  let mut j: BTreeSet<Item> = BTreeSet::new();
-  for item in item_set.iter() {
-    if item.dot >= item.production.right.len() { continue; }
-    else if x == item.production.right[item.dot] {
-      j.insert(Item { production: item.production, dot: item.dot + 1, state: 0 });
+// goto 转换函数, 返回的是项目集的内核
+pub fn goto(closure: &BTreeSet<Item>, x: ProductionItem, productions: &BTreeMap<usize, Production>) -> BTreeSet<Item> {
+  let mut result = BTreeSet::new();
+  for item in closure.iter() {
+    let production = productions.get(&item.production).unwrap();
+    if item.dot < production.right.len() && production.right[item.dot] == x {
+      result.insert(Item { production: item.production, dot: item.dot + 1 });
     }
   }
-  closure(j, productions)
+  result
 }
 
 // 直接求出 action 和 goto 表
 #[allow(unused_doc_comments)]
-pub fn lalr_table(grammar: &Grammar) -> (BTreeMap<(usize, usize), ActionTableElement>, BTreeMap<(usize, usize), usize>, BTreeMap<usize, usize>) {
-  // 用于记录命名非终结符对应的初始状态
-  let mut nonterminal_to_start_state: BTreeMap<usize, usize> = BTreeMap::new();
-  
-
+pub fn lalr_table<'a>(grammar: &Grammar<'a>) -> LalrTable<'a> {
   // 对文法做增广, 给每个命名非终结符增加一条产生式
-  let mut /*下一个产生式的id */ next_production_id = grammar.productions.iter().map(|(_, production)| production.id).max().unwrap_or(0) + 1;
-  let mut /*下一个非终结符id */ next_nonterminal_id = grammar.vocabulary.nonterminals.iter().max().unwrap_or(&0) + 1;
+  let mut /*下一个产生式的id */ next_production_id = grammar.productions.keys().max().map_or(0, |id| id + 1);
+  let /*下一个非终结符id */ next_nonterminal_id = grammar.vocabulary.nonterminals.iter().map(|nonterminal| nonterminal.id).max().map_or(0, |id| id + 1);
   let mut /*产生式 */ productions = grammar.productions.clone();
   let first_augmented_production_id = next_production_id;
-  for (nonterminal, _) in grammar.vocabulary.named_nonterminals.iter() {
-    let p = Production::new(next_production_id, NonTerminal::new(None, next_nonterminal_id), 
-      &vec![ProductionItem::NonTerminal(NonTerminal::new(None, *nonterminal))]);
-    productions.insert(next_production_id, p);
-    next_nonterminal_id += 1;
+  for (i, nonterminal) in grammar.vocabulary.get_all_named_nonterminals().into_iter().enumerate() {
+    let production = Production::new(next_production_id, NonTerminal::new(None, next_nonterminal_id + i),
+      &[ProductionItem::NonTerminal(nonterminal)]);
+    productions.insert(next_production_id, production);
     next_production_id += 1;
   }
-  let productions = productions;/*重新绑定为不可变 */
+  let productions = productions; /*重新绑定为不可变 */
 
 
-
-  // 求所有增广产生式的闭包并编号
-  let mut /*项目集闭包编号 */ next_closure_id = 0;
-  let mut /*lr0 项目集族 */ c: BTreeMap<BTreeSet<Item>, usize> = BTreeMap::new();
+  // 构造 lr0 项目集族(只记录内核)以及 goto 函数, 增广产生式的项目集闭包就是初始状态
+  let mut /*lr0 项目集族 */ kernels: Vec<BTreeSet<Item>> = Vec::new();
+  let mut /*内核到状态编号的映射 */ states: BTreeMap<BTreeSet<Item>, usize> = BTreeMap::new();
+  let mut start_states: BTreeMap<NonTerminal, usize> = BTreeMap::new();
   for production_id in first_augmented_production_id..next_production_id {
-    let production = productions.get(&production_id).unwrap();
-    let item = Item { production, dot: 0, state: 0 };
-    let closure = closure(btreeset! { item }, &productions);
-    c.insert(closure, next_closure_id);
-    if let ProductionItem::NonTerminal(nonterminal_id) = production.right[0] {
-      nonterminal_to_start_state.insert(nonterminal_id.id, next_closure_id);
+    let kernel = BTreeSet::from([Item { production: production_id, dot: 0 }]);
+    if let ProductionItem::NonTerminal(nonterminal) = productions.get(&production_id).unwrap().right[0] {
+      start_states.insert(nonterminal, kernels.len());
     }
-    next_closure_id += 1;
-  }
-  let nonterminal_to_start_state: BTreeMap<usize, usize> = nonterminal_to_start_state; /*重新绑定为不可变 */
-
-  // 构造文法符号集合
-  let mut symbols: Vec<ProductionItem> = Vec::new();
-  for (terminal, _) in grammar.vocabulary.terminals.iter() {
-    symbols.push(ProductionItem::Terminal(Terminal::new(name, id)));
-  }
-  for nonterminal in grammar.vocabulary.nonterminals.iter() {
-    symbols.push(ProductionItem::NonTerminal(*nonterminal));
+    states.insert(kernel.clone(), kernels.len());
+    kernels.push(kernel);
   }
-  let symbols = symbols;
-
-
-  // 构造 lr0 项目集族, 以及 goto 表
-  let mut goto_table: BTreeMap<(usize, ProductionItem), usize> = BTreeMap::new();
-  let mut j = c.clone();
-  loop {
-    let mut k: BTreeMap<BTreeSet<Item>, usize> = BTreeMap::new();
-    for (closure, id) in j.iter() {
-      for x in symbols.iter() {
-        let n = goto(closure.clone(), *x, &productions);
-        if n.len() > 0 && ! c.contains_key(&n) {
-          k.insert(n, next_closure_id);
-          // 这里可以顺便把 goto 表也完成了
-          goto_table.insert((*id, *x), next_closure_id);
-          next_closure_id += 1;
-        }
-      }
-    }
 
-    let before = c.len();
-    c.extend(k.clone());
-    if c.len() <= before { break; }  
-    j = k;
-  }
-  drop(j);
-  let c: BTreeMap<BTreeSet<Item<'_>>, usize> = c;
-  let goto_table: BTreeMap<(usize, ProductionItem), usize> = goto_table;
-
-  // 初始化扩散关系映射和展望符映射
-  let mut look_ahead_map: BTreeMap<Item, BTreeSet<usize>> = BTreeMap::new();
-  let mut dissemination_map: BTreeMap<Item, BTreeSet<Item>> = BTreeMap::new();
-  for (closure, state) in c.iter() {
-    for item in closure.iter().filter(| item | item.dot != 0 || item.dot == 0 && first_augmented_production_id <= item.production.id && next_production_id > item.production.id ) {
-      let item = Item { dot: item.dot, production: item.production, state: *state };
-      look_ahead_map.insert(item, BTreeSet::new());
-      dissemination_map.insert(item, BTreeSet::new());
+  let mut transitions: BTreeMap<(usize, ProductionItem), usize> = BTreeMap::new();
+  let mut state = 0;
+  while state < kernels.len() {
+    let closure = closure(&kernels[state], &productions);
+
+    // 点后面的所有文法符号
+    let symbols = closure.iter().filter_map(|item| {
+      productions.get(&item.production).unwrap().right.get(item.dot).cloned()
+    }).collect::<BTreeSet<_>>();
+
+    for x in symbols {
+      let kernel = goto(&closure, x, &productions);
+      let next_state = match states.get(&kernel) {
+        Some(next_state) => *next_state,
+        None => {
+          states.insert(kernel.clone(), kernels.len());
+          kernels.push(kernel);
+          kernels.len() - 1
+        },
+      };
+      transitions.insert((state, x), next_state);
     }
+    state += 1;
   }
-  drop(c);
-  drop(symbols);
-  
-  let items = look_ahead_map.keys().cloned().collect::<Vec<_>>();
-  // 求所有非终结符的 first 集合
-  let (first_set, _) = first_set_for_nonterminal_and_production(grammar);
+  drop(states);
+
 
   /**
    * for [state, A -> α·β] in 所有的内核项
@@ -324,79 +156,148 @@ pub fn lalr_table(grammar: &Grammar) -> (BTreeMap<(usize, usize), ActionTableEle
    *     else
    *       dissemination_map[state, A -> α·β].insert([goto(state, X), B->γX·δ])
    */
-  let sharp = grammar.vocabulary.terminals.keys().cloned().max().unwrap_or(0) + 114514;
-  for item in items {
-    let j = closure_with_look_ahead(
-      btreeset! { ItemWithLookAhead { production: item.production, dot: item.dot, look_ahead: sharp } }, &productions, &first_set
-    );
-    for /*[B -> γ·Xδ, a] */ item2 in j {
-      if item2.dot >= item2.production.right.len() { continue; }
-      let y = goto_table.get(&(item.state, item2.production.right[item2.dot])).unwrap();
-      if sharp != item2.look_ahead {
-        look_ahead_map.get_mut(&Item { production: item2.production, dot: item2.dot + 1, state: *y })
-          .unwrap().insert(item2.look_ahead);
-      }
-      else {
-        dissemination_map.get_mut(&item)
-          .unwrap().insert(Item { production: item2.production, dot: item2.dot + 1, state: *y });
+  let (first_set, _) = grammar.first_set();
+  let sharp = Terminal::new("#", usize::MAX);
+  let stop = Terminal::new("_STOP", 1);
+
+  let mut look_ahead_map: BTreeMap<(usize, Item), BTreeSet<Terminal>> = BTreeMap::new();
+  let mut dissemination_map: BTreeMap<(usize, Item), BTreeSet<(usize, Item)>> = BTreeMap::new();
+  for (state, kernel) in kernels.iter().enumerate() {
+    for item in kernel.iter() {
+      look_ahead_map.entry((state, *item)).or_default();
+      dissemination_map.entry((state, *item)).or_default();
+    }
+  }
+
+  for (state, kernel) in kernels.iter().enumerate() {
+    for item in kernel.iter() {
+      let j = closure_with_look_ahead(&BTreeSet::from([(*item, sharp)]), &productions, &first_set);
+      for /*[B -> γ·Xδ, a] */ (item2, a) in j {
+        let production = productions.get(&item2.production).unwrap();
+        if item2.dot >= production.right.len() { continue; }
+
+        let next_state = *transitions.get(&(state, production.right[item2.dot])).unwrap();
+        let next_item = Item { production: item2.production, dot: item2.dot + 1 };
+        if a != sharp {
+          look_ahead_map.get_mut(&(next_state, next_item)).unwrap().insert(a);
+        } else {
+          dissemination_map.get_mut(&(state, *item)).unwrap().insert((next_state, next_item));
+        }
       }
     }
   }
-  drop(first_set);
-
-  let dissemination_map = dissemination_map;
-  
-  // 将 $ 添加到初始状态中
-  dissemination_map.keys().filter(| item | { item.dot == 0 }).for_each(|item| {
-    look_ahead_map.get_mut(item).unwrap().insert(0);
-  });
-
-  let mut modified = false;
-  loop {
-    // 不断向前传播即可
+
+  // 将 $ 添加到初始状态中, 初始状态的编号和增广产生式的顺序一致
+  for production_id in first_augmented_production_id..next_production_id {
+    let item = Item { production: production_id, dot: 0 };
+    let state = production_id - first_augmented_production_id;
+    look_ahead_map.get_mut(&(state, item)).unwrap().insert(stop);
+  }
+
+  // 不断向前传播即可
+  let mut modified = true;
+  while modified {
+    modified = false;
     for (k, v) in dissemination_map.iter() {
       let look_ahead = look_ahead_map.get(k).unwrap().clone();
       for item in v.iter() {
         let t = look_ahead_map.get_mut(item).unwrap();
         let before = t.len();
-        t.extend(&look_ahead);
-        if t.len() > before { modified = true }
+        t.extend(look_ahead.iter().cloned());
+        if t.len() > before { modified = true; }
       }
     }
-
-    if ! modified { break }
   }
-
-  let look_ahead_map = look_ahead_map;
   drop(dissemination_map);
 
-  // 最后, 构造 action 和 goto 表
-  let mut action: BTreeMap<(usize, usize), ActionTableElement> = BTreeMap::new(); 
-  let mut goto_table2: BTreeMap<(usize, usize), usize> = BTreeMap::new();
-  for item in look_ahead_map.keys() {
-    if item.dot >= item.production.right.len() {
-      // 是归约项目
-      for look_ahead in look_ahead_map.get(item).unwrap() {
-        action.insert((item.state, *look_ahead), ActionTableElement::Reduce(item.production.id));
+
+  // 最后, 构造 action 和 goto 表, 需要对带展望符的内核求闭包才能得到空产生式的归约项目
+  // 构造过程中 action 表的每一项都记录下产生该动作的产生式, 用于报告冲突
+  let mut action: BTreeMap<(usize, Terminal), (ActionTableElement, usize)> = BTreeMap::new();
+  let mut conflicts: BTreeSet<GrammarConflict> = BTreeSet::new();
+  let mut goto_table: BTreeMap<(usize, NonTerminal), usize> = BTreeMap::new();
+  for (state, kernel) in kernels.iter().enumerate() {
+    let items = kernel.iter().flat_map(|item| {
+      look_ahead_map.get(&(state, *item)).unwrap().iter().map(|look_ahead| (*item, *look_ahead))
+    }).collect::<BTreeSet<_>>();
+
+    for (item, look_ahead) in closure_with_look_ahead(&items, &productions, &first_set) {
+      let production = productions.get(&item.production).unwrap();
+      if item.dot >= production.right.len() {
+        // 是归约项目
+        let element = if item.production >= first_augmented_production_id {
+          ActionTableElement::Accept
+        } else {
+          ActionTableElement::Reduce(item.production)
+        };
+        insert_action(&mut action, &mut conflicts, &productions, &grammar.precedences, (state, look_ahead), element, item.production);
+        continue;
       }
-    }
-    else {
-      let d = goto_table.get(&(item.state, item.production.right[item.dot])).unwrap();
-      match item.production.right[item.dot] {
-        ProductionItem::NonTerminal(rule_id) => {
-          goto_table2.insert((item.state, rule_id), *d);
+
+      let next_state = *transitions.get(&(state, production.right[item.dot])).unwrap();
+      match production.right[item.dot] {
+        ProductionItem::NonTerminal(nonterminal) => {
+          goto_table.insert((state, nonterminal), next_state);
         },
-        ProductionItem::Terminal(terminal_id) => {
-          action.insert((item.state, terminal_id), ActionTableElement::Shift(*d));
+        ProductionItem::Terminal(terminal) => {
+          insert_action(&mut action, &mut conflicts, &productions, &grammar.precedences, (state, terminal), ActionTableElement::Shift(next_state), item.production);
         },
       }
     }
   }
-  drop(look_ahead_map);
 
-  let action = action;
-  let goto_table = goto_table2;
-  
-  (action, goto_table, nonterminal_to_start_state)
+  let action = action.into_iter().map(|(key, (element, _))| (key, element)).collect();
+  LalrTable { action, goto: goto_table, start_states, conflicts: conflicts.into_iter().collect() }
 }
 
+// 出现冲突时, 移入优先于归约, 编号小的产生式优先归约, 接受动作看作是对增广产生式的归约
+// 如果移入的终结符和归约的产生式都声明了优先级, 则根据优先级和结合性决定, 并且不算作冲突
+fn insert_action<'a>(
+  action: &mut BTreeMap<(usize, Terminal<'a>), (ActionTableElement, usize)>,
+  conflicts: &mut BTreeSet<GrammarConflict<'a>>,
+  productions: &BTreeMap<usize, Production<'a>>,
+  precedences: &BTreeMap<Terminal<'a>, Precedence>,
+  key: (usize, Terminal<'a>), element: ActionTableElement, production_id: usize,
+) {
+  let (old_element, old_production_id) = match action.get(&key) {
+    Some(old) => *old,
+    None => { action.insert(key, (element, production_id)); return; },
+  };
+
+  let (kind, chosen, rejected, replace) = match (old_element, element) {
+    // 移入同一个终结符一定转移到同一个状态, 不算冲突
+    (ActionTableElement::Shift(_), ActionTableElement::Shift(_)) => return,
+    (ActionTableElement::Shift(_), _) => match shift_over_reduce(key.1, production_id, productions, precedences) {
+      Some(shift) => { if !shift { action.insert(key, (element, production_id)); } return; },
+      None => (ConflictKind::ShiftReduce, old_production_id, production_id, false),
+    },
+    (_, ActionTableElement::Shift(_)) => match shift_over_reduce(key.1, old_production_id, productions, precedences) {
+      Some(shift) => { if shift { action.insert(key, (element, production_id)); } return; },
+      None => (ConflictKind::ShiftReduce, production_id, old_production_id, true),
+    },
+    _ if old_production_id == production_id => return,
+    (ActionTableElement::Accept, _) => (ConflictKind::ReduceReduce, old_production_id, production_id, false),
+    (_, ActionTableElement::Accept) => (ConflictKind::ReduceReduce, production_id, old_production_id, true),
+    _ if production_id < old_production_id => (ConflictKind::ReduceReduce, production_id, old_production_id, true),
+    _ => (ConflictKind::ReduceReduce, old_production_id, production_id, false),
+  };
+
+  let nonterminal = productions.get(&rejected).unwrap().left;
+  conflicts.insert(GrammarConflict::new(kind, nonterminal, key.1, chosen, rejected));
+  if replace { action.insert(key, (element, production_id)); }
+}
+
+// 根据优先级和结合性判断是否应该移入, 优先级高的一方胜出, 优先级相同时左结合归约, 右结合移入
+fn shift_over_reduce<'a>(
+  terminal: Terminal<'a>, production_id: usize,
+  productions: &BTreeMap<usize, Production<'a>>,
+  precedences: &BTreeMap<Terminal<'a>, Precedence>,
+) -> Option<bool> {
+  let token = precedences.get(&terminal)?;
+  let rule = production_precedence(productions.get(&production_id)?, productions, precedences)?;
+  if token.level != rule.level {
+    Some(token.level > rule.level)
+  } else {
+    Some(token.associativity == Associativity::Right)
+  }
+}
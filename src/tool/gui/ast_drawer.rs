@@ -10,6 +10,12 @@ pub struct ASTDrawer {
   pub tera: Tera,
 }
 
+impl Default for ASTDrawer {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
 impl ASTDrawer {
   pub fn new() -> Self {
     let mut tera = Tera::default();
@@ -29,20 +35,23 @@ impl ASTDrawer {
     for child in ast.children.iter() {
       match child {
         AstContext::Terminal(ctx) => children += &format!("{{ token_name:`{}`, text: `{}`, token_type: `{}` }}", 
-          ASTDrawer::escape(&ctx.symbol.token_name), ASTDrawer::escape(&ctx.symbol.text), ctx.symbol.token_type),
+          ASTDrawer::escape(ctx.symbol.terminal.name), ASTDrawer::escape(&ctx.symbol.text), ctx.symbol.terminal.id),
         AstContext::Rule(ctx) => children += &ASTDrawer::dump(ctx),
         AstContext::Error(ctx) => {
           use chiru::runtime::ast::error_context::ErrorSymbol::*;
+          // 错误信息中包含位置, 行号和列号从 1 开始
+          let message = ASTDrawer::escape(&format!("{}:{} {}", ctx.location.start.line + 1, ctx.location.start.char_position + 1, ctx.message));
+          let expected = ctx.expected.iter().map(|terminal| format!("`{}`", ASTDrawer::escape(terminal.name))).collect::<Vec<_>>().join(", ");
           match &ctx.symbol {
             Redundant(token) => {
-              children += &format!("{{ token_name: `{}`, text: `{}`, token_type: `{}`, error_type: `redundant` }}", 
-                ASTDrawer::escape(&token.token_name), ASTDrawer::escape(&token.text), token.token_type
+              children += &format!("{{ token_name: `{}`, text: `{}`, token_type: `{}`, error_type: `redundant`, message: `{}`, expected: [{}] }}", 
+                ASTDrawer::escape(token.terminal.name), ASTDrawer::escape(&token.text), token.terminal.id, message, expected
               )
             },
-            Mistake(token) => children += &format!("{{ token_name: `{}`, text: `{}`, token_type: `{}`, error_type: `mistake` }}", 
-              ASTDrawer::escape(&token.token_name), ASTDrawer::escape(&token.text), token.token_type
+            Mistake(token) => children += &format!("{{ token_name: `{}`, text: `{}`, token_type: `{}`, error_type: `mistake`, message: `{}`, expected: [{}] }}", 
+              ASTDrawer::escape(token.terminal.name), ASTDrawer::escape(&token.text), token.terminal.id, message, expected
             ),
-            Missing => children += "{ error_type: `missing` }",
+            Missing => children += &format!("{{ text: `<missing>`, error_type: `missing`, message: `{}`, expected: [{}] }}", message, expected),
           }
         },
       }
@@ -50,7 +59,11 @@ impl ASTDrawer {
       children += ",";
     }
     children += "]";
-    format!("{{ rule_name:`{}`, rule_index: `{}`, children:{},}}", ASTDrawer::escape(&ast.rule_name), ast.rule_index, children)
+    let rule_name = match ast.symbol.name {
+      Some(name) => name.to_owned(),
+      None => ast.symbol.id.to_string(),
+    };
+    format!("{{ rule_name:`{}`, rule_index: `{}`, children:{},}}", ASTDrawer::escape(&rule_name), ast.symbol.id, children)
   }
 
   pub fn draw(&self, ast: &RuleContext, name: &str, file: &mut File) {
@@ -61,7 +74,7 @@ impl ASTDrawer {
 
 
     let result = self.tera.render("ast", &context).unwrap();
-    file.write(result.as_bytes()).unwrap();
+    file.write_all(result.as_bytes()).unwrap();
   }
 
 
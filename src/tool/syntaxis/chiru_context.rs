@@ -2,7 +2,7 @@
 
 
 
-// generated from .\src\tool\syntaxis\chiru.chiru by chiru 0.7.0
+// generated from src/tool/syntaxis/chiru.chiru by chiru 0.7.0
  
 
 
@@ -23,355 +23,401 @@ use super::chiru_listener::ChiruListener;
 
 
 
-pub trait LexerRuleContext: ToRule {
+pub trait AlternativeContext<'a>: ToRule<'a> {
   
+  fn element_list(&self) -> Vec<&dyn ElementContext<'a>>;
 
   
 
   
-  fn regular(&self) -> Option<&dyn RegularContext>;
-  fn annotation(&self) -> Option<&dyn AnnotationContext>;
+  fn alternative_label(&self) -> Option<&dyn AlternativeLabelContext<'a>>;
 
   
-  fn colon(&self) -> Option<&TerminalContext>;
-  fn semi(&self) -> Option<&TerminalContext>;
-  fn token_ref(&self) -> Option<&TerminalContext>;
+  fn epsilon(&self) -> Option<&TerminalContext<'a>>;
 
+  
   fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>>;
   fn enter(&self, listener: &mut dyn ChiruListener);
   fn exit(&self, listener: &mut dyn ChiruListener);
 }
 
-impl LexerRuleContext for RuleContext {
+impl<'a> AlternativeContext<'a> for RuleContext<'a> {
 
   
+  fn element_list(&self) -> Vec<&dyn ElementContext<'a>> {
+    self.get_rule_contexts(ChiruParser::ELEMENT).iter().map(|ctx| *ctx as &dyn ElementContext<'a>).collect::<Vec<_>>()
+  } 
 
   
 
   
-  fn regular(&self) -> Option<&dyn RegularContext> {
-    self.get_rule_context(ChiruParser::REGULAR, 0).map(|ctx| ctx as &dyn RegularContext)
-  } 
-  fn annotation(&self) -> Option<&dyn AnnotationContext> {
-    self.get_rule_context(ChiruParser::ANNOTATION, 0).map(|ctx| ctx as &dyn AnnotationContext)
+  fn alternative_label(&self) -> Option<&dyn AlternativeLabelContext<'a>> {
+    self.get_rule_context(ChiruParser::ALTERNATIVE_LABEL, 0).map(|ctx| ctx as &dyn AlternativeLabelContext<'a>)
   } 
 
   
-  fn colon(&self) -> Option<&TerminalContext> {
-    self.get_terminal(ChiruLexer::COLON, 0)
-  } 
-  fn semi(&self) -> Option<&TerminalContext> {
-    self.get_terminal(ChiruLexer::SEMI, 0)
-  } 
-  fn token_ref(&self) -> Option<&TerminalContext> {
-    self.get_terminal(ChiruLexer::TOKEN_REF, 0)
+  fn epsilon(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::EPSILON, 0)
   } 
 
+  
+
 
+  
   fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>> {
-    visitor.visit_lexer_rule(self)
+    visitor.visit_alternative(self)
   }
-
+  
   fn enter(&self, listener: &mut dyn ChiruListener) {
-    listener.enter_lexer_rule(self)
+    listener.enter_alternative(self)
   }
 
   fn exit(&self, listener: &mut dyn ChiruListener) {
-    listener.exit_lexer_rule(self)
+    listener.exit_alternative(self)
   }
+  
 }
 
-pub trait ElementContext: ToRule {
+pub trait AlternativeLabelContext<'a>: ToRule<'a> {
   
 
   
 
   
-  fn ebnf_suffix(&self) -> Option<&dyn EbnfSuffixContext>;
-  fn block(&self) -> Option<&dyn BlockContext>;
 
   
-  fn rule_ref(&self) -> Option<&TerminalContext>;
-  fn rparen(&self) -> Option<&TerminalContext>;
-  fn token_ref(&self) -> Option<&TerminalContext>;
-  fn string_literal(&self) -> Option<&TerminalContext>;
-  fn lparen(&self) -> Option<&TerminalContext>;
+  fn rule_ref(&self) -> Option<&TerminalContext<'a>>;
+  fn token_ref(&self) -> Option<&TerminalContext<'a>>;
+  fn sharp(&self) -> Option<&TerminalContext<'a>>;
 
+  
   fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>>;
   fn enter(&self, listener: &mut dyn ChiruListener);
   fn exit(&self, listener: &mut dyn ChiruListener);
 }
 
-impl ElementContext for RuleContext {
+impl<'a> AlternativeLabelContext<'a> for RuleContext<'a> {
 
   
 
   
 
   
-  fn ebnf_suffix(&self) -> Option<&dyn EbnfSuffixContext> {
-    self.get_rule_context(ChiruParser::EBNF_SUFFIX, 0).map(|ctx| ctx as &dyn EbnfSuffixContext)
-  } 
-  fn block(&self) -> Option<&dyn BlockContext> {
-    self.get_rule_context(ChiruParser::BLOCK, 0).map(|ctx| ctx as &dyn BlockContext)
-  } 
 
   
-  fn rule_ref(&self) -> Option<&TerminalContext> {
+  fn rule_ref(&self) -> Option<&TerminalContext<'a>> {
     self.get_terminal(ChiruLexer::RULE_REF, 0)
   } 
-  fn rparen(&self) -> Option<&TerminalContext> {
-    self.get_terminal(ChiruLexer::RPAREN, 0)
-  } 
-  fn token_ref(&self) -> Option<&TerminalContext> {
+  fn token_ref(&self) -> Option<&TerminalContext<'a>> {
     self.get_terminal(ChiruLexer::TOKEN_REF, 0)
   } 
-  fn string_literal(&self) -> Option<&TerminalContext> {
-    self.get_terminal(ChiruLexer::STRING_LITERAL, 0)
-  } 
-  fn lparen(&self) -> Option<&TerminalContext> {
-    self.get_terminal(ChiruLexer::LPAREN, 0)
+  fn sharp(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::SHARP, 0)
   } 
 
+  
+
 
+  
   fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>> {
-    visitor.visit_element(self)
+    visitor.visit_alternative_label(self)
   }
-
+  
   fn enter(&self, listener: &mut dyn ChiruListener) {
-    listener.enter_element(self)
+    listener.enter_alternative_label(self)
   }
 
   fn exit(&self, listener: &mut dyn ChiruListener) {
-    listener.exit_element(self)
+    listener.exit_alternative_label(self)
   }
+  
 }
 
-pub trait RulesContext: ToRule {
+pub trait AnnotationContext<'a>: ToRule<'a> {
   
-  fn parser_rule_list(&self) -> Vec<&dyn ParserRuleContext>;
-  fn lexer_rule_list(&self) -> Vec<&dyn LexerRuleContext>;
 
   
 
   
+  fn attributes(&self) -> Option<&dyn AttributesContext<'a>>;
+  fn attribute(&self) -> Option<&dyn AttributeContext<'a>>;
 
   
+  fn at(&self) -> Option<&TerminalContext<'a>>;
+  fn sharp(&self) -> Option<&TerminalContext<'a>>;
+  fn lbracket(&self) -> Option<&TerminalContext<'a>>;
+  fn rbracket(&self) -> Option<&TerminalContext<'a>>;
 
+  
   fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>>;
   fn enter(&self, listener: &mut dyn ChiruListener);
   fn exit(&self, listener: &mut dyn ChiruListener);
 }
 
-impl RulesContext for RuleContext {
+impl<'a> AnnotationContext<'a> for RuleContext<'a> {
+
+  
 
   
-  fn parser_rule_list(&self) -> Vec<&dyn ParserRuleContext> {
-    self.get_rule_contexts(ChiruParser::PARSER_RULE).iter().map(|ctx| *ctx as &dyn ParserRuleContext).collect::<Vec<_>>()
-  } 
-  fn lexer_rule_list(&self) -> Vec<&dyn LexerRuleContext> {
-    self.get_rule_contexts(ChiruParser::LEXER_RULE).iter().map(|ctx| *ctx as &dyn LexerRuleContext).collect::<Vec<_>>()
-  } 
 
   
+  fn attributes(&self) -> Option<&dyn AttributesContext<'a>> {
+    self.get_rule_context(ChiruParser::ATTRIBUTES, 0).map(|ctx| ctx as &dyn AttributesContext<'a>)
+  } 
+  fn attribute(&self) -> Option<&dyn AttributeContext<'a>> {
+    self.get_rule_context(ChiruParser::ATTRIBUTE, 0).map(|ctx| ctx as &dyn AttributeContext<'a>)
+  } 
 
   
+  fn at(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::AT, 0)
+  } 
+  fn sharp(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::SHARP, 0)
+  } 
+  fn lbracket(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::LBRACKET, 0)
+  } 
+  fn rbracket(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::RBRACKET, 0)
+  } 
 
   
 
 
+  
   fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>> {
-    visitor.visit_rules(self)
+    visitor.visit_annotation(self)
   }
-
+  
   fn enter(&self, listener: &mut dyn ChiruListener) {
-    listener.enter_rules(self)
+    listener.enter_annotation(self)
   }
 
   fn exit(&self, listener: &mut dyn ChiruListener) {
-    listener.exit_rules(self)
+    listener.exit_annotation(self)
   }
+  
 }
 
-pub trait EbnfSuffixContext: ToRule {
+pub trait AttributeContext<'a>: ToRule<'a> {
   
 
   
-  fn question_list(&self) -> Vec<&TerminalContext>;
+  fn token_ref_list(&self) -> Vec<&TerminalContext<'a>>;
+  fn comma_list(&self) -> Vec<&TerminalContext<'a>>;
+  fn string_literal_list(&self) -> Vec<&TerminalContext<'a>>;
 
   
 
   
-  fn star(&self) -> Option<&TerminalContext>;
-  fn plus(&self) -> Option<&TerminalContext>;
+  fn mode(&self) -> Option<&TerminalContext<'a>>;
+  fn rule_ref(&self) -> Option<&TerminalContext<'a>>;
+  fn lparen(&self) -> Option<&TerminalContext<'a>>;
+  fn rparen(&self) -> Option<&TerminalContext<'a>>;
 
+  
   fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>>;
   fn enter(&self, listener: &mut dyn ChiruListener);
   fn exit(&self, listener: &mut dyn ChiruListener);
 }
 
-impl EbnfSuffixContext for RuleContext {
+impl<'a> AttributeContext<'a> for RuleContext<'a> {
 
   
 
   
-  fn question_list(&self) -> Vec<&TerminalContext> {
-    self.get_terminals(ChiruLexer::QUESTION)
+  fn token_ref_list(&self) -> Vec<&TerminalContext<'a>> {
+    self.get_terminals(ChiruLexer::TOKEN_REF)
+  } 
+  fn comma_list(&self) -> Vec<&TerminalContext<'a>> {
+    self.get_terminals(ChiruLexer::COMMA)
+  } 
+  fn string_literal_list(&self) -> Vec<&TerminalContext<'a>> {
+    self.get_terminals(ChiruLexer::STRING_LITERAL)
   } 
 
   
 
   
-  fn star(&self) -> Option<&TerminalContext> {
-    self.get_terminal(ChiruLexer::STAR, 0)
+  fn mode(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::MODE, 0)
   } 
-  fn plus(&self) -> Option<&TerminalContext> {
-    self.get_terminal(ChiruLexer::PLUS, 0)
+  fn rule_ref(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::RULE_REF, 0)
+  } 
+  fn lparen(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::LPAREN, 0)
+  } 
+  fn rparen(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::RPAREN, 0)
   } 
 
+  
+
 
+  
   fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>> {
-    visitor.visit_ebnf_suffix(self)
+    visitor.visit_attribute(self)
   }
-
+  
   fn enter(&self, listener: &mut dyn ChiruListener) {
-    listener.enter_ebnf_suffix(self)
+    listener.enter_attribute(self)
   }
 
   fn exit(&self, listener: &mut dyn ChiruListener) {
-    listener.exit_ebnf_suffix(self)
+    listener.exit_attribute(self)
   }
+  
 }
 
-pub trait AlternativeContext: ToRule {
+pub trait AttributesContext<'a>: ToRule<'a> {
   
-  fn element_list(&self) -> Vec<&dyn ElementContext>;
+  fn attribute_list(&self) -> Vec<&dyn AttributeContext<'a>>;
 
   
+  fn comma_list(&self) -> Vec<&TerminalContext<'a>>;
 
   
-  fn epsilon(&self) -> Option<&dyn EpsilonContext>;
 
   
 
+  
   fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>>;
   fn enter(&self, listener: &mut dyn ChiruListener);
   fn exit(&self, listener: &mut dyn ChiruListener);
 }
 
-impl AlternativeContext for RuleContext {
+impl<'a> AttributesContext<'a> for RuleContext<'a> {
 
   
-  fn element_list(&self) -> Vec<&dyn ElementContext> {
-    self.get_rule_contexts(ChiruParser::ELEMENT).iter().map(|ctx| *ctx as &dyn ElementContext).collect::<Vec<_>>()
+  fn attribute_list(&self) -> Vec<&dyn AttributeContext<'a>> {
+    self.get_rule_contexts(ChiruParser::ATTRIBUTE).iter().map(|ctx| *ctx as &dyn AttributeContext<'a>).collect::<Vec<_>>()
+  } 
+
+  
+  fn comma_list(&self) -> Vec<&TerminalContext<'a>> {
+    self.get_terminals(ChiruLexer::COMMA)
   } 
 
   
 
   
-  fn epsilon(&self) -> Option<&dyn EpsilonContext> {
-    self.get_rule_context(ChiruParser::EPSILON, 0).map(|ctx| ctx as &dyn EpsilonContext)
-  } 
 
   
 
 
+  
   fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>> {
-    visitor.visit_alternative(self)
+    visitor.visit_attributes(self)
   }
-
+  
   fn enter(&self, listener: &mut dyn ChiruListener) {
-    listener.enter_alternative(self)
+    listener.enter_attributes(self)
   }
 
   fn exit(&self, listener: &mut dyn ChiruListener) {
-    listener.exit_alternative(self)
+    listener.exit_attributes(self)
   }
+  
 }
 
-pub trait AttributesContext: ToRule {
+pub trait BlockContext<'a>: ToRule<'a> {
   
-  fn attribute_list(&self) -> Vec<&dyn AttributeContext>;
+  fn alternative_list(&self) -> Vec<&dyn AlternativeContext<'a>>;
 
   
-  fn comma_list(&self) -> Vec<&TerminalContext>;
+  fn or_list(&self) -> Vec<&TerminalContext<'a>>;
 
   
 
   
 
+  
   fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>>;
   fn enter(&self, listener: &mut dyn ChiruListener);
   fn exit(&self, listener: &mut dyn ChiruListener);
 }
 
-impl AttributesContext for RuleContext {
+impl<'a> BlockContext<'a> for RuleContext<'a> {
 
   
-  fn attribute_list(&self) -> Vec<&dyn AttributeContext> {
-    self.get_rule_contexts(ChiruParser::ATTRIBUTE).iter().map(|ctx| *ctx as &dyn AttributeContext).collect::<Vec<_>>()
+  fn alternative_list(&self) -> Vec<&dyn AlternativeContext<'a>> {
+    self.get_rule_contexts(ChiruParser::ALTERNATIVE).iter().map(|ctx| *ctx as &dyn AlternativeContext<'a>).collect::<Vec<_>>()
   } 
 
   
-  fn comma_list(&self) -> Vec<&TerminalContext> {
-    self.get_terminals(ChiruLexer::COMMA)
+  fn or_list(&self) -> Vec<&TerminalContext<'a>> {
+    self.get_terminals(ChiruLexer::OR)
   } 
 
   
 
   
 
+  
+
 
+  
   fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>> {
-    visitor.visit_attributes(self)
+    visitor.visit_block(self)
   }
-
+  
   fn enter(&self, listener: &mut dyn ChiruListener) {
-    listener.enter_attributes(self)
+    listener.enter_block(self)
   }
 
   fn exit(&self, listener: &mut dyn ChiruListener) {
-    listener.exit_attributes(self)
+    listener.exit_block(self)
   }
+  
 }
 
-pub trait CompilationUnitContext: ToRule {
+pub trait CompilationUnitContext<'a>: ToRule<'a> {
   
+  fn import_rule_list(&self) -> Vec<&dyn ImportRuleContext<'a>>;
 
   
 
   
-  fn grammar_name(&self) -> Option<&dyn GrammarNameContext>;
-  fn rules(&self) -> Option<&dyn RulesContext>;
+  fn grammar_name(&self) -> Option<&dyn GrammarNameContext<'a>>;
+  fn rules(&self) -> Option<&dyn RulesContext<'a>>;
 
   
 
+  
   fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>>;
   fn enter(&self, listener: &mut dyn ChiruListener);
   fn exit(&self, listener: &mut dyn ChiruListener);
 }
 
-impl CompilationUnitContext for RuleContext {
+impl<'a> CompilationUnitContext<'a> for RuleContext<'a> {
 
   
+  fn import_rule_list(&self) -> Vec<&dyn ImportRuleContext<'a>> {
+    self.get_rule_contexts(ChiruParser::IMPORT_RULE).iter().map(|ctx| *ctx as &dyn ImportRuleContext<'a>).collect::<Vec<_>>()
+  } 
 
   
 
   
-  fn grammar_name(&self) -> Option<&dyn GrammarNameContext> {
-    self.get_rule_context(ChiruParser::GRAMMAR_NAME, 0).map(|ctx| ctx as &dyn GrammarNameContext)
+  fn grammar_name(&self) -> Option<&dyn GrammarNameContext<'a>> {
+    self.get_rule_context(ChiruParser::GRAMMAR_NAME, 0).map(|ctx| ctx as &dyn GrammarNameContext<'a>)
   } 
-  fn rules(&self) -> Option<&dyn RulesContext> {
-    self.get_rule_context(ChiruParser::RULES, 0).map(|ctx| ctx as &dyn RulesContext)
+  fn rules(&self) -> Option<&dyn RulesContext<'a>> {
+    self.get_rule_context(ChiruParser::RULES, 0).map(|ctx| ctx as &dyn RulesContext<'a>)
   } 
 
   
 
+  
+
 
+  
   fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>> {
     visitor.visit_compilation_unit(self)
   }
-
+  
   fn enter(&self, listener: &mut dyn ChiruListener) {
     listener.enter_compilation_unit(self)
   }
@@ -379,163 +425,199 @@ impl CompilationUnitContext for RuleContext {
   fn exit(&self, listener: &mut dyn ChiruListener) {
     listener.exit_compilation_unit(self)
   }
+  
 }
 
-pub trait AttributeContext: ToRule {
+pub trait EbnfSuffixContext<'a>: ToRule<'a> {
   
 
   
+  fn question_list(&self) -> Vec<&TerminalContext<'a>>;
 
   
 
   
-  fn rparen(&self) -> Option<&TerminalContext>;
-  fn token_ref(&self) -> Option<&TerminalContext>;
-  fn lparen(&self) -> Option<&TerminalContext>;
-  fn rule_ref(&self) -> Option<&TerminalContext>;
+  fn star(&self) -> Option<&TerminalContext<'a>>;
+  fn plus(&self) -> Option<&TerminalContext<'a>>;
 
+  
   fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>>;
   fn enter(&self, listener: &mut dyn ChiruListener);
   fn exit(&self, listener: &mut dyn ChiruListener);
 }
 
-impl AttributeContext for RuleContext {
+impl<'a> EbnfSuffixContext<'a> for RuleContext<'a> {
 
   
 
   
+  fn question_list(&self) -> Vec<&TerminalContext<'a>> {
+    self.get_terminals(ChiruLexer::QUESTION)
+  } 
 
   
 
   
-  fn rparen(&self) -> Option<&TerminalContext> {
-    self.get_terminal(ChiruLexer::RPAREN, 0)
-  } 
-  fn token_ref(&self) -> Option<&TerminalContext> {
-    self.get_terminal(ChiruLexer::TOKEN_REF, 0)
-  } 
-  fn lparen(&self) -> Option<&TerminalContext> {
-    self.get_terminal(ChiruLexer::LPAREN, 0)
+  fn star(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::STAR, 0)
   } 
-  fn rule_ref(&self) -> Option<&TerminalContext> {
-    self.get_terminal(ChiruLexer::RULE_REF, 0)
+  fn plus(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::PLUS, 0)
   } 
 
+  
+
 
+  
   fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>> {
-    visitor.visit_attribute(self)
+    visitor.visit_ebnf_suffix(self)
   }
-
+  
   fn enter(&self, listener: &mut dyn ChiruListener) {
-    listener.enter_attribute(self)
+    listener.enter_ebnf_suffix(self)
   }
 
   fn exit(&self, listener: &mut dyn ChiruListener) {
-    listener.exit_attribute(self)
+    listener.exit_ebnf_suffix(self)
   }
+  
 }
 
-pub trait ParserRuleContext: ToRule {
+pub trait ElementContext<'a>: ToRule<'a> {
   
 
   
 
   
-  fn block(&self) -> Option<&dyn BlockContext>;
+  fn block(&self) -> Option<&dyn BlockContext<'a>>;
+  fn element_label(&self) -> Option<&dyn ElementLabelContext<'a>>;
+  fn ebnf_suffix(&self) -> Option<&dyn EbnfSuffixContext<'a>>;
 
   
-  fn rule_ref(&self) -> Option<&TerminalContext>;
-  fn colon(&self) -> Option<&TerminalContext>;
-  fn semi(&self) -> Option<&TerminalContext>;
+  fn rule_ref(&self) -> Option<&TerminalContext<'a>>;
+  fn token_ref(&self) -> Option<&TerminalContext<'a>>;
+  fn lparen(&self) -> Option<&TerminalContext<'a>>;
+  fn rparen(&self) -> Option<&TerminalContext<'a>>;
+  fn string_literal(&self) -> Option<&TerminalContext<'a>>;
 
+  
   fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>>;
   fn enter(&self, listener: &mut dyn ChiruListener);
   fn exit(&self, listener: &mut dyn ChiruListener);
 }
 
-impl ParserRuleContext for RuleContext {
+impl<'a> ElementContext<'a> for RuleContext<'a> {
 
   
 
   
 
   
-  fn block(&self) -> Option<&dyn BlockContext> {
-    self.get_rule_context(ChiruParser::BLOCK, 0).map(|ctx| ctx as &dyn BlockContext)
+  fn block(&self) -> Option<&dyn BlockContext<'a>> {
+    self.get_rule_context(ChiruParser::BLOCK, 0).map(|ctx| ctx as &dyn BlockContext<'a>)
+  } 
+  fn element_label(&self) -> Option<&dyn ElementLabelContext<'a>> {
+    self.get_rule_context(ChiruParser::ELEMENT_LABEL, 0).map(|ctx| ctx as &dyn ElementLabelContext<'a>)
+  } 
+  fn ebnf_suffix(&self) -> Option<&dyn EbnfSuffixContext<'a>> {
+    self.get_rule_context(ChiruParser::EBNF_SUFFIX, 0).map(|ctx| ctx as &dyn EbnfSuffixContext<'a>)
   } 
 
   
-  fn rule_ref(&self) -> Option<&TerminalContext> {
+  fn rule_ref(&self) -> Option<&TerminalContext<'a>> {
     self.get_terminal(ChiruLexer::RULE_REF, 0)
   } 
-  fn colon(&self) -> Option<&TerminalContext> {
-    self.get_terminal(ChiruLexer::COLON, 0)
+  fn token_ref(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::TOKEN_REF, 0)
   } 
-  fn semi(&self) -> Option<&TerminalContext> {
-    self.get_terminal(ChiruLexer::SEMI, 0)
+  fn lparen(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::LPAREN, 0)
+  } 
+  fn rparen(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::RPAREN, 0)
+  } 
+  fn string_literal(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::STRING_LITERAL, 0)
   } 
 
+  
+
 
+  
   fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>> {
-    visitor.visit_parser_rule(self)
+    visitor.visit_element(self)
   }
-
+  
   fn enter(&self, listener: &mut dyn ChiruListener) {
-    listener.enter_parser_rule(self)
+    listener.enter_element(self)
   }
 
   fn exit(&self, listener: &mut dyn ChiruListener) {
-    listener.exit_parser_rule(self)
+    listener.exit_element(self)
   }
+  
 }
 
-pub trait BlockContext: ToRule {
+pub trait ElementLabelContext<'a>: ToRule<'a> {
   
-  fn alternative_list(&self) -> Vec<&dyn AlternativeContext>;
 
   
-  fn or_list(&self) -> Vec<&TerminalContext>;
 
   
 
   
+  fn rule_ref(&self) -> Option<&TerminalContext<'a>>;
+  fn token_ref(&self) -> Option<&TerminalContext<'a>>;
+  fn assign(&self) -> Option<&TerminalContext<'a>>;
+  fn plus_assign(&self) -> Option<&TerminalContext<'a>>;
 
+  
   fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>>;
   fn enter(&self, listener: &mut dyn ChiruListener);
   fn exit(&self, listener: &mut dyn ChiruListener);
 }
 
-impl BlockContext for RuleContext {
+impl<'a> ElementLabelContext<'a> for RuleContext<'a> {
+
+  
 
   
-  fn alternative_list(&self) -> Vec<&dyn AlternativeContext> {
-    self.get_rule_contexts(ChiruParser::ALTERNATIVE).iter().map(|ctx| *ctx as &dyn AlternativeContext).collect::<Vec<_>>()
-  } 
 
   
-  fn or_list(&self) -> Vec<&TerminalContext> {
-    self.get_terminals(ChiruLexer::OR)
-  } 
 
   
+  fn rule_ref(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::RULE_REF, 0)
+  } 
+  fn token_ref(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::TOKEN_REF, 0)
+  } 
+  fn assign(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::ASSIGN, 0)
+  } 
+  fn plus_assign(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::PLUS_ASSIGN, 0)
+  } 
 
   
 
 
+  
   fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>> {
-    visitor.visit_block(self)
+    visitor.visit_element_label(self)
   }
-
+  
   fn enter(&self, listener: &mut dyn ChiruListener) {
-    listener.enter_block(self)
+    listener.enter_element_label(self)
   }
 
   fn exit(&self, listener: &mut dyn ChiruListener) {
-    listener.exit_block(self)
+    listener.exit_element_label(self)
   }
+  
 }
 
-pub trait GrammarNameContext: ToRule {
+pub trait GrammarNameContext<'a>: ToRule<'a> {
   
 
   
@@ -543,17 +625,20 @@ pub trait GrammarNameContext: ToRule {
   
 
   
-  fn grammar(&self) -> Option<&TerminalContext>;
-  fn rule_ref(&self) -> Option<&TerminalContext>;
-  fn token_ref(&self) -> Option<&TerminalContext>;
-  fn semi(&self) -> Option<&TerminalContext>;
+  fn grammar(&self) -> Option<&TerminalContext<'a>>;
+  fn lexer(&self) -> Option<&TerminalContext<'a>>;
+  fn parser(&self) -> Option<&TerminalContext<'a>>;
+  fn rule_ref(&self) -> Option<&TerminalContext<'a>>;
+  fn token_ref(&self) -> Option<&TerminalContext<'a>>;
+  fn semi(&self) -> Option<&TerminalContext<'a>>;
 
+  
   fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>>;
   fn enter(&self, listener: &mut dyn ChiruListener);
   fn exit(&self, listener: &mut dyn ChiruListener);
 }
 
-impl GrammarNameContext for RuleContext {
+impl<'a> GrammarNameContext<'a> for RuleContext<'a> {
 
   
 
@@ -562,24 +647,33 @@ impl GrammarNameContext for RuleContext {
   
 
   
-  fn grammar(&self) -> Option<&TerminalContext> {
+  fn grammar(&self) -> Option<&TerminalContext<'a>> {
     self.get_terminal(ChiruLexer::GRAMMAR, 0)
   } 
-  fn rule_ref(&self) -> Option<&TerminalContext> {
+  fn lexer(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::LEXER, 0)
+  } 
+  fn parser(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::PARSER, 0)
+  } 
+  fn rule_ref(&self) -> Option<&TerminalContext<'a>> {
     self.get_terminal(ChiruLexer::RULE_REF, 0)
   } 
-  fn token_ref(&self) -> Option<&TerminalContext> {
+  fn token_ref(&self) -> Option<&TerminalContext<'a>> {
     self.get_terminal(ChiruLexer::TOKEN_REF, 0)
   } 
-  fn semi(&self) -> Option<&TerminalContext> {
+  fn semi(&self) -> Option<&TerminalContext<'a>> {
     self.get_terminal(ChiruLexer::SEMI, 0)
   } 
 
+  
+
 
+  
   fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>> {
     visitor.visit_grammar_name(self)
   }
-
+  
   fn enter(&self, listener: &mut dyn ChiruListener) {
     listener.enter_grammar_name(self)
   }
@@ -587,152 +681,589 @@ impl GrammarNameContext for RuleContext {
   fn exit(&self, listener: &mut dyn ChiruListener) {
     listener.exit_grammar_name(self)
   }
+  
 }
 
-pub trait RegularContext: ToRule {
+pub trait ImportRuleContext<'a>: ToRule<'a> {
   
 
   
+  fn rule_ref_list(&self) -> Vec<&TerminalContext<'a>>;
+  fn token_ref_list(&self) -> Vec<&TerminalContext<'a>>;
+  fn comma_list(&self) -> Vec<&TerminalContext<'a>>;
 
   
 
   
-  fn regular_literal(&self) -> Option<&TerminalContext>;
+  fn import(&self) -> Option<&TerminalContext<'a>>;
+  fn semi(&self) -> Option<&TerminalContext<'a>>;
 
+  
   fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>>;
   fn enter(&self, listener: &mut dyn ChiruListener);
   fn exit(&self, listener: &mut dyn ChiruListener);
 }
 
-impl RegularContext for RuleContext {
+impl<'a> ImportRuleContext<'a> for RuleContext<'a> {
 
   
 
   
+  fn rule_ref_list(&self) -> Vec<&TerminalContext<'a>> {
+    self.get_terminals(ChiruLexer::RULE_REF)
+  } 
+  fn token_ref_list(&self) -> Vec<&TerminalContext<'a>> {
+    self.get_terminals(ChiruLexer::TOKEN_REF)
+  } 
+  fn comma_list(&self) -> Vec<&TerminalContext<'a>> {
+    self.get_terminals(ChiruLexer::COMMA)
+  } 
 
   
 
   
-  fn regular_literal(&self) -> Option<&TerminalContext> {
-    self.get_terminal(ChiruLexer::REGULAR_LITERAL, 0)
+  fn import(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::IMPORT, 0)
+  } 
+  fn semi(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::SEMI, 0)
   } 
 
+  
+
 
+  
   fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>> {
-    visitor.visit_regular(self)
+    visitor.visit_import_rule(self)
   }
-
+  
   fn enter(&self, listener: &mut dyn ChiruListener) {
-    listener.enter_regular(self)
+    listener.enter_import_rule(self)
   }
 
   fn exit(&self, listener: &mut dyn ChiruListener) {
-    listener.exit_regular(self)
+    listener.exit_import_rule(self)
   }
+  
 }
 
-pub trait AnnotationContext: ToRule {
+pub trait LexerAlternativeContext<'a>: ToRule<'a> {
   
+  fn lexer_element_list(&self) -> Vec<&dyn LexerElementContext<'a>>;
 
   
 
   
-  fn attribute(&self) -> Option<&dyn AttributeContext>;
-  fn attributes(&self) -> Option<&dyn AttributesContext>;
 
   
-  fn rbracket(&self) -> Option<&TerminalContext>;
-  fn lbracket(&self) -> Option<&TerminalContext>;
-  fn sharp(&self) -> Option<&TerminalContext>;
-  fn at(&self) -> Option<&TerminalContext>;
 
+  
   fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>>;
   fn enter(&self, listener: &mut dyn ChiruListener);
   fn exit(&self, listener: &mut dyn ChiruListener);
 }
 
-impl AnnotationContext for RuleContext {
+impl<'a> LexerAlternativeContext<'a> for RuleContext<'a> {
+
+  
+  fn lexer_element_list(&self) -> Vec<&dyn LexerElementContext<'a>> {
+    self.get_rule_contexts(ChiruParser::LEXER_ELEMENT).iter().map(|ctx| *ctx as &dyn LexerElementContext<'a>).collect::<Vec<_>>()
+  } 
 
   
 
   
 
   
-  fn attribute(&self) -> Option<&dyn AttributeContext> {
-    self.get_rule_context(ChiruParser::ATTRIBUTE, 0).map(|ctx| ctx as &dyn AttributeContext)
-  } 
-  fn attributes(&self) -> Option<&dyn AttributesContext> {
-    self.get_rule_context(ChiruParser::ATTRIBUTES, 0).map(|ctx| ctx as &dyn AttributesContext)
-  } 
 
   
-  fn rbracket(&self) -> Option<&TerminalContext> {
-    self.get_terminal(ChiruLexer::RBRACKET, 0)
-  } 
-  fn lbracket(&self) -> Option<&TerminalContext> {
-    self.get_terminal(ChiruLexer::LBRACKET, 0)
-  } 
-  fn sharp(&self) -> Option<&TerminalContext> {
-    self.get_terminal(ChiruLexer::SHARP, 0)
-  } 
-  fn at(&self) -> Option<&TerminalContext> {
-    self.get_terminal(ChiruLexer::AT, 0)
-  } 
 
 
+  
   fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>> {
-    visitor.visit_annotation(self)
+    visitor.visit_lexer_alternative(self)
   }
-
+  
   fn enter(&self, listener: &mut dyn ChiruListener) {
-    listener.enter_annotation(self)
+    listener.enter_lexer_alternative(self)
   }
 
   fn exit(&self, listener: &mut dyn ChiruListener) {
-    listener.exit_annotation(self)
+    listener.exit_lexer_alternative(self)
   }
+  
 }
 
-pub trait EpsilonContext: ToRule {
+pub trait LexerBlockContext<'a>: ToRule<'a> {
   
+  fn lexer_alternative_list(&self) -> Vec<&dyn LexerAlternativeContext<'a>>;
 
   
+  fn or_list(&self) -> Vec<&TerminalContext<'a>>;
 
   
 
   
-  fn epsilon(&self) -> Option<&TerminalContext>;
 
+  
   fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>>;
   fn enter(&self, listener: &mut dyn ChiruListener);
   fn exit(&self, listener: &mut dyn ChiruListener);
 }
 
-impl EpsilonContext for RuleContext {
+impl<'a> LexerBlockContext<'a> for RuleContext<'a> {
 
   
+  fn lexer_alternative_list(&self) -> Vec<&dyn LexerAlternativeContext<'a>> {
+    self.get_rule_contexts(ChiruParser::LEXER_ALTERNATIVE).iter().map(|ctx| *ctx as &dyn LexerAlternativeContext<'a>).collect::<Vec<_>>()
+  } 
+
+  
+  fn or_list(&self) -> Vec<&TerminalContext<'a>> {
+    self.get_terminals(ChiruLexer::OR)
+  } 
 
   
 
   
 
   
-  fn epsilon(&self) -> Option<&TerminalContext> {
-    self.get_terminal(ChiruLexer::EPSILON, 0)
-  } 
 
 
+  
   fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>> {
-    visitor.visit_epsilon(self)
+    visitor.visit_lexer_block(self)
   }
-
+  
   fn enter(&self, listener: &mut dyn ChiruListener) {
-    listener.enter_epsilon(self)
+    listener.enter_lexer_block(self)
   }
 
   fn exit(&self, listener: &mut dyn ChiruListener) {
-    listener.exit_epsilon(self)
+    listener.exit_lexer_block(self)
   }
+  
+}
+
+pub trait LexerElementContext<'a>: ToRule<'a> {
+  
+
+  
+
+  
+  fn ebnf_suffix(&self) -> Option<&dyn EbnfSuffixContext<'a>>;
+  fn lexer_block(&self) -> Option<&dyn LexerBlockContext<'a>>;
+  fn regular(&self) -> Option<&dyn RegularContext<'a>>;
+
+  
+  fn token_ref(&self) -> Option<&TerminalContext<'a>>;
+  fn lparen(&self) -> Option<&TerminalContext<'a>>;
+  fn rparen(&self) -> Option<&TerminalContext<'a>>;
+  fn string_literal(&self) -> Option<&TerminalContext<'a>>;
+
+  
+  fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>>;
+  fn enter(&self, listener: &mut dyn ChiruListener);
+  fn exit(&self, listener: &mut dyn ChiruListener);
+}
+
+impl<'a> LexerElementContext<'a> for RuleContext<'a> {
+
+  
+
+  
+
+  
+  fn ebnf_suffix(&self) -> Option<&dyn EbnfSuffixContext<'a>> {
+    self.get_rule_context(ChiruParser::EBNF_SUFFIX, 0).map(|ctx| ctx as &dyn EbnfSuffixContext<'a>)
+  } 
+  fn lexer_block(&self) -> Option<&dyn LexerBlockContext<'a>> {
+    self.get_rule_context(ChiruParser::LEXER_BLOCK, 0).map(|ctx| ctx as &dyn LexerBlockContext<'a>)
+  } 
+  fn regular(&self) -> Option<&dyn RegularContext<'a>> {
+    self.get_rule_context(ChiruParser::REGULAR, 0).map(|ctx| ctx as &dyn RegularContext<'a>)
+  } 
+
+  
+  fn token_ref(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::TOKEN_REF, 0)
+  } 
+  fn lparen(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::LPAREN, 0)
+  } 
+  fn rparen(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::RPAREN, 0)
+  } 
+  fn string_literal(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::STRING_LITERAL, 0)
+  } 
+
+  
+
+
+  
+  fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>> {
+    visitor.visit_lexer_element(self)
+  }
+  
+  fn enter(&self, listener: &mut dyn ChiruListener) {
+    listener.enter_lexer_element(self)
+  }
+
+  fn exit(&self, listener: &mut dyn ChiruListener) {
+    listener.exit_lexer_element(self)
+  }
+  
+}
+
+pub trait LexerRuleContext<'a>: ToRule<'a> {
+  
+
+  
+
+  
+  fn lexer_block(&self) -> Option<&dyn LexerBlockContext<'a>>;
+  fn annotation(&self) -> Option<&dyn AnnotationContext<'a>>;
+
+  
+  fn fragment(&self) -> Option<&TerminalContext<'a>>;
+  fn token_ref(&self) -> Option<&TerminalContext<'a>>;
+  fn colon(&self) -> Option<&TerminalContext<'a>>;
+  fn assign(&self) -> Option<&TerminalContext<'a>>;
+  fn semi(&self) -> Option<&TerminalContext<'a>>;
+
+  
+  fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>>;
+  fn enter(&self, listener: &mut dyn ChiruListener);
+  fn exit(&self, listener: &mut dyn ChiruListener);
+}
+
+impl<'a> LexerRuleContext<'a> for RuleContext<'a> {
+
+  
+
+  
+
+  
+  fn lexer_block(&self) -> Option<&dyn LexerBlockContext<'a>> {
+    self.get_rule_context(ChiruParser::LEXER_BLOCK, 0).map(|ctx| ctx as &dyn LexerBlockContext<'a>)
+  } 
+  fn annotation(&self) -> Option<&dyn AnnotationContext<'a>> {
+    self.get_rule_context(ChiruParser::ANNOTATION, 0).map(|ctx| ctx as &dyn AnnotationContext<'a>)
+  } 
+
+  
+  fn fragment(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::FRAGMENT, 0)
+  } 
+  fn token_ref(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::TOKEN_REF, 0)
+  } 
+  fn colon(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::COLON, 0)
+  } 
+  fn assign(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::ASSIGN, 0)
+  } 
+  fn semi(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::SEMI, 0)
+  } 
+
+  
+
+
+  
+  fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>> {
+    visitor.visit_lexer_rule(self)
+  }
+  
+  fn enter(&self, listener: &mut dyn ChiruListener) {
+    listener.enter_lexer_rule(self)
+  }
+
+  fn exit(&self, listener: &mut dyn ChiruListener) {
+    listener.exit_lexer_rule(self)
+  }
+  
+}
+
+pub trait ModeRuleContext<'a>: ToRule<'a> {
+  
+
+  
+
+  
+
+  
+  fn mode(&self) -> Option<&TerminalContext<'a>>;
+  fn token_ref(&self) -> Option<&TerminalContext<'a>>;
+  fn semi(&self) -> Option<&TerminalContext<'a>>;
+
+  
+  fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>>;
+  fn enter(&self, listener: &mut dyn ChiruListener);
+  fn exit(&self, listener: &mut dyn ChiruListener);
+}
+
+impl<'a> ModeRuleContext<'a> for RuleContext<'a> {
+
+  
+
+  
+
+  
+
+  
+  fn mode(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::MODE, 0)
+  } 
+  fn token_ref(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::TOKEN_REF, 0)
+  } 
+  fn semi(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::SEMI, 0)
+  } 
+
+  
+
+
+  
+  fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>> {
+    visitor.visit_mode_rule(self)
+  }
+  
+  fn enter(&self, listener: &mut dyn ChiruListener) {
+    listener.enter_mode_rule(self)
+  }
+
+  fn exit(&self, listener: &mut dyn ChiruListener) {
+    listener.exit_mode_rule(self)
+  }
+  
+}
+
+pub trait ParserRuleContext<'a>: ToRule<'a> {
+  
+
+  
+
+  
+  fn block(&self) -> Option<&dyn BlockContext<'a>>;
+
+  
+  fn rule_ref(&self) -> Option<&TerminalContext<'a>>;
+  fn colon(&self) -> Option<&TerminalContext<'a>>;
+  fn assign(&self) -> Option<&TerminalContext<'a>>;
+  fn semi(&self) -> Option<&TerminalContext<'a>>;
+
+  
+  fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>>;
+  fn enter(&self, listener: &mut dyn ChiruListener);
+  fn exit(&self, listener: &mut dyn ChiruListener);
+}
+
+impl<'a> ParserRuleContext<'a> for RuleContext<'a> {
+
+  
+
+  
+
+  
+  fn block(&self) -> Option<&dyn BlockContext<'a>> {
+    self.get_rule_context(ChiruParser::BLOCK, 0).map(|ctx| ctx as &dyn BlockContext<'a>)
+  } 
+
+  
+  fn rule_ref(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::RULE_REF, 0)
+  } 
+  fn colon(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::COLON, 0)
+  } 
+  fn assign(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::ASSIGN, 0)
+  } 
+  fn semi(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::SEMI, 0)
+  } 
+
+  
+
+
+  
+  fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>> {
+    visitor.visit_parser_rule(self)
+  }
+  
+  fn enter(&self, listener: &mut dyn ChiruListener) {
+    listener.enter_parser_rule(self)
+  }
+
+  fn exit(&self, listener: &mut dyn ChiruListener) {
+    listener.exit_parser_rule(self)
+  }
+  
+}
+
+pub trait PrecedenceRuleContext<'a>: ToRule<'a> {
+  
+
+  
+
+  
+  fn attribute(&self) -> Option<&dyn AttributeContext<'a>>;
+
+  
+  fn semi(&self) -> Option<&TerminalContext<'a>>;
+  fn at(&self) -> Option<&TerminalContext<'a>>;
+
+  
+  fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>>;
+  fn enter(&self, listener: &mut dyn ChiruListener);
+  fn exit(&self, listener: &mut dyn ChiruListener);
+}
+
+impl<'a> PrecedenceRuleContext<'a> for RuleContext<'a> {
+
+  
+
+  
+
+  
+  fn attribute(&self) -> Option<&dyn AttributeContext<'a>> {
+    self.get_rule_context(ChiruParser::ATTRIBUTE, 0).map(|ctx| ctx as &dyn AttributeContext<'a>)
+  } 
+
+  
+  fn semi(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::SEMI, 0)
+  } 
+  fn at(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::AT, 0)
+  } 
+
+  
+
+
+  
+  fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>> {
+    visitor.visit_precedence_rule(self)
+  }
+  
+  fn enter(&self, listener: &mut dyn ChiruListener) {
+    listener.enter_precedence_rule(self)
+  }
+
+  fn exit(&self, listener: &mut dyn ChiruListener) {
+    listener.exit_precedence_rule(self)
+  }
+  
+}
+
+pub trait RegularContext<'a>: ToRule<'a> {
+  
+
+  
+
+  
+
+  
+  fn regular_literal(&self) -> Option<&TerminalContext<'a>>;
+
+  
+  fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>>;
+  fn enter(&self, listener: &mut dyn ChiruListener);
+  fn exit(&self, listener: &mut dyn ChiruListener);
+}
+
+impl<'a> RegularContext<'a> for RuleContext<'a> {
+
+  
+
+  
+
+  
+
+  
+  fn regular_literal(&self) -> Option<&TerminalContext<'a>> {
+    self.get_terminal(ChiruLexer::REGULAR_LITERAL, 0)
+  } 
+
+  
+
+
+  
+  fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>> {
+    visitor.visit_regular(self)
+  }
+  
+  fn enter(&self, listener: &mut dyn ChiruListener) {
+    listener.enter_regular(self)
+  }
+
+  fn exit(&self, listener: &mut dyn ChiruListener) {
+    listener.exit_regular(self)
+  }
+  
+}
+
+pub trait RulesContext<'a>: ToRule<'a> {
+  
+  fn parser_rule_list(&self) -> Vec<&dyn ParserRuleContext<'a>>;
+  fn lexer_rule_list(&self) -> Vec<&dyn LexerRuleContext<'a>>;
+  fn precedence_rule_list(&self) -> Vec<&dyn PrecedenceRuleContext<'a>>;
+  fn mode_rule_list(&self) -> Vec<&dyn ModeRuleContext<'a>>;
+
+  
+
+  
+
+  
+
+  
+  fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>>;
+  fn enter(&self, listener: &mut dyn ChiruListener);
+  fn exit(&self, listener: &mut dyn ChiruListener);
+}
+
+impl<'a> RulesContext<'a> for RuleContext<'a> {
+
+  
+  fn parser_rule_list(&self) -> Vec<&dyn ParserRuleContext<'a>> {
+    self.get_rule_contexts(ChiruParser::PARSER_RULE).iter().map(|ctx| *ctx as &dyn ParserRuleContext<'a>).collect::<Vec<_>>()
+  } 
+  fn lexer_rule_list(&self) -> Vec<&dyn LexerRuleContext<'a>> {
+    self.get_rule_contexts(ChiruParser::LEXER_RULE).iter().map(|ctx| *ctx as &dyn LexerRuleContext<'a>).collect::<Vec<_>>()
+  } 
+  fn precedence_rule_list(&self) -> Vec<&dyn PrecedenceRuleContext<'a>> {
+    self.get_rule_contexts(ChiruParser::PRECEDENCE_RULE).iter().map(|ctx| *ctx as &dyn PrecedenceRuleContext<'a>).collect::<Vec<_>>()
+  } 
+  fn mode_rule_list(&self) -> Vec<&dyn ModeRuleContext<'a>> {
+    self.get_rule_contexts(ChiruParser::MODE_RULE).iter().map(|ctx| *ctx as &dyn ModeRuleContext<'a>).collect::<Vec<_>>()
+  } 
+
+  
+
+  
+
+  
+
+  
+
+
+  
+  fn accept(&self, visitor: &mut dyn ChiruVisitor) -> Result<Box<dyn Any>, Box<dyn Error>> {
+    visitor.visit_rules(self)
+  }
+  
+  fn enter(&self, listener: &mut dyn ChiruListener) {
+    listener.enter_rules(self)
+  }
+
+  fn exit(&self, listener: &mut dyn ChiruListener) {
+    listener.exit_rules(self)
+  }
+  
 }
 
 
@@ -2,7 +2,7 @@
 
 
 
-// generated from .\src\tool\syntaxis\chiru.chiru by chiru 0.7.0
+// generated from src/tool/syntaxis/chiru.chiru by chiru 0.7.0
  
 
 
@@ -11,54 +11,78 @@ use chiru::runtime::ast::{terminal_context::TerminalContext, error_context::Erro
 use super::{
   chiru_parser::ChiruParser, 
   chiru_context::{
-    BlockContext,AnnotationContext,LexerRuleContext,ParserRuleContext,RulesContext,AttributeContext,GrammarNameContext,RegularContext,ElementContext,AlternativeContext,EpsilonContext,EbnfSuffixContext,AttributesContext,CompilationUnitContext,
+    AlternativeContext,AlternativeLabelContext,AnnotationContext,AttributeContext,AttributesContext,BlockContext,CompilationUnitContext,EbnfSuffixContext,ElementContext,ElementLabelContext,GrammarNameContext,ImportRuleContext,LexerAlternativeContext,LexerBlockContext,LexerElementContext,LexerRuleContext,ModeRuleContext,ParserRuleContext,PrecedenceRuleContext,RegularContext,RulesContext,
+    
   }
 };
 
 
 pub trait ChiruListener {
   
-  fn enter_block(&mut self, _ctx: &dyn BlockContext) {}
-  fn exit_block(&mut self, _ctx: &dyn BlockContext) {}
+  fn enter_alternative(&mut self, _ctx: &dyn AlternativeContext) {}
+  fn exit_alternative(&mut self, _ctx: &dyn AlternativeContext) {}
+  
+  fn enter_alternative_label(&mut self, _ctx: &dyn AlternativeLabelContext) {}
+  fn exit_alternative_label(&mut self, _ctx: &dyn AlternativeLabelContext) {}
   
   fn enter_annotation(&mut self, _ctx: &dyn AnnotationContext) {}
   fn exit_annotation(&mut self, _ctx: &dyn AnnotationContext) {}
   
-  fn enter_lexer_rule(&mut self, _ctx: &dyn LexerRuleContext) {}
-  fn exit_lexer_rule(&mut self, _ctx: &dyn LexerRuleContext) {}
+  fn enter_attribute(&mut self, _ctx: &dyn AttributeContext) {}
+  fn exit_attribute(&mut self, _ctx: &dyn AttributeContext) {}
   
-  fn enter_parser_rule(&mut self, _ctx: &dyn ParserRuleContext) {}
-  fn exit_parser_rule(&mut self, _ctx: &dyn ParserRuleContext) {}
+  fn enter_attributes(&mut self, _ctx: &dyn AttributesContext) {}
+  fn exit_attributes(&mut self, _ctx: &dyn AttributesContext) {}
   
-  fn enter_rules(&mut self, _ctx: &dyn RulesContext) {}
-  fn exit_rules(&mut self, _ctx: &dyn RulesContext) {}
+  fn enter_block(&mut self, _ctx: &dyn BlockContext) {}
+  fn exit_block(&mut self, _ctx: &dyn BlockContext) {}
   
-  fn enter_attribute(&mut self, _ctx: &dyn AttributeContext) {}
-  fn exit_attribute(&mut self, _ctx: &dyn AttributeContext) {}
+  fn enter_compilation_unit(&mut self, _ctx: &dyn CompilationUnitContext) {}
+  fn exit_compilation_unit(&mut self, _ctx: &dyn CompilationUnitContext) {}
+  
+  fn enter_ebnf_suffix(&mut self, _ctx: &dyn EbnfSuffixContext) {}
+  fn exit_ebnf_suffix(&mut self, _ctx: &dyn EbnfSuffixContext) {}
+  
+  fn enter_element(&mut self, _ctx: &dyn ElementContext) {}
+  fn exit_element(&mut self, _ctx: &dyn ElementContext) {}
+  
+  fn enter_element_label(&mut self, _ctx: &dyn ElementLabelContext) {}
+  fn exit_element_label(&mut self, _ctx: &dyn ElementLabelContext) {}
   
   fn enter_grammar_name(&mut self, _ctx: &dyn GrammarNameContext) {}
   fn exit_grammar_name(&mut self, _ctx: &dyn GrammarNameContext) {}
   
-  fn enter_regular(&mut self, _ctx: &dyn RegularContext) {}
-  fn exit_regular(&mut self, _ctx: &dyn RegularContext) {}
+  fn enter_import_rule(&mut self, _ctx: &dyn ImportRuleContext) {}
+  fn exit_import_rule(&mut self, _ctx: &dyn ImportRuleContext) {}
   
-  fn enter_element(&mut self, _ctx: &dyn ElementContext) {}
-  fn exit_element(&mut self, _ctx: &dyn ElementContext) {}
+  fn enter_lexer_alternative(&mut self, _ctx: &dyn LexerAlternativeContext) {}
+  fn exit_lexer_alternative(&mut self, _ctx: &dyn LexerAlternativeContext) {}
   
-  fn enter_alternative(&mut self, _ctx: &dyn AlternativeContext) {}
-  fn exit_alternative(&mut self, _ctx: &dyn AlternativeContext) {}
+  fn enter_lexer_block(&mut self, _ctx: &dyn LexerBlockContext) {}
+  fn exit_lexer_block(&mut self, _ctx: &dyn LexerBlockContext) {}
   
-  fn enter_epsilon(&mut self, _ctx: &dyn EpsilonContext) {}
-  fn exit_epsilon(&mut self, _ctx: &dyn EpsilonContext) {}
+  fn enter_lexer_element(&mut self, _ctx: &dyn LexerElementContext) {}
+  fn exit_lexer_element(&mut self, _ctx: &dyn LexerElementContext) {}
   
-  fn enter_ebnf_suffix(&mut self, _ctx: &dyn EbnfSuffixContext) {}
-  fn exit_ebnf_suffix(&mut self, _ctx: &dyn EbnfSuffixContext) {}
+  fn enter_lexer_rule(&mut self, _ctx: &dyn LexerRuleContext) {}
+  fn exit_lexer_rule(&mut self, _ctx: &dyn LexerRuleContext) {}
   
-  fn enter_attributes(&mut self, _ctx: &dyn AttributesContext) {}
-  fn exit_attributes(&mut self, _ctx: &dyn AttributesContext) {}
+  fn enter_mode_rule(&mut self, _ctx: &dyn ModeRuleContext) {}
+  fn exit_mode_rule(&mut self, _ctx: &dyn ModeRuleContext) {}
   
-  fn enter_compilation_unit(&mut self, _ctx: &dyn CompilationUnitContext) {}
-  fn exit_compilation_unit(&mut self, _ctx: &dyn CompilationUnitContext) {}
+  fn enter_parser_rule(&mut self, _ctx: &dyn ParserRuleContext) {}
+  fn exit_parser_rule(&mut self, _ctx: &dyn ParserRuleContext) {}
+  
+  fn enter_precedence_rule(&mut self, _ctx: &dyn PrecedenceRuleContext) {}
+  fn exit_precedence_rule(&mut self, _ctx: &dyn PrecedenceRuleContext) {}
+  
+  fn enter_regular(&mut self, _ctx: &dyn RegularContext) {}
+  fn exit_regular(&mut self, _ctx: &dyn RegularContext) {}
+  
+  fn enter_rules(&mut self, _ctx: &dyn RulesContext) {}
+  fn exit_rules(&mut self, _ctx: &dyn RulesContext) {}
+  
+
   
 
 
@@ -71,20 +95,27 @@ pub trait ChiruListener {
     // 在这里进行派发即可
     match ctx.get_rule_index() {
       
-      ChiruParser::BLOCK => self.enter_block(ctx), 
+      ChiruParser::ALTERNATIVE => self.enter_alternative(ctx), 
+      ChiruParser::ALTERNATIVE_LABEL => self.enter_alternative_label(ctx), 
       ChiruParser::ANNOTATION => self.enter_annotation(ctx), 
-      ChiruParser::LEXER_RULE => self.enter_lexer_rule(ctx), 
-      ChiruParser::PARSER_RULE => self.enter_parser_rule(ctx), 
-      ChiruParser::RULES => self.enter_rules(ctx), 
       ChiruParser::ATTRIBUTE => self.enter_attribute(ctx), 
-      ChiruParser::GRAMMAR_NAME => self.enter_grammar_name(ctx), 
-      ChiruParser::REGULAR => self.enter_regular(ctx), 
-      ChiruParser::ELEMENT => self.enter_element(ctx), 
-      ChiruParser::ALTERNATIVE => self.enter_alternative(ctx), 
-      ChiruParser::EPSILON => self.enter_epsilon(ctx), 
-      ChiruParser::EBNF_SUFFIX => self.enter_ebnf_suffix(ctx), 
       ChiruParser::ATTRIBUTES => self.enter_attributes(ctx), 
+      ChiruParser::BLOCK => self.enter_block(ctx), 
       ChiruParser::COMPILATION_UNIT => self.enter_compilation_unit(ctx), 
+      ChiruParser::EBNF_SUFFIX => self.enter_ebnf_suffix(ctx), 
+      ChiruParser::ELEMENT => self.enter_element(ctx), 
+      ChiruParser::ELEMENT_LABEL => self.enter_element_label(ctx), 
+      ChiruParser::GRAMMAR_NAME => self.enter_grammar_name(ctx), 
+      ChiruParser::IMPORT_RULE => self.enter_import_rule(ctx), 
+      ChiruParser::LEXER_ALTERNATIVE => self.enter_lexer_alternative(ctx), 
+      ChiruParser::LEXER_BLOCK => self.enter_lexer_block(ctx), 
+      ChiruParser::LEXER_ELEMENT => self.enter_lexer_element(ctx), 
+      ChiruParser::LEXER_RULE => self.enter_lexer_rule(ctx), 
+      ChiruParser::MODE_RULE => self.enter_mode_rule(ctx), 
+      ChiruParser::PARSER_RULE => self.enter_parser_rule(ctx), 
+      ChiruParser::PRECEDENCE_RULE => self.enter_precedence_rule(ctx), 
+      ChiruParser::REGULAR => self.enter_regular(ctx), 
+      ChiruParser::RULES => self.enter_rules(ctx), 
 
       _ => {}
     }
@@ -93,20 +124,27 @@ pub trait ChiruListener {
   fn exit(&mut self, ctx: &RuleContext) {
     match ctx.get_rule_index() {
       
-      ChiruParser::BLOCK => self.exit_block(ctx), 
+      ChiruParser::ALTERNATIVE => self.exit_alternative(ctx), 
+      ChiruParser::ALTERNATIVE_LABEL => self.exit_alternative_label(ctx), 
       ChiruParser::ANNOTATION => self.exit_annotation(ctx), 
-      ChiruParser::LEXER_RULE => self.exit_lexer_rule(ctx), 
-      ChiruParser::PARSER_RULE => self.exit_parser_rule(ctx), 
-      ChiruParser::RULES => self.exit_rules(ctx), 
       ChiruParser::ATTRIBUTE => self.exit_attribute(ctx), 
-      ChiruParser::GRAMMAR_NAME => self.exit_grammar_name(ctx), 
-      ChiruParser::REGULAR => self.exit_regular(ctx), 
-      ChiruParser::ELEMENT => self.exit_element(ctx), 
-      ChiruParser::ALTERNATIVE => self.exit_alternative(ctx), 
-      ChiruParser::EPSILON => self.exit_epsilon(ctx), 
-      ChiruParser::EBNF_SUFFIX => self.exit_ebnf_suffix(ctx), 
       ChiruParser::ATTRIBUTES => self.exit_attributes(ctx), 
+      ChiruParser::BLOCK => self.exit_block(ctx), 
       ChiruParser::COMPILATION_UNIT => self.exit_compilation_unit(ctx), 
+      ChiruParser::EBNF_SUFFIX => self.exit_ebnf_suffix(ctx), 
+      ChiruParser::ELEMENT => self.exit_element(ctx), 
+      ChiruParser::ELEMENT_LABEL => self.exit_element_label(ctx), 
+      ChiruParser::GRAMMAR_NAME => self.exit_grammar_name(ctx), 
+      ChiruParser::IMPORT_RULE => self.exit_import_rule(ctx), 
+      ChiruParser::LEXER_ALTERNATIVE => self.exit_lexer_alternative(ctx), 
+      ChiruParser::LEXER_BLOCK => self.exit_lexer_block(ctx), 
+      ChiruParser::LEXER_ELEMENT => self.exit_lexer_element(ctx), 
+      ChiruParser::LEXER_RULE => self.exit_lexer_rule(ctx), 
+      ChiruParser::MODE_RULE => self.exit_mode_rule(ctx), 
+      ChiruParser::PARSER_RULE => self.exit_parser_rule(ctx), 
+      ChiruParser::PRECEDENCE_RULE => self.exit_precedence_rule(ctx), 
+      ChiruParser::REGULAR => self.exit_regular(ctx), 
+      ChiruParser::RULES => self.exit_rules(ctx), 
 
       _ => {}
     }
@@ -2,30 +2,28 @@
 
 
 
-// generated from .\src\tool\syntaxis\chiru.chiru by chiru 0.7.0
+// generated from src/tool/syntaxis/chiru.chiru by chiru 0.7.0
  
 
 
 use std::error::Error;
-use std::collections::{HashMap, HashSet};
+use std::collections::BTreeMap;
 
 use chiru::runtime::error_strategy::error_listener::ErrorListener;
-use chiru::runtime::ll1_analyzer::ll1_analyze;
+use chiru::runtime::lalr_analyzer::{lalr_analyze, ActionTableElement};
 
-use chiru::maplit::hashmap;
-use chiru::maplit::hashset;
 use chiru::once_cell::sync::Lazy;
 
-use chiru::runtime::vocabulary::Vocabulary;
+use chiru::runtime::vocabulary::{Vocabulary, Terminal, NonTerminal};
 use chiru::runtime::{
-  token_stream::TokenStream, 
+  token_stream::TokenStream,
   error_strategy::error_listener::ConsoleErrorListener,
   production::Production,
   production::ProductionItem
 };
 
 use super::chiru_context::{
-   BlockContext, AnnotationContext, LexerRuleContext, ParserRuleContext, RulesContext, AttributeContext, GrammarNameContext, RegularContext, ElementContext, AlternativeContext, EpsilonContext, EbnfSuffixContext, AttributesContext, CompilationUnitContext,
+   AlternativeContext, AlternativeLabelContext, AnnotationContext, AttributeContext, AttributesContext, BlockContext, CompilationUnitContext, EbnfSuffixContext, ElementContext, ElementLabelContext, GrammarNameContext, ImportRuleContext, LexerAlternativeContext, LexerBlockContext, LexerElementContext, LexerRuleContext, ModeRuleContext, ParserRuleContext, PrecedenceRuleContext, RegularContext, RulesContext,
 };
 
 
@@ -34,544 +32,1441 @@ pub struct ChiruParser {
 }
 
 
-static LL1_TABLE: Lazy<HashMap<(usize, usize), usize>> = Lazy::new(|| { 
-  hashmap!{
-    
-    (23, 6) => 29,
-    (14, 4) => 1,
-    (16, 4) => 7,
-    (19, 6) => 14,
-    (15, 15) => 5,
-    (18, 14) => 11,
-    (23, 1) => 29,
-    (21, 1) => 23,
-    (19, 13) => 15,
-    (19, 4) => 15,
-    (19, 19) => 15,
-    (7, 4) => 25,
-    (11, 16) => 37,
-    (16, 3) => 7,
-    (19, 8) => 14,
-    (5, 3) => 16,
-    (26, 1) => 39,
-    (5, 13) => 16,
-    (5, 19) => 16,
-    (23, 3) => 29,
-    (23, 4) => 29,
-    (8, 10) => 31,
-    (23, 12) => 30,
-    (26, 7) => 40,
-    (16, 16) => 7,
-    (28, 7) => 43,
-    (15, 4) => 5,
-    (9, 15) => 34,
-    (9, 16) => 34,
-    (8, 11) => 31,
-    (21, 14) => 23,
-    (21, 19) => 23,
-    (20, 4) => 19,
-    (18, 8) => 12,
-    (19, 14) => 14,
-    (22, 10) => 26,
-    (4, 4) => 13,
-    (24, 15) => 33,
-    (7, 3) => 25,
-    (28, 13) => 44,
-    (2, 1) => 8,
-    (21, 8) => 23,
-    (2, 15) => 8,
-    (2, 4) => 8,
-    (14, 3) => 2,
-    (21, 4) => 23,
-    (25, 7) => 38,
-    (23, 19) => 29,
-    (21, 10) => 24,
-    (7, 19) => 25,
-    (6, 9) => 18,
-    (10, 20) => 35,
-    (4, 3) => 13,
-    (21, 6) => 23,
-    (26, 18) => 39,
-    (0, 2) => 0,
-    (21, 11) => 24,
-    (2, 3) => 8,
-    (23, 14) => 29,
-    (12, 3) => 41,
-    (19, 3) => 15,
-    (20, 19) => 20,
-    (15, 16) => 5,
-    (20, 3) => 21,
-    (1, 2) => 3,
-    (20, 13) => 22,
-    (13, 3) => 45,
-    (22, 11) => 27,
-    (9, 4) => 34,
-    (8, 12) => 31,
-    (18, 6) => 11,
-    (5, 4) => 16,
-    (5, 9) => 17,
-    (23, 13) => 29,
-    (27, 13) => 42,
-    (28, 1) => 43,
-    (17, 8) => 10,
-    (16, 1) => 6,
-    (21, 13) => 23,
-    (11, 15) => 36,
-    (7, 13) => 25,
-    (4, 13) => 13,
-    (15, 3) => 4,
-    (4, 19) => 13,
-    (2, 16) => 8,
-    (28, 18) => 43,
-    (4, 9) => 13,
-    (19, 1) => 14,
-    (24, 16) => 33,
-    (16, 15) => 7,
-    (21, 12) => 24,
-    (18, 1) => 11,
-    (3, 3) => 9,
-    (21, 3) => 23,
-    (22, 12) => 28,
-    (24, 1) => 32,
-    (23, 8) => 29,
-    (24, 4) => 32,
-    (28, 4) => 43,
-  }
-});
-
-
-static PRODUCTIONS: Lazy<HashMap<usize, Production>>  = Lazy::new(|| {
-  hashmap!{
-    
-    44 => Production::new(44, 28, &vec![ProductionItem::NonTerminal(27),]),
-    8 => Production::new(8, 2, &vec![ProductionItem::NonTerminal(16),]),
-    30 => Production::new(30, 23, &vec![ProductionItem::Terminal(12),]),
-    45 => Production::new(45, 13, &vec![ProductionItem::Terminal(3),ProductionItem::NonTerminal(28),]),
-    1 => Production::new(1, 14, &vec![ProductionItem::Terminal(4),]),
-    11 => Production::new(11, 18, &vec![]),
-    16 => Production::new(16, 5, &vec![ProductionItem::NonTerminal(7),ProductionItem::NonTerminal(19),]),
-    17 => Production::new(17, 5, &vec![ProductionItem::NonTerminal(6),]),
-    24 => Production::new(24, 21, &vec![ProductionItem::NonTerminal(8),]),
-    31 => Production::new(31, 8, &vec![ProductionItem::NonTerminal(22),ProductionItem::NonTerminal(23),]),
-    34 => Production::new(34, 9, &vec![ProductionItem::NonTerminal(24),ProductionItem::Terminal(4),ProductionItem::Terminal(5),ProductionItem::NonTerminal(10),ProductionItem::Terminal(6),]),
-    22 => Production::new(22, 20, &vec![ProductionItem::Terminal(13),ProductionItem::NonTerminal(4),ProductionItem::Terminal(14),]),
-    3 => Production::new(3, 1, &vec![ProductionItem::Terminal(2),ProductionItem::NonTerminal(14),ProductionItem::Terminal(6),]),
-    10 => Production::new(10, 17, &vec![ProductionItem::Terminal(8),ProductionItem::NonTerminal(5),]),
-    23 => Production::new(23, 21, &vec![]),
-    28 => Production::new(28, 22, &vec![ProductionItem::Terminal(12),]),
-    36 => Production::new(36, 11, &vec![ProductionItem::Terminal(15),ProductionItem::NonTerminal(13),]),
-    9 => Production::new(9, 3, &vec![ProductionItem::Terminal(3),ProductionItem::Terminal(5),ProductionItem::NonTerminal(4),ProductionItem::Terminal(6),]),
-    19 => Production::new(19, 20, &vec![ProductionItem::Terminal(4),]),
-    29 => Production::new(29, 23, &vec![]),
-    7 => Production::new(7, 16, &vec![ProductionItem::NonTerminal(15),ProductionItem::NonTerminal(16),]),
-    25 => Production::new(25, 7, &vec![ProductionItem::NonTerminal(20),ProductionItem::NonTerminal(21),]),
-    12 => Production::new(12, 18, &vec![ProductionItem::NonTerminal(17),ProductionItem::NonTerminal(18),]),
-    42 => Production::new(42, 27, &vec![ProductionItem::Terminal(13),ProductionItem::Terminal(4),ProductionItem::Terminal(14),]),
-    15 => Production::new(15, 19, &vec![ProductionItem::NonTerminal(7),ProductionItem::NonTerminal(19),]),
-    32 => Production::new(32, 24, &vec![]),
-    41 => Production::new(41, 12, &vec![ProductionItem::NonTerminal(13),ProductionItem::NonTerminal(26),]),
-    40 => Production::new(40, 26, &vec![ProductionItem::NonTerminal(25),ProductionItem::NonTerminal(26),]),
-    2 => Production::new(2, 14, &vec![ProductionItem::Terminal(3),]),
-    20 => Production::new(20, 20, &vec![ProductionItem::Terminal(19),]),
-    43 => Production::new(43, 28, &vec![]),
-    14 => Production::new(14, 19, &vec![]),
-    21 => Production::new(21, 20, &vec![ProductionItem::Terminal(3),]),
-    5 => Production::new(5, 15, &vec![ProductionItem::NonTerminal(9),]),
-    38 => Production::new(38, 25, &vec![ProductionItem::Terminal(7),ProductionItem::NonTerminal(13),]),
-    6 => Production::new(6, 16, &vec![]),
-    26 => Production::new(26, 22, &vec![ProductionItem::Terminal(10),]),
-    13 => Production::new(13, 4, &vec![ProductionItem::NonTerminal(5),ProductionItem::NonTerminal(18),]),
-    0 => Production::new(0, 0, &vec![ProductionItem::NonTerminal(1),ProductionItem::NonTerminal(2),]),
-    18 => Production::new(18, 6, &vec![ProductionItem::Terminal(9),]),
-    33 => Production::new(33, 24, &vec![ProductionItem::NonTerminal(11),]),
-    27 => Production::new(27, 22, &vec![ProductionItem::Terminal(11),]),
-    4 => Production::new(4, 15, &vec![ProductionItem::NonTerminal(3),]),
-    35 => Production::new(35, 10, &vec![ProductionItem::Terminal(20),]),
-    37 => Production::new(37, 11, &vec![ProductionItem::Terminal(16),ProductionItem::Terminal(17),ProductionItem::NonTerminal(12),ProductionItem::Terminal(18),]),
-    39 => Production::new(39, 26, &vec![]),
-  }
-}); 
-
-// 删掉终结符和非终结符这两个全局静态变量，改用 Vocabulary 管理
-// 非终结符
-pub static NONTERMINALS: Lazy<HashMap<usize, String>> = Lazy::new(|| {
-  hashmap! {
-    
-    4 => String::from("block"),
-    11 => String::from("annotation"),
-    9 => String::from("lexer_rule"),
-    3 => String::from("parser_rule"),
-    2 => String::from("rules"),
-    13 => String::from("attribute"),
-    1 => String::from("grammar_name"),
-    10 => String::from("regular"),
-    7 => String::from("element"),
-    5 => String::from("alternative"),
-    6 => String::from("epsilon"),
-    8 => String::from("ebnf_suffix"),
-    12 => String::from("attributes"),
-    0 => String::from("compilation_unit"),
-  }
-});
-
-
-
-
 pub static VOCABULARY: Lazy<Vocabulary> = Lazy::new(|| {
   let mut result = Vocabulary::new();
 
   // 添加匿名非终结符
-  result.add_unnamed_nonterminals(&vec![7, 17, 13, 4, 5, 6, 10, 1, 3, 16, 18, 11, 9, 15, 0, 8, 27, 21, 19, 26, 28, 24, 23, 22, 14, 20, 12, 2, 25]);
+  result.add_unnamed_nonterminals(&[21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, ]);
 
   // 添加命名非终结符
-  result.add_named_nonterminal(4, "block");
-  result.add_named_nonterminal(13, "attribute");
-  result.add_named_nonterminal(9, "lexer_rule");
+  
+  result.add_named_nonterminal(6, "alternative");
+  result.add_named_nonterminal(7, "alternative_label");
+  result.add_named_nonterminal(16, "annotation");
+  result.add_named_nonterminal(18, "attribute");
+  result.add_named_nonterminal(17, "attributes");
+  result.add_named_nonterminal(5, "block");
   result.add_named_nonterminal(0, "compilation_unit");
-  result.add_named_nonterminal(11, "annotation");
-  result.add_named_nonterminal(7, "element");
+  result.add_named_nonterminal(10, "ebnf_suffix");
+  result.add_named_nonterminal(8, "element");
+  result.add_named_nonterminal(9, "element_label");
   result.add_named_nonterminal(1, "grammar_name");
-  result.add_named_nonterminal(5, "alternative");
-  result.add_named_nonterminal(12, "attributes");
-  result.add_named_nonterminal(2, "rules");
-  result.add_named_nonterminal(10, "regular");
-  result.add_named_nonterminal(3, "parser_rule");
-  result.add_named_nonterminal(6, "epsilon");
-  result.add_named_nonterminal(8, "ebnf_suffix");
+  result.add_named_nonterminal(2, "import_rule");
+  result.add_named_nonterminal(13, "lexer_alternative");
+  result.add_named_nonterminal(12, "lexer_block");
+  result.add_named_nonterminal(14, "lexer_element");
+  result.add_named_nonterminal(11, "lexer_rule");
+  result.add_named_nonterminal(20, "mode_rule");
+  result.add_named_nonterminal(4, "parser_rule");
+  result.add_named_nonterminal(19, "precedence_rule");
+  result.add_named_nonterminal(15, "regular");
+  result.add_named_nonterminal(3, "rules");
 
   // 添加终结符
   
-  result.add_terminal(10 ,"STAR");
-  result.add_terminal(13 ,"LPAREN");
-  result.add_terminal(6 ,"SEMI");
-  result.add_terminal(0 ,"_START");
-  result.add_terminal(5 ,"COLON");
-  result.add_terminal(7 ,"COMMA");
-  result.add_terminal(9 ,"EPSILON");
-  result.add_terminal(20 ,"REGULAR_LITERAL");
-  result.add_terminal(2 ,"GRAMMAR");
-  result.add_terminal(15 ,"AT");
-  result.add_terminal(18 ,"RBRACKET");
-  result.add_terminal(4 ,"TOKEN_REF");
-  result.add_terminal(22 ,"LINE_COMMENT");
-  result.add_terminal(21 ,"WHITE_SPACE");
-  result.add_terminal(1 ,"_STOP");
-  result.add_terminal(17 ,"LBRACKET");
-  result.add_terminal(23 ,"BLOCK_COMMENT");
-  result.add_terminal(3 ,"RULE_REF");
-  result.add_terminal(14 ,"RPAREN");
-  result.add_terminal(19 ,"STRING_LITERAL");
-  result.add_terminal(11 ,"PLUS");
-  result.add_terminal(16 ,"SHARP");
-  result.add_terminal(8 ,"OR");
-  result.add_terminal(12 ,"QUESTION");
-
+  result.add_terminal(12, "ASSIGN");
+  result.add_terminal(22, "AT");
+  result.add_terminal(30, "BLOCK_COMMENT");
+  result.add_terminal(11, "COLON");
+  result.add_terminal(15, "COMMA");
+  result.add_terminal(8, "EPSILON");
+  result.add_terminal(7, "FRAGMENT");
+  result.add_terminal(2, "GRAMMAR");
+  result.add_terminal(5, "IMPORT");
+  result.add_terminal(24, "LBRACKET");
+  result.add_terminal(3, "LEXER");
+  result.add_terminal(29, "LINE_COMMENT");
+  result.add_terminal(20, "LPAREN");
+  result.add_terminal(6, "MODE");
+  result.add_terminal(16, "OR");
+  result.add_terminal(4, "PARSER");
+  result.add_terminal(18, "PLUS");
+  result.add_terminal(13, "PLUS_ASSIGN");
+  result.add_terminal(19, "QUESTION");
+  result.add_terminal(25, "RBRACKET");
+  result.add_terminal(27, "REGULAR_LITERAL");
+  result.add_terminal(21, "RPAREN");
+  result.add_terminal(9, "RULE_REF");
+  result.add_terminal(14, "SEMI");
+  result.add_terminal(23, "SHARP");
+  result.add_terminal(17, "STAR");
+  result.add_terminal(26, "STRING_LITERAL");
+  result.add_terminal(10, "TOKEN_REF");
+  result.add_terminal(28, "WHITE_SPACE");
+  result.add_terminal(0, "_START");
+  result.add_terminal(1, "_STOP");
 
   result
 });
 
+// 通过编号获取终结符和非终结符
+fn terminal(id: usize) -> Terminal<'static> {
+  VOCABULARY.get_terminal_by_id(id).unwrap()
+}
+
+fn nonterminal(id: usize) -> NonTerminal<'static> {
+  VOCABULARY.get_nonterminal_by_id(id).unwrap()
+}
 
 
-pub static SYNC: Lazy<HashSet<(usize, usize)>> = Lazy::new(|| {
-  hashset! {
+static PRODUCTIONS: Lazy<BTreeMap<usize, Production>>  = Lazy::new(|| {
+  BTreeMap::from([
     
-    (3, 16),
-    (22, 19),
-    (22, 12),
-    (25, 1),
-    (22, 4),
-    (21, 6),
-    (14, 1),
-    (23, 19),
-    (18, 14),
-    (23, 6),
-    (13, 4),
-    (19, 1),
-    (21, 3),
-    (0, 1),
-    (1, 4),
-    (18, 1),
-    (19, 14),
-    (22, 13),
-    (3, 3),
-    (28, 1),
-    (20, 14),
-    (23, 8),
-    (17, 8),
-    (26, 18),
-    (14, 6),
-    (11, 4),
-    (27, 4),
-    (28, 4),
-    (25, 7),
-    (5, 8),
-    (22, 8),
-    (23, 4),
-    (21, 1),
-    (11, 1),
-    (12, 1),
-    (3, 15),
-    (8, 3),
-    (21, 14),
-    (7, 6),
-    (20, 12),
-    (23, 1),
-    (3, 4),
-    (1, 15),
-    (5, 6),
-    (27, 1),
-    (8, 8),
-    (20, 6),
-    (21, 8),
-    (6, 8),
-    (6, 6),
-    (9, 15),
-    (16, 1),
-    (9, 16),
-    (13, 7),
-    (4, 1),
-    (15, 15),
-    (5, 1),
-    (20, 3),
-    (20, 13),
-    (3, 1),
-    (17, 6),
-    (10, 1),
-    (20, 10),
-    (8, 4),
-    (6, 1),
-    (26, 1),
-    (5, 14),
-    (13, 1),
-    (21, 13),
-    (8, 14),
-    (1, 16),
-    (13, 18),
-    (4, 6),
-    (20, 11),
-    (15, 1),
-    (7, 8),
-    (8, 1),
-    (24, 4),
-    (20, 4),
-    (2, 1),
-    (28, 18),
-    (17, 14),
-    (1, 3),
-    (15, 16),
-    (7, 4),
-    (7, 3),
-    (21, 19),
-    (22, 14),
-    (9, 3),
-    (22, 6),
-    (18, 6),
-    (9, 4),
-    (20, 1),
-    (28, 7),
-    (7, 13),
-    (20, 8),
-    (8, 19),
-    (8, 13),
-    (6, 14),
-    (19, 8),
-    (23, 14),
-    (21, 4),
-    (19, 6),
-    (12, 18),
-    (20, 19),
-    (15, 4),
-    (7, 1),
-    (22, 3),
-    (7, 19),
-    (8, 6),
-    (23, 3),
-    (9, 1),
-    (1, 1),
-    (10, 6),
-    (27, 18),
-    (17, 1),
-    (7, 14),
-    (23, 13),
-    (22, 1),
-    (27, 7),
-    (25, 18),
-    (15, 3),
-    (24, 1),
-    (4, 14),
-  }
+    (0, Production::new(0, nonterminal(21), &[])),
+    (1, Production::new(1, nonterminal(21), &[ProductionItem::NonTerminal(nonterminal(2)),ProductionItem::NonTerminal(nonterminal(21)),])),
+    (2, Production::new(2, nonterminal(0), &[ProductionItem::NonTerminal(nonterminal(1)),ProductionItem::NonTerminal(nonterminal(21)),ProductionItem::NonTerminal(nonterminal(3)),])),
+    (3, Production::new(3, nonterminal(22), &[ProductionItem::Terminal(terminal(3)),])),
+    (4, Production::new(4, nonterminal(22), &[ProductionItem::Terminal(terminal(4)),])),
+    (5, Production::new(5, nonterminal(23), &[])),
+    (6, Production::new(6, nonterminal(23), &[ProductionItem::NonTerminal(nonterminal(22)),])),
+    (7, Production::new(7, nonterminal(24), &[ProductionItem::Terminal(terminal(10)),])),
+    (8, Production::new(8, nonterminal(24), &[ProductionItem::Terminal(terminal(9)),])),
+    (9, Production::new(9, nonterminal(1), &[ProductionItem::NonTerminal(nonterminal(23)),ProductionItem::Terminal(terminal(2)),ProductionItem::NonTerminal(nonterminal(24)),ProductionItem::Terminal(terminal(14)),])),
+    (10, Production::new(10, nonterminal(25), &[ProductionItem::Terminal(terminal(15)),ProductionItem::NonTerminal(nonterminal(24)),])),
+    (11, Production::new(11, nonterminal(26), &[])),
+    (12, Production::new(12, nonterminal(26), &[ProductionItem::NonTerminal(nonterminal(25)),ProductionItem::NonTerminal(nonterminal(26)),])),
+    (13, Production::new(13, nonterminal(2), &[ProductionItem::Terminal(terminal(5)),ProductionItem::NonTerminal(nonterminal(24)),ProductionItem::NonTerminal(nonterminal(26)),ProductionItem::Terminal(terminal(14)),])),
+    (14, Production::new(14, nonterminal(27), &[ProductionItem::NonTerminal(nonterminal(4)),])),
+    (15, Production::new(15, nonterminal(27), &[ProductionItem::NonTerminal(nonterminal(11)),])),
+    (16, Production::new(16, nonterminal(27), &[ProductionItem::NonTerminal(nonterminal(19)),])),
+    (17, Production::new(17, nonterminal(27), &[ProductionItem::NonTerminal(nonterminal(20)),])),
+    (18, Production::new(18, nonterminal(28), &[])),
+    (19, Production::new(19, nonterminal(28), &[ProductionItem::NonTerminal(nonterminal(27)),ProductionItem::NonTerminal(nonterminal(28)),])),
+    (20, Production::new(20, nonterminal(3), &[ProductionItem::NonTerminal(nonterminal(28)),])),
+    (21, Production::new(21, nonterminal(29), &[ProductionItem::Terminal(terminal(11)),])),
+    (22, Production::new(22, nonterminal(29), &[ProductionItem::Terminal(terminal(12)),])),
+    (23, Production::new(23, nonterminal(4), &[ProductionItem::Terminal(terminal(9)),ProductionItem::NonTerminal(nonterminal(29)),ProductionItem::NonTerminal(nonterminal(5)),ProductionItem::Terminal(terminal(14)),])),
+    (24, Production::new(24, nonterminal(30), &[ProductionItem::Terminal(terminal(16)),ProductionItem::NonTerminal(nonterminal(6)),])),
+    (25, Production::new(25, nonterminal(31), &[])),
+    (26, Production::new(26, nonterminal(31), &[ProductionItem::NonTerminal(nonterminal(30)),ProductionItem::NonTerminal(nonterminal(31)),])),
+    (27, Production::new(27, nonterminal(5), &[ProductionItem::NonTerminal(nonterminal(6)),ProductionItem::NonTerminal(nonterminal(31)),])),
+    (28, Production::new(28, nonterminal(32), &[])),
+    (29, Production::new(29, nonterminal(32), &[ProductionItem::NonTerminal(nonterminal(8)),ProductionItem::NonTerminal(nonterminal(32)),])),
+    (30, Production::new(30, nonterminal(33), &[ProductionItem::NonTerminal(nonterminal(8)),ProductionItem::NonTerminal(nonterminal(32)),])),
+    (31, Production::new(31, nonterminal(33), &[ProductionItem::Terminal(terminal(8)),])),
+    (32, Production::new(32, nonterminal(34), &[])),
+    (33, Production::new(33, nonterminal(34), &[ProductionItem::NonTerminal(nonterminal(7)),])),
+    (34, Production::new(34, nonterminal(6), &[ProductionItem::NonTerminal(nonterminal(33)),ProductionItem::NonTerminal(nonterminal(34)),])),
+    (35, Production::new(35, nonterminal(35), &[ProductionItem::Terminal(terminal(9)),])),
+    (36, Production::new(36, nonterminal(35), &[ProductionItem::Terminal(terminal(10)),])),
+    (37, Production::new(37, nonterminal(7), &[ProductionItem::Terminal(terminal(23)),ProductionItem::NonTerminal(nonterminal(35)),])),
+    (38, Production::new(38, nonterminal(36), &[ProductionItem::Terminal(terminal(10)),])),
+    (39, Production::new(39, nonterminal(36), &[ProductionItem::Terminal(terminal(26)),])),
+    (40, Production::new(40, nonterminal(36), &[ProductionItem::Terminal(terminal(9)),])),
+    (41, Production::new(41, nonterminal(36), &[ProductionItem::Terminal(terminal(20)),ProductionItem::NonTerminal(nonterminal(5)),ProductionItem::Terminal(terminal(21)),])),
+    (42, Production::new(42, nonterminal(37), &[ProductionItem::NonTerminal(nonterminal(9)),ProductionItem::NonTerminal(nonterminal(36)),])),
+    (43, Production::new(43, nonterminal(37), &[ProductionItem::NonTerminal(nonterminal(36)),])),
+    (44, Production::new(44, nonterminal(38), &[])),
+    (45, Production::new(45, nonterminal(38), &[ProductionItem::NonTerminal(nonterminal(10)),])),
+    (46, Production::new(46, nonterminal(8), &[ProductionItem::NonTerminal(nonterminal(37)),ProductionItem::NonTerminal(nonterminal(38)),])),
+    (47, Production::new(47, nonterminal(39), &[ProductionItem::Terminal(terminal(12)),])),
+    (48, Production::new(48, nonterminal(39), &[ProductionItem::Terminal(terminal(13)),])),
+    (49, Production::new(49, nonterminal(9), &[ProductionItem::NonTerminal(nonterminal(35)),ProductionItem::NonTerminal(nonterminal(39)),])),
+    (50, Production::new(50, nonterminal(40), &[ProductionItem::Terminal(terminal(17)),])),
+    (51, Production::new(51, nonterminal(40), &[ProductionItem::Terminal(terminal(18)),])),
+    (52, Production::new(52, nonterminal(40), &[ProductionItem::Terminal(terminal(19)),])),
+    (53, Production::new(53, nonterminal(41), &[])),
+    (54, Production::new(54, nonterminal(41), &[ProductionItem::Terminal(terminal(19)),])),
+    (55, Production::new(55, nonterminal(10), &[ProductionItem::NonTerminal(nonterminal(40)),ProductionItem::NonTerminal(nonterminal(41)),])),
+    (56, Production::new(56, nonterminal(42), &[])),
+    (57, Production::new(57, nonterminal(42), &[ProductionItem::NonTerminal(nonterminal(16)),])),
+    (58, Production::new(58, nonterminal(43), &[])),
+    (59, Production::new(59, nonterminal(43), &[ProductionItem::Terminal(terminal(7)),])),
+    (60, Production::new(60, nonterminal(11), &[ProductionItem::NonTerminal(nonterminal(42)),ProductionItem::NonTerminal(nonterminal(43)),ProductionItem::Terminal(terminal(10)),ProductionItem::NonTerminal(nonterminal(29)),ProductionItem::NonTerminal(nonterminal(12)),ProductionItem::Terminal(terminal(14)),])),
+    (61, Production::new(61, nonterminal(44), &[ProductionItem::Terminal(terminal(16)),ProductionItem::NonTerminal(nonterminal(13)),])),
+    (62, Production::new(62, nonterminal(45), &[])),
+    (63, Production::new(63, nonterminal(45), &[ProductionItem::NonTerminal(nonterminal(44)),ProductionItem::NonTerminal(nonterminal(45)),])),
+    (64, Production::new(64, nonterminal(12), &[ProductionItem::NonTerminal(nonterminal(13)),ProductionItem::NonTerminal(nonterminal(45)),])),
+    (65, Production::new(65, nonterminal(46), &[ProductionItem::NonTerminal(nonterminal(14)),])),
+    (66, Production::new(66, nonterminal(46), &[ProductionItem::NonTerminal(nonterminal(14)),ProductionItem::NonTerminal(nonterminal(46)),])),
+    (67, Production::new(67, nonterminal(13), &[ProductionItem::NonTerminal(nonterminal(46)),])),
+    (68, Production::new(68, nonterminal(47), &[ProductionItem::NonTerminal(nonterminal(15)),])),
+    (69, Production::new(69, nonterminal(47), &[ProductionItem::Terminal(terminal(26)),])),
+    (70, Production::new(70, nonterminal(47), &[ProductionItem::Terminal(terminal(10)),])),
+    (71, Production::new(71, nonterminal(47), &[ProductionItem::Terminal(terminal(20)),ProductionItem::NonTerminal(nonterminal(12)),ProductionItem::Terminal(terminal(21)),])),
+    (72, Production::new(72, nonterminal(14), &[ProductionItem::NonTerminal(nonterminal(47)),ProductionItem::NonTerminal(nonterminal(38)),])),
+    (73, Production::new(73, nonterminal(15), &[ProductionItem::Terminal(terminal(27)),])),
+    (74, Production::new(74, nonterminal(16), &[ProductionItem::Terminal(terminal(22)),ProductionItem::NonTerminal(nonterminal(18)),])),
+    (75, Production::new(75, nonterminal(16), &[ProductionItem::Terminal(terminal(23)),ProductionItem::Terminal(terminal(24)),ProductionItem::NonTerminal(nonterminal(17)),ProductionItem::Terminal(terminal(25)),])),
+    (76, Production::new(76, nonterminal(48), &[ProductionItem::Terminal(terminal(15)),ProductionItem::NonTerminal(nonterminal(18)),])),
+    (77, Production::new(77, nonterminal(49), &[])),
+    (78, Production::new(78, nonterminal(49), &[ProductionItem::NonTerminal(nonterminal(48)),ProductionItem::NonTerminal(nonterminal(49)),])),
+    (79, Production::new(79, nonterminal(17), &[ProductionItem::NonTerminal(nonterminal(18)),ProductionItem::NonTerminal(nonterminal(49)),])),
+    (80, Production::new(80, nonterminal(50), &[ProductionItem::Terminal(terminal(9)),])),
+    (81, Production::new(81, nonterminal(50), &[ProductionItem::Terminal(terminal(6)),])),
+    (82, Production::new(82, nonterminal(51), &[ProductionItem::Terminal(terminal(10)),])),
+    (83, Production::new(83, nonterminal(51), &[ProductionItem::Terminal(terminal(26)),])),
+    (84, Production::new(84, nonterminal(52), &[ProductionItem::Terminal(terminal(15)),ProductionItem::NonTerminal(nonterminal(51)),])),
+    (85, Production::new(85, nonterminal(53), &[])),
+    (86, Production::new(86, nonterminal(53), &[ProductionItem::NonTerminal(nonterminal(52)),ProductionItem::NonTerminal(nonterminal(53)),])),
+    (87, Production::new(87, nonterminal(54), &[ProductionItem::Terminal(terminal(20)),ProductionItem::NonTerminal(nonterminal(51)),ProductionItem::NonTerminal(nonterminal(53)),ProductionItem::Terminal(terminal(21)),])),
+    (88, Production::new(88, nonterminal(55), &[])),
+    (89, Production::new(89, nonterminal(55), &[ProductionItem::NonTerminal(nonterminal(54)),])),
+    (90, Production::new(90, nonterminal(18), &[ProductionItem::NonTerminal(nonterminal(50)),ProductionItem::NonTerminal(nonterminal(55)),])),
+    (91, Production::new(91, nonterminal(19), &[ProductionItem::Terminal(terminal(22)),ProductionItem::NonTerminal(nonterminal(18)),ProductionItem::Terminal(terminal(14)),])),
+    (92, Production::new(92, nonterminal(20), &[ProductionItem::Terminal(terminal(6)),ProductionItem::Terminal(terminal(10)),ProductionItem::Terminal(terminal(14)),])),
+  ])
+});
+
+static ACTION_TABLE: Lazy<BTreeMap<(usize, Terminal), ActionTableElement>> = Lazy::new(|| {
+  BTreeMap::from([
+    
+    ((0, terminal(8)), ActionTableElement::Shift(28)),
+    ((0, terminal(9)), ActionTableElement::Shift(30)),
+    ((0, terminal(10)), ActionTableElement::Shift(32)),
+    ((0, terminal(20)), ActionTableElement::Shift(29)),
+    ((0, terminal(26)), ActionTableElement::Shift(31)),
+    ((1, terminal(23)), ActionTableElement::Shift(34)),
+    ((2, terminal(22)), ActionTableElement::Shift(36)),
+    ((2, terminal(23)), ActionTableElement::Shift(37)),
+    ((3, terminal(6)), ActionTableElement::Shift(40)),
+    ((3, terminal(9)), ActionTableElement::Shift(41)),
+    ((4, terminal(6)), ActionTableElement::Shift(40)),
+    ((4, terminal(9)), ActionTableElement::Shift(41)),
+    ((5, terminal(8)), ActionTableElement::Shift(28)),
+    ((5, terminal(9)), ActionTableElement::Shift(30)),
+    ((5, terminal(10)), ActionTableElement::Shift(32)),
+    ((5, terminal(20)), ActionTableElement::Shift(29)),
+    ((5, terminal(26)), ActionTableElement::Shift(31)),
+    ((6, terminal(2)), ActionTableElement::Reduce(5)),
+    ((6, terminal(3)), ActionTableElement::Shift(50)),
+    ((6, terminal(4)), ActionTableElement::Shift(51)),
+    ((7, terminal(17)), ActionTableElement::Shift(56)),
+    ((7, terminal(18)), ActionTableElement::Shift(54)),
+    ((7, terminal(19)), ActionTableElement::Shift(55)),
+    ((8, terminal(9)), ActionTableElement::Shift(30)),
+    ((8, terminal(10)), ActionTableElement::Shift(32)),
+    ((8, terminal(20)), ActionTableElement::Shift(29)),
+    ((8, terminal(26)), ActionTableElement::Shift(31)),
+    ((9, terminal(9)), ActionTableElement::Shift(59)),
+    ((9, terminal(10)), ActionTableElement::Shift(60)),
+    ((10, terminal(2)), ActionTableElement::Reduce(5)),
+    ((10, terminal(3)), ActionTableElement::Shift(50)),
+    ((10, terminal(4)), ActionTableElement::Shift(51)),
+    ((11, terminal(5)), ActionTableElement::Shift(63)),
+    ((12, terminal(10)), ActionTableElement::Shift(72)),
+    ((12, terminal(20)), ActionTableElement::Shift(69)),
+    ((12, terminal(26)), ActionTableElement::Shift(71)),
+    ((12, terminal(27)), ActionTableElement::Shift(70)),
+    ((13, terminal(10)), ActionTableElement::Shift(72)),
+    ((13, terminal(20)), ActionTableElement::Shift(69)),
+    ((13, terminal(26)), ActionTableElement::Shift(71)),
+    ((13, terminal(27)), ActionTableElement::Shift(70)),
+    ((14, terminal(10)), ActionTableElement::Shift(72)),
+    ((14, terminal(20)), ActionTableElement::Shift(69)),
+    ((14, terminal(26)), ActionTableElement::Shift(71)),
+    ((14, terminal(27)), ActionTableElement::Shift(70)),
+    ((15, terminal(7)), ActionTableElement::Reduce(56)),
+    ((15, terminal(10)), ActionTableElement::Reduce(56)),
+    ((15, terminal(22)), ActionTableElement::Shift(36)),
+    ((15, terminal(23)), ActionTableElement::Shift(37)),
+    ((16, terminal(6)), ActionTableElement::Shift(80)),
+    ((17, terminal(9)), ActionTableElement::Shift(82)),
+    ((18, terminal(22)), ActionTableElement::Shift(84)),
+    ((19, terminal(27)), ActionTableElement::Shift(70)),
+    ((20, terminal(1)), ActionTableElement::Reduce(18)),
+    ((20, terminal(6)), ActionTableElement::Shift(80)),
+    ((20, terminal(7)), ActionTableElement::Reduce(56)),
+    ((20, terminal(9)), ActionTableElement::Shift(82)),
+    ((20, terminal(10)), ActionTableElement::Reduce(56)),
+    ((20, terminal(22)), ActionTableElement::Shift(93)),
+    ((20, terminal(23)), ActionTableElement::Shift(37)),
+    ((21, terminal(1)), ActionTableElement::Reduce(32)),
+    ((21, terminal(14)), ActionTableElement::Reduce(32)),
+    ((21, terminal(16)), ActionTableElement::Reduce(32)),
+    ((21, terminal(21)), ActionTableElement::Reduce(32)),
+    ((21, terminal(23)), ActionTableElement::Shift(34)),
+    ((22, terminal(12)), ActionTableElement::Shift(97)),
+    ((22, terminal(13)), ActionTableElement::Shift(98)),
+    ((23, terminal(1)), ActionTableElement::Reduce(43)),
+    ((23, terminal(9)), ActionTableElement::Reduce(43)),
+    ((23, terminal(10)), ActionTableElement::Reduce(43)),
+    ((23, terminal(14)), ActionTableElement::Reduce(43)),
+    ((23, terminal(16)), ActionTableElement::Reduce(43)),
+    ((23, terminal(17)), ActionTableElement::Reduce(43)),
+    ((23, terminal(18)), ActionTableElement::Reduce(43)),
+    ((23, terminal(19)), ActionTableElement::Reduce(43)),
+    ((23, terminal(20)), ActionTableElement::Reduce(43)),
+    ((23, terminal(21)), ActionTableElement::Reduce(43)),
+    ((23, terminal(23)), ActionTableElement::Reduce(43)),
+    ((23, terminal(26)), ActionTableElement::Reduce(43)),
+    ((24, terminal(1)), ActionTableElement::Reduce(44)),
+    ((24, terminal(9)), ActionTableElement::Reduce(44)),
+    ((24, terminal(10)), ActionTableElement::Reduce(44)),
+    ((24, terminal(14)), ActionTableElement::Reduce(44)),
+    ((24, terminal(16)), ActionTableElement::Reduce(44)),
+    ((24, terminal(17)), ActionTableElement::Shift(56)),
+    ((24, terminal(18)), ActionTableElement::Shift(54)),
+    ((24, terminal(19)), ActionTableElement::Shift(55)),
+    ((24, terminal(20)), ActionTableElement::Reduce(44)),
+    ((24, terminal(21)), ActionTableElement::Reduce(44)),
+    ((24, terminal(23)), ActionTableElement::Reduce(44)),
+    ((24, terminal(26)), ActionTableElement::Reduce(44)),
+    ((25, terminal(1)), ActionTableElement::Accept),
+    ((26, terminal(1)), ActionTableElement::Reduce(28)),
+    ((26, terminal(9)), ActionTableElement::Shift(30)),
+    ((26, terminal(10)), ActionTableElement::Shift(32)),
+    ((26, terminal(14)), ActionTableElement::Reduce(28)),
+    ((26, terminal(16)), ActionTableElement::Reduce(28)),
+    ((26, terminal(20)), ActionTableElement::Shift(29)),
+    ((26, terminal(21)), ActionTableElement::Reduce(28)),
+    ((26, terminal(23)), ActionTableElement::Reduce(28)),
+    ((26, terminal(26)), ActionTableElement::Shift(31)),
+    ((27, terminal(9)), ActionTableElement::Shift(104)),
+    ((27, terminal(10)), ActionTableElement::Shift(105)),
+    ((27, terminal(20)), ActionTableElement::Shift(29)),
+    ((27, terminal(26)), ActionTableElement::Shift(31)),
+    ((28, terminal(1)), ActionTableElement::Reduce(31)),
+    ((28, terminal(14)), ActionTableElement::Reduce(31)),
+    ((28, terminal(16)), ActionTableElement::Reduce(31)),
+    ((28, terminal(21)), ActionTableElement::Reduce(31)),
+    ((28, terminal(23)), ActionTableElement::Reduce(31)),
+    ((29, terminal(8)), ActionTableElement::Shift(28)),
+    ((29, terminal(9)), ActionTableElement::Shift(30)),
+    ((29, terminal(10)), ActionTableElement::Shift(32)),
+    ((29, terminal(20)), ActionTableElement::Shift(29)),
+    ((29, terminal(26)), ActionTableElement::Shift(31)),
+    ((30, terminal(1)), ActionTableElement::Reduce(40)),
+    ((30, terminal(9)), ActionTableElement::Reduce(40)),
+    ((30, terminal(10)), ActionTableElement::Reduce(40)),
+    ((30, terminal(12)), ActionTableElement::Reduce(35)),
+    ((30, terminal(13)), ActionTableElement::Reduce(35)),
+    ((30, terminal(14)), ActionTableElement::Reduce(40)),
+    ((30, terminal(16)), ActionTableElement::Reduce(40)),
+    ((30, terminal(17)), ActionTableElement::Reduce(40)),
+    ((30, terminal(18)), ActionTableElement::Reduce(40)),
+    ((30, terminal(19)), ActionTableElement::Reduce(40)),
+    ((30, terminal(20)), ActionTableElement::Reduce(40)),
+    ((30, terminal(21)), ActionTableElement::Reduce(40)),
+    ((30, terminal(23)), ActionTableElement::Reduce(40)),
+    ((30, terminal(26)), ActionTableElement::Reduce(40)),
+    ((31, terminal(1)), ActionTableElement::Reduce(39)),
+    ((31, terminal(9)), ActionTableElement::Reduce(39)),
+    ((31, terminal(10)), ActionTableElement::Reduce(39)),
+    ((31, terminal(14)), ActionTableElement::Reduce(39)),
+    ((31, terminal(16)), ActionTableElement::Reduce(39)),
+    ((31, terminal(17)), ActionTableElement::Reduce(39)),
+    ((31, terminal(18)), ActionTableElement::Reduce(39)),
+    ((31, terminal(19)), ActionTableElement::Reduce(39)),
+    ((31, terminal(20)), ActionTableElement::Reduce(39)),
+    ((31, terminal(21)), ActionTableElement::Reduce(39)),
+    ((31, terminal(23)), ActionTableElement::Reduce(39)),
+    ((31, terminal(26)), ActionTableElement::Reduce(39)),
+    ((32, terminal(1)), ActionTableElement::Reduce(38)),
+    ((32, terminal(9)), ActionTableElement::Reduce(38)),
+    ((32, terminal(10)), ActionTableElement::Reduce(38)),
+    ((32, terminal(12)), ActionTableElement::Reduce(36)),
+    ((32, terminal(13)), ActionTableElement::Reduce(36)),
+    ((32, terminal(14)), ActionTableElement::Reduce(38)),
+    ((32, terminal(16)), ActionTableElement::Reduce(38)),
+    ((32, terminal(17)), ActionTableElement::Reduce(38)),
+    ((32, terminal(18)), ActionTableElement::Reduce(38)),
+    ((32, terminal(19)), ActionTableElement::Reduce(38)),
+    ((32, terminal(20)), ActionTableElement::Reduce(38)),
+    ((32, terminal(21)), ActionTableElement::Reduce(38)),
+    ((32, terminal(23)), ActionTableElement::Reduce(38)),
+    ((32, terminal(26)), ActionTableElement::Reduce(38)),
+    ((33, terminal(1)), ActionTableElement::Accept),
+    ((34, terminal(9)), ActionTableElement::Shift(59)),
+    ((34, terminal(10)), ActionTableElement::Shift(60)),
+    ((35, terminal(1)), ActionTableElement::Accept),
+    ((36, terminal(6)), ActionTableElement::Shift(40)),
+    ((36, terminal(9)), ActionTableElement::Shift(41)),
+    ((37, terminal(24)), ActionTableElement::Shift(109)),
+    ((38, terminal(1)), ActionTableElement::Reduce(88)),
+    ((38, terminal(7)), ActionTableElement::Reduce(88)),
+    ((38, terminal(10)), ActionTableElement::Reduce(88)),
+    ((38, terminal(14)), ActionTableElement::Reduce(88)),
+    ((38, terminal(15)), ActionTableElement::Reduce(88)),
+    ((38, terminal(20)), ActionTableElement::Shift(112)),
+    ((38, terminal(25)), ActionTableElement::Reduce(88)),
+    ((39, terminal(1)), ActionTableElement::Accept),
+    ((40, terminal(1)), ActionTableElement::Reduce(81)),
+    ((40, terminal(7)), ActionTableElement::Reduce(81)),
+    ((40, terminal(10)), ActionTableElement::Reduce(81)),
+    ((40, terminal(14)), ActionTableElement::Reduce(81)),
+    ((40, terminal(15)), ActionTableElement::Reduce(81)),
+    ((40, terminal(20)), ActionTableElement::Reduce(81)),
+    ((40, terminal(25)), ActionTableElement::Reduce(81)),
+    ((41, terminal(1)), ActionTableElement::Reduce(80)),
+    ((41, terminal(7)), ActionTableElement::Reduce(80)),
+    ((41, terminal(10)), ActionTableElement::Reduce(80)),
+    ((41, terminal(14)), ActionTableElement::Reduce(80)),
+    ((41, terminal(15)), ActionTableElement::Reduce(80)),
+    ((41, terminal(20)), ActionTableElement::Reduce(80)),
+    ((41, terminal(25)), ActionTableElement::Reduce(80)),
+    ((42, terminal(1)), ActionTableElement::Reduce(77)),
+    ((42, terminal(15)), ActionTableElement::Shift(115)),
+    ((42, terminal(25)), ActionTableElement::Reduce(77)),
+    ((43, terminal(1)), ActionTableElement::Accept),
+    ((44, terminal(1)), ActionTableElement::Reduce(25)),
+    ((44, terminal(14)), ActionTableElement::Reduce(25)),
+    ((44, terminal(16)), ActionTableElement::Shift(118)),
+    ((44, terminal(21)), ActionTableElement::Reduce(25)),
+    ((45, terminal(1)), ActionTableElement::Accept),
+    ((46, terminal(2)), ActionTableElement::Reduce(6)),
+    ((47, terminal(2)), ActionTableElement::Shift(119)),
+    ((48, terminal(1)), ActionTableElement::Accept),
+    ((49, terminal(1)), ActionTableElement::Reduce(0)),
+    ((49, terminal(5)), ActionTableElement::Shift(63)),
+    ((49, terminal(6)), ActionTableElement::Reduce(0)),
+    ((49, terminal(7)), ActionTableElement::Reduce(0)),
+    ((49, terminal(9)), ActionTableElement::Reduce(0)),
+    ((49, terminal(10)), ActionTableElement::Reduce(0)),
+    ((49, terminal(22)), ActionTableElement::Reduce(0)),
+    ((49, terminal(23)), ActionTableElement::Reduce(0)),
+    ((50, terminal(2)), ActionTableElement::Reduce(3)),
+    ((51, terminal(2)), ActionTableElement::Reduce(4)),
+    ((52, terminal(1)), ActionTableElement::Reduce(53)),
+    ((52, terminal(9)), ActionTableElement::Reduce(53)),
+    ((52, terminal(10)), ActionTableElement::Reduce(53)),
+    ((52, terminal(14)), ActionTableElement::Reduce(53)),
+    ((52, terminal(16)), ActionTableElement::Reduce(53)),
+    ((52, terminal(19)), ActionTableElement::Shift(123)),
+    ((52, terminal(20)), ActionTableElement::Reduce(53)),
+    ((52, terminal(21)), ActionTableElement::Reduce(53)),
+    ((52, terminal(23)), ActionTableElement::Reduce(53)),
+    ((52, terminal(26)), ActionTableElement::Reduce(53)),
+    ((52, terminal(27)), ActionTableElement::Reduce(53)),
+    ((53, terminal(1)), ActionTableElement::Accept),
+    ((54, terminal(1)), ActionTableElement::Reduce(51)),
+    ((54, terminal(9)), ActionTableElement::Reduce(51)),
+    ((54, terminal(10)), ActionTableElement::Reduce(51)),
+    ((54, terminal(14)), ActionTableElement::Reduce(51)),
+    ((54, terminal(16)), ActionTableElement::Reduce(51)),
+    ((54, terminal(19)), ActionTableElement::Reduce(51)),
+    ((54, terminal(20)), ActionTableElement::Reduce(51)),
+    ((54, terminal(21)), ActionTableElement::Reduce(51)),
+    ((54, terminal(23)), ActionTableElement::Reduce(51)),
+    ((54, terminal(26)), ActionTableElement::Reduce(51)),
+    ((54, terminal(27)), ActionTableElement::Reduce(51)),
+    ((55, terminal(1)), ActionTableElement::Reduce(52)),
+    ((55, terminal(9)), ActionTableElement::Reduce(52)),
+    ((55, terminal(10)), ActionTableElement::Reduce(52)),
+    ((55, terminal(14)), ActionTableElement::Reduce(52)),
+    ((55, terminal(16)), ActionTableElement::Reduce(52)),
+    ((55, terminal(19)), ActionTableElement::Reduce(52)),
+    ((55, terminal(20)), ActionTableElement::Reduce(52)),
+    ((55, terminal(21)), ActionTableElement::Reduce(52)),
+    ((55, terminal(23)), ActionTableElement::Reduce(52)),
+    ((55, terminal(26)), ActionTableElement::Reduce(52)),
+    ((55, terminal(27)), ActionTableElement::Reduce(52)),
+    ((56, terminal(1)), ActionTableElement::Reduce(50)),
+    ((56, terminal(9)), ActionTableElement::Reduce(50)),
+    ((56, terminal(10)), ActionTableElement::Reduce(50)),
+    ((56, terminal(14)), ActionTableElement::Reduce(50)),
+    ((56, terminal(16)), ActionTableElement::Reduce(50)),
+    ((56, terminal(19)), ActionTableElement::Reduce(50)),
+    ((56, terminal(20)), ActionTableElement::Reduce(50)),
+    ((56, terminal(21)), ActionTableElement::Reduce(50)),
+    ((56, terminal(23)), ActionTableElement::Reduce(50)),
+    ((56, terminal(26)), ActionTableElement::Reduce(50)),
+    ((56, terminal(27)), ActionTableElement::Reduce(50)),
+    ((57, terminal(1)), ActionTableElement::Accept),
+    ((58, terminal(1)), ActionTableElement::Accept),
+    ((59, terminal(1)), ActionTableElement::Reduce(35)),
+    ((59, terminal(12)), ActionTableElement::Reduce(35)),
+    ((59, terminal(13)), ActionTableElement::Reduce(35)),
+    ((59, terminal(14)), ActionTableElement::Reduce(35)),
+    ((59, terminal(16)), ActionTableElement::Reduce(35)),
+    ((59, terminal(21)), ActionTableElement::Reduce(35)),
+    ((60, terminal(1)), ActionTableElement::Reduce(36)),
+    ((60, terminal(12)), ActionTableElement::Reduce(36)),
+    ((60, terminal(13)), ActionTableElement::Reduce(36)),
+    ((60, terminal(14)), ActionTableElement::Reduce(36)),
+    ((60, terminal(16)), ActionTableElement::Reduce(36)),
+    ((60, terminal(21)), ActionTableElement::Reduce(36)),
+    ((61, terminal(1)), ActionTableElement::Accept),
+    ((62, terminal(1)), ActionTableElement::Accept),
+    ((63, terminal(9)), ActionTableElement::Shift(125)),
+    ((63, terminal(10)), ActionTableElement::Shift(126)),
+    ((64, terminal(1)), ActionTableElement::Reduce(67)),
+    ((64, terminal(14)), ActionTableElement::Reduce(67)),
+    ((64, terminal(16)), ActionTableElement::Reduce(67)),
+    ((64, terminal(21)), ActionTableElement::Reduce(67)),
+    ((65, terminal(1)), ActionTableElement::Reduce(44)),
+    ((65, terminal(10)), ActionTableElement::Reduce(44)),
+    ((65, terminal(14)), ActionTableElement::Reduce(44)),
+    ((65, terminal(16)), ActionTableElement::Reduce(44)),
+    ((65, terminal(17)), ActionTableElement::Shift(56)),
+    ((65, terminal(18)), ActionTableElement::Shift(54)),
+    ((65, terminal(19)), ActionTableElement::Shift(55)),
+    ((65, terminal(20)), ActionTableElement::Reduce(44)),
+    ((65, terminal(21)), ActionTableElement::Reduce(44)),
+    ((65, terminal(26)), ActionTableElement::Reduce(44)),
+    ((65, terminal(27)), ActionTableElement::Reduce(44)),
+    ((66, terminal(1)), ActionTableElement::Accept),
+    ((67, terminal(1)), ActionTableElement::Reduce(65)),
+    ((67, terminal(10)), ActionTableElement::Shift(72)),
+    ((67, terminal(14)), ActionTableElement::Reduce(65)),
+    ((67, terminal(16)), ActionTableElement::Reduce(65)),
+    ((67, terminal(20)), ActionTableElement::Shift(69)),
+    ((67, terminal(21)), ActionTableElement::Reduce(65)),
+    ((67, terminal(26)), ActionTableElement::Shift(71)),
+    ((67, terminal(27)), ActionTableElement::Shift(70)),
+    ((68, terminal(1)), ActionTableElement::Reduce(68)),
+    ((68, terminal(10)), ActionTableElement::Reduce(68)),
+    ((68, terminal(14)), ActionTableElement::Reduce(68)),
+    ((68, terminal(16)), ActionTableElement::Reduce(68)),
+    ((68, terminal(17)), ActionTableElement::Reduce(68)),
+    ((68, terminal(18)), ActionTableElement::Reduce(68)),
+    ((68, terminal(19)), ActionTableElement::Reduce(68)),
+    ((68, terminal(20)), ActionTableElement::Reduce(68)),
+    ((68, terminal(21)), ActionTableElement::Reduce(68)),
+    ((68, terminal(26)), ActionTableElement::Reduce(68)),
+    ((68, terminal(27)), ActionTableElement::Reduce(68)),
+    ((69, terminal(10)), ActionTableElement::Shift(72)),
+    ((69, terminal(20)), ActionTableElement::Shift(69)),
+    ((69, terminal(26)), ActionTableElement::Shift(71)),
+    ((69, terminal(27)), ActionTableElement::Shift(70)),
+    ((70, terminal(1)), ActionTableElement::Reduce(73)),
+    ((70, terminal(10)), ActionTableElement::Reduce(73)),
+    ((70, terminal(14)), ActionTableElement::Reduce(73)),
+    ((70, terminal(16)), ActionTableElement::Reduce(73)),
+    ((70, terminal(17)), ActionTableElement::Reduce(73)),
+    ((70, terminal(18)), ActionTableElement::Reduce(73)),
+    ((70, terminal(19)), ActionTableElement::Reduce(73)),
+    ((70, terminal(20)), ActionTableElement::Reduce(73)),
+    ((70, terminal(21)), ActionTableElement::Reduce(73)),
+    ((70, terminal(26)), ActionTableElement::Reduce(73)),
+    ((70, terminal(27)), ActionTableElement::Reduce(73)),
+    ((71, terminal(1)), ActionTableElement::Reduce(69)),
+    ((71, terminal(10)), ActionTableElement::Reduce(69)),
+    ((71, terminal(14)), ActionTableElement::Reduce(69)),
+    ((71, terminal(16)), ActionTableElement::Reduce(69)),
+    ((71, terminal(17)), ActionTableElement::Reduce(69)),
+    ((71, terminal(18)), ActionTableElement::Reduce(69)),
+    ((71, terminal(19)), ActionTableElement::Reduce(69)),
+    ((71, terminal(20)), ActionTableElement::Reduce(69)),
+    ((71, terminal(21)), ActionTableElement::Reduce(69)),
+    ((71, terminal(26)), ActionTableElement::Reduce(69)),
+    ((71, terminal(27)), ActionTableElement::Reduce(69)),
+    ((72, terminal(1)), ActionTableElement::Reduce(70)),
+    ((72, terminal(10)), ActionTableElement::Reduce(70)),
+    ((72, terminal(14)), ActionTableElement::Reduce(70)),
+    ((72, terminal(16)), ActionTableElement::Reduce(70)),
+    ((72, terminal(17)), ActionTableElement::Reduce(70)),
+    ((72, terminal(18)), ActionTableElement::Reduce(70)),
+    ((72, terminal(19)), ActionTableElement::Reduce(70)),
+    ((72, terminal(20)), ActionTableElement::Reduce(70)),
+    ((72, terminal(21)), ActionTableElement::Reduce(70)),
+    ((72, terminal(26)), ActionTableElement::Reduce(70)),
+    ((72, terminal(27)), ActionTableElement::Reduce(70)),
+    ((73, terminal(1)), ActionTableElement::Reduce(62)),
+    ((73, terminal(14)), ActionTableElement::Reduce(62)),
+    ((73, terminal(16)), ActionTableElement::Shift(132)),
+    ((73, terminal(21)), ActionTableElement::Reduce(62)),
+    ((74, terminal(1)), ActionTableElement::Accept),
+    ((75, terminal(1)), ActionTableElement::Accept),
+    ((76, terminal(7)), ActionTableElement::Shift(134)),
+    ((76, terminal(10)), ActionTableElement::Reduce(58)),
+    ((77, terminal(7)), ActionTableElement::Reduce(57)),
+    ((77, terminal(10)), ActionTableElement::Reduce(57)),
+    ((78, terminal(1)), ActionTableElement::Accept),
+    ((79, terminal(1)), ActionTableElement::Accept),
+    ((80, terminal(10)), ActionTableElement::Shift(135)),
+    ((81, terminal(1)), ActionTableElement::Accept),
+    ((82, terminal(11)), ActionTableElement::Shift(138)),
+    ((82, terminal(12)), ActionTableElement::Shift(137)),
+    ((83, terminal(1)), ActionTableElement::Accept),
+    ((84, terminal(6)), ActionTableElement::Shift(40)),
+    ((84, terminal(9)), ActionTableElement::Shift(41)),
+    ((85, terminal(1)), ActionTableElement::Accept),
+    ((86, terminal(1)), ActionTableElement::Reduce(18)),
+    ((86, terminal(6)), ActionTableElement::Shift(80)),
+    ((86, terminal(7)), ActionTableElement::Reduce(56)),
+    ((86, terminal(9)), ActionTableElement::Shift(82)),
+    ((86, terminal(10)), ActionTableElement::Reduce(56)),
+    ((86, terminal(22)), ActionTableElement::Shift(93)),
+    ((86, terminal(23)), ActionTableElement::Shift(37)),
+    ((87, terminal(1)), ActionTableElement::Reduce(20)),
+    ((88, terminal(1)), ActionTableElement::Reduce(15)),
+    ((88, terminal(6)), ActionTableElement::Reduce(15)),
+    ((88, terminal(7)), ActionTableElement::Reduce(15)),
+    ((88, terminal(9)), ActionTableElement::Reduce(15)),
+    ((88, terminal(10)), ActionTableElement::Reduce(15)),
+    ((88, terminal(22)), ActionTableElement::Reduce(15)),
+    ((88, terminal(23)), ActionTableElement::Reduce(15)),
+    ((89, terminal(1)), ActionTableElement::Reduce(17)),
+    ((89, terminal(6)), ActionTableElement::Reduce(17)),
+    ((89, terminal(7)), ActionTableElement::Reduce(17)),
+    ((89, terminal(9)), ActionTableElement::Reduce(17)),
+    ((89, terminal(10)), ActionTableElement::Reduce(17)),
+    ((89, terminal(22)), ActionTableElement::Reduce(17)),
+    ((89, terminal(23)), ActionTableElement::Reduce(17)),
+    ((90, terminal(1)), ActionTableElement::Reduce(14)),
+    ((90, terminal(6)), ActionTableElement::Reduce(14)),
+    ((90, terminal(7)), ActionTableElement::Reduce(14)),
+    ((90, terminal(9)), ActionTableElement::Reduce(14)),
+    ((90, terminal(10)), ActionTableElement::Reduce(14)),
+    ((90, terminal(22)), ActionTableElement::Reduce(14)),
+    ((90, terminal(23)), ActionTableElement::Reduce(14)),
+    ((91, terminal(1)), ActionTableElement::Reduce(16)),
+    ((91, terminal(6)), ActionTableElement::Reduce(16)),
+    ((91, terminal(7)), ActionTableElement::Reduce(16)),
+    ((91, terminal(9)), ActionTableElement::Reduce(16)),
+    ((91, terminal(10)), ActionTableElement::Reduce(16)),
+    ((91, terminal(22)), ActionTableElement::Reduce(16)),
+    ((91, terminal(23)), ActionTableElement::Reduce(16)),
+    ((92, terminal(1)), ActionTableElement::Accept),
+    ((93, terminal(6)), ActionTableElement::Shift(40)),
+    ((93, terminal(9)), ActionTableElement::Shift(41)),
+    ((94, terminal(1)), ActionTableElement::Reduce(34)),
+    ((94, terminal(14)), ActionTableElement::Reduce(34)),
+    ((94, terminal(16)), ActionTableElement::Reduce(34)),
+    ((94, terminal(21)), ActionTableElement::Reduce(34)),
+    ((95, terminal(1)), ActionTableElement::Reduce(33)),
+    ((95, terminal(14)), ActionTableElement::Reduce(33)),
+    ((95, terminal(16)), ActionTableElement::Reduce(33)),
+    ((95, terminal(21)), ActionTableElement::Reduce(33)),
+    ((96, terminal(1)), ActionTableElement::Reduce(49)),
+    ((96, terminal(9)), ActionTableElement::Reduce(49)),
+    ((96, terminal(10)), ActionTableElement::Reduce(49)),
+    ((96, terminal(20)), ActionTableElement::Reduce(49)),
+    ((96, terminal(26)), ActionTableElement::Reduce(49)),
+    ((97, terminal(1)), ActionTableElement::Reduce(47)),
+    ((97, terminal(9)), ActionTableElement::Reduce(47)),
+    ((97, terminal(10)), ActionTableElement::Reduce(47)),
+    ((97, terminal(20)), ActionTableElement::Reduce(47)),
+    ((97, terminal(26)), ActionTableElement::Reduce(47)),
+    ((98, terminal(1)), ActionTableElement::Reduce(48)),
+    ((98, terminal(9)), ActionTableElement::Reduce(48)),
+    ((98, terminal(10)), ActionTableElement::Reduce(48)),
+    ((98, terminal(20)), ActionTableElement::Reduce(48)),
+    ((98, terminal(26)), ActionTableElement::Reduce(48)),
+    ((99, terminal(1)), ActionTableElement::Reduce(46)),
+    ((99, terminal(9)), ActionTableElement::Reduce(46)),
+    ((99, terminal(10)), ActionTableElement::Reduce(46)),
+    ((99, terminal(14)), ActionTableElement::Reduce(46)),
+    ((99, terminal(16)), ActionTableElement::Reduce(46)),
+    ((99, terminal(20)), ActionTableElement::Reduce(46)),
+    ((99, terminal(21)), ActionTableElement::Reduce(46)),
+    ((99, terminal(23)), ActionTableElement::Reduce(46)),
+    ((99, terminal(26)), ActionTableElement::Reduce(46)),
+    ((100, terminal(1)), ActionTableElement::Reduce(45)),
+    ((100, terminal(9)), ActionTableElement::Reduce(45)),
+    ((100, terminal(10)), ActionTableElement::Reduce(45)),
+    ((100, terminal(14)), ActionTableElement::Reduce(45)),
+    ((100, terminal(16)), ActionTableElement::Reduce(45)),
+    ((100, terminal(20)), ActionTableElement::Reduce(45)),
+    ((100, terminal(21)), ActionTableElement::Reduce(45)),
+    ((100, terminal(23)), ActionTableElement::Reduce(45)),
+    ((100, terminal(26)), ActionTableElement::Reduce(45)),
+    ((100, terminal(27)), ActionTableElement::Reduce(45)),
+    ((101, terminal(1)), ActionTableElement::Reduce(30)),
+    ((101, terminal(14)), ActionTableElement::Reduce(30)),
+    ((101, terminal(16)), ActionTableElement::Reduce(30)),
+    ((101, terminal(21)), ActionTableElement::Reduce(30)),
+    ((101, terminal(23)), ActionTableElement::Reduce(30)),
+    ((102, terminal(1)), ActionTableElement::Reduce(28)),
+    ((102, terminal(9)), ActionTableElement::Shift(30)),
+    ((102, terminal(10)), ActionTableElement::Shift(32)),
+    ((102, terminal(14)), ActionTableElement::Reduce(28)),
+    ((102, terminal(16)), ActionTableElement::Reduce(28)),
+    ((102, terminal(20)), ActionTableElement::Shift(29)),
+    ((102, terminal(21)), ActionTableElement::Reduce(28)),
+    ((102, terminal(23)), ActionTableElement::Reduce(28)),
+    ((102, terminal(26)), ActionTableElement::Shift(31)),
+    ((103, terminal(1)), ActionTableElement::Reduce(42)),
+    ((103, terminal(9)), ActionTableElement::Reduce(42)),
+    ((103, terminal(10)), ActionTableElement::Reduce(42)),
+    ((103, terminal(14)), ActionTableElement::Reduce(42)),
+    ((103, terminal(16)), ActionTableElement::Reduce(42)),
+    ((103, terminal(17)), ActionTableElement::Reduce(42)),
+    ((103, terminal(18)), ActionTableElement::Reduce(42)),
+    ((103, terminal(19)), ActionTableElement::Reduce(42)),
+    ((103, terminal(20)), ActionTableElement::Reduce(42)),
+    ((103, terminal(21)), ActionTableElement::Reduce(42)),
+    ((103, terminal(23)), ActionTableElement::Reduce(42)),
+    ((103, terminal(26)), ActionTableElement::Reduce(42)),
+    ((104, terminal(1)), ActionTableElement::Reduce(40)),
+    ((104, terminal(9)), ActionTableElement::Reduce(40)),
+    ((104, terminal(10)), ActionTableElement::Reduce(40)),
+    ((104, terminal(14)), ActionTableElement::Reduce(40)),
+    ((104, terminal(16)), ActionTableElement::Reduce(40)),
+    ((104, terminal(17)), ActionTableElement::Reduce(40)),
+    ((104, terminal(18)), ActionTableElement::Reduce(40)),
+    ((104, terminal(19)), ActionTableElement::Reduce(40)),
+    ((104, terminal(20)), ActionTableElement::Reduce(40)),
+    ((104, terminal(21)), ActionTableElement::Reduce(40)),
+    ((104, terminal(23)), ActionTableElement::Reduce(40)),
+    ((104, terminal(26)), ActionTableElement::Reduce(40)),
+    ((105, terminal(1)), ActionTableElement::Reduce(38)),
+    ((105, terminal(9)), ActionTableElement::Reduce(38)),
+    ((105, terminal(10)), ActionTableElement::Reduce(38)),
+    ((105, terminal(14)), ActionTableElement::Reduce(38)),
+    ((105, terminal(16)), ActionTableElement::Reduce(38)),
+    ((105, terminal(17)), ActionTableElement::Reduce(38)),
+    ((105, terminal(18)), ActionTableElement::Reduce(38)),
+    ((105, terminal(19)), ActionTableElement::Reduce(38)),
+    ((105, terminal(20)), ActionTableElement::Reduce(38)),
+    ((105, terminal(21)), ActionTableElement::Reduce(38)),
+    ((105, terminal(23)), ActionTableElement::Reduce(38)),
+    ((105, terminal(26)), ActionTableElement::Reduce(38)),
+    ((106, terminal(21)), ActionTableElement::Shift(143)),
+    ((107, terminal(1)), ActionTableElement::Reduce(37)),
+    ((107, terminal(14)), ActionTableElement::Reduce(37)),
+    ((107, terminal(16)), ActionTableElement::Reduce(37)),
+    ((107, terminal(21)), ActionTableElement::Reduce(37)),
+    ((108, terminal(1)), ActionTableElement::Reduce(74)),
+    ((108, terminal(7)), ActionTableElement::Reduce(74)),
+    ((108, terminal(10)), ActionTableElement::Reduce(74)),
+    ((109, terminal(6)), ActionTableElement::Shift(40)),
+    ((109, terminal(9)), ActionTableElement::Shift(41)),
+    ((110, terminal(1)), ActionTableElement::Reduce(89)),
+    ((110, terminal(7)), ActionTableElement::Reduce(89)),
+    ((110, terminal(10)), ActionTableElement::Reduce(89)),
+    ((110, terminal(14)), ActionTableElement::Reduce(89)),
+    ((110, terminal(15)), ActionTableElement::Reduce(89)),
+    ((110, terminal(25)), ActionTableElement::Reduce(89)),
+    ((111, terminal(1)), ActionTableElement::Reduce(90)),
+    ((111, terminal(7)), ActionTableElement::Reduce(90)),
+    ((111, terminal(10)), ActionTableElement::Reduce(90)),
+    ((111, terminal(14)), ActionTableElement::Reduce(90)),
+    ((111, terminal(15)), ActionTableElement::Reduce(90)),
+    ((111, terminal(25)), ActionTableElement::Reduce(90)),
+    ((112, terminal(10)), ActionTableElement::Shift(147)),
+    ((112, terminal(26)), ActionTableElement::Shift(146)),
+    ((113, terminal(1)), ActionTableElement::Reduce(77)),
+    ((113, terminal(15)), ActionTableElement::Shift(115)),
+    ((113, terminal(25)), ActionTableElement::Reduce(77)),
+    ((114, terminal(1)), ActionTableElement::Reduce(79)),
+    ((114, terminal(25)), ActionTableElement::Reduce(79)),
+    ((115, terminal(6)), ActionTableElement::Shift(40)),
+    ((115, terminal(9)), ActionTableElement::Shift(41)),
+    ((116, terminal(1)), ActionTableElement::Reduce(25)),
+    ((116, terminal(14)), ActionTableElement::Reduce(25)),
+    ((116, terminal(16)), ActionTableElement::Shift(118)),
+    ((116, terminal(21)), ActionTableElement::Reduce(25)),
+    ((117, terminal(1)), ActionTableElement::Reduce(27)),
+    ((117, terminal(14)), ActionTableElement::Reduce(27)),
+    ((117, terminal(21)), ActionTableElement::Reduce(27)),
+    ((118, terminal(8)), ActionTableElement::Shift(28)),
+    ((118, terminal(9)), ActionTableElement::Shift(30)),
+    ((118, terminal(10)), ActionTableElement::Shift(32)),
+    ((118, terminal(20)), ActionTableElement::Shift(29)),
+    ((118, terminal(26)), ActionTableElement::Shift(31)),
+    ((119, terminal(9)), ActionTableElement::Shift(125)),
+    ((119, terminal(10)), ActionTableElement::Shift(126)),
+    ((120, terminal(1)), ActionTableElement::Reduce(18)),
+    ((120, terminal(6)), ActionTableElement::Shift(80)),
+    ((120, terminal(7)), ActionTableElement::Reduce(56)),
+    ((120, terminal(9)), ActionTableElement::Shift(82)),
+    ((120, terminal(10)), ActionTableElement::Reduce(56)),
+    ((120, terminal(22)), ActionTableElement::Shift(93)),
+    ((120, terminal(23)), ActionTableElement::Shift(37)),
+    ((121, terminal(1)), ActionTableElement::Reduce(0)),
+    ((121, terminal(5)), ActionTableElement::Shift(63)),
+    ((121, terminal(6)), ActionTableElement::Reduce(0)),
+    ((121, terminal(7)), ActionTableElement::Reduce(0)),
+    ((121, terminal(9)), ActionTableElement::Reduce(0)),
+    ((121, terminal(10)), ActionTableElement::Reduce(0)),
+    ((121, terminal(22)), ActionTableElement::Reduce(0)),
+    ((121, terminal(23)), ActionTableElement::Reduce(0)),
+    ((122, terminal(1)), ActionTableElement::Reduce(55)),
+    ((122, terminal(9)), ActionTableElement::Reduce(55)),
+    ((122, terminal(10)), ActionTableElement::Reduce(55)),
+    ((122, terminal(14)), ActionTableElement::Reduce(55)),
+    ((122, terminal(16)), ActionTableElement::Reduce(55)),
+    ((122, terminal(20)), ActionTableElement::Reduce(55)),
+    ((122, terminal(21)), ActionTableElement::Reduce(55)),
+    ((122, terminal(23)), ActionTableElement::Reduce(55)),
+    ((122, terminal(26)), ActionTableElement::Reduce(55)),
+    ((122, terminal(27)), ActionTableElement::Reduce(55)),
+    ((123, terminal(1)), ActionTableElement::Reduce(54)),
+    ((123, terminal(9)), ActionTableElement::Reduce(54)),
+    ((123, terminal(10)), ActionTableElement::Reduce(54)),
+    ((123, terminal(14)), ActionTableElement::Reduce(54)),
+    ((123, terminal(16)), ActionTableElement::Reduce(54)),
+    ((123, terminal(20)), ActionTableElement::Reduce(54)),
+    ((123, terminal(21)), ActionTableElement::Reduce(54)),
+    ((123, terminal(23)), ActionTableElement::Reduce(54)),
+    ((123, terminal(26)), ActionTableElement::Reduce(54)),
+    ((123, terminal(27)), ActionTableElement::Reduce(54)),
+    ((124, terminal(14)), ActionTableElement::Reduce(11)),
+    ((124, terminal(15)), ActionTableElement::Shift(157)),
+    ((125, terminal(14)), ActionTableElement::Reduce(8)),
+    ((125, terminal(15)), ActionTableElement::Reduce(8)),
+    ((126, terminal(14)), ActionTableElement::Reduce(7)),
+    ((126, terminal(15)), ActionTableElement::Reduce(7)),
+    ((127, terminal(1)), ActionTableElement::Reduce(72)),
+    ((127, terminal(10)), ActionTableElement::Reduce(72)),
+    ((127, terminal(14)), ActionTableElement::Reduce(72)),
+    ((127, terminal(16)), ActionTableElement::Reduce(72)),
+    ((127, terminal(20)), ActionTableElement::Reduce(72)),
+    ((127, terminal(21)), ActionTableElement::Reduce(72)),
+    ((127, terminal(26)), ActionTableElement::Reduce(72)),
+    ((127, terminal(27)), ActionTableElement::Reduce(72)),
+    ((128, terminal(1)), ActionTableElement::Reduce(66)),
+    ((128, terminal(14)), ActionTableElement::Reduce(66)),
+    ((128, terminal(16)), ActionTableElement::Reduce(66)),
+    ((128, terminal(21)), ActionTableElement::Reduce(66)),
+    ((129, terminal(21)), ActionTableElement::Shift(158)),
+    ((130, terminal(1)), ActionTableElement::Reduce(62)),
+    ((130, terminal(14)), ActionTableElement::Reduce(62)),
+    ((130, terminal(16)), ActionTableElement::Shift(132)),
+    ((130, terminal(21)), ActionTableElement::Reduce(62)),
+    ((131, terminal(1)), ActionTableElement::Reduce(64)),
+    ((131, terminal(14)), ActionTableElement::Reduce(64)),
+    ((131, terminal(21)), ActionTableElement::Reduce(64)),
+    ((132, terminal(10)), ActionTableElement::Shift(72)),
+    ((132, terminal(20)), ActionTableElement::Shift(69)),
+    ((132, terminal(26)), ActionTableElement::Shift(71)),
+    ((132, terminal(27)), ActionTableElement::Shift(70)),
+    ((133, terminal(10)), ActionTableElement::Shift(161)),
+    ((134, terminal(10)), ActionTableElement::Reduce(59)),
+    ((135, terminal(14)), ActionTableElement::Shift(162)),
+    ((136, terminal(8)), ActionTableElement::Shift(28)),
+    ((136, terminal(9)), ActionTableElement::Shift(30)),
+    ((136, terminal(10)), ActionTableElement::Shift(32)),
+    ((136, terminal(20)), ActionTableElement::Shift(29)),
+    ((136, terminal(26)), ActionTableElement::Shift(31)),
+    ((137, terminal(8)), ActionTableElement::Reduce(22)),
+    ((137, terminal(9)), ActionTableElement::Reduce(22)),
+    ((137, terminal(10)), ActionTableElement::Reduce(22)),
+    ((137, terminal(20)), ActionTableElement::Reduce(22)),
+    ((137, terminal(26)), ActionTableElement::Reduce(22)),
+    ((137, terminal(27)), ActionTableElement::Reduce(22)),
+    ((138, terminal(8)), ActionTableElement::Reduce(21)),
+    ((138, terminal(9)), ActionTableElement::Reduce(21)),
+    ((138, terminal(10)), ActionTableElement::Reduce(21)),
+    ((138, terminal(20)), ActionTableElement::Reduce(21)),
+    ((138, terminal(26)), ActionTableElement::Reduce(21)),
+    ((138, terminal(27)), ActionTableElement::Reduce(21)),
+    ((139, terminal(14)), ActionTableElement::Shift(164)),
+    ((140, terminal(1)), ActionTableElement::Reduce(19)),
+    ((141, terminal(7)), ActionTableElement::Reduce(74)),
+    ((141, terminal(10)), ActionTableElement::Reduce(74)),
+    ((141, terminal(14)), ActionTableElement::Shift(164)),
+    ((142, terminal(1)), ActionTableElement::Reduce(29)),
+    ((142, terminal(14)), ActionTableElement::Reduce(29)),
+    ((142, terminal(16)), ActionTableElement::Reduce(29)),
+    ((142, terminal(21)), ActionTableElement::Reduce(29)),
+    ((142, terminal(23)), ActionTableElement::Reduce(29)),
+    ((143, terminal(1)), ActionTableElement::Reduce(41)),
+    ((143, terminal(9)), ActionTableElement::Reduce(41)),
+    ((143, terminal(10)), ActionTableElement::Reduce(41)),
+    ((143, terminal(14)), ActionTableElement::Reduce(41)),
+    ((143, terminal(16)), ActionTableElement::Reduce(41)),
+    ((143, terminal(17)), ActionTableElement::Reduce(41)),
+    ((143, terminal(18)), ActionTableElement::Reduce(41)),
+    ((143, terminal(19)), ActionTableElement::Reduce(41)),
+    ((143, terminal(20)), ActionTableElement::Reduce(41)),
+    ((143, terminal(21)), ActionTableElement::Reduce(41)),
+    ((143, terminal(23)), ActionTableElement::Reduce(41)),
+    ((143, terminal(26)), ActionTableElement::Reduce(41)),
+    ((144, terminal(25)), ActionTableElement::Shift(165)),
+    ((145, terminal(15)), ActionTableElement::Shift(168)),
+    ((145, terminal(21)), ActionTableElement::Reduce(85)),
+    ((146, terminal(15)), ActionTableElement::Reduce(83)),
+    ((146, terminal(21)), ActionTableElement::Reduce(83)),
+    ((147, terminal(15)), ActionTableElement::Reduce(82)),
+    ((147, terminal(21)), ActionTableElement::Reduce(82)),
+    ((148, terminal(1)), ActionTableElement::Reduce(78)),
+    ((148, terminal(25)), ActionTableElement::Reduce(78)),
+    ((149, terminal(1)), ActionTableElement::Reduce(76)),
+    ((149, terminal(15)), ActionTableElement::Reduce(76)),
+    ((149, terminal(25)), ActionTableElement::Reduce(76)),
+    ((150, terminal(1)), ActionTableElement::Reduce(26)),
+    ((150, terminal(14)), ActionTableElement::Reduce(26)),
+    ((150, terminal(21)), ActionTableElement::Reduce(26)),
+    ((151, terminal(1)), ActionTableElement::Reduce(24)),
+    ((151, terminal(14)), ActionTableElement::Reduce(24)),
+    ((151, terminal(16)), ActionTableElement::Reduce(24)),
+    ((151, terminal(21)), ActionTableElement::Reduce(24)),
+    ((152, terminal(14)), ActionTableElement::Shift(169)),
+    ((153, terminal(1)), ActionTableElement::Reduce(2)),
+    ((154, terminal(1)), ActionTableElement::Reduce(1)),
+    ((154, terminal(6)), ActionTableElement::Reduce(1)),
+    ((154, terminal(7)), ActionTableElement::Reduce(1)),
+    ((154, terminal(9)), ActionTableElement::Reduce(1)),
+    ((154, terminal(10)), ActionTableElement::Reduce(1)),
+    ((154, terminal(22)), ActionTableElement::Reduce(1)),
+    ((154, terminal(23)), ActionTableElement::Reduce(1)),
+    ((155, terminal(14)), ActionTableElement::Reduce(11)),
+    ((155, terminal(15)), ActionTableElement::Shift(157)),
+    ((156, terminal(14)), ActionTableElement::Shift(171)),
+    ((157, terminal(9)), ActionTableElement::Shift(125)),
+    ((157, terminal(10)), ActionTableElement::Shift(126)),
+    ((158, terminal(1)), ActionTableElement::Reduce(71)),
+    ((158, terminal(10)), ActionTableElement::Reduce(71)),
+    ((158, terminal(14)), ActionTableElement::Reduce(71)),
+    ((158, terminal(16)), ActionTableElement::Reduce(71)),
+    ((158, terminal(17)), ActionTableElement::Reduce(71)),
+    ((158, terminal(18)), ActionTableElement::Reduce(71)),
+    ((158, terminal(19)), ActionTableElement::Reduce(71)),
+    ((158, terminal(20)), ActionTableElement::Reduce(71)),
+    ((158, terminal(21)), ActionTableElement::Reduce(71)),
+    ((158, terminal(26)), ActionTableElement::Reduce(71)),
+    ((158, terminal(27)), ActionTableElement::Reduce(71)),
+    ((159, terminal(1)), ActionTableElement::Reduce(63)),
+    ((159, terminal(14)), ActionTableElement::Reduce(63)),
+    ((159, terminal(21)), ActionTableElement::Reduce(63)),
+    ((160, terminal(1)), ActionTableElement::Reduce(61)),
+    ((160, terminal(14)), ActionTableElement::Reduce(61)),
+    ((160, terminal(16)), ActionTableElement::Reduce(61)),
+    ((160, terminal(21)), ActionTableElement::Reduce(61)),
+    ((161, terminal(11)), ActionTableElement::Shift(138)),
+    ((161, terminal(12)), ActionTableElement::Shift(137)),
+    ((162, terminal(1)), ActionTableElement::Reduce(92)),
+    ((162, terminal(6)), ActionTableElement::Reduce(92)),
+    ((162, terminal(7)), ActionTableElement::Reduce(92)),
+    ((162, terminal(9)), ActionTableElement::Reduce(92)),
+    ((162, terminal(10)), ActionTableElement::Reduce(92)),
+    ((162, terminal(22)), ActionTableElement::Reduce(92)),
+    ((162, terminal(23)), ActionTableElement::Reduce(92)),
+    ((163, terminal(14)), ActionTableElement::Shift(174)),
+    ((164, terminal(1)), ActionTableElement::Reduce(91)),
+    ((164, terminal(6)), ActionTableElement::Reduce(91)),
+    ((164, terminal(7)), ActionTableElement::Reduce(91)),
+    ((164, terminal(9)), ActionTableElement::Reduce(91)),
+    ((164, terminal(10)), ActionTableElement::Reduce(91)),
+    ((164, terminal(22)), ActionTableElement::Reduce(91)),
+    ((164, terminal(23)), ActionTableElement::Reduce(91)),
+    ((165, terminal(1)), ActionTableElement::Reduce(75)),
+    ((165, terminal(7)), ActionTableElement::Reduce(75)),
+    ((165, terminal(10)), ActionTableElement::Reduce(75)),
+    ((166, terminal(15)), ActionTableElement::Shift(168)),
+    ((166, terminal(21)), ActionTableElement::Reduce(85)),
+    ((167, terminal(21)), ActionTableElement::Shift(176)),
+    ((168, terminal(10)), ActionTableElement::Shift(147)),
+    ((168, terminal(26)), ActionTableElement::Shift(146)),
+    ((169, terminal(1)), ActionTableElement::Reduce(9)),
+    ((169, terminal(5)), ActionTableElement::Reduce(9)),
+    ((169, terminal(6)), ActionTableElement::Reduce(9)),
+    ((169, terminal(7)), ActionTableElement::Reduce(9)),
+    ((169, terminal(9)), ActionTableElement::Reduce(9)),
+    ((169, terminal(10)), ActionTableElement::Reduce(9)),
+    ((169, terminal(22)), ActionTableElement::Reduce(9)),
+    ((169, terminal(23)), ActionTableElement::Reduce(9)),
+    ((170, terminal(14)), ActionTableElement::Reduce(12)),
+    ((171, terminal(1)), ActionTableElement::Reduce(13)),
+    ((171, terminal(5)), ActionTableElement::Reduce(13)),
+    ((171, terminal(6)), ActionTableElement::Reduce(13)),
+    ((171, terminal(7)), ActionTableElement::Reduce(13)),
+    ((171, terminal(9)), ActionTableElement::Reduce(13)),
+    ((171, terminal(10)), ActionTableElement::Reduce(13)),
+    ((171, terminal(22)), ActionTableElement::Reduce(13)),
+    ((171, terminal(23)), ActionTableElement::Reduce(13)),
+    ((172, terminal(14)), ActionTableElement::Reduce(10)),
+    ((172, terminal(15)), ActionTableElement::Reduce(10)),
+    ((173, terminal(10)), ActionTableElement::Shift(72)),
+    ((173, terminal(20)), ActionTableElement::Shift(69)),
+    ((173, terminal(26)), ActionTableElement::Shift(71)),
+    ((173, terminal(27)), ActionTableElement::Shift(70)),
+    ((174, terminal(1)), ActionTableElement::Reduce(23)),
+    ((174, terminal(6)), ActionTableElement::Reduce(23)),
+    ((174, terminal(7)), ActionTableElement::Reduce(23)),
+    ((174, terminal(9)), ActionTableElement::Reduce(23)),
+    ((174, terminal(10)), ActionTableElement::Reduce(23)),
+    ((174, terminal(22)), ActionTableElement::Reduce(23)),
+    ((174, terminal(23)), ActionTableElement::Reduce(23)),
+    ((175, terminal(21)), ActionTableElement::Reduce(86)),
+    ((176, terminal(1)), ActionTableElement::Reduce(87)),
+    ((176, terminal(7)), ActionTableElement::Reduce(87)),
+    ((176, terminal(10)), ActionTableElement::Reduce(87)),
+    ((176, terminal(14)), ActionTableElement::Reduce(87)),
+    ((176, terminal(15)), ActionTableElement::Reduce(87)),
+    ((176, terminal(25)), ActionTableElement::Reduce(87)),
+    ((177, terminal(15)), ActionTableElement::Reduce(84)),
+    ((177, terminal(21)), ActionTableElement::Reduce(84)),
+    ((178, terminal(14)), ActionTableElement::Shift(179)),
+    ((179, terminal(1)), ActionTableElement::Reduce(60)),
+    ((179, terminal(6)), ActionTableElement::Reduce(60)),
+    ((179, terminal(7)), ActionTableElement::Reduce(60)),
+    ((179, terminal(9)), ActionTableElement::Reduce(60)),
+    ((179, terminal(10)), ActionTableElement::Reduce(60)),
+    ((179, terminal(22)), ActionTableElement::Reduce(60)),
+    ((179, terminal(23)), ActionTableElement::Reduce(60)),
+  ])
+});
+
+static GOTO_TABLE: Lazy<BTreeMap<(usize, NonTerminal), usize>> = Lazy::new(|| {
+  BTreeMap::from([
+    
+    ((0, nonterminal(6)), 25),
+    ((0, nonterminal(8)), 26),
+    ((0, nonterminal(9)), 27),
+    ((0, nonterminal(33)), 21),
+    ((0, nonterminal(35)), 22),
+    ((0, nonterminal(36)), 23),
+    ((0, nonterminal(37)), 24),
+    ((1, nonterminal(7)), 33),
+    ((2, nonterminal(16)), 35),
+    ((3, nonterminal(18)), 39),
+    ((3, nonterminal(50)), 38),
+    ((4, nonterminal(17)), 43),
+    ((4, nonterminal(18)), 42),
+    ((4, nonterminal(50)), 38),
+    ((5, nonterminal(5)), 45),
+    ((5, nonterminal(6)), 44),
+    ((5, nonterminal(8)), 26),
+    ((5, nonterminal(9)), 27),
+    ((5, nonterminal(33)), 21),
+    ((5, nonterminal(35)), 22),
+    ((5, nonterminal(36)), 23),
+    ((5, nonterminal(37)), 24),
+    ((6, nonterminal(0)), 48),
+    ((6, nonterminal(1)), 49),
+    ((6, nonterminal(22)), 46),
+    ((6, nonterminal(23)), 47),
+    ((7, nonterminal(10)), 53),
+    ((7, nonterminal(40)), 52),
+    ((8, nonterminal(8)), 57),
+    ((8, nonterminal(9)), 27),
+    ((8, nonterminal(35)), 22),
+    ((8, nonterminal(36)), 23),
+    ((8, nonterminal(37)), 24),
+    ((9, nonterminal(9)), 58),
+    ((9, nonterminal(35)), 22),
+    ((10, nonterminal(1)), 61),
+    ((10, nonterminal(22)), 46),
+    ((10, nonterminal(23)), 47),
+    ((11, nonterminal(2)), 62),
+    ((12, nonterminal(13)), 66),
+    ((12, nonterminal(14)), 67),
+    ((12, nonterminal(15)), 68),
+    ((12, nonterminal(46)), 64),
+    ((12, nonterminal(47)), 65),
+    ((13, nonterminal(12)), 74),
+    ((13, nonterminal(13)), 73),
+    ((13, nonterminal(14)), 67),
+    ((13, nonterminal(15)), 68),
+    ((13, nonterminal(46)), 64),
+    ((13, nonterminal(47)), 65),
+    ((14, nonterminal(14)), 75),
+    ((14, nonterminal(15)), 68),
+    ((14, nonterminal(47)), 65),
+    ((15, nonterminal(11)), 78),
+    ((15, nonterminal(16)), 77),
+    ((15, nonterminal(42)), 76),
+    ((16, nonterminal(20)), 79),
+    ((17, nonterminal(4)), 81),
+    ((18, nonterminal(19)), 83),
+    ((19, nonterminal(15)), 85),
+    ((20, nonterminal(3)), 92),
+    ((20, nonterminal(4)), 90),
+    ((20, nonterminal(11)), 88),
+    ((20, nonterminal(16)), 77),
+    ((20, nonterminal(19)), 91),
+    ((20, nonterminal(20)), 89),
+    ((20, nonterminal(27)), 86),
+    ((20, nonterminal(28)), 87),
+    ((20, nonterminal(42)), 76),
+    ((21, nonterminal(7)), 95),
+    ((21, nonterminal(34)), 94),
+    ((22, nonterminal(39)), 96),
+    ((24, nonterminal(10)), 100),
+    ((24, nonterminal(38)), 99),
+    ((24, nonterminal(40)), 52),
+    ((26, nonterminal(8)), 102),
+    ((26, nonterminal(9)), 27),
+    ((26, nonterminal(32)), 101),
+    ((26, nonterminal(35)), 22),
+    ((26, nonterminal(36)), 23),
+    ((26, nonterminal(37)), 24),
+    ((27, nonterminal(36)), 103),
+    ((29, nonterminal(5)), 106),
+    ((29, nonterminal(6)), 44),
+    ((29, nonterminal(8)), 26),
+    ((29, nonterminal(9)), 27),
+    ((29, nonterminal(33)), 21),
+    ((29, nonterminal(35)), 22),
+    ((29, nonterminal(36)), 23),
+    ((29, nonterminal(37)), 24),
+    ((34, nonterminal(35)), 107),
+    ((36, nonterminal(18)), 108),
+    ((36, nonterminal(50)), 38),
+    ((38, nonterminal(54)), 110),
+    ((38, nonterminal(55)), 111),
+    ((42, nonterminal(48)), 113),
+    ((42, nonterminal(49)), 114),
+    ((44, nonterminal(30)), 116),
+    ((44, nonterminal(31)), 117),
+    ((49, nonterminal(2)), 121),
+    ((49, nonterminal(21)), 120),
+    ((52, nonterminal(41)), 122),
+    ((63, nonterminal(24)), 124),
+    ((65, nonterminal(10)), 100),
+    ((65, nonterminal(38)), 127),
+    ((65, nonterminal(40)), 52),
+    ((67, nonterminal(14)), 67),
+    ((67, nonterminal(15)), 68),
+    ((67, nonterminal(46)), 128),
+    ((67, nonterminal(47)), 65),
+    ((69, nonterminal(12)), 129),
+    ((69, nonterminal(13)), 73),
+    ((69, nonterminal(14)), 67),
+    ((69, nonterminal(15)), 68),
+    ((69, nonterminal(46)), 64),
+    ((69, nonterminal(47)), 65),
+    ((73, nonterminal(44)), 130),
+    ((73, nonterminal(45)), 131),
+    ((76, nonterminal(43)), 133),
+    ((82, nonterminal(29)), 136),
+    ((84, nonterminal(18)), 139),
+    ((84, nonterminal(50)), 38),
+    ((86, nonterminal(4)), 90),
+    ((86, nonterminal(11)), 88),
+    ((86, nonterminal(16)), 77),
+    ((86, nonterminal(19)), 91),
+    ((86, nonterminal(20)), 89),
+    ((86, nonterminal(27)), 86),
+    ((86, nonterminal(28)), 140),
+    ((86, nonterminal(42)), 76),
+    ((93, nonterminal(18)), 141),
+    ((93, nonterminal(50)), 38),
+    ((102, nonterminal(8)), 102),
+    ((102, nonterminal(9)), 27),
+    ((102, nonterminal(32)), 142),
+    ((102, nonterminal(35)), 22),
+    ((102, nonterminal(36)), 23),
+    ((102, nonterminal(37)), 24),
+    ((109, nonterminal(17)), 144),
+    ((109, nonterminal(18)), 42),
+    ((109, nonterminal(50)), 38),
+    ((112, nonterminal(51)), 145),
+    ((113, nonterminal(48)), 113),
+    ((113, nonterminal(49)), 148),
+    ((115, nonterminal(18)), 149),
+    ((115, nonterminal(50)), 38),
+    ((116, nonterminal(30)), 116),
+    ((116, nonterminal(31)), 150),
+    ((118, nonterminal(6)), 151),
+    ((118, nonterminal(8)), 26),
+    ((118, nonterminal(9)), 27),
+    ((118, nonterminal(33)), 21),
+    ((118, nonterminal(35)), 22),
+    ((118, nonterminal(36)), 23),
+    ((118, nonterminal(37)), 24),
+    ((119, nonterminal(24)), 152),
+    ((120, nonterminal(3)), 153),
+    ((120, nonterminal(4)), 90),
+    ((120, nonterminal(11)), 88),
+    ((120, nonterminal(16)), 77),
+    ((120, nonterminal(19)), 91),
+    ((120, nonterminal(20)), 89),
+    ((120, nonterminal(27)), 86),
+    ((120, nonterminal(28)), 87),
+    ((120, nonterminal(42)), 76),
+    ((121, nonterminal(2)), 121),
+    ((121, nonterminal(21)), 154),
+    ((124, nonterminal(25)), 155),
+    ((124, nonterminal(26)), 156),
+    ((130, nonterminal(44)), 130),
+    ((130, nonterminal(45)), 159),
+    ((132, nonterminal(13)), 160),
+    ((132, nonterminal(14)), 67),
+    ((132, nonterminal(15)), 68),
+    ((132, nonterminal(46)), 64),
+    ((132, nonterminal(47)), 65),
+    ((136, nonterminal(5)), 163),
+    ((136, nonterminal(6)), 44),
+    ((136, nonterminal(8)), 26),
+    ((136, nonterminal(9)), 27),
+    ((136, nonterminal(33)), 21),
+    ((136, nonterminal(35)), 22),
+    ((136, nonterminal(36)), 23),
+    ((136, nonterminal(37)), 24),
+    ((145, nonterminal(52)), 166),
+    ((145, nonterminal(53)), 167),
+    ((155, nonterminal(25)), 155),
+    ((155, nonterminal(26)), 170),
+    ((157, nonterminal(24)), 172),
+    ((161, nonterminal(29)), 173),
+    ((166, nonterminal(52)), 166),
+    ((166, nonterminal(53)), 175),
+    ((168, nonterminal(51)), 177),
+    ((173, nonterminal(12)), 178),
+    ((173, nonterminal(13)), 73),
+    ((173, nonterminal(14)), 67),
+    ((173, nonterminal(15)), 68),
+    ((173, nonterminal(46)), 64),
+    ((173, nonterminal(47)), 65),
+  ])
 });
 
+static START_STATES: Lazy<BTreeMap<NonTerminal, usize>> = Lazy::new(|| {
+  BTreeMap::from([
+    
+    (nonterminal(0), 6),
+    (nonterminal(1), 10),
+    (nonterminal(2), 11),
+    (nonterminal(3), 20),
+    (nonterminal(4), 17),
+    (nonterminal(5), 5),
+    (nonterminal(6), 0),
+    (nonterminal(7), 1),
+    (nonterminal(8), 8),
+    (nonterminal(9), 9),
+    (nonterminal(10), 7),
+    (nonterminal(11), 15),
+    (nonterminal(12), 13),
+    (nonterminal(13), 12),
+    (nonterminal(14), 14),
+    (nonterminal(15), 19),
+    (nonterminal(16), 2),
+    (nonterminal(17), 4),
+    (nonterminal(18), 3),
+    (nonterminal(19), 18),
+    (nonterminal(20), 16),
+  ])
+});
+
+
+impl Default for ChiruParser {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
 
 #[allow(unused)]
 impl ChiruParser {
 
   // 使用模板生成 每个非终结符的编号
   
-  pub const BLOCK: usize = 4; 
-  pub const ANNOTATION: usize = 11; 
-  pub const LEXER_RULE: usize = 9; 
-  pub const PARSER_RULE: usize = 3; 
-  pub const RULES: usize = 2; 
-  pub const ATTRIBUTE: usize = 13; 
-  pub const GRAMMAR_NAME: usize = 1; 
-  pub const REGULAR: usize = 10; 
-  pub const ELEMENT: usize = 7; 
-  pub const ALTERNATIVE: usize = 5; 
-  pub const EPSILON: usize = 6; 
-  pub const EBNF_SUFFIX: usize = 8; 
-  pub const ATTRIBUTES: usize = 12; 
+  pub const ALTERNATIVE: usize = 6; 
+  pub const ALTERNATIVE_LABEL: usize = 7; 
+  pub const ANNOTATION: usize = 16; 
+  pub const ATTRIBUTE: usize = 18; 
+  pub const ATTRIBUTES: usize = 17; 
+  pub const BLOCK: usize = 5; 
   pub const COMPILATION_UNIT: usize = 0; 
+  pub const EBNF_SUFFIX: usize = 10; 
+  pub const ELEMENT: usize = 8; 
+  pub const ELEMENT_LABEL: usize = 9; 
+  pub const GRAMMAR_NAME: usize = 1; 
+  pub const IMPORT_RULE: usize = 2; 
+  pub const LEXER_ALTERNATIVE: usize = 13; 
+  pub const LEXER_BLOCK: usize = 12; 
+  pub const LEXER_ELEMENT: usize = 14; 
+  pub const LEXER_RULE: usize = 11; 
+  pub const MODE_RULE: usize = 20; 
+  pub const PARSER_RULE: usize = 4; 
+  pub const PRECEDENCE_RULE: usize = 19; 
+  pub const REGULAR: usize = 15; 
+  pub const RULES: usize = 3; 
 
 
 
   pub fn new() -> Self {
     Self {
-      error_listeners: vec![Box::new(ConsoleErrorListener::new()),], 
+      error_listeners: vec![Box::new(ConsoleErrorListener::new()),],
     }
   }
 
 
   // 使用模板生成
   
-  pub fn block(&self, token_stream: &mut TokenStream) -> Result<Box<dyn BlockContext>, Box<dyn Error>> {
+  pub fn alternative<'a>(&self, token_stream: &mut TokenStream<'a>) -> Result<Box<dyn AlternativeContext<'a> + 'a>, Box<dyn Error>> {
 
-    if token_stream.peek_next_token()?.token_type == 0 {
+    if token_stream.peek_next_token()?.terminal.id == 0 {
       token_stream.consume()?;
     }
-    
-    let result = ll1_analyze(token_stream, Self::BLOCK,
-      &LL1_TABLE, &PRODUCTIONS,&NONTERMINALS,&SYNC, &self.error_listeners)?;
+
+    let result = lalr_analyze(token_stream, nonterminal(Self::ALTERNATIVE),
+      &ACTION_TABLE, &GOTO_TABLE, &START_STATES, &PRODUCTIONS, &self.error_listeners)?;
     Ok(Box::new(result))
   } 
-  pub fn annotation(&self, token_stream: &mut TokenStream) -> Result<Box<dyn AnnotationContext>, Box<dyn Error>> {
+  pub fn alternative_label<'a>(&self, token_stream: &mut TokenStream<'a>) -> Result<Box<dyn AlternativeLabelContext<'a> + 'a>, Box<dyn Error>> {
 
-    if token_stream.peek_next_token()?.token_type == 0 {
+    if token_stream.peek_next_token()?.terminal.id == 0 {
       token_stream.consume()?;
     }
-    
-    let result = ll1_analyze(token_stream, Self::ANNOTATION,
-      &LL1_TABLE, &PRODUCTIONS,&NONTERMINALS,&SYNC, &self.error_listeners)?;
+
+    let result = lalr_analyze(token_stream, nonterminal(Self::ALTERNATIVE_LABEL),
+      &ACTION_TABLE, &GOTO_TABLE, &START_STATES, &PRODUCTIONS, &self.error_listeners)?;
     Ok(Box::new(result))
   } 
-  pub fn lexer_rule(&self, token_stream: &mut TokenStream) -> Result<Box<dyn LexerRuleContext>, Box<dyn Error>> {
+  pub fn annotation<'a>(&self, token_stream: &mut TokenStream<'a>) -> Result<Box<dyn AnnotationContext<'a> + 'a>, Box<dyn Error>> {
 
-    if token_stream.peek_next_token()?.token_type == 0 {
+    if token_stream.peek_next_token()?.terminal.id == 0 {
       token_stream.consume()?;
     }
-    
-    let result = ll1_analyze(token_stream, Self::LEXER_RULE,
-      &LL1_TABLE, &PRODUCTIONS,&NONTERMINALS,&SYNC, &self.error_listeners)?;
+
+    let result = lalr_analyze(token_stream, nonterminal(Self::ANNOTATION),
+      &ACTION_TABLE, &GOTO_TABLE, &START_STATES, &PRODUCTIONS, &self.error_listeners)?;
     Ok(Box::new(result))
   } 
-  pub fn parser_rule(&self, token_stream: &mut TokenStream) -> Result<Box<dyn ParserRuleContext>, Box<dyn Error>> {
+  pub fn attribute<'a>(&self, token_stream: &mut TokenStream<'a>) -> Result<Box<dyn AttributeContext<'a> + 'a>, Box<dyn Error>> {
 
-    if token_stream.peek_next_token()?.token_type == 0 {
+    if token_stream.peek_next_token()?.terminal.id == 0 {
       token_stream.consume()?;
     }
-    
-    let result = ll1_analyze(token_stream, Self::PARSER_RULE,
-      &LL1_TABLE, &PRODUCTIONS,&NONTERMINALS,&SYNC, &self.error_listeners)?;
+
+    let result = lalr_analyze(token_stream, nonterminal(Self::ATTRIBUTE),
+      &ACTION_TABLE, &GOTO_TABLE, &START_STATES, &PRODUCTIONS, &self.error_listeners)?;
     Ok(Box::new(result))
   } 
-  pub fn rules(&self, token_stream: &mut TokenStream) -> Result<Box<dyn RulesContext>, Box<dyn Error>> {
+  pub fn attributes<'a>(&self, token_stream: &mut TokenStream<'a>) -> Result<Box<dyn AttributesContext<'a> + 'a>, Box<dyn Error>> {
 
-    if token_stream.peek_next_token()?.token_type == 0 {
+    if token_stream.peek_next_token()?.terminal.id == 0 {
       token_stream.consume()?;
     }
-    
-    let result = ll1_analyze(token_stream, Self::RULES,
-      &LL1_TABLE, &PRODUCTIONS,&NONTERMINALS,&SYNC, &self.error_listeners)?;
+
+    let result = lalr_analyze(token_stream, nonterminal(Self::ATTRIBUTES),
+      &ACTION_TABLE, &GOTO_TABLE, &START_STATES, &PRODUCTIONS, &self.error_listeners)?;
     Ok(Box::new(result))
   } 
-  pub fn attribute(&self, token_stream: &mut TokenStream) -> Result<Box<dyn AttributeContext>, Box<dyn Error>> {
+  pub fn block<'a>(&self, token_stream: &mut TokenStream<'a>) -> Result<Box<dyn BlockContext<'a> + 'a>, Box<dyn Error>> {
 
-    if token_stream.peek_next_token()?.token_type == 0 {
+    if token_stream.peek_next_token()?.terminal.id == 0 {
       token_stream.consume()?;
     }
-    
-    let result = ll1_analyze(token_stream, Self::ATTRIBUTE,
-      &LL1_TABLE, &PRODUCTIONS,&NONTERMINALS,&SYNC, &self.error_listeners)?;
+
+    let result = lalr_analyze(token_stream, nonterminal(Self::BLOCK),
+      &ACTION_TABLE, &GOTO_TABLE, &START_STATES, &PRODUCTIONS, &self.error_listeners)?;
     Ok(Box::new(result))
   } 
-  pub fn grammar_name(&self, token_stream: &mut TokenStream) -> Result<Box<dyn GrammarNameContext>, Box<dyn Error>> {
+  pub fn compilation_unit<'a>(&self, token_stream: &mut TokenStream<'a>) -> Result<Box<dyn CompilationUnitContext<'a> + 'a>, Box<dyn Error>> {
 
-    if token_stream.peek_next_token()?.token_type == 0 {
+    if token_stream.peek_next_token()?.terminal.id == 0 {
       token_stream.consume()?;
     }
-    
-    let result = ll1_analyze(token_stream, Self::GRAMMAR_NAME,
-      &LL1_TABLE, &PRODUCTIONS,&NONTERMINALS,&SYNC, &self.error_listeners)?;
+
+    let result = lalr_analyze(token_stream, nonterminal(Self::COMPILATION_UNIT),
+      &ACTION_TABLE, &GOTO_TABLE, &START_STATES, &PRODUCTIONS, &self.error_listeners)?;
     Ok(Box::new(result))
   } 
-  pub fn regular(&self, token_stream: &mut TokenStream) -> Result<Box<dyn RegularContext>, Box<dyn Error>> {
+  pub fn ebnf_suffix<'a>(&self, token_stream: &mut TokenStream<'a>) -> Result<Box<dyn EbnfSuffixContext<'a> + 'a>, Box<dyn Error>> {
 
-    if token_stream.peek_next_token()?.token_type == 0 {
+    if token_stream.peek_next_token()?.terminal.id == 0 {
       token_stream.consume()?;
     }
-    
-    let result = ll1_analyze(token_stream, Self::REGULAR,
-      &LL1_TABLE, &PRODUCTIONS,&NONTERMINALS,&SYNC, &self.error_listeners)?;
+
+    let result = lalr_analyze(token_stream, nonterminal(Self::EBNF_SUFFIX),
+      &ACTION_TABLE, &GOTO_TABLE, &START_STATES, &PRODUCTIONS, &self.error_listeners)?;
     Ok(Box::new(result))
   } 
-  pub fn element(&self, token_stream: &mut TokenStream) -> Result<Box<dyn ElementContext>, Box<dyn Error>> {
+  pub fn element<'a>(&self, token_stream: &mut TokenStream<'a>) -> Result<Box<dyn ElementContext<'a> + 'a>, Box<dyn Error>> {
 
-    if token_stream.peek_next_token()?.token_type == 0 {
+    if token_stream.peek_next_token()?.terminal.id == 0 {
       token_stream.consume()?;
     }
-    
-    let result = ll1_analyze(token_stream, Self::ELEMENT,
-      &LL1_TABLE, &PRODUCTIONS,&NONTERMINALS,&SYNC, &self.error_listeners)?;
+
+    let result = lalr_analyze(token_stream, nonterminal(Self::ELEMENT),
+      &ACTION_TABLE, &GOTO_TABLE, &START_STATES, &PRODUCTIONS, &self.error_listeners)?;
     Ok(Box::new(result))
   } 
-  pub fn alternative(&self, token_stream: &mut TokenStream) -> Result<Box<dyn AlternativeContext>, Box<dyn Error>> {
+  pub fn element_label<'a>(&self, token_stream: &mut TokenStream<'a>) -> Result<Box<dyn ElementLabelContext<'a> + 'a>, Box<dyn Error>> {
 
-    if token_stream.peek_next_token()?.token_type == 0 {
+    if token_stream.peek_next_token()?.terminal.id == 0 {
       token_stream.consume()?;
     }
-    
-    let result = ll1_analyze(token_stream, Self::ALTERNATIVE,
-      &LL1_TABLE, &PRODUCTIONS,&NONTERMINALS,&SYNC, &self.error_listeners)?;
+
+    let result = lalr_analyze(token_stream, nonterminal(Self::ELEMENT_LABEL),
+      &ACTION_TABLE, &GOTO_TABLE, &START_STATES, &PRODUCTIONS, &self.error_listeners)?;
     Ok(Box::new(result))
   } 
-  pub fn epsilon(&self, token_stream: &mut TokenStream) -> Result<Box<dyn EpsilonContext>, Box<dyn Error>> {
+  pub fn grammar_name<'a>(&self, token_stream: &mut TokenStream<'a>) -> Result<Box<dyn GrammarNameContext<'a> + 'a>, Box<dyn Error>> {
 
-    if token_stream.peek_next_token()?.token_type == 0 {
+    if token_stream.peek_next_token()?.terminal.id == 0 {
       token_stream.consume()?;
     }
-    
-    let result = ll1_analyze(token_stream, Self::EPSILON,
-      &LL1_TABLE, &PRODUCTIONS,&NONTERMINALS,&SYNC, &self.error_listeners)?;
+
+    let result = lalr_analyze(token_stream, nonterminal(Self::GRAMMAR_NAME),
+      &ACTION_TABLE, &GOTO_TABLE, &START_STATES, &PRODUCTIONS, &self.error_listeners)?;
     Ok(Box::new(result))
   } 
-  pub fn ebnf_suffix(&self, token_stream: &mut TokenStream) -> Result<Box<dyn EbnfSuffixContext>, Box<dyn Error>> {
+  pub fn import_rule<'a>(&self, token_stream: &mut TokenStream<'a>) -> Result<Box<dyn ImportRuleContext<'a> + 'a>, Box<dyn Error>> {
 
-    if token_stream.peek_next_token()?.token_type == 0 {
+    if token_stream.peek_next_token()?.terminal.id == 0 {
       token_stream.consume()?;
     }
-    
-    let result = ll1_analyze(token_stream, Self::EBNF_SUFFIX,
-      &LL1_TABLE, &PRODUCTIONS,&NONTERMINALS,&SYNC, &self.error_listeners)?;
+
+    let result = lalr_analyze(token_stream, nonterminal(Self::IMPORT_RULE),
+      &ACTION_TABLE, &GOTO_TABLE, &START_STATES, &PRODUCTIONS, &self.error_listeners)?;
     Ok(Box::new(result))
   } 
-  pub fn attributes(&self, token_stream: &mut TokenStream) -> Result<Box<dyn AttributesContext>, Box<dyn Error>> {
+  pub fn lexer_alternative<'a>(&self, token_stream: &mut TokenStream<'a>) -> Result<Box<dyn LexerAlternativeContext<'a> + 'a>, Box<dyn Error>> {
 
-    if token_stream.peek_next_token()?.token_type == 0 {
+    if token_stream.peek_next_token()?.terminal.id == 0 {
       token_stream.consume()?;
     }
-    
-    let result = ll1_analyze(token_stream, Self::ATTRIBUTES,
-      &LL1_TABLE, &PRODUCTIONS,&NONTERMINALS,&SYNC, &self.error_listeners)?;
+
+    let result = lalr_analyze(token_stream, nonterminal(Self::LEXER_ALTERNATIVE),
+      &ACTION_TABLE, &GOTO_TABLE, &START_STATES, &PRODUCTIONS, &self.error_listeners)?;
     Ok(Box::new(result))
   } 
-  pub fn compilation_unit(&self, token_stream: &mut TokenStream) -> Result<Box<dyn CompilationUnitContext>, Box<dyn Error>> {
+  pub fn lexer_block<'a>(&self, token_stream: &mut TokenStream<'a>) -> Result<Box<dyn LexerBlockContext<'a> + 'a>, Box<dyn Error>> {
 
-    if token_stream.peek_next_token()?.token_type == 0 {
+    if token_stream.peek_next_token()?.terminal.id == 0 {
       token_stream.consume()?;
     }
-    
-    let result = ll1_analyze(token_stream, Self::COMPILATION_UNIT,
-      &LL1_TABLE, &PRODUCTIONS,&NONTERMINALS,&SYNC, &self.error_listeners)?;
+
+    let result = lalr_analyze(token_stream, nonterminal(Self::LEXER_BLOCK),
+      &ACTION_TABLE, &GOTO_TABLE, &START_STATES, &PRODUCTIONS, &self.error_listeners)?;
+    Ok(Box::new(result))
+  } 
+  pub fn lexer_element<'a>(&self, token_stream: &mut TokenStream<'a>) -> Result<Box<dyn LexerElementContext<'a> + 'a>, Box<dyn Error>> {
+
+    if token_stream.peek_next_token()?.terminal.id == 0 {
+      token_stream.consume()?;
+    }
+
+    let result = lalr_analyze(token_stream, nonterminal(Self::LEXER_ELEMENT),
+      &ACTION_TABLE, &GOTO_TABLE, &START_STATES, &PRODUCTIONS, &self.error_listeners)?;
+    Ok(Box::new(result))
+  } 
+  pub fn lexer_rule<'a>(&self, token_stream: &mut TokenStream<'a>) -> Result<Box<dyn LexerRuleContext<'a> + 'a>, Box<dyn Error>> {
+
+    if token_stream.peek_next_token()?.terminal.id == 0 {
+      token_stream.consume()?;
+    }
+
+    let result = lalr_analyze(token_stream, nonterminal(Self::LEXER_RULE),
+      &ACTION_TABLE, &GOTO_TABLE, &START_STATES, &PRODUCTIONS, &self.error_listeners)?;
+    Ok(Box::new(result))
+  } 
+  pub fn mode_rule<'a>(&self, token_stream: &mut TokenStream<'a>) -> Result<Box<dyn ModeRuleContext<'a> + 'a>, Box<dyn Error>> {
+
+    if token_stream.peek_next_token()?.terminal.id == 0 {
+      token_stream.consume()?;
+    }
+
+    let result = lalr_analyze(token_stream, nonterminal(Self::MODE_RULE),
+      &ACTION_TABLE, &GOTO_TABLE, &START_STATES, &PRODUCTIONS, &self.error_listeners)?;
+    Ok(Box::new(result))
+  } 
+  pub fn parser_rule<'a>(&self, token_stream: &mut TokenStream<'a>) -> Result<Box<dyn ParserRuleContext<'a> + 'a>, Box<dyn Error>> {
+
+    if token_stream.peek_next_token()?.terminal.id == 0 {
+      token_stream.consume()?;
+    }
+
+    let result = lalr_analyze(token_stream, nonterminal(Self::PARSER_RULE),
+      &ACTION_TABLE, &GOTO_TABLE, &START_STATES, &PRODUCTIONS, &self.error_listeners)?;
+    Ok(Box::new(result))
+  } 
+  pub fn precedence_rule<'a>(&self, token_stream: &mut TokenStream<'a>) -> Result<Box<dyn PrecedenceRuleContext<'a> + 'a>, Box<dyn Error>> {
+
+    if token_stream.peek_next_token()?.terminal.id == 0 {
+      token_stream.consume()?;
+    }
+
+    let result = lalr_analyze(token_stream, nonterminal(Self::PRECEDENCE_RULE),
+      &ACTION_TABLE, &GOTO_TABLE, &START_STATES, &PRODUCTIONS, &self.error_listeners)?;
+    Ok(Box::new(result))
+  } 
+  pub fn regular<'a>(&self, token_stream: &mut TokenStream<'a>) -> Result<Box<dyn RegularContext<'a> + 'a>, Box<dyn Error>> {
+
+    if token_stream.peek_next_token()?.terminal.id == 0 {
+      token_stream.consume()?;
+    }
+
+    let result = lalr_analyze(token_stream, nonterminal(Self::REGULAR),
+      &ACTION_TABLE, &GOTO_TABLE, &START_STATES, &PRODUCTIONS, &self.error_listeners)?;
+    Ok(Box::new(result))
+  } 
+  pub fn rules<'a>(&self, token_stream: &mut TokenStream<'a>) -> Result<Box<dyn RulesContext<'a> + 'a>, Box<dyn Error>> {
+
+    if token_stream.peek_next_token()?.terminal.id == 0 {
+      token_stream.consume()?;
+    }
+
+    let result = lalr_analyze(token_stream, nonterminal(Self::RULES),
+      &ACTION_TABLE, &GOTO_TABLE, &START_STATES, &PRODUCTIONS, &self.error_listeners)?;
     Ok(Box::new(result))
   } 
 
@@ -582,3 +1477,4 @@ impl ChiruParser {
 
 
 
+
@@ -2,7 +2,7 @@
 
 
 
-// generated from .\src\tool\syntaxis\chiru.chiru by chiru 0.7.0
+// generated from src/tool/syntaxis/chiru.chiru by chiru 0.7.0
  
 
 
@@ -12,7 +12,8 @@ use chiru::runtime::ast::{rule_context::RuleContext, ast_context::AstContext, er
 
 use super::{
   chiru_context::{
-    BlockContext,AnnotationContext,LexerRuleContext,ParserRuleContext,RulesContext,AttributeContext,GrammarNameContext,RegularContext,ElementContext,AlternativeContext,EpsilonContext,EbnfSuffixContext,AttributesContext,CompilationUnitContext,
+    AlternativeContext,AlternativeLabelContext,AnnotationContext,AttributeContext,AttributesContext,BlockContext,CompilationUnitContext,EbnfSuffixContext,ElementContext,ElementLabelContext,GrammarNameContext,ImportRuleContext,LexerAlternativeContext,LexerBlockContext,LexerElementContext,LexerRuleContext,ModeRuleContext,ParserRuleContext,PrecedenceRuleContext,RegularContext,RulesContext,
+    
   },
   chiru_parser::ChiruParser, 
 };
@@ -20,7 +21,11 @@ use super::{
 
 pub trait ChiruVisitor {
   
-  fn visit_block(&mut self, ctx: &dyn BlockContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
+  fn visit_alternative(&mut self, ctx: &dyn AlternativeContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
+    self.visit_children(ctx.as_rule())
+  }
+  
+  fn visit_alternative_label(&mut self, ctx: &dyn AlternativeLabelContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
     self.visit_children(ctx.as_rule())
   }
   
@@ -28,19 +33,31 @@ pub trait ChiruVisitor {
     self.visit_children(ctx.as_rule())
   }
   
-  fn visit_lexer_rule(&mut self, ctx: &dyn LexerRuleContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
+  fn visit_attribute(&mut self, ctx: &dyn AttributeContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
     self.visit_children(ctx.as_rule())
   }
   
-  fn visit_parser_rule(&mut self, ctx: &dyn ParserRuleContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
+  fn visit_attributes(&mut self, ctx: &dyn AttributesContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
     self.visit_children(ctx.as_rule())
   }
   
-  fn visit_rules(&mut self, ctx: &dyn RulesContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
+  fn visit_block(&mut self, ctx: &dyn BlockContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
     self.visit_children(ctx.as_rule())
   }
   
-  fn visit_attribute(&mut self, ctx: &dyn AttributeContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
+  fn visit_compilation_unit(&mut self, ctx: &dyn CompilationUnitContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
+    self.visit_children(ctx.as_rule())
+  }
+  
+  fn visit_ebnf_suffix(&mut self, ctx: &dyn EbnfSuffixContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
+    self.visit_children(ctx.as_rule())
+  }
+  
+  fn visit_element(&mut self, ctx: &dyn ElementContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
+    self.visit_children(ctx.as_rule())
+  }
+  
+  fn visit_element_label(&mut self, ctx: &dyn ElementLabelContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
     self.visit_children(ctx.as_rule())
   }
   
@@ -48,53 +65,74 @@ pub trait ChiruVisitor {
     self.visit_children(ctx.as_rule())
   }
   
-  fn visit_regular(&mut self, ctx: &dyn RegularContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
+  fn visit_import_rule(&mut self, ctx: &dyn ImportRuleContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
     self.visit_children(ctx.as_rule())
   }
   
-  fn visit_element(&mut self, ctx: &dyn ElementContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
+  fn visit_lexer_alternative(&mut self, ctx: &dyn LexerAlternativeContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
     self.visit_children(ctx.as_rule())
   }
   
-  fn visit_alternative(&mut self, ctx: &dyn AlternativeContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
+  fn visit_lexer_block(&mut self, ctx: &dyn LexerBlockContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
     self.visit_children(ctx.as_rule())
   }
   
-  fn visit_epsilon(&mut self, ctx: &dyn EpsilonContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
+  fn visit_lexer_element(&mut self, ctx: &dyn LexerElementContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
     self.visit_children(ctx.as_rule())
   }
   
-  fn visit_ebnf_suffix(&mut self, ctx: &dyn EbnfSuffixContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
+  fn visit_lexer_rule(&mut self, ctx: &dyn LexerRuleContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
     self.visit_children(ctx.as_rule())
   }
   
-  fn visit_attributes(&mut self, ctx: &dyn AttributesContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
+  fn visit_mode_rule(&mut self, ctx: &dyn ModeRuleContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
     self.visit_children(ctx.as_rule())
   }
   
-  fn visit_compilation_unit(&mut self, ctx: &dyn CompilationUnitContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
+  fn visit_parser_rule(&mut self, ctx: &dyn ParserRuleContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
     self.visit_children(ctx.as_rule())
   }
   
+  fn visit_precedence_rule(&mut self, ctx: &dyn PrecedenceRuleContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
+    self.visit_children(ctx.as_rule())
+  }
+  
+  fn visit_regular(&mut self, ctx: &dyn RegularContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
+    self.visit_children(ctx.as_rule())
+  }
+  
+  fn visit_rules(&mut self, ctx: &dyn RulesContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
+    self.visit_children(ctx.as_rule())
+  }
+  
+
+  
 
   
   fn visit(&mut self, ast: &RuleContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
     match ast.get_rule_index() {
       
-      ChiruParser::BLOCK => self.visit_block(ast),
+      ChiruParser::ALTERNATIVE => self.visit_alternative(ast),
+      ChiruParser::ALTERNATIVE_LABEL => self.visit_alternative_label(ast),
       ChiruParser::ANNOTATION => self.visit_annotation(ast),
-      ChiruParser::LEXER_RULE => self.visit_lexer_rule(ast),
-      ChiruParser::PARSER_RULE => self.visit_parser_rule(ast),
-      ChiruParser::RULES => self.visit_rules(ast),
       ChiruParser::ATTRIBUTE => self.visit_attribute(ast),
-      ChiruParser::GRAMMAR_NAME => self.visit_grammar_name(ast),
-      ChiruParser::REGULAR => self.visit_regular(ast),
-      ChiruParser::ELEMENT => self.visit_element(ast),
-      ChiruParser::ALTERNATIVE => self.visit_alternative(ast),
-      ChiruParser::EPSILON => self.visit_epsilon(ast),
-      ChiruParser::EBNF_SUFFIX => self.visit_ebnf_suffix(ast),
       ChiruParser::ATTRIBUTES => self.visit_attributes(ast),
+      ChiruParser::BLOCK => self.visit_block(ast),
       ChiruParser::COMPILATION_UNIT => self.visit_compilation_unit(ast),
+      ChiruParser::EBNF_SUFFIX => self.visit_ebnf_suffix(ast),
+      ChiruParser::ELEMENT => self.visit_element(ast),
+      ChiruParser::ELEMENT_LABEL => self.visit_element_label(ast),
+      ChiruParser::GRAMMAR_NAME => self.visit_grammar_name(ast),
+      ChiruParser::IMPORT_RULE => self.visit_import_rule(ast),
+      ChiruParser::LEXER_ALTERNATIVE => self.visit_lexer_alternative(ast),
+      ChiruParser::LEXER_BLOCK => self.visit_lexer_block(ast),
+      ChiruParser::LEXER_ELEMENT => self.visit_lexer_element(ast),
+      ChiruParser::LEXER_RULE => self.visit_lexer_rule(ast),
+      ChiruParser::MODE_RULE => self.visit_mode_rule(ast),
+      ChiruParser::PARSER_RULE => self.visit_parser_rule(ast),
+      ChiruParser::PRECEDENCE_RULE => self.visit_precedence_rule(ast),
+      ChiruParser::REGULAR => self.visit_regular(ast),
+      ChiruParser::RULES => self.visit_rules(ast),
 
       _ => self.visit_children(ast)
     }
@@ -2,8 +2,15 @@ use std::{collections::{HashMap, HashSet}, any::Any, error::Error};
 
 use maplit::hashset;
 
+use chiru::runtime::error;
+
 use crate::tool::syntaxis::chiru_visitor::ChiruVisitor;
 
+// (terminal_list, terminal, nonterminal_list, nonterminal)
+pub type ContextSets = (HashSet<usize>, HashSet<usize>, HashSet<usize>, HashSet<usize>);
+
+// 元素标签 -> (标签所指向的非终结符, 是否为列表), 指向终结符时为 None
+pub type ElementLabels = HashMap<String, (Option<usize>, bool)>;
 
 
 /**
@@ -19,7 +26,16 @@ pub struct ContextVisitor {
 
   terminals: HashMap<String, usize>,
 
-  pub table: HashMap<usize, (HashSet<usize>, HashSet<usize>, HashSet<usize>, HashSet<usize>)>,
+  pub table: HashMap<usize, ContextSets>,
+
+  // 每个非终结符中的元素标签
+  pub element_label_table: HashMap<usize, ElementLabels>,
+
+  // 带标签的备选分支, 标签 -> (所属非终结符, 备选分支的集合, 元素标签), 同一个规则中的同名标签会合并
+  pub label_table: HashMap<String, (usize, ContextSets, ElementLabels)>,
+
+  // 当前备选分支中的元素标签
+  element_labels: ElementLabels,
 }
 
 
@@ -28,10 +44,37 @@ impl ContextVisitor {
   pub fn new(nonterminals: HashMap<String, usize>, terminals: HashMap<String, usize>) -> Self {
     Self {
       table: HashMap::new(),
+      element_label_table: HashMap::new(),
+      label_table: HashMap::new(),
+      element_labels: HashMap::new(),
       nonterminals, 
       terminals,
     }
   }
+
+  // 合并两个备选分支的集合
+  fn merge(result: &mut ContextSets, other: ContextSets) {
+    result.0.extend(other.0);
+    result.1.extend(other.1);
+    result.2.extend(other.2);
+    result.3.extend(other.3);
+  }
+
+  // 添加一个元素标签, 同名的标签必须指向同一种元素, 只要有一处使用 += 就视为列表
+  fn add_element_label(labels: &mut ElementLabels, name: &str, target: (Option<usize>, bool)) -> Result<(), Box<dyn Error>> {
+    match labels.get_mut(name) {
+      Some(existing) if existing.0 != target.0 => Err(format!("label '{}' refers to different kinds of elements", name).into()),
+      Some(existing) => { existing.1 |= target.1; Ok(()) },
+      None => { labels.insert(name.to_owned(), target); Ok(()) },
+    }
+  }
+
+  fn merge_element_labels(labels: &mut ElementLabels, other: &ElementLabels) -> Result<(), Box<dyn Error>> {
+    for (name, target) in other.iter() {
+      Self::add_element_label(labels, name, *target)?;
+    }
+    Ok(())
+  }
 }
 
 
@@ -57,33 +100,50 @@ impl ChiruVisitor for ContextVisitor {
     let name = &ctx.rule_ref().unwrap().symbol.text;
     let id = *self.nonterminals.get(name).unwrap();
     
-    // 解析并填表
-    let result = ctx.block().unwrap().accept(self)?.downcast::<(HashSet<usize>, HashSet<usize>, HashSet<usize>, HashSet<usize>)>().unwrap();
-    self.table.insert(id, *result);
+    // 解析并填表, 带标签的备选分支额外记录在 label_table 中
+    let mut result: ContextSets = (HashSet::new(), HashSet::new(), HashSet::new(), HashSet::new());
+    let mut element_labels: ElementLabels = HashMap::new();
+    for alternative in ctx.block().unwrap().alternative_list().iter() {
+      self.element_labels.clear();
+      let sets = alternative.accept(self)?.downcast::<ContextSets>().unwrap();
+      let alternative_element_labels = std::mem::take(&mut self.element_labels);
+
+      if let Some(label) = alternative.alternative_label().and_then(|label| label.rule_ref().or(label.token_ref())) {
+        let entry = self.label_table.entry(label.symbol.text.clone())
+          .or_insert_with(|| (id, (HashSet::new(), HashSet::new(), HashSet::new(), HashSet::new()), HashMap::new()));
+        if entry.0 != id {
+          let message = format!("label '{}' in rule '{}' is already used by another rule", label.symbol.text, name);
+          return Err(Box::new(error::Error::grammar_error(Some(label.symbol.location), &message)));
+        }
+        Self::merge(&mut entry.1, *sets.clone());
+        Self::merge_element_labels(&mut entry.2, &alternative_element_labels)?;
+      }
+      Self::merge(&mut result, *sets);
+      Self::merge_element_labels(&mut element_labels, &alternative_element_labels)?;
+    }
+    self.table.insert(id, result);
+    self.element_label_table.insert(id, element_labels);
     self.default_result()
   }
 
   // 返回一个 hashset 的元组 (terminal_list, terminal, nonterminal_list, nonterminal) : (HashSet<usize>, ...)
   fn visit_block(&mut self, ctx: &dyn crate::tool::syntaxis::chiru_context::BlockContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
 
-    let mut result: (HashSet<usize>, HashSet<usize>, HashSet<usize>, HashSet<usize>) = (HashSet::new(), HashSet::new(), HashSet::new(), HashSet::new());
+    let mut result: ContextSets = (HashSet::new(), HashSet::new(), HashSet::new(), HashSet::new());
       
     for v in ctx.alternative_list().iter() {
-      let re = v.accept(self)?.downcast::<(HashSet<usize>, HashSet<usize>, HashSet<usize>, HashSet<usize>)>().unwrap();
-      result.0.extend(re.0);
-      result.1.extend(re.1);
-      result.2.extend(re.2);
-      result.3.extend(re.3);
+      let re = v.accept(self)?.downcast::<ContextSets>().unwrap();
+      Self::merge(&mut result, *re);
     }
     Ok(Box::new(result))
   }
 
   // (terminal_list, terminal, nonterminal_list, nonterminal)
   fn visit_alternative(&mut self, ctx: &dyn crate::tool::syntaxis::chiru_context::AlternativeContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
-    let mut result: (HashSet<usize>, HashSet<usize>, HashSet<usize>, HashSet<usize>) = (HashSet::new(), HashSet::new(), HashSet::new(), HashSet::new());
-    let mut children: Vec<Box<(HashSet<usize>, HashSet<usize>, HashSet<usize>, HashSet<usize>)>> = Vec::new();
+    let mut result: ContextSets = (HashSet::new(), HashSet::new(), HashSet::new(), HashSet::new());
+    let mut children: Vec<Box<ContextSets>> = Vec::new();
     for elem in ctx.element_list().iter() {
-      let child = elem.accept(self)?.downcast::<(HashSet<usize>, HashSet<usize>, HashSet<usize>, HashSet<usize>)>().unwrap();
+      let child = elem.accept(self)?.downcast::<ContextSets>().unwrap();
       children.push(child);
     }
 
@@ -127,6 +187,31 @@ impl ChiruVisitor for ContextVisitor {
 
   // (terminal_list, terminal, nonterminal_list, nonterminal)
   fn visit_element(&mut self, ctx: &dyn crate::tool::syntaxis::chiru_context::ElementContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
+    let result = self.element_sets(ctx)?.downcast::<ContextSets>().unwrap();
+
+    // 记录元素的标签, 标签只能指向终结符或者同一个非终结符
+    if let Some(label) = ctx.element_label() {
+      let symbol = &label.rule_ref().or(label.token_ref()).unwrap().symbol;
+      let name = &symbol.text;
+      let nonterminals = result.2.union(&result.3).collect::<HashSet<_>>();
+      let target = if nonterminals.is_empty() {
+        None
+      } else if nonterminals.len() == 1 && result.0.is_empty() && result.1.is_empty() {
+        nonterminals.into_iter().next().copied()
+      } else {
+        let message = format!("label '{}' must refer to tokens or to a single rule", name);
+        return Err(Box::new(error::Error::grammar_error(Some(symbol.location), &message)));
+      };
+      Self::add_element_label(&mut self.element_labels, name, (target, label.plus_assign().is_some()))?;
+    }
+    Ok(result)
+  }
+}
+
+
+impl ContextVisitor {
+  // 计算元素对应的集合, 不考虑标签
+  fn element_sets(&mut self, ctx: &dyn crate::tool::syntaxis::chiru_context::ElementContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
     if let Some(token) = ctx.token_ref() {
       let name = &token.symbol.text;
 
@@ -136,28 +221,28 @@ impl ChiruVisitor for ContextVisitor {
 
       if let Some(suffix) = ctx.ebnf_suffix() {
         // 如果有后缀
-        if let Some(_) = suffix.star() {
+        if suffix.star().is_some() {
           // item *
-          let result: (HashSet<usize>, HashSet<usize>, HashSet<usize>, HashSet<usize>) = 
+          let result: ContextSets = 
             (hashset! { *token_id }, hashset! {}, hashset! {}, hashset! {});
-          return Ok(Box::new(result));
-        } else if let Some(_) = suffix.plus() {
+          Ok(Box::new(result))
+        } else if suffix.plus().is_some() {
           // item +
-          let result: (HashSet<usize>, HashSet<usize>, HashSet<usize>, HashSet<usize>) = 
+          let result: ContextSets = 
             (hashset! { *token_id }, hashset! {}, hashset! {}, hashset! {});
-          return Ok(Box::new(result));
+          Ok(Box::new(result))
         } else {
           // item ?
-          let result: (HashSet<usize>, HashSet<usize>, HashSet<usize>, HashSet<usize>) = 
+          let result: ContextSets = 
             (hashset! {}, hashset! { *token_id }, hashset! {}, hashset! {});
-          return Ok(Box::new(result));
+          Ok(Box::new(result))
         }
         
       } else {
         // 如果没有后缀
-        let result: (HashSet<usize>, HashSet<usize>, HashSet<usize>, HashSet<usize>) = 
+        let result: ContextSets = 
           (hashset! {}, hashset! { *token_id }, hashset! {}, hashset! {});
-        return Ok(Box::new(result));
+        Ok(Box::new(result))
       }
       
     }
@@ -166,43 +251,34 @@ impl ChiruVisitor for ContextVisitor {
       let rule_id = self.nonterminals.get(name).unwrap();
 
       if let Some(suffix) = ctx.ebnf_suffix() {
-        if let Some(_) = suffix.star() {
+        if suffix.star().is_some() {
           // item *
-          let result: (HashSet<usize>, HashSet<usize>, HashSet<usize>, HashSet<usize>) = 
+          let result: ContextSets = 
             (hashset! {}, hashset! {}, hashset! { *rule_id }, hashset! {});
-          return Ok(Box::new(result));
-        } else if let Some(_) = suffix.plus() {
+          Ok(Box::new(result))
+        } else if suffix.plus().is_some() {
           // item +
-          let result: (HashSet<usize>, HashSet<usize>, HashSet<usize>, HashSet<usize>) = 
+          let result: ContextSets = 
             (hashset! {}, hashset! {}, hashset! { *rule_id }, hashset! {});
-          return Ok(Box::new(result));
+          Ok(Box::new(result))
         } else {
           // item ?
-          let result: (HashSet<usize>, HashSet<usize>, HashSet<usize>, HashSet<usize>) = 
+          let result: ContextSets = 
             (hashset! {}, hashset! {}, hashset! {}, hashset! { *rule_id });
-          return Ok(Box::new(result));
+          Ok(Box::new(result))
         }
       } else {
-        let result: (HashSet<usize>, HashSet<usize>, HashSet<usize>, HashSet<usize>) = 
+        let result: ContextSets = 
           (hashset! {}, hashset! {}, hashset! {}, hashset! { *rule_id });
-        return Ok(Box::new(result));
+        Ok(Box::new(result))
       }
       
     } else if let Some(block) = ctx.block() {
 
-      let mut result = block.accept(self)?.downcast::<(HashSet<usize>, HashSet<usize>, HashSet<usize>, HashSet<usize>)>().unwrap();
+      let mut result = block.accept(self)?.downcast::<ContextSets>().unwrap();
       if let Some(suffix) = ctx.ebnf_suffix() {
         // 将 0/1 全部添加到 list 中
-        if let Some(_) = suffix.star() {
-          result.0.extend(&result.1);
-          result.2.extend(&result.3);
-
-          result.1.clear();
-          result.3.clear();
-
-          return Ok(result);
-
-        } else if let Some(_) = suffix.plus() {
+        if suffix.star().is_some() || suffix.plus().is_some() {
           result.0.extend(&result.1);
           result.2.extend(&result.3);
 
@@ -214,12 +290,12 @@ impl ChiruVisitor for ContextVisitor {
       }
 
 
-      return Ok(result);
+      Ok(result)
     } else {
       // 字符串常量，不管
-      let result: (HashSet<usize>, HashSet<usize>, HashSet<usize>, HashSet<usize>) = 
+      let result: ContextSets = 
         (hashset! {}, hashset! {}, hashset! {}, hashset! {});
-      return Ok(Box::new(result));
+      Ok(Box::new(result))
     }
   }
 
@@ -227,3 +303,149 @@ impl ChiruVisitor for ContextVisitor {
 }
 
 
+
+
+#[cfg(test)]
+mod tests {
+  use std::{collections::HashSet, error::Error};
+
+  use chiru::runtime::token_stream::TokenStream;
+
+  use crate::tool::{analyzer::{CommonLexer, CommonParser}, cli::Analyzer, grammar::{tests::grammar, Grammar}};
+  use crate::tool::syntaxis::{chiru_lexer::ChiruLexer, chiru_parser::ChiruParser};
+  use super::ContextVisitor;
+
+  fn visit(grammar: &Grammar, source: &str) -> Result<ContextVisitor, Box<dyn Error>> {
+    let lexer = ChiruLexer::new(source);
+    let mut tokens = TokenStream::new(&lexer, 0);
+    let ast = ChiruParser::new().compilation_unit(&mut tokens)?;
+    let mut visitor = ContextVisitor::new(
+      grammar.vocabulary.get_all_named_nonterminals().iter().map(|nonterminal| (nonterminal.name.unwrap().to_owned(), nonterminal.id)).collect(),
+      grammar.vocabulary.get_all_terminals().iter().map(|terminal| (terminal.name.to_owned(), terminal.id)).collect(),
+    );
+    ast.accept(&mut visitor)?;
+    Ok(visitor)
+  }
+
+  #[test]
+  fn alternative_label_test() {
+    let source = r####"
+      grammar Expr;
+      expr: expr PLUS term # Add | expr MINUS term # Add | term # Single;
+      term: NUM # Number | LPAREN expr RPAREN # Paren;
+      NUM: r###"[0-9]+"###;
+      PLUS: "+";
+      MINUS: "-";
+      LPAREN: "(";
+      RPAREN: ")";
+    "####;
+    let grammar = grammar(source);
+    let visitor = visit(&grammar, source).unwrap();
+    let id = |name: &str| grammar.vocabulary.get_nonterminal_by_name(name).map(|nonterminal| nonterminal.id)
+      .or(grammar.vocabulary.get_terminal_by_name(name).map(|terminal| terminal.id)).unwrap();
+
+    let mut labels = visitor.label_table.iter().map(|(label, (rule, _, _))| (label.as_str(), *rule)).collect::<Vec<_>>();
+    labels.sort();
+    assert_eq!(labels, vec![("Add", id("expr")), ("Number", id("term")), ("Paren", id("term")), ("Single", id("expr"))]);
+
+    // 每个标签的 context 只包含对应备选分支中的元素, 同名的标签合并
+    let (_, (terminal_list, terminal, nonterminal_list, nonterminal), _) = &visitor.label_table["Add"];
+    assert!(terminal_list.is_empty() && nonterminal_list.is_empty());
+    assert_eq!(*terminal, HashSet::from([id("PLUS"), id("MINUS")]));
+    assert_eq!(*nonterminal, HashSet::from([id("expr"), id("term")]));
+    let (_, (_, terminal, _, nonterminal), _) = &visitor.label_table["Paren"];
+    assert_eq!(*terminal, HashSet::from([id("LPAREN"), id("RPAREN")]));
+    assert_eq!(*nonterminal, HashSet::from([id("expr")]));
+
+    // 语法树中的节点记录了匹配的备选分支
+    for analyzer in [Analyzer::LL1, Analyzer::LALR] {
+      let mut grammar = grammar.clone();
+      if analyzer == Analyzer::LL1 {
+        grammar.eliminate_left_recursion();
+        grammar.left_factor();
+      }
+      let lexer = CommonLexer::from_grammar(&grammar, "1-(2)").unwrap();
+      let mut tokens = TokenStream::new(&lexer, 0);
+      let parser = CommonParser::from_grammar(&grammar, analyzer);
+      let ast = parser.parse(&mut tokens, grammar.vocabulary.get_nonterminal_by_name("expr").unwrap()).unwrap();
+      assert_eq!(ast.get_label(), Some("Add"));
+      assert_eq!(ast.get_rule_contexts(id("expr"))[0].get_label(), Some("Single"));
+      assert_eq!(ast.get_rule_contexts(id("term"))[0].get_label(), Some("Paren"));
+    }
+  }
+
+  #[test]
+  fn duplicate_alternative_label_test() {
+    let source = r####"
+      grammar Expr;
+      expr: term # Single;
+      term: NUM # Single;
+      NUM: r###"[0-9]+"###;
+    "####;
+    let error = visit(&grammar(source), source).err().unwrap();
+    assert_eq!(error.to_string(), "4:19: grammar error: label 'Single' in rule 'term' is already used by another rule");
+  }
+
+  #[test]
+  fn element_label_test() {
+    let source = r####"
+      grammar Expr;
+      expr: lhs=expr op=(PLUS | MINUS) rhs=expr | items+=NUM (COMMA items+=NUM)*;
+      NUM: r###"[0-9]+"###;
+      PLUS: "+";
+      MINUS: "-";
+      COMMA: ",";
+    "####;
+    let grammar = grammar(source);
+    let visitor = visit(&grammar, source).unwrap();
+    let expr = grammar.vocabulary.get_nonterminal_by_name("expr").unwrap();
+
+    // 标签指向的非终结符以及是否为列表
+    let labels = &visitor.element_label_table[&expr.id];
+    assert_eq!(labels.len(), 4);
+    assert_eq!(labels["lhs"], (Some(expr.id), false));
+    assert_eq!(labels["rhs"], (Some(expr.id), false));
+    assert_eq!(labels["op"], (None, false));
+    assert_eq!(labels["items"], (None, true));
+
+    // 标签访问到的是对应位置上的子节点
+    for analyzer in [Analyzer::LL1, Analyzer::LALR] {
+      let mut grammar = grammar.clone();
+      if analyzer == Analyzer::LL1 {
+        grammar.eliminate_left_recursion();
+        grammar.left_factor();
+      }
+      let lexer = CommonLexer::from_grammar(&grammar, "1,2-3").unwrap();
+      let mut tokens = TokenStream::new(&lexer, 0);
+      let parser = CommonParser::from_grammar(&grammar, analyzer);
+      let ast = parser.parse(&mut tokens, expr).unwrap();
+
+      let lhs = ast.get_labeled_rule_context("lhs", 0).unwrap();
+      let items = lhs.get_labeled_terminals("items").iter().map(|item| item.symbol.text.as_str()).collect::<Vec<_>>();
+      assert_eq!(items, vec!["1", "2"]);
+      assert_eq!(ast.get_labeled_terminal("op", 0).unwrap().symbol.text, "-");
+      let rhs = ast.get_labeled_rule_context("rhs", 0).unwrap();
+      assert_eq!(rhs.get_labeled_terminal("items", 0).unwrap().symbol.text, "3");
+      assert!(ast.get_labeled_rule_context("rhs", 1).is_none());
+    }
+  }
+
+  #[test]
+  fn invalid_element_label_test() {
+    let source = r####"
+      grammar Expr;
+      expr: value=(NUM | expr);
+      NUM: r###"[0-9]+"###;
+    "####;
+    let error = visit(&grammar(source), source).err().unwrap();
+    assert_eq!(error.to_string(), "3:13: grammar error: label 'value' must refer to tokens or to a single rule");
+
+    let source = r####"
+      grammar Expr;
+      expr: value=NUM | value=expr;
+      NUM: r###"[0-9]+"###;
+    "####;
+    let error = visit(&grammar(source), source).err().unwrap();
+    assert_eq!(error.to_string(), "label 'value' refers to different kinds of elements");
+  }
+}
@@ -1,13 +1,13 @@
 
 
-use std::{collections::HashMap, error::Error, any::Any};
-use chiru::runtime::production::{Production, ProductionItem};
-use crate::tool::{grammar::{Grammar, lexer_rule::LexerRule}, syntaxis::{chiru_visitor::ChiruVisitor, chiru_context::{ElementContext, ParserRuleContext, BlockContext}}};
+use std::{collections::HashMap, error::Error, any::Any, ops::Range};
+use chiru::runtime::{ast::{rule_context::RuleContext, terminal_context::TerminalContext}, error, production::{Production, ProductionItem}, vocabulary::NonTerminal};
+use crate::tool::{grammar::{Grammar, lexer_rule::LexerRule, precedence::{Associativity, Precedence}}, visitor::string_literal_to_token_visitor::StringLiteralToTokenVisitor, syntaxis::{chiru_visitor::ChiruVisitor, chiru_context::{ElementContext, ParserRuleContext, BlockContext, PrecedenceRuleContext}}};
 
 
 
 
-/**
+/*
  * 第一遍 将所有的语法规则中的字符串字面量转换为 token
  * 第二遍 将所有词法符号和语法符号编号并填表
  * 第三遍 构造产生式
@@ -16,31 +16,37 @@ use crate::tool::{grammar::{Grammar, lexer_rule::LexerRule}, syntaxis::{chiru_vi
 
 
 
-// 负责生成产生式
-pub struct GrammarVisitor<'a> {
-  pub grammar: Grammar<'a>,
+// 产生式的右部以及右部中每个元素的标签
+type Alternative = (Vec<ProductionItem<'static>>, Vec<Option<&'static str>>);
+
+
+// 负责生成产生式, 文法在程序运行期间一直有效, 因此其中符号的名称都是 'static 的
+pub struct GrammarVisitor {
+  pub grammar: Grammar<'static>,
   
   pub next_rule_id: usize, // 为匿名非终结符编号
   pub next_production_id: usize, // 产生式的编号
 
-  // 先在 visitor 中维护一个匿名非终结符产生式的集合，最后再添加到 grammar 中去。map 的键为产生式右部列表
-  block_cache: HashMap<Vec<Vec<ProductionItem<'a>>>, usize>,
-  star_cache: HashMap<ProductionItem<'a>, usize>,
-  plus_cache: HashMap<ProductionItem<'a>, usize>,
-  question_cache: HashMap<ProductionItem<'a>, usize>,
+  // 先在 visitor 中维护一个匿名非终结符产生式的集合，最后再添加到 grammar 中去。map 的键为产生式右部列表以及元素的标签
+  block_cache: HashMap<Vec<Alternative>, usize>,
+  star_cache: HashMap<ProductionItem<'static>, usize>,
+  plus_cache: HashMap<ProductionItem<'static>, usize>,
+  question_cache: HashMap<ProductionItem<'static>, usize>,
 }
 
-impl<'a> GrammarVisitor<'a> {
+impl GrammarVisitor {
   pub fn new(name: &str, parser_rule_map: &HashMap<String, usize>, lexer_rule_map: &HashMap<String, LexerRule>) -> Self {
     let mut grammar = Grammar::new(name);
 
-    grammar.lexer_rule_map = lexer_rule_map.clone();
+    grammar.lexer_rule_map = lexer_rule_map.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
 
     // 将已经识别的终结符加入 vocabulary 
-    lexer_rule_map.values().for_each(|v| { grammar.vocabulary.add_terminal(v.token_type, &v.token_name); });
+    grammar.vocabulary.add_terminal(0, "_START");
+    grammar.vocabulary.add_terminal(1, "_STOP");
+    lexer_rule_map.values().for_each(|v| { grammar.vocabulary.add_terminal(v.token_type, Self::leak(&v.token_name)); });
 
     parser_rule_map.iter().for_each(|(name, id)| {
-      grammar.vocabulary.add_named_nonterminal(*id, name);
+      grammar.vocabulary.add_named_nonterminal(*id, Self::leak(name));
     });
 
     let next_rule_id = *parser_rule_map.values().max().unwrap_or(&0) + 1;
@@ -53,9 +59,34 @@ impl<'a> GrammarVisitor<'a> {
       question_cache: HashMap::new(), block_cache: HashMap::new(), 
     }
   }
+
+  fn leak(name: &str) -> &'static str {
+    Box::leak(name.to_owned().into_boxed_str())
+  }
+
+  // 添加一个匿名非终结符
+  fn add_unnamed_nonterminal(&mut self) -> NonTerminal<'static> {
+    let nonterminal = NonTerminal::new(None, self.next_rule_id);
+    self.grammar.vocabulary.add_unnamed_nonterminal(self.next_rule_id);
+    self.next_rule_id += 1;
+    nonterminal
+  }
+
+  // 引用了未定义的规则或 token
+  fn undefined(kind: &str, symbol: &TerminalContext) -> Box<dyn Error> {
+    let message = format!("undefined {} '{}'", kind, symbol.symbol.text);
+    Box::new(error::Error::grammar_error(Some(symbol.symbol.location), &message))
+  }
+
+  // 语法树节点在语法文件中的字节范围
+  fn span(ctx: &RuleContext) -> Range<usize> {
+    let start = ctx.get_start_token().map_or(0, |token| token.location.byte_index_start);
+    let stop = ctx.get_stop_token().map_or(start, |token| token.location.byte_index_stop);
+    start..stop
+  }
 }
 
-impl<'a> ChiruVisitor for GrammarVisitor<'a> {
+impl ChiruVisitor for GrammarVisitor {
 
   fn visit_grammar_name(&mut self, ctx: &dyn crate::tool::syntaxis::chiru_context::GrammarNameContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
 
@@ -74,44 +105,85 @@ impl<'a> ChiruVisitor for GrammarVisitor<'a> {
     for rule in ctx.parser_rule_list().iter() {
       rule.accept(self)?;
     }
+
+    // 优先级按照声明的顺序递增
+    for rule in ctx.precedence_rule_list().iter() {
+      rule.accept(self)?;
+    }
     self.default_result()
   }
 
 
 
+  // 记录终结符的优先级和结合性 无需返回值 -> void
+  fn visit_precedence_rule(&mut self, ctx: &dyn PrecedenceRuleContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
+    let attribute = ctx.attribute().unwrap();
+    let name = match attribute.rule_ref() {
+      Some(name) => &name.symbol.text,
+      None => return self.default_result(),
+    };
+    let associativity = match name.as_str() {
+      "left" => Associativity::Left,
+      "right" => Associativity::Right,
+      _ => return self.default_result(),
+    };
+
+    let level = self.grammar.precedences.values().map(|precedence| precedence.level + 1).max().unwrap_or(0);
+    for token in attribute.token_ref_list().iter() {
+      if let Some(terminal) = self.grammar.vocabulary.get_terminal_by_name(&token.symbol.text) {
+        self.grammar.precedences.insert(terminal, Precedence::new(level, associativity));
+      }
+    }
+    // 字符串字面量对应语法规则中的字面量生成的 token, 与 visit_element 中相同
+    for literal in attribute.string_literal_list().iter() {
+      let key = StringLiteralToTokenVisitor::literal_key(&literal.symbol.text)?;
+      let terminal = self.grammar.lexer_rule_map.get(&key)
+        .and_then(|rule| self.grammar.vocabulary.get_terminal_by_name(&rule.token_name))
+        .ok_or_else(|| Self::undefined("token for string literal", literal))?;
+      self.grammar.precedences.insert(terminal, Precedence::new(level, associativity));
+    }
+    self.default_result()
+  }
+
   // 添加命名产生式 无需返回值 -> void
   fn visit_parser_rule(&mut self, ctx: &dyn ParserRuleContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
     // 这个地方不要调用 block.accept
     let name = &ctx.rule_ref().unwrap().symbol.text;
-    let left_id = self.grammar.vocabulary.get_nonterminal_id_by_name(name).unwrap();
+    let left = self.grammar.vocabulary.get_nonterminal_by_name(name).unwrap();
     
     for alternative in ctx.block().unwrap().alternative_list().iter() {
-      let right = alternative.accept(self)?;
-      let right = right.downcast::<Vec<ProductionItem>>().unwrap();
+      let (right, element_labels) = *alternative.accept(self)?.downcast::<Alternative>().unwrap();
 
       let production_id = self.next_production_id;
       self.next_production_id += 1;
-      let production = Production::new(production_id, left_id, right.as_ref());
+      // 只有规则最外层的备选分支才可以带有标签
+      let label = alternative.alternative_label().and_then(|label| label.rule_ref().or(label.token_ref()))
+        .map(|label| Self::leak(&label.symbol.text));
+      let production = Production::new(production_id, left, &right).with_label(label).with_element_labels(&element_labels);
 
       // 先不检查重复的产生式 这里不检查才是合理的。
       self.grammar.productions.insert(production_id, production);
+      self.grammar.production_spans.insert(production_id, Self::span(alternative.as_rule()));
     }
     self.default_result()
   }
 
 
 
-  // 返回一条产生式的右部分 -> Vec<ProductionItem>
+  // 返回一条产生式的右部分以及元素的标签 -> Alternative
   fn visit_alternative(&mut self, ctx: &dyn crate::tool::syntaxis::chiru_context::AlternativeContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
-    if let Some(_) = ctx.epsilon() {
-      return Ok(Box::new(Vec::<ProductionItem>::new()));
+    let mut result: Alternative = (Vec::new(), Vec::new());
+    if ctx.epsilon().is_some() {
+      return Ok(Box::new(result));
     }
 
-    let mut result: Vec<ProductionItem> = Vec::new();
     for element in ctx.element_list().iter() {
       let elem = element.accept(self)?
         .downcast::<ProductionItem>().unwrap();
-      result.push(elem.as_ref().clone());
+      let label = element.element_label().and_then(|label| label.rule_ref().or(label.token_ref()))
+        .map(|label| Self::leak(&label.symbol.text));
+      result.0.push(*elem.as_ref());
+      result.1.push(label);
     }
 
     Ok(Box::new(result))
@@ -125,16 +197,21 @@ impl<'a> ChiruVisitor for GrammarVisitor<'a> {
     // 首先解析出一个 item
     let item: ProductionItem; // let id: usize;
     if let Some(token) = ctx.token_ref() {
-      let id = self.grammar.vocabulary.get_terminal_id_by_name(&token.symbol.text).unwrap();
-      item = ProductionItem::Terminal(id);
+      let terminal = self.grammar.vocabulary.get_terminal_by_name(&token.symbol.text)
+        .ok_or_else(|| Self::undefined("token", token))?;
+      item = ProductionItem::Terminal(terminal);
     }
     else if let Some(literal) = ctx.string_literal() {
-      let id = self.grammar.vocabulary.get_terminal_id_by_name(&literal.symbol.text).unwrap();
-      item = ProductionItem::Terminal(id);
+      // 字符串字面量对应的 token 以转义之后的字面量作为键
+      let key = StringLiteralToTokenVisitor::literal_key(&literal.symbol.text)?;
+      let token_name = &self.grammar.lexer_rule_map.get(&key).unwrap().token_name;
+      let terminal = self.grammar.vocabulary.get_terminal_by_name(token_name).unwrap();
+      item = ProductionItem::Terminal(terminal);
     }
     else if let Some(rule) = ctx.rule_ref() {
-      let id = self.grammar.vocabulary.get_nonterminal_id_by_name(&rule.symbol.text).unwrap();
-      item = ProductionItem::NonTerminal(id);
+      let nonterminal = self.grammar.vocabulary.get_nonterminal_by_name(&rule.symbol.text)
+        .ok_or_else(|| Self::undefined("rule", rule))?;
+      item = ProductionItem::NonTerminal(nonterminal);
     }
     else if let Some(block) = ctx.block() {
       item = *block.accept(self)?.downcast::<ProductionItem>().unwrap();
@@ -146,66 +223,67 @@ impl<'a> ChiruVisitor for GrammarVisitor<'a> {
 
 
     if let Some(suffix) = ctx.ebnf_suffix() {
-      if let Some(_) = suffix.star() {
+      if suffix.star().is_some() {
         // item * => item2 -> item item2 | epsilon
 
         if let Some(item_id) = self.star_cache.get(&item) {
-          return Ok(Box::new(ProductionItem::NonTerminal(*item_id)));
+          return Ok(Box::new(ProductionItem::NonTerminal(NonTerminal::new(None, *item_id))));
         }
 
         // 添加一个非终结符 匿名 item2
-        self.grammar.vocabulary.add_unnamed_nonterminal(self.next_rule_id);
-        let item2 = ProductionItem::NonTerminal(self.next_rule_id);
+        let nonterminal = self.add_unnamed_nonterminal();
+        let item2 = ProductionItem::NonTerminal(nonterminal);
 
         let production_id_1 = self.next_production_id;
         let production_id_2 = self.next_production_id + 1;
         self.next_production_id += 2;
 
         // 添加两条产生式
-        let p1 = Production::new(production_id_1, self.next_rule_id, &vec![]);
-        let p2 = Production::new(production_id_2, self.next_rule_id, &vec![item, item2]);
+        let p1 = Production::new(production_id_1, nonterminal, &[]);
+        let p2 = Production::new(production_id_2, nonterminal, &[item, item2]);
 
         self.grammar.productions.insert(production_id_1, p1);
         self.grammar.productions.insert(production_id_2, p2);
+        self.grammar.production_spans.insert(production_id_1, Self::span(ctx.as_rule()));
+        self.grammar.production_spans.insert(production_id_2, Self::span(ctx.as_rule()));
 
-        self.star_cache.insert(item, self.next_rule_id);
-
-        self.next_rule_id += 1;
-        return Ok(Box::new(item2));
+        self.star_cache.insert(item, nonterminal.id);
+        Ok(Box::new(item2))
       }
-      else if let Some(_) = suffix.plus() {
+      else if suffix.plus().is_some() {
         // item * => item2 -> item item2 | item
         if let Some(item_id) = self.plus_cache.get(&item) {
-          return Ok(Box::new(ProductionItem::NonTerminal(*item_id)));
+          return Ok(Box::new(ProductionItem::NonTerminal(NonTerminal::new(None, *item_id))));
         }
 
         // 添加一个非终结符 匿名
-        self.grammar.vocabulary.add_unnamed_nonterminal(self.next_rule_id);
-        let item2 = ProductionItem::NonTerminal(self.next_rule_id);
+        let nonterminal = self.add_unnamed_nonterminal();
+        let item2 = ProductionItem::NonTerminal(nonterminal);
 
         // 添加两条产生式
         let production_id_1 = self.next_production_id;
         let production_id_2 = self.next_production_id + 1;
         self.next_production_id += 2;
 
-        let p1 = Production::new(production_id_1, self.next_rule_id, &vec![item]);
-        let p2 = Production::new(production_id_2, self.next_rule_id, &vec![item, item2]);
+        let p1 = Production::new(production_id_1, nonterminal, &[item]);
+        let p2 = Production::new(production_id_2, nonterminal, &[item, item2]);
         self.grammar.productions.insert(production_id_1, p1);
         self.grammar.productions.insert(production_id_2, p2);
+        self.grammar.production_spans.insert(production_id_1, Self::span(ctx.as_rule()));
+        self.grammar.production_spans.insert(production_id_2, Self::span(ctx.as_rule()));
 
-        self.plus_cache.insert(item, self.next_rule_id);
-        self.next_rule_id += 1;
-        return Ok(Box::new(item2));
+        self.plus_cache.insert(item, nonterminal.id);
+        Ok(Box::new(item2))
       }
       else {
         // item * => item2 -> item | epsilon
         if let Some(item_id) = self.question_cache.get(&item) {
-          return Ok(Box::new(ProductionItem::NonTerminal(*item_id)));
+          return Ok(Box::new(ProductionItem::NonTerminal(NonTerminal::new(None, *item_id))));
         }
 
         // 添加一个非终结符
-        self.grammar.vocabulary.add_unnamed_nonterminal(self.next_rule_id);
-        let item2 = ProductionItem::NonTerminal(self.next_rule_id);
+        let nonterminal = self.add_unnamed_nonterminal();
+        let item2 = ProductionItem::NonTerminal(nonterminal);
 
         // 添加两条产生式
         let production_id_1 = self.next_production_id;
@@ -213,13 +291,14 @@ impl<'a> ChiruVisitor for GrammarVisitor<'a> {
         self.next_production_id += 2;
 
 
-        let p1 = Production::new(production_id_1, self.next_rule_id, &vec![]);
-        let p2 = Production::new(production_id_2, self.next_rule_id, &vec![item]);
+        let p1 = Production::new(production_id_1, nonterminal, &[]);
+        let p2 = Production::new(production_id_2, nonterminal, &[item]);
         self.grammar.productions.insert(production_id_1, p1);
         self.grammar.productions.insert(production_id_2, p2);
-        self.question_cache.insert(item, self.next_rule_id);
-        self.next_rule_id += 1;
-        return Ok(Box::new(item2));
+        self.grammar.production_spans.insert(production_id_1, Self::span(ctx.as_rule()));
+        self.grammar.production_spans.insert(production_id_2, Self::span(ctx.as_rule()));
+        self.question_cache.insert(item, nonterminal.id);
+        Ok(Box::new(item2))
       }
     }
     else {
@@ -237,37 +316,35 @@ impl<'a> ChiruVisitor for GrammarVisitor<'a> {
     let rights = 
       ctx.alternative_list().iter().map(|alternative| {
         alternative.accept(self).unwrap()
-          .downcast::<Vec<ProductionItem>>().unwrap().as_ref().clone()
+          .downcast::<Alternative>().unwrap().as_ref().clone()
       }).collect::<Vec<_>>();
 
     // 先检查是否缓存中存在  
     if let Some(id) = self.block_cache.get(&rights) {
-      return Ok(Box::new(ProductionItem::NonTerminal(*id)));
+      return Ok(Box::new(ProductionItem::NonTerminal(NonTerminal::new(None, *id))));
     }
 
 
 
     // 添加一个非终结符，并返回其 id  ( xx | xxx)  (xxx xxx)* 检查是否已经存在, 否则新建并返回 NonTerminal(id)。
     // 每个右部都添加一个产生式
-    let id = self.next_rule_id;
-    self.next_rule_id += 1;
-
-    self.grammar.vocabulary.add_unnamed_nonterminal(id);
+    let nonterminal = self.add_unnamed_nonterminal();
 
     // 插入缓存
-    self.block_cache.insert(rights.clone(), id);
+    self.block_cache.insert(rights.clone(), nonterminal.id);
 
-    for right in rights.iter() {
+    for ((right, element_labels), alternative) in rights.iter().zip(ctx.alternative_list().iter()) {
       let production_id = self.next_production_id;
       self.next_production_id += 1;
-      let production = Production::new(production_id, id, right);
+      let production = Production::new(production_id, nonterminal, right).with_element_labels(element_labels);
 
       self.grammar.productions.insert(production_id, production);
+      self.grammar.production_spans.insert(production_id, Self::span(alternative.as_rule()));
     } 
 
 
     
-    Ok(Box::new(ProductionItem::NonTerminal(id)))
+    Ok(Box::new(ProductionItem::NonTerminal(nonterminal)))
   }
 
 }
@@ -1,14 +1,24 @@
-use std::{any::Any, collections::HashMap, error::Error};
+use std::{any::Any, collections::{HashMap, HashSet}, error::Error};
 
-use crate::tool::{syntaxis::{chiru_visitor::ChiruVisitor, chiru_context::LexerRuleContext}, grammar::lexer_rule::LexerRule};
+use chiru::runtime::error;
+use chiru::runtime::lexer_rule::ModeAction;
+use chiru::runtime::location::Location;
 
+use crate::tool::{syntaxis::{chiru_visitor::ChiruVisitor, chiru_context::{CompilationUnitContext, LexerRuleContext, LexerBlockContext, ModeRuleContext, AttributeContext}}, grammar::lexer_rule::{LexerRule, raw_literal, strip_raw_literal}};
 
+use super::string_literal_to_token_visitor::StringLiteralToTokenVisitor;
 
 
 
 
 
 
+// 词法规则右部展开之前的形式, 对其他 token 或 fragment 的引用要在所有规则都收集完之后才能展开
+enum LexerPattern {
+  Regex(String),
+  Reference(String, Location),
+}
+
 
 pub struct LexerRuleVisitor {
   
@@ -19,6 +29,16 @@ pub struct LexerRuleVisitor {
   pub lexer_rule_map: HashMap<String, LexerRule>,
 
   pub channel_map: HashMap<String, usize>,
+
+  // 词法模式的名称和编号, 默认模式 DEFAULT_MODE 的编号为 0
+  pub mode_map: HashMap<String, usize>,
+
+  // 当前词法规则所属的模式
+  pub current_mode: usize,
+
+  // 所有词法规则和 fragment 的右部, fragment 不会生成 token
+  patterns: HashMap<String, Vec<LexerPattern>>,
+  pub fragments: HashSet<String>,
 }
 
 
@@ -30,92 +50,202 @@ impl LexerRuleVisitor {
       lexer_rule_map,
       channel_map: HashMap::new(),
       next_channel_id: 1,
+      mode_map: HashMap::from([("DEFAULT_MODE".to_owned(), 0)]),
+      current_mode: 0,
+      patterns: HashMap::new(),
+      fragments: HashSet::new(),
+    }
+  }
+
+  // 获取模式的编号, 第一次出现时分配一个新的编号
+  fn mode_id(&mut self, name: &str) -> usize {
+    let next_mode_id = self.mode_map.len();
+    *self.mode_map.entry(name.to_owned()).or_insert(next_mode_id)
+  }
+
+  // 处理词法规则上的注解
+  fn apply_attribute(&mut self, att: &dyn AttributeContext, lexer_rule: &mut LexerRule) {
+    let text = match att.rule_ref().or(att.mode()) {
+      Some(text) => &text.symbol.text,
+      None => return,
+    };
+    let argument = att.token_ref_list().first().map(|c| c.symbol.text.to_owned());
+
+    match (text.as_str(), argument) {
+      ("ignore" | "skip", _) => lexer_rule.skip = true,
+      ("channel", Some(argument)) => {
+        lexer_rule.channel = match self.channel_map.get(&argument) {
+          Some(channel) => *channel,
+          None => {
+            let channel = self.next_channel_id;
+            self.channel_map.insert(argument, channel);
+            self.next_channel_id += 1;
+            channel
+          },
+        };
+      },
+      ("push_mode", Some(argument)) => lexer_rule.mode_action = Some(ModeAction::Push(self.mode_id(&argument))),
+      ("mode", Some(argument)) => lexer_rule.mode_action = Some(ModeAction::Set(self.mode_id(&argument))),
+      ("pop_mode", _) => lexer_rule.mode_action = Some(ModeAction::Pop),
+      _ => {},
+    }
+  }
+
+  // 将词法规则的右部转换为正则表达式片段, 每个元素都用非捕获组包裹, 以便加上后缀
+  fn block_pattern(block: &dyn LexerBlockContext, result: &mut Vec<LexerPattern>) -> Result<(), Box<dyn Error>> {
+    for (index, alternative) in block.lexer_alternative_list().iter().enumerate() {
+      if index > 0 { result.push(LexerPattern::Regex(String::from("|"))); }
+
+      for element in alternative.lexer_element_list().iter() {
+        result.push(LexerPattern::Regex(String::from("(?:")));
+        if let Some(regular) = element.regular().and_then(|regular| regular.regular_literal()) {
+          result.push(LexerPattern::Regex(strip_raw_literal(&regular.symbol.text)));
+        } else if let Some(literal) = element.string_literal() {
+          let regex = StringLiteralToTokenVisitor::literal_regex(&literal.symbol.text)
+            .map_err(|message| error::Error::grammar_error(Some(literal.symbol.location), &message))?;
+          result.push(LexerPattern::Regex(regex));
+        } else if let Some(token) = element.token_ref() {
+          result.push(LexerPattern::Reference(token.symbol.text.to_owned(), token.symbol.location));
+        } else if let Some(block) = element.lexer_block() {
+          Self::block_pattern(block, result)?;
+        }
+        result.push(LexerPattern::Regex(String::from(")")));
+
+        // 如 * 或者非贪婪的 *?
+        if let Some(suffix) = element.ebnf_suffix() {
+          let suffix = suffix.star().or(suffix.plus()).into_iter().chain(suffix.question_list())
+            .map(|terminal| terminal.symbol.text.as_str()).collect::<String>();
+          result.push(LexerPattern::Regex(suffix));
+        }
+      }
+    }
+    Ok(())
+  }
+
+  // 右部只有一个正则表达式时, 保留原来的写法
+  fn single_regular(block: &dyn LexerBlockContext) -> Option<String> {
+    let alternatives = block.lexer_alternative_list();
+    let elements = match alternatives.as_slice() {
+      [alternative] => alternative.lexer_element_list(),
+      _ => return None,
+    };
+    match elements.as_slice() {
+      [element] if element.ebnf_suffix().is_none() => element.regular()
+        .and_then(|regular| regular.regular_literal()).map(|regular| regular.symbol.text.to_owned()),
+      _ => None,
+    }
+  }
+
+  // 展开规则中的引用, stack 为正在展开的规则, 用于检查循环引用
+  fn expand(&self, name: &str, stack: &mut Vec<String>, cache: &mut HashMap<String, String>) -> Result<String, Box<dyn Error>> {
+    if let Some(regex) = cache.get(name) { return Ok(regex.to_owned()); }
+
+    stack.push(name.to_owned());
+    let mut result = String::new();
+    for pattern in self.patterns[name].iter() {
+      match pattern {
+        LexerPattern::Regex(regex) => result += regex,
+        LexerPattern::Reference(reference, location) => {
+          if stack.contains(reference) {
+            let cycle = stack.iter().skip_while(|rule| *rule != reference).chain([reference])
+              .map(|rule| rule.as_str()).collect::<Vec<_>>().join(" -> ");
+            let message = format!("cyclic reference in lexer rules: {}", cycle);
+            return Err(Box::new(error::Error::grammar_error(Some(*location), &message)));
+          }
+          if !self.patterns.contains_key(reference) {
+            let message = format!("undefined token or fragment '{}'", reference);
+            return Err(Box::new(error::Error::grammar_error(Some(*location), &message)));
+          }
+          result += &self.expand(reference, stack, cache)?;
+        },
+      }
+    }
+    stack.pop();
+
+    cache.insert(name.to_owned(), result.clone());
+    Ok(result)
+  }
+
+  // 访问完所有语法文件之后调用, 为引用了其他规则的 token 生成最终的正则表达式
+  pub fn expand_references(&mut self) -> Result<(), Box<dyn Error>> {
+    let mut names = self.lexer_rule_map.iter()
+      .filter(|(name, rule)| rule.regex.is_empty() && self.patterns.contains_key(*name))
+      .map(|(name, rule)| (rule.priority, name.to_owned())).collect::<Vec<_>>();
+    names.sort();
+
+    let mut cache = HashMap::new();
+    for (_, name) in names {
+      let regex = self.expand(&name, &mut Vec::new(), &mut cache)?;
+      self.lexer_rule_map.get_mut(&name).unwrap().regex = raw_literal(&regex);
     }
+    Ok(())
   }
 }
 
 
 
 impl ChiruVisitor for LexerRuleVisitor {
+
+  // 每个语法文件都从默认模式开始
+  fn visit_compilation_unit(&mut self, ctx: &dyn CompilationUnitContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
+    self.current_mode = 0;
+    self.visit_children(ctx.as_rule())
+  }
   
   
+  // 之后定义的词法规则都属于该模式
+  fn visit_mode_rule(&mut self, ctx: &dyn ModeRuleContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
+    let name = &ctx.token_ref().unwrap().symbol.text;
+    self.current_mode = self.mode_id(name);
+    self.default_result()
+  }
+
   fn visit_lexer_rule(&mut self, ctx: &dyn LexerRuleContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
     // 获取名称
     let name = &ctx.token_ref().unwrap().symbol.text;
 
     // 检查是否已经定义
-    if self.lexer_rule_map.contains_key(name) {
+    if self.lexer_rule_map.contains_key(name) || self.patterns.contains_key(name) {
       // 重复定义，报错
 
-      // 字符串字面量都使用了 '' 包裹，一定不会和普通 token 重复
+      // 字符串字面量都使用了 "" 包裹，一定不会和普通 token 重复
       return self.default_result();
     }
 
+    // 语法错误恢复之后可能缺少右部
+    let block = match ctx.lexer_block() {
+      Some(block) => block,
+      None => {
+        let message = format!("lexer rule '{}' has no regular expression", name);
+        return Err(Box::new(error::Error::grammar_error(Some(ctx.token_ref().unwrap().symbol.location), &message)));
+      },
+    };
+    let mut pattern = Vec::new();
+    Self::block_pattern(block, &mut pattern)?;
+    self.patterns.insert(name.to_owned(), pattern);
+
+    if ctx.fragment().is_some() {
+      self.fragments.insert(name.to_owned());
+      return self.default_result();
+    }
 
-    let mut channel = 0;
-    let mut skip = false;
+
+    let mut lexer_rule = LexerRule::new(self.next_token_id, name, "", 0, false);
+    lexer_rule.mode = self.current_mode;
 
     if let Some(annotation) = ctx.annotation() {
       // 查看注解
       if let Some(att) = annotation.attribute() {
-        if let Some(text) = att.rule_ref() {
-
-          // 处理annotation
-          let text = &text.symbol.text;
-          if text == "ignore" || text == "skip" {
-            skip = true;
-          }
-          else if text == "channel" {
-            if let Some(c) = att.token_ref() {
-              let text = &c.symbol.text;
-              if self.channel_map.contains_key(text) {
-                channel = *self.channel_map.get(text).unwrap();
-              }
-              else {
-                channel = self.next_channel_id;
-                self.channel_map.insert(text.to_owned(), channel);
-                self.next_channel_id += 1;
-              }
-            }
-          }
-        }
-        
+        self.apply_attribute(att, &mut lexer_rule);
       } else if let Some(attr_list) = annotation.attributes() {
-        attr_list.attribute_list().iter().for_each(| att | {
-          if let Some(text) = att.rule_ref() {
-
-            // 处理annotation
-            let text = &text.symbol.text;
-            if text == "ignore" || text == "skip" {
-              skip = true;
-            }
-            else if text == "channel" {
-              if let Some(c) = att.token_ref() {
-                let text = &c.symbol.text;
-                if self.channel_map.contains_key(text) {
-                  channel = *self.channel_map.get(text).unwrap();
-                }
-                else {
-                  channel = self.next_channel_id;
-                  self.channel_map.insert(text.to_owned(), channel);
-                  self.next_channel_id += 1;
-                }
-              }
-            }
-          }
-          
-
-        });
+        for att in attr_list.attribute_list().iter() {
+          self.apply_attribute(*att, &mut lexer_rule);
+        }
       }
+    }
 
-    } 
-
-
-    let regular = &ctx.regular().unwrap().regular_literal().unwrap().symbol.text; // .replace("\\/", "/");
-
-    let lexer_rule = LexerRule::new(self.next_token_id, &name, regular, 
-      channel, skip
-    );
+    // 引用了其他规则的正则表达式在 expand_references 中生成
+    lexer_rule.regex = Self::single_regular(block).unwrap_or_default();
 
     self.next_token_id += 1;
 
@@ -130,3 +260,129 @@ impl ChiruVisitor for LexerRuleVisitor {
 
 
 
+
+
+#[cfg(test)]
+mod tests {
+  use chiru::runtime::{lexer::Lexer, lexer_rule::ModeAction};
+
+  use crate::tool::{analyzer::CommonLexer, grammar::tests::{diagnostics, grammar, grammar_from}};
+
+  #[test]
+  fn lexer_mode_test() {
+    let grammar = grammar(r####"
+      lexer grammar Mode;
+
+      @push_mode(STRING_MODE)
+      STR_START: r###"""###;
+      @mode(COMMENT_MODE)
+      HASH: "#";
+      WORD: r###"[a-z]+"###;
+      @ignore
+      WS: r###"[ \n]+"###;
+
+      mode STRING_MODE;
+      @pop_mode
+      STR_END: r###"""###;
+      @push_mode(INTERPOLATION)
+      LBRACE: "{";
+      STR_TEXT: r###"[^"{]+"###;
+
+      mode INTERPOLATION;
+      @pop_mode
+      RBRACE: "}";
+      ID: r###"[a-z]+"###;
+
+      mode COMMENT_MODE;
+      @mode(DEFAULT_MODE)
+      NEWLINE: r###"\n"###;
+      COMMENT_TEXT: r###"[^\n]+"###;
+    "####);
+
+    let modes = |name: &str| grammar.lexer_modes[name];
+    assert_eq!(grammar.lexer_modes.len(), 4);
+    assert_eq!(modes("DEFAULT_MODE"), 0);
+    let rule = |name: &str| (grammar.lexer_rule_map[name].mode, grammar.lexer_rule_map[name].mode_action);
+    assert_eq!(rule("STR_START"), (0, Some(ModeAction::Push(modes("STRING_MODE")))));
+    assert_eq!(rule("HASH"), (0, Some(ModeAction::Set(modes("COMMENT_MODE")))));
+    assert_eq!(rule("STR_END"), (modes("STRING_MODE"), Some(ModeAction::Pop)));
+    assert_eq!(rule("ID"), (modes("INTERPOLATION"), None));
+    assert_eq!(rule("NEWLINE"), (modes("COMMENT_MODE"), Some(ModeAction::Set(0))));
+
+    // 同样的文本在不同的模式下得到不同的 token
+    let input = "ab \"x {cd} y\" # zz\nef";
+    let lexer = CommonLexer::from_grammar(&grammar, input).unwrap();
+    let tokens = lexer.iter().map(|token| (token.terminal.name, token.text.to_owned())).collect::<Vec<_>>();
+    let tokens = tokens.iter().map(|(name, text)| (*name, text.as_str())).collect::<Vec<_>>();
+    assert_eq!(tokens, vec![
+      ("WORD", "ab"), ("STR_START", "\""), ("STR_TEXT", "x "), ("LBRACE", "{"), ("ID", "cd"), ("RBRACE", "}"),
+      ("STR_TEXT", " y"), ("STR_END", "\""), ("HASH", "#"), ("COMMENT_TEXT", " zz"), ("NEWLINE", "\n"), ("WORD", "ef"),
+    ]);
+  }
+  #[test]
+  fn fragment_test() {
+    let grammar = grammar(r####"
+      lexer grammar Numbers;
+      FLOAT: DIGITS "." DIGITS? (("e" | "E") SIGN? DIGITS)?;
+      INT: DIGITS;
+      fragment SIGN: "+" | "-";
+      fragment DIGITS: r###"[0-9]+"###;
+      @ignore
+      WS: r###" +"###;
+    "####);
+
+    // fragment 不会生成 token, 引用处展开为对应的正则表达式
+    assert!(!grammar.lexer_rule_map.contains_key("DIGITS") && !grammar.lexer_rule_map.contains_key("SIGN"));
+    assert!(!grammar.lexer_rule_map["FLOAT"].regex.contains("DIGITS"));
+    assert_eq!(grammar.lexer_rule_map["INT"].regex, r##"r#"(?:(?:[0-9]+))"#"##);
+
+    let lexer = CommonLexer::from_grammar(&grammar, "1.5e-3 42 7. 0.25E8").unwrap();
+    let tokens = lexer.iter().map(|token| (token.terminal.name, token.text.to_owned())).collect::<Vec<_>>();
+    let tokens = tokens.iter().map(|(name, text)| (*name, text.as_str())).collect::<Vec<_>>();
+    assert_eq!(tokens, vec![("FLOAT", "1.5e-3"), ("INT", "42"), ("FLOAT", "7."), ("FLOAT", "0.25E8")]);
+  }
+
+  #[test]
+  fn fragment_cycle_test() {
+    let error = |source: &str| grammar_from(&[source], &Default::default()).err().unwrap().to_string();
+
+    assert_eq!(error(r####"
+      lexer grammar Cycle;
+      ID: A;
+      fragment A: "a" B?;
+      fragment B: "b" A;
+    "####), "5:23: grammar error: cyclic reference in lexer rules: A -> B -> A");
+
+    assert_eq!(error(r####"
+      lexer grammar Cycle;
+      ID: "x" ID?;
+    "####), "3:15: grammar error: cyclic reference in lexer rules: ID -> ID");
+
+    assert_eq!(error(r####"
+      lexer grammar Undefined;
+      ID: "x" MISSING;
+    "####), "3:15: grammar error: undefined token or fragment 'MISSING'");
+  }
+
+  #[test]
+  fn regex_diagnostics_test() {
+    let source = r####"
+      grammar Word;
+      s: WORD | NUM | ID;
+      WORD: r###"\b[a-z]+\b"###;
+      NUM: r###"[0-9"###;
+      ID: r###"(?-u:\b)[A-Z]+"###;
+    "####;
+    let span = |text: &str| {
+      let start = source.find(text).unwrap();
+      start..start + text.len()
+    };
+
+    // 与词法分析器使用同样的方式构造 DFA, Unicode 单词边界和语法错误都在语义检查时报告
+    let diagnostics = diagnostics(source);
+    assert_eq!(diagnostics.iter().map(|(_, range)| range.clone()).collect::<Vec<_>>(),
+      [span(r####"r###"\b[a-z]+\b"###"####), span(r####"r###"[0-9"###"####)]);
+    assert!(diagnostics[0].0.starts_with("error: invalid regular expression for token 'WORD': unsupported regex feature for DFAs"));
+    assert!(diagnostics[1].0.starts_with("error: invalid regular expression for token 'NUM': error building NFA: error parsing regex"));
+  }
+}
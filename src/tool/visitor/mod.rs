@@ -13,3 +13,4 @@ pub mod parser_rule_visitor;
 
 pub mod context_visitor;
 
+pub mod validation_visitor;
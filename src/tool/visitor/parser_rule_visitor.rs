@@ -13,6 +13,12 @@ pub struct ParserRuleVisitor {
   pub parser_rule_map: HashMap<String, usize>,
 }
 
+impl Default for ParserRuleVisitor {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
 impl ParserRuleVisitor {
   pub fn new() -> Self {
     Self { next_rule_id: 0, parser_rule_map: HashMap::new(), }
@@ -1,36 +1,131 @@
-
-
 // 重新写词法规则的分析器
 
-use std::{collections::HashMap, any::Any, error::Error};
+use std::{collections::{HashMap, HashSet}, any::Any, error::Error};
+
+use chiru::runtime::error;
 
-use crate::tool::{syntaxis::{chiru_visitor::ChiruVisitor, chiru_context::ElementContext}, grammar::lexer_rule::LexerRule};
+use crate::tool::{syntaxis::{chiru_visitor::ChiruVisitor, chiru_context::{ElementContext, LexerRuleContext}}, grammar::lexer_rule::{LexerRule, raw_literal}};
 
 
 
-// 负责将字符串字面量转换为 token 。并分配 id 
+// 负责将字符串字面量转换为 token 。并分配 id
+// 字面量在所有词法规则之前编号, 因此与标识符等规则匹配长度相同时, 优先匹配为字面量
 pub struct StringLiteralToTokenVisitor {
   pub next_token_id: usize,
 
+  // 键为转义之后的字符串加上引号, 如 "+" 和 "\n", 同一个字符串的不同写法对应同一个 token
   pub lexer_rule_map: HashMap<String, LexerRule>,
+
+  // 词法规则定义的 token 名称, 字面量的名称不能与之重复
+  token_names: HashSet<String>,
+
+  // 按照出现顺序记录每个字面量的键和转义之后的字符串, 用于命名
+  literals: Vec<(String, String)>,
 }
 
 impl StringLiteralToTokenVisitor {
-  // 将字符串字面量转义为对应的实际字符串
-  fn escape(input: &str) -> String { 
-    // 对 str 进行转义处理
-    input.to_owned()
+  // 将字符串字面量转义为对应的实际字符串, 输入包含两端的引号
+  pub fn escape(input: &str) -> Result<String, String> {
+    let content = input.strip_prefix('"').and_then(|s| s.strip_suffix('"'))
+      .ok_or_else(|| format!("invalid string literal {}", input))?;
+
+    let mut result = String::new();
+    let mut chars = content.chars();
+    while let Some(c) = chars.next() {
+      if c != '\\' { result.push(c); continue; }
+
+      match chars.next() {
+        Some('n') => result.push('\n'),
+        Some('r') => result.push('\r'),
+        Some('t') => result.push('\t'),
+        Some('a') => result.push('\x07'),
+        Some('f') => result.push('\x0c'),
+        Some('v') => result.push('\x0b'),
+        Some('u') => {
+          // \u{..}, 十六进制, 可以带有 0x 前缀
+          let rest = chars.as_str();
+          let end = rest.find('}').filter(|_| rest.starts_with('{'))
+            .ok_or_else(|| format!("invalid unicode escape in string literal {}", input))?;
+          let digits = &rest[1..end];
+          let code = u32::from_str_radix(digits.strip_prefix("0x").unwrap_or(digits), 16).ok()
+            .and_then(char::from_u32)
+            .ok_or_else(|| format!("invalid unicode code point '{}' in string literal {}", digits, input))?;
+          result.push(code);
+          chars = rest[end+1..].chars();
+        },
+        // \\ 和 \" 以及其他字符都表示字符本身
+        Some(other) => result.push(other),
+        None => return Err(format!("invalid string literal {}", input)),
+      }
+    }
+
+    if result.is_empty() {
+      return Err(String::from("empty string literal is not allowed"));
+    }
+    Ok(result)
+  }
+
+  // 字面量在 lexer_rule_map 中的键, 使用 rust 的字符串格式, 保证只有一行
+  pub fn literal_key(input: &str) -> Result<String, String> {
+    Ok(format!("{:?}", Self::escape(input)?))
   }
-  
+
   // 将字符串字面量转义为识别它的正则表达式
-  fn regular_escape(input: &str) -> String { 
-    input.to_owned()
+  pub fn literal_regex(input: &str) -> Result<String, String> {
+    // 控制字符使用转义序列表示, 保证正则表达式只有一行
+    let regex = regex::escape(&Self::escape(input)?).chars().map(|c| match c {
+      '\n' => String::from("\\n"),
+      '\r' => String::from("\\r"),
+      '\t' => String::from("\\t"),
+      c if c.is_control() => format!("\\x{{{:x}}}", c as u32),
+      c => c.to_string(),
+    }).collect::<String>();
+    Ok(regex)
+  }
+
+  // 输出与 REGULAR_LITERAL 相同的 r#"..."# 形式
+  fn regular_escape(input: &str) -> Result<String, String> {
+    Ok(raw_literal(&Self::literal_regex(input)?))
+  }
+
+  // 字面量 token 的名称, 关键字形式的字面量如 "select" 命名为 KW_SELECT, 符号如 "<=" 命名为 LT_EQUAL
+  fn readable_name(text: &str) -> Option<String> {
+    if text.split('_').all(|word| !word.is_empty() && word.chars().all(|c| c.is_ascii_alphanumeric())) {
+      return Some(format!("KW_{}", text.to_ascii_uppercase()));
+    }
+
+    let names = text.chars().map(|c| match c {
+      '+' => Some("PLUS"), '-' => Some("MINUS"), '*' => Some("STAR"), '/' => Some("SLASH"), '%' => Some("PERCENT"),
+      '=' => Some("EQUAL"), '<' => Some("LT"), '>' => Some("GT"), '!' => Some("BANG"), '?' => Some("QUESTION"),
+      ':' => Some("COLON"), ';' => Some("SEMI"), ',' => Some("COMMA"), '.' => Some("DOT"),
+      '(' => Some("LPAREN"), ')' => Some("RPAREN"), '[' => Some("LBRACKET"), ']' => Some("RBRACKET"),
+      '{' => Some("LBRACE"), '}' => Some("RBRACE"), '&' => Some("AMP"), '|' => Some("PIPE"),
+      '^' => Some("CARET"), '~' => Some("TILDE"), '@' => Some("AT"), '#' => Some("HASH"), '$' => Some("DOLLAR"),
+      '\'' => Some("QUOTE"), '"' => Some("DQUOTE"), '\\' => Some("BACKSLASH"), '`' => Some("BACKTICK"),
+      _ => None,
+    }).collect::<Option<Vec<_>>>()?;
+    Some(names.join("_"))
   }
 
   pub fn new(next_token_id: usize) -> Self {
     Self {
       next_token_id,
       lexer_rule_map: HashMap::new(),
+      token_names: HashSet::new(),
+      literals: Vec::new(),
+    }
+  }
+
+  // 访问完所有语法文件之后再为字面量命名, 与词法规则或者之前的字面量重名时使用 _T_xx
+  pub fn assign_token_names(&mut self) {
+    let mut used = self.token_names.clone();
+    for (key, text) in self.literals.iter() {
+      let rule = self.lexer_rule_map.get_mut(key).unwrap();
+      let name = Self::readable_name(text)
+        .filter(|name| !used.contains(name))
+        .unwrap_or_else(|| format!("_T_{}", rule.token_type));
+      used.insert(name.clone());
+      rule.token_name = name;
     }
   }
 
@@ -38,49 +133,134 @@ impl StringLiteralToTokenVisitor {
 
 impl ChiruVisitor for StringLiteralToTokenVisitor {
   fn visit_element(&mut self, ctx: &dyn ElementContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
-    
+
     // 处理 element 中的字符串常量即可
     if let Some(value) = ctx.string_literal() {
-      
+
       // 需要进行相关转义处理
-      let text = StringLiteralToTokenVisitor::escape(&value.symbol.text);// 为了防止字符串的内容和某个 token 同名, 因此不删除引号
+      let to_error = |message: String| error::Error::grammar_error(Some(value.symbol.location), &message);
+      let text = StringLiteralToTokenVisitor::escape(&value.symbol.text).map_err(to_error)?;
+      let key = format!("{:?}", text);// 为了防止字符串的内容和某个 token 同名, 因此不删除引号
 
       // 检查该字符串是否已经定义
-      if self.lexer_rule_map.contains_key(&text) {
+      if self.lexer_rule_map.contains_key(&key) {
         // 如果已经定义，则忽略它
         return self.default_result();
       }
 
-      let regular_string = StringLiteralToTokenVisitor::regular_escape(&value.symbol.text);
-      
-      // 定义之 token name = _T_xx
+      let regular_string = StringLiteralToTokenVisitor::regular_escape(&value.symbol.text).map_err(to_error)?;
+
+      // 名称在 assign_token_names 中确定
       let lexer_rule = LexerRule::new(
-        self.next_token_id, 
+        self.next_token_id,
         &format!("_T_{}", self.next_token_id),
         &regular_string,
-        
+
         // 之后需要修改
         0, false
       );
-      
+
       self.next_token_id += 1;
 
       // 将其插入即可
-      self.lexer_rule_map.insert(text, lexer_rule);
-      
+      self.lexer_rule_map.insert(key.clone(), lexer_rule);
+      self.literals.push((key, text));
+
     }
 
 
+    // block 中可能还有字符串常量
+    self.visit_children(ctx.as_rule())
+  }
 
-    self.default_result()
+  fn visit_lexer_rule(&mut self, ctx: &dyn LexerRuleContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
+    if let Some(name) = ctx.token_ref() {
+      self.token_names.insert(name.symbol.text.to_owned());
+    }
+    self.visit_children(ctx.as_rule())
   }
 
 }
 
 
+#[cfg(test)]
+mod tests {
+  use crate::tool::grammar::tests::grammar;
 
+  use super::StringLiteralToTokenVisitor as Visitor;
 
+  #[test]
+  fn escape_test() {
+    assert_eq!(Visitor::escape(r#""a\nb""#).unwrap(), "a\nb");
+    assert_eq!(Visitor::escape(r#""\r\t\a\f\v""#).unwrap(), "\r\t\x07\x0c\x0b");
+    assert_eq!(Visitor::escape(r#""\"\\""#).unwrap(), "\"\\");
+    // 未知的转义表示字符本身
+    assert_eq!(Visitor::escape(r#""\q""#).unwrap(), "q");
+    assert_eq!(Visitor::escape(r#""你\u{597d}\u{0x41}""#).unwrap(), "你好A");
 
+    assert!(Visitor::escape(r#""""#).is_err());
+    assert!(Visitor::escape(r#""\u{zz}""#).is_err());
+    assert!(Visitor::escape(r#""\u{110000}""#).is_err());
+    assert!(Visitor::escape(r#""\u{41""#).is_err());
+    assert!(Visitor::escape(r#""\""#).is_err());
+    assert!(Visitor::escape("abc").is_err());
+  }
 
+  #[test]
+  fn literal_regex_test() {
+    // 正则表达式恰好匹配字面量本身
+    for (literal, text, regex) in [
+      (r#""a.b*c""#, "a.b*c", r"a\.b\*c"),
+      (r#""(?:[^$])|{2}+""#, "(?:[^$])|{2}+", r"\(\?:\[\^\$\]\)\|\{2\}\+"),
+      (r#""\\d""#, "\\d", r"\\d"),
+      (r#""\"""#, "\"", "\""),
+      (r#""a\nb""#, "a\nb", r"a\nb"),
+      (r#""\a""#, "\x07", r"\x{7}"),
+      (r#""你好""#, "你好", "你好"),
+    ] {
+      let result = Visitor::literal_regex(literal).unwrap();
+      assert_eq!(result, regex);
+      assert!(!result.contains('\n'));
+      let re = regex::Regex::new(&format!("^(?:{})$", result)).unwrap();
+      assert!(re.is_match(text), "{}", literal);
+    }
+    assert!(!regex::Regex::new(&format!("^(?:{})$", Visitor::literal_regex(r#""a.b""#).unwrap())).unwrap().is_match("axb"));
+  }
 
+  #[test]
+  fn readable_name_test() {
+    assert_eq!(Visitor::readable_name("select").as_deref(), Some("KW_SELECT"));
+    assert_eq!(Visitor::readable_name("end_if").as_deref(), Some("KW_END_IF"));
+    assert_eq!(Visitor::readable_name("<=").as_deref(), Some("LT_EQUAL"));
+    assert_eq!(Visitor::readable_name("\\\"").as_deref(), Some("BACKSLASH_DQUOTE"));
+    assert_eq!(Visitor::readable_name("你"), None);
+    assert_eq!(Visitor::readable_name("a b"), None);
+    assert_eq!(Visitor::readable_name("_x"), None);
+    assert_eq!(Visitor::readable_name("\n"), None);
+  }
+
+  #[test]
+  fn readable_name_collision_test() {
+    let grammar = grammar(r#"
+      grammar Names;
+      s: "+" "select" "SELECT" "<=" "\u{3c}=" "==" "\n" PLUS;
+      PLUS: "plus";
+      @ignore
+      WS: " ";
+    "#);
+    let name = |key: &str| grammar.lexer_rule_map[key].token_name.clone();
+    let token_type = |key: &str| grammar.lexer_rule_map[key].token_type;
 
+    // 与词法规则重名
+    assert_eq!(name("\"+\""), format!("_T_{}", token_type("\"+\"")));
+    // 与之前的字面量重名
+    assert_eq!(name("\"select\""), "KW_SELECT");
+    assert_eq!(name("\"SELECT\""), format!("_T_{}", token_type("\"SELECT\"")));
+    // 同一个字符串的不同写法是同一个 token
+    assert_eq!(name("\"<=\""), "LT_EQUAL");
+    assert_eq!(grammar.lexer_rule_map.keys().filter(|key| key.contains('<')).count(), 1);
+    assert_eq!(name("\"==\""), "EQUAL_EQUAL");
+    // 没有可读名称
+    assert_eq!(name("\"\\n\""), format!("_T_{}", token_type("\"\\n\"")));
+  }
+}
@@ -0,0 +1,413 @@
+use std::{any::Any, collections::{HashMap, HashSet}, error::Error};
+
+use chiru::runtime::{ast::terminal_context::TerminalContext, lexer_dfa::LexerDfa};
+
+use crate::tool::{code_generator::name_case::NameCase, grammar::{diagnostic::{Diagnostic, Span}, lexer_rule::strip_raw_literal}, visitor::string_literal_to_token_visitor::StringLiteralToTokenVisitor, syntaxis::{chiru_visitor::ChiruVisitor, chiru_context::{AttributeContext, ElementContext, GrammarNameContext, LexerElementContext, LexerRuleContext, ModeRuleContext, ParserRuleContext, PrecedenceRuleContext}}};
+
+
+
+// 对词法规则的注解, 以及注解是否需要参数
+const LEXER_ANNOTATIONS: [(&str, bool); 6] = [
+  ("ignore", false), ("skip", false), ("channel", true), ("push_mode", true), ("mode", true), ("pop_mode", false),
+];
+
+
+// 语法规则中对其他规则或 token 的引用
+struct Reference {
+  name: String,
+  span: Span,
+  is_token: bool,
+}
+
+
+// 语法文件的种类
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum GrammarKind {
+  Combined,
+  Lexer,
+  Parser,
+}
+
+
+/**
+ * 在构造文法之前检查语法文件以及它导入的所有语法文件, 收集以下问题
+ * 错误: 未定义的规则和 token, 重复定义的规则和 token, 非法的正则表达式, 未知的注解和结合性, 未声明的词法模式,
+ *       与规则同名的备选分支标签, lexer grammar 中的语法规则以及 parser grammar 中的词法规则, 在语法规则中引用的 fragment
+ * 警告: 输入文件中未使用的规则, token 和 fragment, 从开始规则不可达的规则, 不能匹配任何 token 的规则
+ */
+pub struct ValidationVisitor {
+  pub diagnostics: Vec<Diagnostic>,
+
+  // 正在检查的语法文件的编号以及种类, 访问每个文件之前需要设置 current_file
+  pub current_file: usize,
+  grammar_kind: GrammarKind,
+
+  // 按照定义的顺序记录所有的规则和 token, 包括重复定义的
+  parser_rules: Vec<(String, Span)>,
+  lexer_rules: Vec<(String, Span)>,
+
+  // 带有注解的 token 即使没有被引用也是有用的, 如被忽略的空白
+  annotated_tokens: HashSet<String>,
+
+  // fragment 与 token 使用同一个命名空间, 但只能在词法规则中引用
+  fragments: HashSet<String>,
+  // 在词法规则中引用的 token 和 fragment
+  lexer_references: Vec<Reference>,
+
+  modes: HashSet<String>,
+  mode_references: Vec<(String, Span)>,
+  alternative_labels: Vec<(String, Span)>,
+
+  // 每个规则引用的规则和 token
+  references: HashMap<String, Vec<Reference>>,
+  // 在优先级声明中引用的 token
+  precedence_references: Vec<Reference>,
+  // 直接包含字符串字面量的规则
+  rules_with_literals: HashSet<String>,
+  // 语法规则中使用的字符串字面量, 以 literal_key 为键, 只有这些字面量会生成 token
+  literals: HashSet<String>,
+  // 在优先级声明中使用的字符串字面量, 必须在语法规则中出现过
+  precedence_literals: Vec<(String, String, Span)>,
+
+  current_rule: String,
+}
+
+
+impl Default for ValidationVisitor {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl ValidationVisitor {
+  pub fn new() -> Self {
+    Self {
+      diagnostics: Vec::new(),
+      current_file: 0,
+      grammar_kind: GrammarKind::Combined,
+      parser_rules: Vec::new(),
+      lexer_rules: Vec::new(),
+      annotated_tokens: HashSet::new(),
+      fragments: HashSet::new(),
+      lexer_references: Vec::new(),
+      modes: HashSet::from(["DEFAULT_MODE".to_owned()]),
+      mode_references: Vec::new(),
+      alternative_labels: Vec::new(),
+      references: HashMap::new(),
+      precedence_references: Vec::new(),
+      rules_with_literals: HashSet::new(),
+      literals: HashSet::new(),
+      precedence_literals: Vec::new(),
+      current_rule: String::new(),
+    }
+  }
+
+  fn span(&self, terminal: &TerminalContext) -> Span {
+    Span::new(self.current_file, terminal.symbol.location.byte_index_start..terminal.symbol.location.byte_index_stop)
+  }
+
+  // 检查词法规则上的注解
+  fn check_attribute(&mut self, att: &dyn AttributeContext) {
+    let name = match att.rule_ref().or(att.mode()) {
+      Some(name) => name,
+      None => return,
+    };
+
+    match LEXER_ANNOTATIONS.iter().find(|(annotation, _)| *annotation == name.symbol.text) {
+      None => {
+        let message = format!("unknown annotation '{}'", name.symbol.text);
+        self.diagnostics.push(Diagnostic::error(&message, self.span(name)));
+      },
+      Some((_, true)) if att.token_ref_list().is_empty() && att.string_literal_list().is_empty() => {
+        let message = format!("annotation '{}' requires an argument", name.symbol.text);
+        self.diagnostics.push(Diagnostic::error(&message, self.span(name)));
+      },
+      Some(("push_mode" | "mode", _)) => {
+        for mode in att.token_ref_list().iter() {
+          self.mode_references.push((mode.symbol.text.to_owned(), self.span(mode)));
+        }
+      },
+      _ => {},
+    }
+
+    // 只有优先级声明可以使用字符串字面量
+    for literal in att.string_literal_list().iter() {
+      let message = format!("annotation '{}' does not accept string literals", name.symbol.text);
+      self.diagnostics.push(Diagnostic::error(&message, self.span(literal)));
+    }
+  }
+
+  // 只要有一处定义就不是重复定义, 之后的定义都报告为重复
+  fn check_duplicates(definitions: &[(String, Span)], kind: &str, diagnostics: &mut Vec<Diagnostic>) {
+    let mut defined: HashMap<&str, &Span> = HashMap::new();
+    for (name, span) in definitions.iter() {
+      match defined.get(name.as_str()) {
+        Some(first) => {
+          let message = format!("{} '{}' is already defined", kind, name);
+          diagnostics.push(Diagnostic::error(&message, span.clone()).with_related((*first).clone(), "first defined here"));
+        },
+        None => { defined.insert(name, span); },
+      }
+    }
+  }
+
+  // 从开始规则出发能够到达的规则
+  fn reachable_rules(&self) -> HashSet<&str> {
+    let mut result = HashSet::new();
+    let mut stack = self.parser_rules.first().map(|(name, _)| vec![name.as_str()]).unwrap_or_default();
+    while let Some(rule) = stack.pop() {
+      if !result.insert(rule) { continue; }
+      for reference in self.references.get(rule).into_iter().flatten() {
+        if !reference.is_token { stack.push(&reference.name); }
+      }
+    }
+    result
+  }
+
+  // 能够匹配至少一个 token 的规则, 其余的规则只能匹配空串或者无限递归
+  fn productive_rules(&self) -> HashSet<&str> {
+    let mut result: HashSet<&str> = self.rules_with_literals.iter().map(|rule| rule.as_str()).collect();
+    let mut modified = true;
+    while modified {
+      modified = false;
+      for (rule, references) in self.references.iter() {
+        if result.contains(rule.as_str()) { continue; }
+        if references.iter().any(|reference| reference.is_token || result.contains(reference.name.as_str())) {
+          result.insert(rule);
+          modified = true;
+        }
+      }
+    }
+    result
+  }
+
+  // 访问结束之后调用, 返回所有的诊断信息, 按照在语法文件中的位置排序
+  pub fn finish(mut self) -> Vec<Diagnostic> {
+    let mut diagnostics = std::mem::take(&mut self.diagnostics);
+
+    Self::check_duplicates(&self.parser_rules, "rule", &mut diagnostics);
+    Self::check_duplicates(&self.lexer_rules, "token", &mut diagnostics);
+
+    let rules: HashSet<&str> = self.parser_rules.iter().map(|(name, _)| name.as_str()).collect();
+    let tokens: HashSet<&str> = self.lexer_rules.iter().map(|(name, _)| name.as_str()).collect();
+
+    // 未定义的引用
+    let references = self.references.values().flatten().chain(self.precedence_references.iter());
+    for reference in references {
+      let (defined, kind) = if reference.is_token { (tokens.contains(reference.name.as_str()), "token") } else { (rules.contains(reference.name.as_str()), "rule") };
+      if !defined {
+        let message = format!("undefined {} '{}'", kind, reference.name);
+        diagnostics.push(Diagnostic::error(&message, reference.span.clone()));
+      } else if reference.is_token && self.fragments.contains(&reference.name) {
+        let message = format!("fragment '{}' can only be used in lexer rules", reference.name);
+        diagnostics.push(Diagnostic::error(&message, reference.span.clone()));
+      }
+    }
+    // 字面量只有在语法规则中使用时才会生成 token
+    for (_, text, span) in self.precedence_literals.iter().filter(|(key, _, _)| !self.literals.contains(key)) {
+      let message = format!("string literal {} in precedence declaration is not used in any parser rule", text);
+      diagnostics.push(Diagnostic::error(&message, span.clone()));
+    }
+    for reference in self.lexer_references.iter().filter(|reference| !tokens.contains(reference.name.as_str())) {
+      let message = format!("undefined token or fragment '{}'", reference.name);
+      diagnostics.push(Diagnostic::error(&message, reference.span.clone()));
+    }
+
+    for (mode, span) in self.mode_references.iter() {
+      if !self.modes.contains(mode) {
+        let message = format!("undeclared mode '{}'", mode);
+        diagnostics.push(Diagnostic::error(&message, span.clone()));
+      }
+    }
+
+    // 备选分支的标签会生成同名的上下文, 转换为 PascalCase 之后不能与规则同名
+    for (label, span) in self.alternative_labels.iter() {
+      let pascal_case = NameCase::new(label).pascal_case;
+      if let Some((_, rule_span)) = self.parser_rules.iter().find(|(rule, _)| NameCase::new(rule).pascal_case == pascal_case) {
+        let message = format!("alternative label '{}' conflicts with rule name", label);
+        diagnostics.push(Diagnostic::error(&message, span.clone()).with_related(rule_span.clone(), "rule defined here"));
+      }
+    }
+
+    // 未使用的 token, 优先级声明不算使用, 导入的文件中的定义可能是给其他语法使用的, 不进行检查
+    let used_tokens: HashSet<&str> = self.references.values().flatten().chain(self.lexer_references.iter())
+      .filter(|reference| reference.is_token).map(|reference| reference.name.as_str()).collect();
+    for (name, span) in self.lexer_rules.iter().filter(|(_, span)| span.file == 0) {
+      if !used_tokens.contains(name.as_str()) && !self.annotated_tokens.contains(name) {
+        let kind = if self.fragments.contains(name) { "fragment" } else { "token" };
+        let message = format!("{} '{}' is never used", kind, name);
+        diagnostics.push(Diagnostic::warning(&message, span.clone()));
+      }
+    }
+
+    // 未使用和不可达的规则, 第一个规则为开始规则
+    let used_rules: HashSet<&str> = self.references.iter()
+      .flat_map(|(rule, references)| references.iter().filter(move |reference| !reference.is_token && reference.name != *rule))
+      .map(|reference| reference.name.as_str()).collect();
+    let reachable = self.reachable_rules();
+    let productive = self.productive_rules();
+    let mut reported = HashSet::new();
+    for (name, span) in self.parser_rules.iter().skip(1) {
+      if !reported.insert(name) || span.file != 0 { continue; }
+      if !used_rules.contains(name.as_str()) {
+        diagnostics.push(Diagnostic::warning(&format!("rule '{}' is never used", name), span.clone()));
+      } else if !reachable.contains(name.as_str()) {
+        diagnostics.push(Diagnostic::warning(&format!("rule '{}' is unreachable from the start rule", name), span.clone()));
+      }
+    }
+
+    let mut reported = HashSet::new();
+    for (name, span) in self.parser_rules.iter() {
+      if reported.insert(name) && !productive.contains(name.as_str()) {
+        diagnostics.push(Diagnostic::warning(&format!("rule '{}' never matches any token", name), span.clone()));
+      }
+    }
+
+    diagnostics.sort_by_key(|diagnostic| (diagnostic.span.file, diagnostic.span.range.start, diagnostic.severity));
+    diagnostics
+  }
+}
+
+
+
+impl ChiruVisitor for ValidationVisitor {
+
+  fn visit_grammar_name(&mut self, ctx: &dyn GrammarNameContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
+    self.grammar_kind = if ctx.lexer().is_some() {
+      GrammarKind::Lexer
+    } else if ctx.parser().is_some() {
+      GrammarKind::Parser
+    } else {
+      GrammarKind::Combined
+    };
+    self.default_result()
+  }
+
+  fn visit_parser_rule(&mut self, ctx: &dyn ParserRuleContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
+    let name = match ctx.rule_ref() {
+      Some(name) => name,
+      None => return self.default_result(),
+    };
+    if self.grammar_kind == GrammarKind::Lexer {
+      let message = format!("parser rule '{}' is not allowed in a lexer grammar", name.symbol.text);
+      self.diagnostics.push(Diagnostic::error(&message, self.span(name)));
+    }
+    self.parser_rules.push((name.symbol.text.to_owned(), self.span(name)));
+    self.current_rule = name.symbol.text.to_owned();
+    self.references.entry(self.current_rule.clone()).or_default();
+
+    for alternative in ctx.block().map(|block| block.alternative_list()).unwrap_or_default().iter() {
+      if let Some(label) = alternative.alternative_label().and_then(|label| label.rule_ref().or(label.token_ref())) {
+        self.alternative_labels.push((label.symbol.text.to_owned(), self.span(label)));
+      }
+    }
+    self.visit_children(ctx.as_rule())
+  }
+
+  fn visit_element(&mut self, ctx: &dyn ElementContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
+    let reference = match (ctx.token_ref(), ctx.rule_ref()) {
+      (Some(token), _) => Some(Reference { name: token.symbol.text.to_owned(), span: self.span(token), is_token: true }),
+      (_, Some(rule)) => Some(Reference { name: rule.symbol.text.to_owned(), span: self.span(rule), is_token: false }),
+      _ => None,
+    };
+    if let Some(reference) = reference {
+      self.references.entry(self.current_rule.clone()).or_default().push(reference);
+    }
+    if let Some(literal) = ctx.string_literal() {
+      self.rules_with_literals.insert(self.current_rule.clone());
+      match StringLiteralToTokenVisitor::literal_key(&literal.symbol.text) {
+        Ok(key) => { self.literals.insert(key); },
+        Err(message) => self.diagnostics.push(Diagnostic::error(&message, self.span(literal))),
+      }
+    }
+
+    // block 中还有其他元素
+    self.visit_children(ctx.as_rule())
+  }
+
+  fn visit_lexer_rule(&mut self, ctx: &dyn LexerRuleContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
+    let name = match ctx.token_ref() {
+      Some(name) => name,
+      None => return self.default_result(),
+    };
+    if self.grammar_kind == GrammarKind::Parser {
+      let message = format!("lexer rule '{}' is not allowed in a parser grammar", name.symbol.text);
+      self.diagnostics.push(Diagnostic::error(&message, self.span(name)));
+    }
+    self.lexer_rules.push((name.symbol.text.to_owned(), self.span(name)));
+    self.current_rule = name.symbol.text.to_owned();
+
+    if let (Some(fragment), Some(_)) = (ctx.fragment(), ctx.annotation()) {
+      let message = format!("fragment '{}' cannot have annotations", name.symbol.text);
+      self.diagnostics.push(Diagnostic::error(&message, self.span(fragment)));
+    }
+    if ctx.fragment().is_some() {
+      self.fragments.insert(name.symbol.text.to_owned());
+    } else if let Some(annotation) = ctx.annotation() {
+      self.annotated_tokens.insert(name.symbol.text.to_owned());
+      if let Some(att) = annotation.attribute() {
+        self.check_attribute(att);
+      } else if let Some(attr_list) = annotation.attributes() {
+        for att in attr_list.attribute_list().iter() {
+          self.check_attribute(*att);
+        }
+      }
+    }
+
+    match ctx.lexer_block() {
+      Some(block) => block.accept(self),
+      None => self.default_result(),
+    }
+  }
+
+  fn visit_lexer_element(&mut self, ctx: &dyn LexerElementContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
+    // 检查正则表达式能否编译为词法分析器使用的 DFA, 与 LexerDfa::new 使用相同的构造方式
+    if let Some(regular) = ctx.regular().and_then(|regular| regular.regular_literal()) {
+      if let Err(err) = LexerDfa::check_pattern(&strip_raw_literal(&regular.symbol.text)) {
+        let message = format!("invalid regular expression for token '{}': {}", self.current_rule, err);
+        self.diagnostics.push(Diagnostic::error(&message, self.span(regular)));
+      }
+    }
+    if let Some(literal) = ctx.string_literal() {
+      if let Err(message) = StringLiteralToTokenVisitor::escape(&literal.symbol.text) {
+        self.diagnostics.push(Diagnostic::error(&message, self.span(literal)));
+      }
+    }
+    if let Some(token) = ctx.token_ref() {
+      self.lexer_references.push(Reference { name: token.symbol.text.to_owned(), span: self.span(token), is_token: true });
+    }
+
+    // 括号中还有其他元素
+    self.visit_children(ctx.as_rule())
+  }
+
+  fn visit_mode_rule(&mut self, ctx: &dyn ModeRuleContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
+    if let Some(name) = ctx.token_ref() {
+      self.modes.insert(name.symbol.text.to_owned());
+    }
+    self.default_result()
+  }
+
+  fn visit_precedence_rule(&mut self, ctx: &dyn PrecedenceRuleContext) -> Result<Box<dyn Any>, Box<dyn Error>> {
+    let attribute = match ctx.attribute() {
+      Some(attribute) => attribute,
+      None => return self.default_result(),
+    };
+    if let Some(name) = attribute.rule_ref().or(attribute.mode()) {
+      if !matches!(name.symbol.text.as_str(), "left" | "right") {
+        let message = format!("unknown associativity '{}', expected 'left' or 'right'", name.symbol.text);
+        self.diagnostics.push(Diagnostic::error(&message, self.span(name)));
+      }
+    }
+    for token in attribute.token_ref_list().iter() {
+      self.precedence_references.push(Reference { name: token.symbol.text.to_owned(), span: self.span(token), is_token: true });
+    }
+    for literal in attribute.string_literal_list().iter() {
+      match StringLiteralToTokenVisitor::literal_key(&literal.symbol.text) {
+        Ok(key) => self.precedence_literals.push((key, literal.symbol.text.to_owned(), self.span(literal))),
+        Err(message) => self.diagnostics.push(Diagnostic::error(&message, self.span(literal))),
+      }
+    }
+    self.default_result()
+  }
+}
@@ -1,6 +1,15 @@
+#[allow(unused)]
 mod generate;
 
+use std::{cell::RefCell, collections::{BTreeMap, BTreeSet}, rc::Rc};
 
+use chiru::runtime::{
+  ast::{ast_context::AstContext, error_context::ErrorSymbol},
+  error_strategy::{error_listener::ErrorListener, error_strategy::{BailErrorSrategy, DefaultErrorStrategy, ErrorStrategy, SingleTokenErrorStrategy}},
+  lexer::{Lexer, TokenIter}, lexer_dfa::LexerDfa, lexer_rule::LexerRule, lalr_analyzer::{lalr_analyze, ActionTableElement}, ll1_analyzer::ll1_analyze, location::Location,
+  error::Error, production::{Production, ProductionItem}, token::Token, token_stream::TokenStream, vocabulary::{NonTerminal, Terminal}
+};
+use generate::{chiru_lexer::ChiruLexer, chiru_parser::ChiruParser};
 
 
 #[test]
@@ -15,4 +24,345 @@ fn foo_test() {
 
 }
 
+#[test]
+fn lalr_parser_test() {
+  // tests/generate 中的语法分析器使用 lalr 分析表生成
+  let input = r####"
+    grammar Expr;
+    expr: expr (PLUS | MINUS) term | term;
+    term: NUMBER;
+
+    @left(PLUS, MINUS);
+
+    PLUS: r##"\+"##;
+    MINUS: r##"-"##;
+    NUMBER: r##"[0-9]+"##;
+    @ignore
+    WHITE_SPACE: r##"[ \r\n\t]+"##;
+  "####;
+
+  let lexer = ChiruLexer::new(input);
+  let mut tokens = TokenStream::new(&lexer, 0);
+  let parser = ChiruParser::new();
+  let ast = parser.compilation_unit(&mut tokens).unwrap();
+
+  let rules = ast.rules().unwrap();
+  assert_eq!(rules.parser_rule_list().len(), 2);
+  assert_eq!(rules.lexer_rule_list().len(), 4);
+
+  let precedence = rules.precedence_rule_list()[0].attribute().unwrap();
+  assert_eq!(precedence.rule_ref().unwrap().symbol.text, "left");
+  assert_eq!(precedence.token_ref_list().len(), 2);
+  assert!(ast.as_rule().get_errornodes().is_empty());
+}
+
+#[test]
+fn longest_match_test() {
+  // 最长匹配: model 是 RULE_REF 而不是 MODE 加上 l; 长度相同时, 先定义的 MODE 优先于 RULE_REF
+  let lexer = ChiruLexer::new("model mode += :=");
+  let tokens = lexer.iter().map(|token| token.terminal.id).collect::<Vec<_>>();
+  assert_eq!(tokens, vec![
+    ChiruLexer::RULE_REF, ChiruLexer::MODE, ChiruLexer::PLUS_ASSIGN, ChiruLexer::COLON,
+  ]);
+}
+
+#[test]
+fn lexer_dfa_bytes_test() {
+  // 从字节中恢复的自动机与直接构造的自动机匹配结果相同, 包括非贪婪匹配和不同的词法模式
+  let rule = |pattern: &str, mode: usize| LexerRule {
+    pattern: pattern.to_owned(), token_type: 2, channel: 0, token_name: String::from("T"),
+    skip: false, mode, mode_action: None,
+  };
+  let rules = vec![rule(r"(?s)/\*.*?\*/", 0), rule("[a-z]+", 0), rule("mode", 0), rule(r"[^}]+", 1)];
+  let dfa = LexerDfa::new(&rules).unwrap();
+  let bytes = dfa.to_bytes();
+  let restored = LexerDfa::from_bytes(&bytes).unwrap();
+
+  let cases = [(0, "/* a */ b */", 0), (0, "mode", 0), (0, "modes", 0), (0, "x /**/", 2), (0, "{", 0), (1, "ab}", 0), (2, "", 0)];
+  for (mode, input, cursor) in cases {
+    assert_eq!(restored.longest_match(mode, input, cursor), dfa.longest_match(mode, input, cursor));
+  }
+  assert_eq!(restored.longest_match(0, "/* a */ b */", 0), Some((0, 7)));
+  assert_eq!(restored.longest_match(0, "modes", 0), Some((1, 5)));
+  assert_eq!(restored.longest_match(1, "ab}", 0), Some((3, 2)));
+
+  // 不完整或者多余的字节都会返回错误, 而不是 panic
+  assert!(LexerDfa::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+  assert!(LexerDfa::from_bytes(&[bytes.as_slice(), &[0]].concat()).is_err());
+  assert!(LexerDfa::from_bytes(&[0xff; 16]).is_err());
+}
+
+
+#[test]
+fn unmatched_char_test() {
+  // 记录所有无法匹配的字符及其字节位置
+  struct RecordListener(Rc<RefCell<Vec<(String, usize)>>>);
+  impl ErrorListener for RecordListener {
+    fn lexer_error(&self, text: &str, location: &Location) {
+      self.0.borrow_mut().push((text.to_owned(), location.byte_index_start));
+    }
+  }
+
+  let errors = Rc::new(RefCell::new(Vec::new()));
+  let mut lexer = ChiruLexer::new("mode ~ model §");
+  lexer.remove_all_error_listeners();
+  lexer.add_error_listener(Box::new(RecordListener(errors.clone())));
+
+  let tokens = lexer.iter().map(|token| token.terminal.id).collect::<Vec<_>>();
+  assert_eq!(tokens, vec![ChiruLexer::MODE, ChiruLexer::RULE_REF]);
+  assert_eq!(*errors.borrow(), vec![("~".to_owned(), 5), ("§".to_owned(), 13)]);
+}
+
+
+#[test]
+fn syntax_error_listener_test() {
+  // 记录所有语法错误的信息和位置
+  struct RecordListener(Rc<RefCell<Vec<(String, usize, usize)>>>);
+  impl ErrorListener for RecordListener {
+    fn syntax_error(&self, _rule: NonTerminal<'_>, _offending_token: &Token<'_>, location: &Location, _expected: &BTreeSet<Terminal<'_>>, message: &str) {
+      self.0.borrow_mut().push((message.to_owned(), location.start.line, location.start.char_position));
+    }
+  }
+
+  let input = "grammar Foo;\nexpr: NUMBER; ;\nterm: NUMBER";
+
+  let errors = Rc::new(RefCell::new(Vec::new()));
+  let lexer = ChiruLexer::new(input);
+  let mut tokens = TokenStream::new(&lexer, 0);
+  let mut parser = ChiruParser::new();
+  parser.error_listeners = vec![Box::new(RecordListener(errors.clone()))];
+  let ast = parser.compilation_unit(&mut tokens).unwrap();
+
+  // 错误节点中记录了位置和错误信息
+  let tree = ast.as_rule().to_string();
+  assert!(tree.contains("<2:15 extraneous input ';' expecting {AT, FRAGMENT, MODE, RULE_REF, SHARP, TOKEN_REF, _STOP}>"));
+  assert!(tree.contains("<3:13 missing SEMI at '_STOP'>"));
+
+  assert_eq!(*errors.borrow(), vec![
+    ("extraneous input ';' expecting {AT, FRAGMENT, MODE, RULE_REF, SHARP, TOKEN_REF, _STOP}".to_owned(), 1, 14),
+    ("missing SEMI at '_STOP'".to_owned(), 2, 12),
+  ]);
+}
+
+
+#[test]
+fn look_ahead_test() {
+  let lexer = ChiruLexer::new("grammar Foo;");
+  let mut tokens = TokenStream::new(&lexer, 0);
+  tokens.consume().unwrap();
+
+  let names = (1..=5).map(|n| tokens.look_ahead(n).unwrap().terminal.name).collect::<Vec<_>>();
+  assert_eq!(names, vec!["GRAMMAR", "TOKEN_REF", "SEMI", "_STOP", "_STOP"]);
+}
+
+
+// 手工构造词法规则的测试用词法分析器, 规则的 token_type 从 2 开始依次编号, WS 会被跳过
+struct TestLexer<'a> { input: &'a str, rules: Vec<LexerRule>, dfa: LexerDfa, error_listeners: Vec<Box<dyn ErrorListener>> }
+
+impl Lexer for TestLexer<'_> {
+  fn iter(&self) -> TokenIter<'_> {
+    TokenIter::new(self.input, &self.rules, &self.dfa, &self.error_listeners)
+  }
+}
 
+fn test_lexer_rules(names: &[&str]) -> Vec<LexerRule> {
+  names.iter().enumerate().map(|(index, name)| {
+    let pattern = match *name {
+      "LPAREN" => r"\(", "RPAREN" => r"\)", "RBRACKET" => r"\]", "SEMI" => ";", "NUM" => "[0-9]+", "WS" => " +",
+      _ => unreachable!(),
+    };
+    LexerRule {
+      pattern: pattern.to_owned(), token_type: index + 2, channel: 0, token_name: name.to_string(),
+      skip: *name == "WS", mode: 0, mode_action: None,
+    }
+  }).collect()
+}
+
+
+#[test]
+fn single_token_recovery_test() {
+  // s -> LPAREN NUM RPAREN SEMI
+  let names = ["LPAREN", "NUM", "RPAREN", "SEMI", "RBRACKET", "WS"];
+  let rules = test_lexer_rules(&names);
+
+  let s = NonTerminal::new(Some("s"), 0);
+  let terminals = names.iter().enumerate().map(|(index, name)| Terminal::new(name, index + 2)).collect::<Vec<_>>();
+  let productions = BTreeMap::from([(0, Production::new(0, s, &[
+    ProductionItem::Terminal(terminals[0]), ProductionItem::Terminal(terminals[1]),
+    ProductionItem::Terminal(terminals[2]), ProductionItem::Terminal(terminals[3]),
+  ]))]);
+  let table = BTreeMap::from([((s, terminals[0]), 0)]);
+  let sync = BTreeSet::from([(s, Terminal::new("_STOP", 1))]);
+
+  let parse = |input: &str| {
+    let dfa = LexerDfa::new(&rules).unwrap();
+    let lexer = TestLexer { input, rules: rules.clone(), dfa, error_listeners: vec![] };
+    let mut tokens = TokenStream::new(&lexer, 0);
+    tokens.consume().unwrap();
+    let ast = ll1_analyze(&mut tokens, s, &table, &productions, &sync, &BTreeMap::new(), &[], &SingleTokenErrorStrategy).unwrap();
+    ast.children.iter().map(|child| match child {
+      AstContext::Terminal(ctx) => ctx.symbol.terminal.name.to_owned(),
+      AstContext::Error(ctx) => match &ctx.symbol {
+        ErrorSymbol::Redundant(token) => format!("redundant {}", token.terminal.name),
+        ErrorSymbol::Mistake(token) => format!("mistake {}", token.terminal.name),
+        ErrorSymbol::Missing => "missing".to_owned(),
+      },
+      AstContext::Rule(_) => unreachable!(),
+    }).collect::<Vec<_>>()
+  };
+
+  // 删除多余的 ]
+  assert_eq!(parse("( 1 ] ) ;"), vec!["LPAREN", "NUM", "redundant RBRACKET", "RPAREN", "SEMI"]);
+  // 插入缺失的 )
+  assert_eq!(parse("( 1 ;"), vec!["LPAREN", "NUM", "missing", "SEMI"]);
+  // 将 ] 替换为 )
+  assert_eq!(parse("( 1 ] ;"), vec!["LPAREN", "NUM", "mistake RBRACKET", "SEMI"]);
+}
+
+
+#[test]
+fn ll1_trailing_input_test() {
+  // s -> NUM s', s' -> SEMI NUM s' | ε, 开始符号分析完毕之后还有剩余的 token
+  let names = ["NUM", "SEMI", "RPAREN", "WS"];
+  let rules = test_lexer_rules(&names);
+
+  let s = NonTerminal::new(Some("s"), 0);
+  let tail = NonTerminal::new(None, 1);
+  let terminals = names.iter().enumerate().map(|(index, name)| Terminal::new(name, index + 2)).collect::<Vec<_>>();
+  let stop = Terminal::new("_STOP", 1);
+  let productions = BTreeMap::from([
+    (0, Production::new(0, s, &[ProductionItem::Terminal(terminals[0]), ProductionItem::NonTerminal(tail)])),
+    (1, Production::new(1, tail, &[ProductionItem::Terminal(terminals[1]), ProductionItem::Terminal(terminals[0]), ProductionItem::NonTerminal(tail)])),
+    (2, Production::new(2, tail, &[])),
+  ]);
+  let table = BTreeMap::from([((s, terminals[0]), 0), ((tail, terminals[1]), 1), ((tail, stop), 2), ((tail, terminals[2]), 2)]);
+  // 用同步集合模拟 s 出现在 ( s ) 之中的情况, 此时 ) 可以跟在 s 之后
+  let sync = BTreeSet::from([(s, stop), (s, terminals[2]), (tail, stop), (tail, terminals[2])]);
+
+  let parse = |input: &str, error_strategy: &dyn ErrorStrategy| {
+    let dfa = LexerDfa::new(&rules).unwrap();
+    let lexer = TestLexer { input, rules: rules.clone(), dfa, error_listeners: vec![] };
+    let mut tokens = TokenStream::new(&lexer, 0);
+    tokens.consume().unwrap();
+    ll1_analyze(&mut tokens, s, &table, &productions, &sync, &BTreeMap::new(), &[], error_strategy).map(|ast| ast.to_string())
+  };
+
+  assert!(parse("1 ; 2", &BailErrorSrategy).is_ok());
+  // 剩余的 token 不能被忽略
+  let error = parse("1 ; 2 ) 3", &BailErrorSrategy).unwrap_err();
+  assert_eq!(error.to_string(), "1:7: syntax error: mismatched input ')' expecting _STOP");
+
+  // 单个多余的 token 直接删除, 否则一直丢弃到输入末尾
+  let tree = parse("1 ; 2 )", &SingleTokenErrorStrategy).unwrap();
+  assert!(tree.contains("<1:7 extraneous input ')' expecting _STOP>"));
+  let tree = parse("1 ; 2 ) 3", &DefaultErrorStrategy).unwrap();
+  assert!(tree.contains("<1:7 extraneous input ')' expecting _STOP>"));
+  assert!(tree.contains("<1:9 extraneous input '3' expecting _STOP>"));
+}
+
+
+#[test]
+fn lalr_analyze_test() {
+  // s -> LPAREN e RPAREN, e -> NUM
+  let names = ["LPAREN", "NUM", "RPAREN", "RBRACKET", "WS"];
+  let rules = test_lexer_rules(&names);
+
+  let s = NonTerminal::new(Some("s"), 0);
+  let e = NonTerminal::new(Some("e"), 1);
+  let terminals = names.iter().enumerate().map(|(index, name)| Terminal::new(name, index + 2)).collect::<Vec<_>>();
+  let stop = Terminal::new("_STOP", 1);
+  let productions = BTreeMap::from([
+    (0, Production::new(0, s, &[ProductionItem::Terminal(terminals[0]), ProductionItem::NonTerminal(e), ProductionItem::Terminal(terminals[2])])),
+    (1, Production::new(1, e, &[ProductionItem::Terminal(terminals[1])])),
+  ]);
+
+  // 0: s' -> . s    1: s -> ( . e )    2: e -> NUM .    3: s -> ( e . )    4: s -> ( e ) .    5: s' -> s .
+  let action_table = BTreeMap::from([
+    ((0, terminals[0]), ActionTableElement::Shift(1)),
+    ((1, terminals[1]), ActionTableElement::Shift(2)),
+    ((2, terminals[2]), ActionTableElement::Reduce(1)),
+    ((3, terminals[2]), ActionTableElement::Shift(4)),
+    ((4, stop), ActionTableElement::Reduce(0)),
+    ((5, stop), ActionTableElement::Accept),
+  ]);
+  let goto_table = BTreeMap::from([((0, s), 5), ((1, e), 3)]);
+  let start_states = BTreeMap::from([(s, 0)]);
+
+  let parse = |input: &str| {
+    let dfa = LexerDfa::new(&rules).unwrap();
+    let lexer = TestLexer { input, rules: rules.clone(), dfa, error_listeners: vec![] };
+    let mut tokens = TokenStream::new(&lexer, 0);
+    tokens.consume().unwrap();
+    lalr_analyze(&mut tokens, s, &action_table, &goto_table, &start_states, &productions, &[]).unwrap().to_string()
+  };
+
+  // 移入、归约、接受
+  assert_eq!(parse("( 1 )"), "(s LPAREN (e NUM) RPAREN)");
+  // 删除多余的 ]
+  assert_eq!(parse("( 1 ] )"), "(s LPAREN (e NUM) <1:5 extraneous input ']' expecting RPAREN> RPAREN)");
+  // 在输入末尾插入缺失的 )
+  assert_eq!(parse("( 1"), "(s LPAREN (e NUM) <1:4 missing RPAREN at '_STOP'>)");
+}
+
+
+#[test]
+fn lalr_recover_fail_test() {
+  let names = ["LPAREN", "NUM", "RPAREN", "WS"];
+  let rules = test_lexer_rules(&names);
+
+  let s = NonTerminal::new(Some("s"), 0);
+  let terminals = names.iter().enumerate().map(|(index, name)| Terminal::new(name, index + 2)).collect::<Vec<_>>();
+  let stop = Terminal::new("_STOP", 1);
+  let start_states = BTreeMap::from([(s, 0)]);
+
+  let parse = |input: &str, action_table: &BTreeMap<(usize, Terminal), ActionTableElement>, goto_table: &BTreeMap<(usize, NonTerminal), usize>, productions: &BTreeMap<usize, Production>| {
+    let dfa = LexerDfa::new(&rules).unwrap();
+    let lexer = TestLexer { input, rules: rules.clone(), dfa, error_listeners: vec![] };
+    let mut tokens = TokenStream::new(&lexer, 0);
+    tokens.consume().unwrap();
+    match lalr_analyze(&mut tokens, s, action_table, goto_table, &start_states, productions, &[]) {
+      Ok(ast) => Some(ast.to_string()),
+      Err(error) => match error.downcast_ref::<Error>() {
+        Some(Error::ParserRecoverFail) => None,
+        _ => panic!("unexpected error: {}", error),
+      },
+    }
+  };
+
+  // s -> ( NUM ) ( NUM ), 在输入末尾插入缺失的终结符, 每个错误节点报告实际插入的终结符
+  let items = [0, 1, 2, 0, 1].map(|index| ProductionItem::Terminal(terminals[index]));
+  let productions = BTreeMap::from([(0, Production::new(0, s, &items))]);
+  let mut action_table = (0..5).map(|state| ((state, terminals[[0, 1, 2, 0, 1][state]]), ActionTableElement::Shift(state + 1))).collect::<BTreeMap<_, _>>();
+  action_table.insert((5, stop), ActionTableElement::Reduce(0));
+  action_table.insert((6, stop), ActionTableElement::Accept);
+  let goto_table = BTreeMap::from([((0, s), 6)]);
+  assert_eq!(parse("( 1 ) (", &action_table, &goto_table, &productions).unwrap(), "(s LPAREN NUM RPAREN LPAREN <1:8 missing NUM at '_STOP'>)");
+  assert_eq!(
+    parse("( 1", &action_table, &goto_table, &productions).unwrap(),
+    "(s LPAREN NUM <1:4 missing RPAREN at '_STOP'> <1:4 missing LPAREN at '_STOP'> <1:4 missing NUM at '_STOP'>)"
+  );
+
+  // 状态 1 可以无限地移入 (, 但永远无法接受, 搜索的状态数达到上限之后放弃恢复
+  let productions = BTreeMap::from([(0, Production::new(0, s, &[ProductionItem::Terminal(terminals[0])]))]);
+  let action_table = BTreeMap::from([
+    ((0, terminals[0]), ActionTableElement::Shift(1)),
+    ((1, terminals[0]), ActionTableElement::Shift(1)),
+  ]);
+  assert_eq!(parse("(", &action_table, &BTreeMap::new(), &productions), None);
+}
+
+
+#[test]
+fn error_display_test() {
+  use std::error::Error as _;
+
+  let position = chiru::runtime::position::Position { line: 2, char_position: 4 };
+  let location = Location::new(position, position, 10, 11);
+  assert_eq!(Error::syntax_error(location, "missing SEMI at '}'").to_string(), "3:5: syntax error: missing SEMI at '}'");
+  assert_eq!(Error::grammar_error(None, "undefined rule 'expr'").to_string(), "grammar error: undefined rule 'expr'");
+
+  // io 错误作为 source 保留下来
+  let error = Error::from(std::io::Error::new(std::io::ErrorKind::NotFound, "no such file"));
+  assert_eq!(error.to_string(), "failed to read or write file");
+  assert_eq!(error.source().unwrap().to_string(), "no such file");
+}
@@ -2,17 +2,18 @@
 
 
 
-// generated from .\src\tool\syntaxis\chiru.chiru by chiru 0.7.0
+// generated from src/tool/syntaxis/chiru.chiru by chiru 0.7.0
  
 
 
 use chiru::once_cell::sync::Lazy;
-use chiru::regex::Regex;
 
 use chiru::runtime::error_strategy::error_listener::{ErrorListener, ConsoleErrorListener};
 use chiru::runtime::lexer::TokenIter;
 use chiru::runtime::lexer::Lexer;
-use chiru::runtime::lexer_rule::LexerRule;
+use chiru::runtime::lexer_dfa::LexerDfa;
+#[allow(unused_imports)]
+use chiru::runtime::lexer_rule::{LexerRule, ModeAction};
 
 pub struct ChiruLexer<'a> {
   pub input: &'a str, 
@@ -25,193 +26,877 @@ static LEXER_RULE_LIST: Lazy<Vec<LexerRule>> = Lazy::new(|| {
   vec![
     
     LexerRule { 
-      rule: Regex::new(r###"grammar"###).unwrap(),  
+      pattern: String::from(r###"grammar"###),  
       token_type: 2, 
       channel: 0, 
       token_name: String::from("GRAMMAR"), 
       skip: false,
+      mode: 0,
+      mode_action: None,
     }, 
     LexerRule { 
-      rule: Regex::new(r###"[a-z][a-zA-Z0-9_]*"###).unwrap(),  
+      pattern: String::from(r###"lexer"###),  
       token_type: 3, 
       channel: 0, 
-      token_name: String::from("RULE_REF"), 
+      token_name: String::from("LEXER"), 
       skip: false,
+      mode: 0,
+      mode_action: None,
     }, 
     LexerRule { 
-      rule: Regex::new(r###"[A-Z][a-zA-Z0-9_]*"###).unwrap(),  
+      pattern: String::from(r###"parser"###),  
       token_type: 4, 
       channel: 0, 
-      token_name: String::from("TOKEN_REF"), 
+      token_name: String::from("PARSER"), 
       skip: false,
+      mode: 0,
+      mode_action: None,
     }, 
     LexerRule { 
-      rule: Regex::new(r###"::=|:=|->|=>|:|="###).unwrap(),  
+      pattern: String::from(r###"import"###),  
       token_type: 5, 
       channel: 0, 
-      token_name: String::from("COLON"), 
+      token_name: String::from("IMPORT"), 
       skip: false,
+      mode: 0,
+      mode_action: None,
     }, 
     LexerRule { 
-      rule: Regex::new(r###";"###).unwrap(),  
+      pattern: String::from(r###"mode"###),  
       token_type: 6, 
       channel: 0, 
-      token_name: String::from("SEMI"), 
+      token_name: String::from("MODE"), 
       skip: false,
+      mode: 0,
+      mode_action: None,
     }, 
     LexerRule { 
-      rule: Regex::new(r###","###).unwrap(),  
+      pattern: String::from(r###"fragment"###),  
       token_type: 7, 
       channel: 0, 
-      token_name: String::from("COMMA"), 
+      token_name: String::from("FRAGMENT"), 
       skip: false,
+      mode: 0,
+      mode_action: None,
     }, 
     LexerRule { 
-      rule: Regex::new(r###"\|"###).unwrap(),  
+      pattern: String::from(r###"ε|epsilon"###),  
       token_type: 8, 
       channel: 0, 
-      token_name: String::from("OR"), 
+      token_name: String::from("EPSILON"), 
       skip: false,
+      mode: 0,
+      mode_action: None,
     }, 
     LexerRule { 
-      rule: Regex::new(r###"ε|epsilon"###).unwrap(),  
+      pattern: String::from(r###"[a-z][a-zA-Z0-9_]*"###),  
       token_type: 9, 
       channel: 0, 
-      token_name: String::from("EPSILON"), 
+      token_name: String::from("RULE_REF"), 
       skip: false,
+      mode: 0,
+      mode_action: None,
     }, 
     LexerRule { 
-      rule: Regex::new(r###"\*"###).unwrap(),  
+      pattern: String::from(r###"[A-Z][a-zA-Z0-9_]*"###),  
       token_type: 10, 
       channel: 0, 
-      token_name: String::from("STAR"), 
+      token_name: String::from("TOKEN_REF"), 
       skip: false,
+      mode: 0,
+      mode_action: None,
     }, 
     LexerRule { 
-      rule: Regex::new(r###"\+"###).unwrap(),  
+      pattern: String::from(r###"::=|:=|->|=>|:"###),  
       token_type: 11, 
       channel: 0, 
-      token_name: String::from("PLUS"), 
+      token_name: String::from("COLON"), 
       skip: false,
+      mode: 0,
+      mode_action: None,
     }, 
     LexerRule { 
-      rule: Regex::new(r###"\?"###).unwrap(),  
+      pattern: String::from(r###"="###),  
       token_type: 12, 
       channel: 0, 
-      token_name: String::from("QUESTION"), 
+      token_name: String::from("ASSIGN"), 
       skip: false,
+      mode: 0,
+      mode_action: None,
     }, 
     LexerRule { 
-      rule: Regex::new(r###"\("###).unwrap(),  
+      pattern: String::from(r###"\+="###),  
       token_type: 13, 
       channel: 0, 
-      token_name: String::from("LPAREN"), 
+      token_name: String::from("PLUS_ASSIGN"), 
       skip: false,
+      mode: 0,
+      mode_action: None,
     }, 
     LexerRule { 
-      rule: Regex::new(r###"\)"###).unwrap(),  
+      pattern: String::from(r###";"###),  
       token_type: 14, 
       channel: 0, 
-      token_name: String::from("RPAREN"), 
+      token_name: String::from("SEMI"), 
       skip: false,
+      mode: 0,
+      mode_action: None,
     }, 
     LexerRule { 
-      rule: Regex::new(r###"@"###).unwrap(),  
+      pattern: String::from(r###","###),  
       token_type: 15, 
       channel: 0, 
-      token_name: String::from("AT"), 
+      token_name: String::from("COMMA"), 
       skip: false,
+      mode: 0,
+      mode_action: None,
     }, 
     LexerRule { 
-      rule: Regex::new(r###"#"###).unwrap(),  
+      pattern: String::from(r###"\|"###),  
       token_type: 16, 
       channel: 0, 
-      token_name: String::from("SHARP"), 
+      token_name: String::from("OR"), 
       skip: false,
+      mode: 0,
+      mode_action: None,
     }, 
     LexerRule { 
-      rule: Regex::new(r###"\["###).unwrap(),  
+      pattern: String::from(r###"\*"###),  
       token_type: 17, 
       channel: 0, 
-      token_name: String::from("LBRACKET"), 
+      token_name: String::from("STAR"), 
       skip: false,
+      mode: 0,
+      mode_action: None,
     }, 
     LexerRule { 
-      rule: Regex::new(r###"\]"###).unwrap(),  
+      pattern: String::from(r###"\+"###),  
       token_type: 18, 
       channel: 0, 
-      token_name: String::from("RBRACKET"), 
+      token_name: String::from("PLUS"), 
       skip: false,
+      mode: 0,
+      mode_action: None,
     }, 
     LexerRule { 
-      rule: Regex::new(r###""((\\\\|\\"|\\a|\\d|\\n|\\r|\\t|\\f|\\v|\\u\{(0x|0)?[a-f0-9]+\})|\d|[^\a\d\n\r\t\f\v\\"])*""###).unwrap(),  
+      pattern: String::from(r###"\?"###),  
       token_type: 19, 
       channel: 0, 
-      token_name: String::from("STRING_LITERAL"), 
+      token_name: String::from("QUESTION"), 
       skip: false,
+      mode: 0,
+      mode_action: None,
     }, 
     LexerRule { 
-      rule: Regex::new(r###"(?s)r##".*?"##"###).unwrap(),  
+      pattern: String::from(r###"\("###),  
       token_type: 20, 
       channel: 0, 
-      token_name: String::from("REGULAR_LITERAL"), 
+      token_name: String::from("LPAREN"), 
       skip: false,
+      mode: 0,
+      mode_action: None,
     }, 
     LexerRule { 
-      rule: Regex::new(r###"[ \r\n\t\f]+"###).unwrap(),  
+      pattern: String::from(r###"\)"###),  
       token_type: 21, 
       channel: 0, 
+      token_name: String::from("RPAREN"), 
+      skip: false,
+      mode: 0,
+      mode_action: None,
+    }, 
+    LexerRule { 
+      pattern: String::from(r###"@"###),  
+      token_type: 22, 
+      channel: 0, 
+      token_name: String::from("AT"), 
+      skip: false,
+      mode: 0,
+      mode_action: None,
+    }, 
+    LexerRule { 
+      pattern: String::from(r###"#"###),  
+      token_type: 23, 
+      channel: 0, 
+      token_name: String::from("SHARP"), 
+      skip: false,
+      mode: 0,
+      mode_action: None,
+    }, 
+    LexerRule { 
+      pattern: String::from(r###"\["###),  
+      token_type: 24, 
+      channel: 0, 
+      token_name: String::from("LBRACKET"), 
+      skip: false,
+      mode: 0,
+      mode_action: None,
+    }, 
+    LexerRule { 
+      pattern: String::from(r###"\]"###),  
+      token_type: 25, 
+      channel: 0, 
+      token_name: String::from("RBRACKET"), 
+      skip: false,
+      mode: 0,
+      mode_action: None,
+    }, 
+    LexerRule { 
+      pattern: String::from(r###""((\\\\|\\"|\\a|\\d|\\n|\\r|\\t|\\f|\\v|\\u\{(0x|0)?[a-f0-9]+\})|\d|[^\a\d\n\r\t\f\v\\"])*""###),  
+      token_type: 26, 
+      channel: 0, 
+      token_name: String::from("STRING_LITERAL"), 
+      skip: false,
+      mode: 0,
+      mode_action: None,
+    }, 
+    LexerRule { 
+      pattern: String::from(r###"(?s)r##".*?"##"###),  
+      token_type: 27, 
+      channel: 0, 
+      token_name: String::from("REGULAR_LITERAL"), 
+      skip: false,
+      mode: 0,
+      mode_action: None,
+    }, 
+    LexerRule { 
+      pattern: String::from(r###"[ \r\n\t\f]+"###),  
+      token_type: 28, 
+      channel: 0, 
       token_name: String::from("WHITE_SPACE"), 
       skip: true,
+      mode: 0,
+      mode_action: None,
     }, 
     LexerRule { 
-      rule: Regex::new(r###"//.*?\n"###).unwrap(),  
-      token_type: 22, 
+      pattern: String::from(r###"//.*?\n"###),  
+      token_type: 29, 
       channel: 1, 
       token_name: String::from("LINE_COMMENT"), 
       skip: false,
+      mode: 0,
+      mode_action: None,
     }, 
     LexerRule { 
-      rule: Regex::new(r###"(?s)/\*.*?\*/"###).unwrap(),  
-      token_type: 23, 
+      pattern: String::from(r###"(?s)/\*.*?\*/"###),  
+      token_type: 30, 
       channel: 1, 
       token_name: String::from("BLOCK_COMMENT"), 
       skip: false,
+      mode: 0,
+      mode_action: None,
     }, 
   ]
 });
 
 
 
+// 生成代码时由所有词法规则编译得到的自动机, 格式见 LexerDfa::to_bytes
+static LEXER_DFA_TABLE: &[u8] = b"\
+  \x01\0\0\0\0\0\0\0\x1d\0\0\0B%\0\0rust-regex-automata-dfa-sparse\0\0\xff\xfe\0\0\x02\0\0\0\0\0\0\0\x02\0\0\0\
+  \xc8\0\0\0\x1d\0\0\0\0\0\0\0\0\0\0\x01\x02\x03\x04\x05\x06\x06\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x08\x09\x0a\x0b\x0c\x0c\x0c\x0c\x0d\x0e\x0f\x10\x11\x12\x13\x14\x15\x16\x16\x16\x16\x16\x16\x16\
+  \x16\x16\x17\x18\x19\x1a\x1b\x1c\x1d\x1e\x1e\x1e\x1e\x1e\x1e\x1e\x1e\x1e\x1e\x1e\x1e\x1e\x1e\x1e\x1e\x1e\x1e\x1e\x1e\x1e\x1e\x1e\x1e\x1e\x1e\x1f\x20!\x22#$%&&'()*+,--./0123456789\
+  :;;<=>??@ABCDEFGHHIJKKLLMNOPQQRSTUVWXYZ[\x5c]]^_`abcdefghijklmnopqr\
+  stuvwxyyzz{{{{{{{{{{{{|}}}}}}}}}}~\x7f\x80\x81\x81\x81\x82\x83\x84\x85\x85\x85\x85\x85\x85\x85\x85\x86\x87\x87\x88\x89\x8a\x8b\x8c\x8c\x8c\x8d\x8e\x8e\x8e\
+  \x8e\x8e\x8e\x8e\x8e\x8e\x8e\x8e\x82\x22\0\0\x01\0\0\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\x06\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\x0e\0\0\0\0\
+  \x01\x80\0\0\0\0\0\0\x01\0\0\0\x07\0\0\0\0\x02\x80\x0b\x0b\0\0\xae\x1a\0\0\0\0\0\0\x01\0\0\0\x07\0\0\0\0\x0a\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%;<\x8e\0\x004\0\
+  \0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\x004\0\0\0\x01\0\0\0\x07\0\0\0\0\x0c\x80\0\x09\x0a\x0a\x0b?{\x82\x83\x83\x84\x87\x88\x88\x89\
+  \x8a\x8b\x8b\x8c\x8c\x8d\x8d\0\0\xbd\x1a\0\0\x08\x1b\0\0\xbd\x1a\0\0Y\x1b\0\0h\x1b\0\0w\x1b\0\0\x86\x1b\0\0w\x1b\0\0\x95\x1b\0\0\xa4\x1b\0\0\xb3\x1b\0\0\0\0\0\0\x01\0\0\0\x19\0\0\
+  \0\0\x0d\x80\0\x09\x0a\x0a\x0b\x0b\x0c?{\x82\x83\x83\x84\x87\x88\x88\x89\x8a\x8b\x8b\x8c\x8c\x8d\x8d\0\0\xbd\x1a\0\0\x08\x1b\0\0\xc2\x1b\0\0\xbd\x1a\0\0Y\x1b\0\0h\x1b\0\0w\x1b\0\0\x86\x1b\0\0w\x1b\
+  \0\0\x95\x1b\0\0\xa4\x1b\0\0\xb3\x1b\0\0\0\0\0\0\x01\0\0\0\x19\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\x19\0\0\0\0\x02\x80@y\0\0\xbd\x1a\0\0\0\0\0\0\x01\0\0\0\x19\0\
+  \0\0\0\x02\x80\x5cy\0\0Y\x1b\0\0\0\0\0\0\x01\0\0\0\x19\0\0\0\0\x02\x80@y\0\0Y\x1b\0\0\0\0\0\0\x01\0\0\0\x19\0\0\0\0\x02\x80@[\0\0Y\x1b\0\0\0\0\0\0\x01\
+  \0\0\0\x19\0\0\0\0\x02\x80My\0\0w\x1b\0\0\0\0\0\0\x01\0\0\0\x19\0\0\0\0\x02\x80@y\0\0w\x1b\0\0\0\0\0\0\x01\0\0\0\x19\0\0\0\0\x02\x80@L\0\0w\x1b\0\0\
+  \0\0\0\0\x01\0\0\0\x19\0\0\0\0\x0c\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%3445;<\x8e\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\
+  \0\0\0\x5c\0\0\0T\x02\0\0\x5c\0\0\x004\0\0\x004\0\0\0\x01\0\0\0\x07\0\0\0\0\x0c\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%4556;<\x8e\0\x004\0\0\0\x5c\0\
+  \0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\0\xa7\x02\0\0\x5c\0\0\x004\0\0\x004\0\0\0\x01\0\0\0\x07\0\0\0\0\x0c\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22#\
+  #$$%'(();<\x8e\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\0\xfa\x02\0\0\x5c\0\0\x004\0\0\x004\0\0\0\x01\0\0\
+  \0\x07\0\0\0\0\x0c\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%3445;<\x8e\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\0\
+  M\x03\0\0\x5c\0\0\x004\0\0\x004\0\0\0\x01\0\0\0\x07\0\0\0\0\x0a\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%;<\x8e\0\0\x94\x03\0\0\xa9\x03\0\0\x94\x03\0\0\xa9\x03\0\0\x94\
+  \x03\0\0\xa9\x03\0\0\x94\x03\0\0\xa9\x03\0\0\x94\x03\0\0\x94\x03\0\0\x01\0\0\0\x07\0\0\0\0\x01\x80\0\0\0\0\0\0\x02\0\0\0\x02\0\0\0\x07\0\0\0\0\x0a\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\
+  \x22##$$%;<\x8e\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\x004\0\0\0\x02\0\0\0\x02\0\0\0\x07\0\0\0\0\
+  \x0c\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%&''(;<\x8e\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\0G\x04\0\0\x5c\0\
+  \0\x004\0\0\x004\0\0\0\x01\0\0\0\x07\0\0\0\0\x0c\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%'(();<\x8e\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\
+  \0\x5c\0\0\x004\0\0\0\x5c\0\0\0\x9a\x04\0\0\x5c\0\0\x004\0\0\x004\0\0\0\x01\0\0\0\x07\0\0\0\0\x0a\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%;<\x8e\0\0\xe1\x04\0\0\
+  \xf6\x04\0\0\xe1\x04\0\0\xf6\x04\0\0\xe1\x04\0\0\xf6\x04\0\0\xe1\x04\0\0\xf6\x04\0\0\xe1\x04\0\0\xe1\x04\0\0\x01\0\0\0\x07\0\0\0\0\x01\x80\0\0\0\0\0\0\x02\0\0\0\x04\0\0\0\x07\0\0\
+  \0\0\x0a\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%;<\x8e\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\x004\0\0\0\
+  \x02\0\0\0\x04\0\0\0\x07\0\0\0\0\x0c\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%9::;;<\x8e\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\
+  \0\0\0\x5c\0\0\0\x94\x05\0\0\x5c\0\0\x004\0\0\x004\0\0\0\x01\0\0\0\x07\0\0\0\0\x0c\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%'(();<\x8e\0\x004\0\0\0\x5c\0\
+  \0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\0\xe7\x05\0\0\x5c\0\0\x004\0\0\x004\0\0\0\x01\0\0\0\x07\0\0\0\0\x0c\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22#\
+  #$$%3445;<\x8e\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\0:\x06\0\0\x5c\0\0\x004\0\0\x004\0\0\0\x01\0\0\
+  \0\x07\0\0\0\0\x0a\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%;<\x8e\0\0\x81\x06\0\0\x96\x06\0\0\x81\x06\0\0\x96\x06\0\0\x81\x06\0\0\x96\x06\0\0\x81\x06\0\0\x96\x06\0\0\x81\x06\0\0\
+  \x81\x06\0\0\x01\0\0\0\x07\0\0\0\0\x01\x80\0\0\0\0\0\0\x02\0\0\0\x01\0\0\0\x07\0\0\0\0\x0a\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%;<\x8e\0\x004\0\0\0\x5c\0\0\x00\
+  4\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\x004\0\0\0\x02\0\0\0\x01\0\0\0\x07\0\0\0\0\x0c\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%12\
+  23;<\x8e\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\x07\0\0\x5c\0\0\x004\0\0\x004\0\0\0\x01\0\0\0\x07\0\0\0\0\
+  \x0c\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%0112;<\x8e\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\0\x87\x07\0\0\x5c\0\
+  \0\x004\0\0\x004\0\0\0\x01\0\0\0\x07\0\0\0\0\x0c\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%3445;<\x8e\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\
+  \0\x5c\0\0\x004\0\0\0\x5c\0\0\0\xda\x07\0\0\x5c\0\0\x004\0\0\x004\0\0\0\x01\0\0\0\x07\0\0\0\0\x0c\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%5667;<\x8e\0\x00\
+  4\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\0-\x08\0\0\x5c\0\0\x004\0\0\x004\0\0\0\x01\0\0\0\x07\0\0\0\0\x0a\x80\0\x14\x15\x16\x17\
+  \x1d\x1e\x1e\x1f\x22##$$%;<\x8e\0\0t\x08\0\0\x89\x08\0\0t\x08\0\0\x89\x08\0\0t\x08\0\0\x89\x08\0\0t\x08\0\0\x89\x08\0\0t\x08\0\0t\x08\0\0\x01\0\0\0\x07\0\0\0\0\
+  \x01\x80\0\0\0\0\0\0\x02\0\0\0\x03\0\0\0\x07\0\0\0\0\x0a\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%;<\x8e\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\
+  \0\0\x004\0\0\0\x5c\0\0\x004\0\0\x004\0\0\0\x02\0\0\0\x03\0\0\0\x07\0\0\0\0\x0b\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%%&;<\x8e\0\x004\0\0\0\x5c\0\0\x00\
+  4\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0!\x09\0\0\x5c\0\0\x004\0\0\x004\0\0\0\x01\0\0\0\x07\0\0\0\0\x0c\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%./\
+  /0;<\x8e\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\0t\x09\0\0\x5c\0\0\x004\0\0\x004\0\0\0\x01\0\0\0\x07\0\0\0\0\
+  \x0c\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%.//0;<\x8e\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\0\xc7\x09\0\0\x5c\0\
+  \0\x004\0\0\x004\0\0\0\x01\0\0\0\x07\0\0\0\0\x0b\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%%&;<\x8e\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\
+  \0\0\x004\0\0\0\x14\x0a\0\0\x5c\0\0\x004\0\0\x004\0\0\0\x01\0\0\0\x07\0\0\0\0\x0c\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%3445;<\x8e\0\x004\0\0\0\x5c\0\
+  \0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\0g\x0a\0\0\x5c\0\0\x004\0\0\x004\0\0\0\x01\0\0\0\x07\0\0\0\0\x0a\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22#\
+  #$$%;<\x8e\0\0\xae\x0a\0\0\xc3\x0a\0\0\xae\x0a\0\0\xc3\x0a\0\0\xae\x0a\0\0\xc3\x0a\0\0\xae\x0a\0\0\xc3\x0a\0\0\xae\x0a\0\0\xae\x0a\0\0\x01\0\0\0\x07\0\0\0\0\x01\x80\0\0\0\0\
+  \0\0\x02\0\0\0\0\0\0\0\x07\0\0\0\0\x0a\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%;<\x8e\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\
+  \0\x5c\0\0\x004\0\0\x004\0\0\0\x02\0\0\0\0\0\0\0\x07\0\0\0\0\x0b\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%%&;<\x8e\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\
+  \0\x004\0\0\0\x5c\0\0\x004\0\0\0[\x0b\0\0\x5c\0\0\x004\0\0\x004\0\0\0\x01\0\0\0\x07\0\0\0\0\x0c\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%)**+;<\x8e\0\
+  \x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\0\xae\x0b\0\0\x5c\0\0\x004\0\0\x004\0\0\0\x01\0\0\0\x07\0\0\0\0\x0c\x80\0\x14\x15\x16\
+  \x17\x1d\x1e\x1e\x1f\x22##$$%.//0;<\x8e\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\0\x01\x0c\0\0\x5c\0\0\x004\0\0\x00\
+  4\0\0\0\x01\0\0\0\x07\0\0\0\0\x0c\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%'(();<\x8e\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\
+  \0\0\0\x5c\0\0\0T\x0c\0\0\x5c\0\0\x004\0\0\x004\0\0\0\x01\0\0\0\x07\0\0\0\0\x0c\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%/001;<\x8e\0\x004\0\0\0\x5c\0\
+  \0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\0\xa7\x0c\0\0\x5c\0\0\x004\0\0\x004\0\0\0\x01\0\0\0\x07\0\0\0\0\x0c\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22#\
+  #$$%5667;<\x8e\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\0\xfa\x0c\0\0\x5c\0\0\x004\0\0\x004\0\0\0\x01\0\0\
+  \0\x07\0\0\0\0\x0a\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%;<\x8e\0\0A\x0d\0\0V\x0d\0\0A\x0d\0\0V\x0d\0\0A\x0d\0\0V\x0d\0\0A\x0d\0\0V\x0d\0\0A\x0d\0\0\
+  A\x0d\0\0\x01\0\0\0\x07\0\0\0\0\x01\x80\0\0\0\0\0\0\x02\0\0\0\x05\0\0\0\x07\0\0\0\0\x0a\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%;<\x8e\0\x004\0\0\0\x5c\0\0\x00\
+  4\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\x004\0\0\0\x02\0\0\0\x05\0\0\0\x07\0\0\0\0\x0c\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%45\
+  56;<\x8e\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\0\xf4\x0d\0\0\x5c\0\0\x004\0\0\x004\0\0\0\x01\0\0\0\x07\0\0\0\0\
+  \x0c\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%+,,-;<\x8e\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\0G\x0e\0\0\x5c\0\
+  \0\x004\0\0\x004\0\0\0\x01\0\0\0\x07\0\0\0\0\x0c\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%-../;<\x8e\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\
+  \0\x5c\0\0\x004\0\0\0\x5c\0\0\0\x9a\x0e\0\0\x5c\0\0\x004\0\0\x004\0\0\0\x01\0\0\0\x07\0\0\0\0\x0c\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%0112;<\x8e\0\x00\
+  4\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\0\xed\x0e\0\0\x5c\0\0\x004\0\0\x004\0\0\0\x01\0\0\0\x07\0\0\0\0\x0c\x80\0\x14\x15\x16\x17\
+  \x1d\x1e\x1e\x1f\x22##$$%/001;<\x8e\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\0@\x0f\0\0\x5c\0\0\x004\0\0\x004\
+  \0\0\0\x01\0\0\0\x07\0\0\0\0\x0a\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%;<\x8e\0\0\x87\x0f\0\0\x9c\x0f\0\0\x87\x0f\0\0\x9c\x0f\0\0\x87\x0f\0\0\x9c\x0f\0\0\x87\x0f\0\0\x9c\x0f\
+  \0\0\x87\x0f\0\0\x87\x0f\0\0\x01\0\0\0\x07\0\0\0\0\x01\x80\0\0\0\0\0\0\x02\0\0\0\x06\0\0\0\x07\0\0\0\0\x0a\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%;<\x8e\0\x004\0\
+  \0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\x004\0\0\0\x02\0\0\0\x06\0\0\0\x07\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\x17\
+  \0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\x16\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\x08\0\0\0\0\x0a\x80\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%;<\x8e\0\0\x09\x10\0\0\
+  \x1a\x10\0\0\x09\x10\0\0\x1a\x10\0\0\x09\x10\0\0\x1a\x10\0\0\x09\x10\0\0\x1a\x10\0\0\x09\x10\0\0\x09\x10\0\0\x01\0\0\0\x08\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\x14\0\0\0\0\x01\x80\
+  \0\0\0\0\0\0\x01\0\0\0\x11\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\x0a\0\0\0\0\x02\x80\0\x8e\0\0\xab\x10\0\0\xab\x10\0\0\x01\0\0\0\x0a\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\
+  \0\0\0\x09\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\x0c\0\0\0\0\x02\x80\x1a\x1a\0\0\x5c\x18\0\0\0\0\0\0\x01\0\0\0\x09\0\0\0\0\x02\x80\0\x8e\0\0\xab\x10\0\0\xab\x10\0\0\x01\0\
+  \0\0\x09\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\x1b\0\0\0\0\x0c\x80\0\x0e\x0f\x0f\x10?{\x82\x83\x83\x84\x87\x88\x88\x89\x8a\x8b\x8b\x8c\x8c\x8d\x8d\0\0k\x18\0\0\x88\x19\0\0k\x18\0\0\xdf\x19\
+  \0\0\xee\x19\0\0\xfd\x19\0\0\x0c\x1a\0\0\xfd\x19\0\0\x1b\x1a\0\0*\x1a\0\x009\x1a\0\0\0\0\0\0\x01\0\0\0\x1c\0\0\0\0\x0e\x80\0\x0e\x0f\x0f\x10\x13\x14\x14\x15?{\x82\x83\x83\x84\x87\x88\x88\x89\
+  \x8a\x8b\x8b\x8c\x8c\x8d\x8d\0\0k\x18\0\0\x88\x19\0\0k\x18\0\0H\x1a\0\0k\x18\0\0\xdf\x19\0\0\xee\x19\0\0\xfd\x19\0\0\x0c\x1a\0\0\xfd\x19\0\0\x1b\x1a\0\0*\x1a\0\x009\x1a\0\0\0\0\0\
+  \0\x01\0\0\0\x1c\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\x1c\0\0\0\0\x02\x80@y\0\0k\x18\0\0\0\0\0\0\x01\0\0\0\x1c\0\0\0\0\x02\x80\x5cy\0\0\xdf\x19\0\0\0\0\0\0\
+  \x01\0\0\0\x1c\0\0\0\0\x02\x80@y\0\0\xdf\x19\0\0\0\0\0\0\x01\0\0\0\x1c\0\0\0\0\x02\x80@[\0\0\xdf\x19\0\0\0\0\0\0\x01\0\0\0\x1c\0\0\0\0\x02\x80My\0\0\xfd\x19\0\
+  \0\0\0\0\0\x01\0\0\0\x1c\0\0\0\0\x02\x80@y\0\0\xfd\x19\0\0\0\0\0\0\x01\0\0\0\x1c\0\0\0\0\x02\x80@L\0\0\xfd\x19\0\0\0\0\0\0\x01\0\0\0\x1c\0\0\0\0\x01\x80\0\0\
+  \0\0\0\0\x01\0\0\0\x0d\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\x10\0\0\0\0\x02\x80\0\x8e\0\0\xa9\x12\0\0\xa9\x12\0\0\x01\0\0\0\x10\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\
+  \0\x0b\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\x0f\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\x13\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\x12\0\0\0\0\x01\x80\0\0\0\0\0\
+  \0\x01\0\0\0\x15\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\x18\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\x1a\0\0\0\0\x08\x80\0\x02\x03\x04\x05\x05\x06\x06\x07\x07\x08\x08\x09\x8e\0\0\x0f\x13\
+  \0\0\x20\x13\0\0\x0f\x13\0\0\x20\x13\0\0\x0f\x13\0\0\x20\x13\0\0\x0f\x13\0\0\x0f\x13\0\0\x01\0\0\0\x1a\0\0\0\0%\0\x03\x04\x06\x06\x08\x08\x0a\x0a\x0b\x0b\x0d\x0d\x0e\x0e\x0f\x0f\x10\x10\x11\x11\x12\x12\x14\
+  \x14\x17\x17\x18\x18\x1a\x1a\x1c\x1c\x1d\x1d\x1e\x1e\x1f\x1f!!%'(())**++,,--..//012233445;==||\0\0\x90\x17\0\0\x90\x17\0\0\x90\x17\0\0\xc3\
+  \x17\0\0y\x19\0\0\x9f\x1a\0\0j\x1c\0\0y\x1c\0\0\x88\x1c\0\0\xa3\x1c\0\0\xb2\x1c\0\x007\x22\0\0L\x22\0\0s\x22\0\0u\x17\0\0<\x14\0\0K\x14\0\0Z\x14\0\0\x99\x14\0\0\xa8\
+  \x14\0\0\xb7\x14\0\0\xf6\x14\0\0A\x15\0\0\x8c\x15\0\0\xb7\x14\0\0\xd7\x15\0\0\xb7\x14\0\0\x22\x16\0\0m\x16\0\0\xb7\x14\0\0\xb8\x16\0\0\xb7\x14\0\0\xfd\x16\0\0\xb7\x14\0\0H\x17\0\0W\
+  \x17\0\0\0\0\0\0\0\x02\0\0\x8e\0\0r\x10\0\0r\x10\0\0\0\x02\0\0\x8e\0\0a\x10\0\0a\x10\0\0\0\x0a\0\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%;<\x8e\0\0\x09\x10\0\0\
+  \x1a\x10\0\0\x09\x10\0\0\x1a\x10\0\0\x09\x10\0\0\x1a\x10\0\0\x09\x10\0\0\x1a\x10\0\0\x09\x10\0\0\x09\x10\0\0\0\x02\0\0\x8e\0\0\xf8\x0f\0\0\xf8\x0f\0\0\0\x02\0\0\x8e\0\0\xe7\x0f\0\0\xe7\x0f\
+  \0\0\0\x0a\0\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%;<\x8e\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\x004\0\0\
+  \0\0\x0c\0\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%1223;<\x8e\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\0\xa1\x0d\0\0\
+  \x5c\0\0\x004\0\0\x004\0\0\0\0\x0c\0\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%3445;<\x8e\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\
+  \0\0\0\x5c\0\0\0\x0e\x0b\0\0\x5c\0\0\x004\0\0\x004\0\0\0\0\x0c\0\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%3445;<\x8e\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\
+  \0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\0\xd4\x08\0\0\x5c\0\0\x004\0\0\x004\0\0\0\0\x0c\0\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%.//0;<\x8e\0\x004\0\0\
+  \0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\0\xe1\x06\0\0\x5c\0\0\x004\0\0\x004\0\0\0\0\x0c\0\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%'\
+  (();<\x8e\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\0A\x05\0\0\x5c\0\0\x004\0\0\x004\0\0\0\0\x0c\0\0\x14\x15\x16\x17\
+  \x1d\x1e\x1e\x1f\x22##$$%0112;<\x8e\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\0\xf4\x03\0\0\x5c\0\0\x004\0\0\x004\
+  \0\0\0\0\x0b\0\0\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%%&;<\x8e\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x01\x02\0\0\x5c\0\0\x00\
+  4\0\0\x004\0\0\0\0\x0c\0\0\x0a\x0b\x0b\x0c\x14\x15\x16\x17\x1d\x1e\x1e\x1f\x22##$$%;<\x8e\0\x004\0\0\0E\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\0\0\x004\0\0\0\x5c\
+  \0\0\x004\0\0\0\x5c\0\0\x004\0\0\x004\0\0\0\0\x02\0\0\x8e\0\0#\0\0\0#\0\0\0\0\x02\0pp\0\0f\x17\0\0\0\0\0\0\0\x02\0\0\x8e\0\0\x12\0\0\0\x12\0\0\0\
+  \0\x04\0\0\x1a\x1b\x1b\x1c\x8e\0\0\x83\x10\0\0\x94\x10\0\0\x83\x10\0\0\x83\x10\0\0\0\x08\0\0\x02\x03\x04\x05\x05\x06\x06\x07\x07\x08\x08\x09\x8e\0\0\x0f\x13\0\0\x20\x13\0\0\x0f\x13\0\0\x20\x13\0\0\x0f\x13\
+  \0\0\x20\x13\0\0\x0f\x13\0\0\x0f\x13\0\0\0\x19\0\0\0\x02\x02\x07\x09\x0a\x0a\x0b\x1f\x20\x20!?{}~~\x7f\x7f\x80\x80\x81\x81\x82\x82\x83\x83\x84\x84\x85\x85\x86\x86\x87\x87\x88\x88\x89\x89\x8a\x8a\x8b\x8b\x8c\x8c\x8d\
+  \x8d\0\0\xc3\x17\0\0\xc3\x17\0\0\xc3\x17\0\0\xc1\x1c\0\0\xc3\x17\0\0\xd0\x1c\0\0\xc3\x17\0\0\x15\x1d\0\0$\x1d\0\0\x15\x1d\0\x003\x1d\0\0\x15\x1d\0\0B\x1d\0\0Q\x1d\0\0\xf6\x1d\0\0k\
+  \x1e\0\0z\x1e\0\0k\x1e\0\0\xcb\x1e\0\0k\x1e\0\0\xda\x1e\0\0\xf5\x1e\0\0:\x1f\0\0I\x1f\0\0\0\0\0\0\0\x02\0\0\x8e\0\0\xab\x10\0\0\xab\x10\0\0\0\x0c\0\0\x0e\x0f\x0f\x10?{\
+  \x82\x83\x83\x84\x87\x88\x88\x89\x8a\x8b\x8b\x8c\x8c\x8d\x8d\0\0k\x18\0\0\x88\x19\0\0k\x18\0\0\xdf\x19\0\0\xee\x19\0\0\xfd\x19\0\0\x0c\x1a\0\0\xfd\x19\0\0\x1b\x1a\0\0*\x1a\0\x009\x1a\0\0\0\0\0\
+  \0\0\x0c\0\0\x03\x04\x04\x05?{\x82\x83\x83\x84\x87\x88\x88\x89\x8a\x8b\x8b\x8c\x8c\x8d\x8d\0\0\xb6\x18\0\0\x01\x19\0\0\xb6\x18\0\0\x10\x19\0\0\x1f\x19\0\0.\x19\0\0=\x19\0\0.\x19\0\0L\x19\0\0\
+  [\x19\0\0j\x19\0\0\0\0\0\0\0\x02\0\0\x8e\0\0\xfb\x10\0\0\xfb\x10\0\0\0\x02\0@y\0\0\xb6\x18\0\0\0\0\0\0\0\x02\0\x5cy\0\0\x10\x19\0\0\0\0\0\0\0\x02\0@y\0\0\
+  \x10\x19\0\0\0\0\0\0\0\x02\0@[\0\0\x10\x19\0\0\0\0\0\0\0\x02\0My\0\0.\x19\0\0\0\0\0\0\0\x02\0@y\0\0.\x19\0\0\0\0\0\0\0\x02\0@L\0\0.\x19\0\0\
+  \0\0\0\0\0\x02\0\0\x8e\0\0\xed\x12\0\0\xed\x12\0\0\0\x0e\0\0\x0e\x0f\x0f\x10\x13\x14\x14\x15?{\x82\x83\x83\x84\x87\x88\x88\x89\x8a\x8b\x8b\x8c\x8c\x8d\x8d\0\0k\x18\0\0\x88\x19\0\0k\x18\0\0H\x1a\
+  \0\0k\x18\0\0\xdf\x19\0\0\xee\x19\0\0\xfd\x19\0\0\x0c\x1a\0\0\xfd\x19\0\0\x1b\x1a\0\0*\x1a\0\x009\x1a\0\0\0\0\0\0\0\x02\0@y\0\0k\x18\0\0\0\0\0\0\0\x02\0\x5cy\0\0\
+  \xdf\x19\0\0\0\0\0\0\0\x02\0@y\0\0\xdf\x19\0\0\0\0\0\0\0\x02\0@[\0\0\xdf\x19\0\0\0\0\0\0\0\x02\0My\0\0\xfd\x19\0\0\0\0\0\0\0\x02\0@y\0\0\xfd\x19\0\0\
+  \0\0\0\0\0\x02\0@L\0\0\xfd\x19\0\0\0\0\0\0\0\x0e\0\0\x0e\x0f\x0f\x10?@z{\x82\x83\x83\x84\x87\x88\x88\x89\x8a\x8b\x8b\x8c\x8c\x8d\x8d\x8e\x8e\0\0\x0c\x11\0\0_\x11\0\0\x0c\x11\0\0\xbe\x11\
+  \0\0\xcf\x11\0\0\xe6\x11\0\0\xfd\x11\0\0\x14\x12\0\0\xfd\x11\0\0+\x12\0\0B\x12\0\0Y\x12\0\0\xbe\x11\0\0\xbe\x11\0\0\0\x02\0\0\x8e\0\0\xdc\x12\0\0\xdc\x12\0\0\0\x02\0\x0a\x0a\0\0\
+  \xbd\x1a\0\0\0\0\0\0\0\x0c\0\0\x09\x0a\x0a\x0b?{\x82\x83\x83\x84\x87\x88\x88\x89\x8a\x8b\x8b\x8c\x8c\x8d\x8d\0\0\xbd\x1a\0\0\x08\x1b\0\0\xbd\x1a\0\0Y\x1b\0\0h\x1b\0\0w\x1b\0\0\x86\x1b\0\0w\
+  \x1b\0\0\x95\x1b\0\0\xa4\x1b\0\0\xb3\x1b\0\0\0\0\0\0\0\x0d\0\0\x09\x0a\x0a\x0b\x0b\x0c?{\x82\x83\x83\x84\x87\x88\x88\x89\x8a\x8b\x8b\x8c\x8c\x8d\x8d\0\0\xbd\x1a\0\0\x08\x1b\0\0\xc2\x1b\0\0\xbd\x1a\0\0\
+  Y\x1b\0\0h\x1b\0\0w\x1b\0\0\x86\x1b\0\0w\x1b\0\0\x95\x1b\0\0\xa4\x1b\0\0\xb3\x1b\0\0\0\0\0\0\0\x02\0@y\0\0\xbd\x1a\0\0\0\0\0\0\0\x02\0\x5cy\0\0Y\x1b\0\0\0\0\
+  \0\0\0\x02\0@y\0\0Y\x1b\0\0\0\0\0\0\0\x02\0@[\0\0Y\x1b\0\0\0\0\0\0\0\x02\0My\0\0w\x1b\0\0\0\0\0\0\0\x02\0@y\0\0w\x1b\0\0\0\0\0\0\0\x02\
+  \0@L\0\0w\x1b\0\0\0\0\0\0\0\x0d\0\0\x09\x0a\x0a\x0b\x0b\x0c?{\x82\x83\x83\x84\x87\x88\x88\x89\x8a\x8b\x8b\x8c\x8c\x8d\x8d\0\0\xbd\x1a\0\0\x08\x1b\0\0\x13\x1c\0\0\xbd\x1a\0\0Y\x1b\0\0h\x1b\
+  \0\0w\x1b\0\0\x86\x1b\0\0w\x1b\0\0\x95\x1b\0\0\xa4\x1b\0\0\xb3\x1b\0\0\0\0\0\0\0\x0e\0\0\x09\x0a\x0a\x0b?@z{\x82\x83\x83\x84\x87\x88\x88\x89\x8a\x8b\x8b\x8c\x8c\x8d\x8d\x8e\x8e\0\0\xa3\0\0\
+  \0\xf6\0\0\0\xa3\0\0\0O\x01\0\0`\x01\0\0w\x01\0\0\x8e\x01\0\0\xa5\x01\0\0\x8e\x01\0\0\xbc\x01\0\0\xd3\x01\0\0\xea\x01\0\0O\x01\0\0O\x01\0\0\0\x02\0\0\x8e\0\0\xcb\x12\0\0\
+  \xcb\x12\0\0\0\x02\0\0\x8e\0\0\xba\x12\0\0\xba\x12\0\0\0\x04\0\0\x19\x1a\x1a\x1b\x8e\0\0\x81\x12\0\0\x92\x12\0\0\x81\x12\0\0\x81\x12\0\0\0\x02\0\0\x8e\0\0p\x12\0\0p\x12\0\0\0\x02\0\
+  \x1b\x1b\0\0\x5c\x18\0\0\0\0\0\0\0\x02\0\0\x8e\0\0\xfe\x12\0\0\xfe\x12\0\0\0\x0b\0\x0a\x0a\x20\x20%%''))0044667788\0\0\xc3\x17\0\0\xc3\x17\0\0\xc3\x17\0\0\
+  \xc3\x17\0\0\xc3\x17\0\0\xc3\x17\0\0\xc3\x17\0\0\xc3\x17\0\0\xbc!\0\0\xc3\x17\0\0\0\0\0\0\0\x02\0@y\0\0\xc3\x17\0\0\0\0\0\0\0\x02\0@y\0\0\xc3\x17\0\0\0\0\0\0\0\x02\
+  \0@y\0\0\xc3\x17\0\0\0\0\0\0\0\x02\0@y\0\0\xc3\x17\0\0\0\0\0\0\0\x1b\0\x5c_``aabbccddeeffgghhiijjkkllmmnno\
+  oppqqrrssttuuvvwwxy\0\0\x15\x1d\0\0b!\0\0\x15\x1d\0\0b!\0\0\x15\x1d\0\0b!\0\0\x15\x1d\0\0b!\0\0\x15\x1d\0\0b!\0\0\x15\x1d\0\
+  \0b!\0\0\x15\x1d\0\0b!\0\0\x15\x1d\0\0b!\0\0\x15\x1d\0\0b!\0\0\x15\x1d\0\0b!\0\0\x15\x1d\0\0D!\0\0\x15\x1d\0\0D!\0\0$\x1d\0\0\x15\x1d\0\0\0\0\0\
+  \0\0\x13\0@@AABBCZ[[\x5c\x5c]_``aabbcdeefghhiijkllmy\0\0\x15\x1d\0\0B\x1d\0\0D!\0\0\x15\x1d\0\0$\x1d\0\0D!\
+  \0\0\x15\x1d\0\0\x9e!\0\0\x15\x1d\0\0D!\0\0\x15\x1d\0\0\xad!\0\0\x15\x1d\0\0D!\0\x003\x1d\0\0\x15\x1d\0\0\xad!\0\0\x15\x1d\0\0\0\0\0\0\0\x02\0@y\0\0\x15\x1d\0\
+  \0\0\0\0\0\0\x0d\0@STTU]^^__`abbccddeijjky\0\0\x15\x1d\0\0$\x1d\0\0\x15\x1d\0\0D!\0\0B\x1d\0\0\x15\x1d\0\0\x8f!\0\0\x15\x1d\
+  \0\0D!\0\0\x15\x1d\0\x003\x1d\0\0\x15\x1d\0\0\0\0\0\0\0\x02\0@[\0\0\x15\x1d\0\0\0\0\0\0\0\x04\0@vwwxy\0\0\x15\x1d\0\0D!\0\0\x15\x1d\0\0\0\0\0\0\
+  \0\x0b\0MMNNOQRRSWXXYYZZ[[\x5cy\0\0X\x1f\0\0\x85\x1f\0\0k\x1e\0\0N\x20\0\0k\x1e\0\0\x99\x20\0\0\xb4\x20\0\0\xcf\x20\0\0\x1a!\0\0k\x1e\0\
+  \0\0\0\0\0\0\x02\0@y\0\0k\x1e\0\0\0\0\0\0\0\x02\0@L\0\0k\x1e\0\0\0\0\0\0\0\x07\0@NOOPnooppqy\0\0\x15\x1d\0\0$\x1d\0\0\x15\x1d\0\x00\
+  3\x1d\0\0B\x1d\0\0\x15\x1d\0\0\0\0\0\0\0!\0@@AABBCCDDEFGGHIJJKMNNOOPPQTUUVVWWXXY]^^__``a\
+  ijjkkllmoppqqrwxxyy\0\0\x15\x1d\0\0b!\0\0\x15\x1d\0\x003\x1d\0\0q!\0\0\x15\x1d\0\0D!\0\0\x15\x1d\0\x003\x1d\0\0\x15\x1d\0\0D!\0\
+  \0\x15\x1d\0\0D!\0\0\x15\x1d\0\0D!\0\0\x15\x1d\0\0\x80!\0\x003\x1d\0\0\x15\x1d\0\0$\x1d\0\0\x15\x1d\0\0D!\0\0\x15\x1d\0\x003\x1d\0\0\x15\x1d\0\0D!\0\0\x15\x1d\0\
+  \0D!\0\0$\x1d\0\0\x15\x1d\0\0D!\0\0\x15\x1d\0\0\0\0\0\0\0\x0c\0@CDDEcddeeffgghhioppqy\0\0\x15\x1d\0\x003\x1d\0\0\x15\x1d\0\0\
+  $\x1d\0\0\x15\x1d\0\0B\x1d\0\0\x15\x1d\0\0D!\0\0\x15\x1d\0\x003\x1d\0\0\x15\x1d\0\0\0\0\0\0\0\x04\0@mnnoy\0\0\x15\x1d\0\x003\x1d\0\0\x15\x1d\0\0\0\0\0\0\0\
+  \x04\0@Z[[\x5cy\0\0\x15\x1d\0\0S!\0\0\x15\x1d\0\0\0\0\0\0\0\x0c\0@DEEFIJJKOPPQRSST_``ay\0\0\x15\x1d\0\0B\x1d\0\0\x15\x1d\0\
+  \x003\x1d\0\0\x15\x1d\0\x003\x1d\0\0\x15\x1d\0\x005!\0\0\x15\x1d\0\0D!\0\0\x15\x1d\0\0\0\0\0\0\0\x04\0@ijjky\0\0\x15\x1d\0\x003\x1d\0\0\x15\x1d\0\0\0\0\0\0\
+  \0\x02\0@y\0\0\xc3\x17\0\0\0\0\0\0\0\x02\0@y\0\0\xc3\x17\0\0\0\0\0\0\0\x02\0@y\0\0\xc3\x17\0\0\0\0\0\0\0\x02\0@y\0\0\xc3\x17\0\0\0\0\0\0\0\x02\0@\
+  y\0\0\xc3\x17\0\0\0\0\0\0\0\x02\0@y\0\0\xc3\x17\0\0\0\0\0\0\0\x02\0@y\0\0\xc3\x17\0\0\0\0\0\0\0\x02\0@y\0\0\xc3\x17\0\0\0\0\0\0\0\x02\0@y\0\0\xc3\
+  \x17\0\0\0\0\0\0\0\x02\0<<\0\0\xcb!\0\0\0\0\0\0\0\x04\0\x15\x15\x16\x16%)\0\0\xe6!\0\0\x07\x22\0\0\x07\x22\0\0\0\0\0\0\0\x05\0\x15\x16%)::>>\0\0\x07\x22\
+  \0\0\x07\x22\0\0\x22\x22\0\0\xc3\x17\0\0\0\0\0\0\0\x04\0\x15\x16%)>>\0\0\x07\x22\0\0\x07\x22\0\0\xc3\x17\0\0\0\0\0\0\0\x03\0\x15\x16%)\0\0\x07\x22\0\0\x07\x22\0\0\0\0\
+  \0\0\0\x03\0\x0f\x0f\x14\x14\0\0k\x18\0\0\xb6\x18\0\0\0\0\0\0\0\x06\0\0\x16\x17\x17\x18\x19\x1a\x1a\x1b\x8e\0\0\xab\x10\0\0\xcd\x10\0\0\xab\x10\0\0\xe4\x10\0\0\xab\x10\0\0\xab\x10\0\0\0\x02\
+  \0\0\x8e\0\0\xbc\x10\0\0\xbc\x10\0\0\0\x02\0\0\0\0\0\0\0\0\0\0\0\0\0\x03\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\x01\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\
+  \x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x06\0\0\0\xff\xff\xff\xff\xff\xff\xff\xff[\x13\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0[\x13\0\0[\x13\
+  \0\0[\x13\0\0[\x13\0\0[\x13\0\0[\x13\0\0\x20\x13\0\0\x09\0\0\0\x12\0\0\0\x20\x13\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0[\x03\0\0rust-regex-automata-dfa-sparse\0\0\xff\xfe\0\0\x02\0\
+  \0\0\0\0\0\0\x02\0\0\0\x0b\0\0\0\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x02\x02\x02\x02\x02\x03\x04\x04\x04\x04\x04\x05\
+  \x06\x06\x06\x06\x07\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\
+  \x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\
+  \x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x9b\0\0\0\x01\0\0\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x02\0\x03\x03\0\0\x8c\
+  \0\0\0\0\0\0\0\0\x02\0\x01\x01\0\0A\0\0\0\0\0\0\0\0\x02\0\x05\x05\0\0P\0\0\0\0\0\0\0\0\x02\0\x05\x05\0\0_\0\0\0\0\0\0\0\0\x02\0\x01\x01\0\0n\0\0\0\0\
+  \0\0\0\0\x02\0\x07\x07\0\0}\0\0\0\0\0\0\0\0\x02\0\0\x08\0\0\x12\0\0\0\x12\0\0\0\0\x02\0\x07\x07\0\x002\0\0\0\0\0\0\0\0\x02\0\0\0\0\0\0\0\0\0\0\0\0\0\x03\
+  \0\0\x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\
+  \x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\x01\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x06\0\0\0\xff\xff\xff\xff\xff\xff\xff\
+  \xff#\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0\x12\0\0\0\x09\0\0\0\x12\0\0\
+  \0\x12\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\0\0\0=\x03\0\0rus\
+  t-regex-automata-dfa-sparse\0\0\xff\xfe\0\0\x02\0\0\0\0\0\0\0\x02\0\0\0\x09\0\0\0\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x02\x02\x02\x02\x02\x02\x03\x04\x04\x04\x04\x04\x05\x06\x06\x06\x06\x06\x07\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\
+  \x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\
+  \x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08}\0\0\0\x01\0\0\0\0\0\0\
+  \0\0\x01\0\0\0\0\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x02\0\x03\x03\0\0n\0\0\0\0\0\0\0\0\x02\0\x07\x07\0\0A\0\0\0\0\0\0\0\0\x02\0\x01\x01\0\0\
+  P\0\0\0\0\0\0\0\0\x02\0\x05\x05\0\0_\0\0\0\0\0\0\0\0\x02\0\0\x08\0\0\x12\0\0\0\x12\0\0\0\0\x02\0\x01\x01\0\x002\0\0\0\0\0\0\0\0\x02\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\x03\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\
+  \x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\x01\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x06\0\0\0\xff\xff\
+  \xff\xff\xff\xff\xff\xff#\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0\x12\0\0\0\x09\0\
+  \0\0\x12\0\0\0\x12\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x02\0\0\0L\x03\
+  \0\0rust-regex-automata-dfa-sparse\0\0\xff\xfe\0\0\x02\0\0\0\0\0\0\0\x02\0\0\0\x0a\0\0\0\x01\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x02\x02\x02\x03\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x05\x06\x07\x08\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\
+  \x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\
+  \x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x8c\0\0\0\x01\0\
+  \0\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x02\0\x05\x05\0\0}\0\0\0\0\0\0\0\0\x02\0\x07\x07\0\0A\0\0\0\0\0\0\0\0\x02\
+  \0\x08\x08\0\0P\0\0\0\0\0\0\0\0\x02\0\x03\x03\0\0_\0\0\0\0\0\0\0\0\x02\0\x07\x07\0\0n\0\0\0\0\0\0\0\0\x02\0\0\x09\0\0\x12\0\0\0\x12\0\0\0\0\x02\0\x01\x01\0\
+  \x002\0\0\0\0\0\0\0\0\x02\0\0\0\0\0\0\0\0\0\0\0\0\0\x03\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x01\
+  \x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\x01\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\
+  \x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\x06\0\0\0\xff\xff\xff\xff\xff\xff\xff\xff#\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0#\0\0\0#\0\0\0#\0\
+  \0\0#\0\0\0#\0\0\0#\0\0\0\x12\0\0\0\x09\0\0\0\x12\0\0\0\x12\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\x03\0\0\0L\x03\0\0rust-regex-automata-dfa-sparse\0\0\xff\xfe\0\0\x02\0\0\0\0\0\
+  \0\0\x02\0\0\0\x0a\0\0\0\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x02\x02\x02\x03\x04\x05\x06\x07\
+  \x08\x09\x0a\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\
+  \x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\
+  \x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x8c\0\0\0\x01\0\0\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x02\0\x01\x01\0\0}\0\0\0\0\
+  \0\0\0\0\x02\0\x06\x06\0\0A\0\0\0\0\0\0\0\0\x02\0\x05\x05\0\0P\0\0\0\0\0\0\0\0\x02\0\x08\x08\0\0_\0\0\0\0\0\0\0\0\x02\0\x0a\x0a\0\0n\0\0\0\0\0\0\0\0\
+  \x02\0\0\x0b\0\0\x12\0\0\0\x12\0\0\0\0\x02\0\x03\x03\0\x002\0\0\0\0\0\0\0\0\x02\0\0\0\0\0\0\0\0\0\0\0\0\0\x03\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\
+  \0\x01\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x06\0\0\0\xff\xff\xff\xff\xff\xff\xff\xff#\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0\x12\0\0\0\x09\0\0\0\x12\0\0\0\x12\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x04\0\0\0.\x03\0\0rust-regex-automata-df\
+  a-sparse\0\0\xff\xfe\0\0\x02\0\0\0\0\0\0\0\x02\0\0\0\x08\0\0\0\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\x01\x02\x03\x03\x03\x03\x03\x03\x03\x04\x05\x06\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\
+  \x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\
+  \x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07n\0\0\0\x01\0\0\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x01\x80\0\0\0\0\0\0\
+  \x01\0\0\0\0\0\0\0\0\x02\0\x04\x04\0\0_\0\0\0\0\0\0\0\0\x02\0\x01\x01\0\0A\0\0\0\0\0\0\0\0\x02\0\x02\x02\0\0P\0\0\0\0\0\0\0\0\x02\0\0\x07\0\0\x12\0\0\0\
+  \x12\0\0\0\0\x02\0\x06\x06\0\x002\0\0\0\0\0\0\0\0\x02\0\0\0\0\0\0\0\0\0\0\0\0\0\x03\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\x01\0\x01\x01\x01\x01\x01\x01\x01\
+  \x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x06\0\0\0\xff\xff\xff\xff\xff\xff\xff\xff#\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  #\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0\x12\0\0\0\x09\0\0\0\x12\0\0\0\x12\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x05\0\0\0j\x03\0\0rust-regex-automata-dfa-sparse\0\0\
+  \xff\xfe\0\0\x02\0\0\0\0\0\0\0\x02\0\0\0\x0c\0\0\0\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x02\x02\x02\x03\x04\x05\
+  \x06\x06\x06\x06\x06\x07\x08\x09\x09\x09\x0a\x0b\x0c\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\
+  \x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\
+  \x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\x0d\xaa\0\0\0\x01\0\0\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x02\
+  \0\x04\x04\0\0\x9b\0\0\0\0\0\0\0\0\x02\0\x01\x01\0\0A\0\0\0\0\0\0\0\0\x02\0\x05\x05\0\0P\0\0\0\0\0\0\0\0\x02\0\x07\x07\0\0_\0\0\0\0\0\0\0\0\x02\0\x03\x03\0\
+  \0n\0\0\0\0\0\0\0\0\x02\0\x08\x08\0\0}\0\0\0\0\0\0\0\0\x02\0\x0c\x0c\0\0\x8c\0\0\0\0\0\0\0\0\x02\0\0\x0d\0\0\x12\0\0\0\x12\0\0\0\0\x02\0\x0a\x0a\0\x002\0\0\
+  \0\0\0\0\0\0\x02\0\0\0\0\0\0\0\0\0\0\0\0\0\x03\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\
+  \x01\x01\x01\x01\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\x01\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\
+  \x01\x01\x01\x01\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\x06\0\0\0\xff\xff\xff\xff\xff\xff\xff\xff#\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\
+  \0\0#\0\0\0#\0\0\0\x12\0\0\0\x09\0\0\0\x12\0\0\0\x12\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\x06\0\0\0p\x03\0\0rust-regex-automata-dfa-sparse\0\0\xff\xfe\0\0\x02\0\0\0\0\0\0\0\x02\0\
+  \0\0\x0c\0\0\0\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x02\x02\x02\x03\x04\x04\x05\x06\x07\x08\x09\x0a\x0a\x0b\x0c\x0c\
+  \x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0d\
+  \x0e\x0e\x0e\x0e\x0e\x0e\x0e\x0e\x0e\x0e\x0e\x0e\x0e\x0e\x0e\x0e\x0e\x0e\x0e\x0e\x0e\x0e\x0e\x0e\x0f\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\
+  \x10\x10\x10\x10\x10\x10\x10\x10\x10\x10\xb0\0\0\0\x01\0\0\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x03\0\x01\x01\x0f\x0f\0\0V\0\0\x008\0\0\
+  \0\0\0\0\0\0\x02\0\x0d\x0d\0\0G\0\0\0\0\0\0\0\0\x02\0\0\x10\0\0\x12\0\0\0\x12\0\0\0\0\x02\0\x09\x09\0\0e\0\0\0\0\0\0\0\0\x02\0\x0b\x0b\0\0t\0\0\0\0\0\0\
+  \0\0\x02\0\x03\x03\0\0\x83\0\0\0\0\0\0\0\0\x02\0\x05\x05\0\0\x92\0\0\0\0\0\0\0\0\x02\0\x08\x08\0\0\xa1\0\0\0\0\0\0\0\0\x02\0\x07\x07\0\0G\0\0\0\0\0\0\0\0\x02\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\x03\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\
+  \0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\x01\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\x06\0\0\0\xff\xff\xff\xff\xff\xff\xff\xff#\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\
+  \0\0\x12\0\0\0\x09\0\0\0\x12\0\0\0\x12\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\x07\0\0\0x\x03\0\0rust-regex-automata-dfa-sparse\0\0\xff\xfe\0\0\x02\0\0\0\0\0\0\0\x02\0\0\0\x06\0\0\0\x01\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x02\x02\x02\x02\
+  \x02\x02\x02\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x04\x04\x04\x04\x05\x06\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x08\x08\x08\
+  \x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\
+  \x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\
+  \x08\x08\xb8\0\0\0\x01\0\0\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x0a\x80\0\0\x01\x01\x02\x02\x03\x03\x04\x04\x05\x05\x06\x06\x07\x07\x08\x08\0\0\x12\
+  \0\0\0#\0\0\0\x12\0\0\0#\0\0\0\x12\0\0\0#\0\0\0\x12\0\0\0#\0\0\0\x12\0\0\0\x12\0\0\0\x01\0\0\0\0\0\0\0\0\x02\0\x07\x07\0\0y\0\0\0\0\0\0\0\0\x0a\
+  \0\0\0\x01\x01\x02\x02\x03\x03\x04\x04\x05\x05\x06\x06\x07\x07\x08\x08\0\0\x12\0\0\0#\0\0\0\x12\0\0\0#\0\0\0\x12\0\0\0#\0\0\0\x12\0\0\0#\0\0\0\x12\0\0\0\x12\0\0\0\0\x02\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\x03\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\
+  \0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\x01\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\x06\0\0\0\xff\xff\xff\xff\xff\xff\xff\xffj\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0j\0\0\0j\0\0\0j\0\0\0j\0\0\0j\0\0\0j\0\
+  \0\0#\0\0\0\x09\0\0\0\x12\0\0\0#\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\x08\0\0\0x\x03\0\0rust-regex-automata-dfa-sparse\0\0\xff\xfe\0\0\x02\0\0\0\0\0\0\0\x02\0\0\0\x06\0\0\0\x01\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x02\x02\x02\x02\
+  \x02\x02\x02\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x04\x04\x04\x04\x05\x06\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x08\x08\x08\
+  \x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\
+  \x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\
+  \x08\x08\xb8\0\0\0\x01\0\0\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x0a\x80\0\0\x01\x01\x02\x02\x03\x03\x04\x04\x05\x05\x06\x06\x07\x07\x08\x08\0\0\x12\
+  \0\0\0#\0\0\0\x12\0\0\0#\0\0\0\x12\0\0\0#\0\0\0\x12\0\0\0#\0\0\0\x12\0\0\0\x12\0\0\0\x01\0\0\0\0\0\0\0\0\x02\0\x03\x03\0\0y\0\0\0\0\0\0\0\0\x0a\
+  \0\0\0\x01\x01\x02\x02\x03\x03\x04\x04\x05\x05\x06\x06\x07\x07\x08\x08\0\0\x12\0\0\0#\0\0\0\x12\0\0\0#\0\0\0\x12\0\0\0#\0\0\0\x12\0\0\0#\0\0\0\x12\0\0\0\x12\0\0\0\0\x02\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\x03\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\
+  \0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\x01\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\x06\0\0\0\xff\xff\xff\xff\xff\xff\xff\xffj\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0j\0\0\0j\0\0\0j\0\0\0j\0\0\0j\0\0\0j\0\
+  \0\0#\0\0\0\x09\0\0\0\x12\0\0\0#\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\x09\0\0\0\x80\x03\0\0rust-regex-automata-dfa-sparse\0\0\xff\xfe\0\0\x02\0\0\0\0\0\0\0\x02\0\0\0\x0a\0\0\0\x01\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x03\x04\x04\x05\
+  \x06\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\
+  \x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\
+  \x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\
+  \x07\x07\xc0\0\0\0\x01\0\0\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x02\x80\x05\x05\0\0l\0\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\
+  \x02\x80\0\x07\0\0\x12\0\0\0\x12\0\0\0\x01\0\0\0\0\0\0\0\0\x04\0\x01\x01\x03\x03\x05\x05\0\0\x8a\0\0\0\x99\0\0\0{\0\0\0\0\0\0\0\0\x02\0\0\x07\0\0\x12\0\0\0\x12\0\0\0\
+  \0\x02\0\x06\x06\0\0l\0\0\0\0\0\0\0\0\x02\0\x06\x06\0\0l\0\0\0\0\0\0\0\0\x06\0\0\x02\x03\x03\x04\x04\x05\x05\x06\x07\0\0\x12\0\0\0#\0\0\0\x12\0\0\0:\0\0\0\x12\0\0\
+  \0\x12\0\0\0\0\x02\0\0\0\0\0\0\0\0\0\0\0\0\0\x03\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\
+  \x01\x01\x01\x01\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\x01\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\
+  \x01\x01\x01\x01\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\x06\0\0\0\xff\xff\xff\xff\xff\xff\xff\xffQ\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0Q\0\0\0Q\0\0\0Q\0\0\0Q\0\
+  \0\0Q\0\0\0Q\0\0\0:\0\0\0\x09\0\0\0\x12\0\0\0:\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\x0a\0\0\0\x01\x03\0\0rust-regex-automata-dfa-sparse\0\0\xff\xfe\0\0\x02\0\0\0\0\0\0\0\x02\0\
+  \0\0\x05\0\0\0\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\x01\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02A\0\0\0\x01\0\0\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x02\0\x01\x01\0\x002\0\0\0\0\0\0\0\0\
+  \x02\0\0\x02\0\0\x12\0\0\0\x12\0\0\0\0\x02\0\0\0\0\0\0\0\0\0\0\0\0\0\x03\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\x01\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\
+  \x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x06\0\0\0\xff\xff\xff\xff\xff\xff\xff\xff#\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0#\0\0\0#\
+  \0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0\x12\0\0\0\x09\0\0\0\x12\0\0\0\x12\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x0b\0\0\0\x10\x03\0\0rust-regex-automata-dfa-sparse\0\0\xff\xfe\0\0\x02\
+  \0\0\0\0\0\0\0\x02\0\0\0\x06\0\0\0\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x03\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\
+  \x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\
+  \x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\
+  \x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04P\0\0\0\x01\0\0\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x02\0\x01\x01\0\0\
+  A\0\0\0\0\0\0\0\0\x02\0\0\x04\0\0\x12\0\0\0\x12\0\0\0\0\x02\0\x03\x03\0\x002\0\0\0\0\0\0\0\0\x02\0\0\0\0\0\0\0\0\0\0\0\0\0\x03\0\0\x04\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\
+  \x01\x01\x01\x01\x01\x01\0\0\0\0\x01\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x06\0\0\0\xff\xff\xff\xff\xff\xff\xff\xff#\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0\x12\0\0\0\x09\0\0\0\x12\0\0\0\x12\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x0c\0\0\0\x01\x03\0\0rust-regex-au\
+  tomata-dfa-sparse\0\0\xff\xfe\0\0\x02\0\0\0\0\0\0\0\x02\0\0\0\x05\0\0\0\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02A\0\0\0\x01\0\0\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\
+  \0\x01\x80\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x02\0\x01\x01\0\x002\0\0\0\0\0\0\0\0\x02\0\0\x02\0\0\x12\0\0\0\x12\0\0\0\0\x02\0\0\0\0\0\0\0\0\0\0\0\0\0\x03\0\
+  \0\x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\
+  \x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\x01\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x06\0\0\0\xff\xff\xff\xff\xff\xff\xff\xff\
+  #\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0\x12\0\0\0\x09\0\0\0\x12\0\0\0\
+  \x12\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x0d\0\0\0\x01\x03\0\0rust\
+  -regex-automata-dfa-sparse\0\0\xff\xfe\0\0\x02\0\0\0\0\0\0\0\x02\0\0\0\x05\0\0\0\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02A\0\0\0\x01\0\0\0\0\0\0\0\
+  \0\x01\0\0\0\0\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x02\0\x01\x01\0\x002\0\0\0\0\0\0\0\0\x02\0\0\x02\0\0\x12\0\0\0\x12\0\0\0\0\x02\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\x03\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\x01\x01\
+  \x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\x01\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x06\0\0\
+  \0\xff\xff\xff\xff\xff\xff\xff\xff#\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0\x12\0\0\
+  \0\x09\0\0\0\x12\0\0\0\x12\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x0e\0\0\
+  \0\x01\x03\0\0rust-regex-automata-dfa-sparse\0\0\xff\xfe\0\0\x02\0\0\0\0\0\0\0\x02\0\0\0\x05\0\0\0\x01\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02A\0\0\
+  \0\x01\0\0\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x02\0\x01\x01\0\x002\0\0\0\0\0\0\0\0\x02\0\0\x02\0\0\x12\0\0\0\x12\0\0\
+  \0\0\x02\0\0\0\0\0\0\0\0\0\0\0\0\0\x03\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\
+  \0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\x01\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\
+  \x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\x06\0\0\0\xff\xff\xff\xff\xff\xff\xff\xff#\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\
+  \0\0#\0\0\0\x12\0\0\0\x09\0\0\0\x12\0\0\0\x12\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\x0f\0\0\0\x01\x03\0\0rust-regex-automata-dfa-sparse\0\0\xff\xfe\0\0\x02\0\0\0\0\0\0\0\x02\0\0\0\x05\0\
+  \0\0\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02A\0\0\0\x01\0\0\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x02\0\x01\x01\0\x002\0\0\0\0\0\0\0\0\x02\0\0\x02\
+  \0\0\x12\0\0\0\x12\0\0\0\0\x02\0\0\0\0\0\0\0\0\0\0\0\0\0\x03\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\
+  \x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\x01\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\
+  \x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x06\0\0\0\xff\xff\xff\xff\xff\xff\xff\xff#\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0#\0\0\0#\0\0\0#\
+  \0\0\0#\0\0\0#\0\0\0#\0\0\0\x12\0\0\0\x09\0\0\0\x12\0\0\0\x12\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x10\0\0\0\x01\x03\0\0rust-regex-automata-dfa-sparse\0\0\xff\xfe\0\0\x02\0\0\0\0\
+  \0\0\0\x02\0\0\0\x05\0\0\0\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02A\0\0\0\x01\0\0\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x02\0\x01\x01\0\x002\0\0\0\
+  \0\0\0\0\0\x02\0\0\x02\0\0\x12\0\0\0\x12\0\0\0\0\x02\0\0\0\0\0\0\0\0\0\0\0\0\0\x03\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\x01\0\x01\x01\x01\x01\x01\x01\x01\
+  \x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x06\0\0\0\xff\xff\xff\xff\xff\xff\xff\xff#\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  #\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0\x12\0\0\0\x09\0\0\0\x12\0\0\0\x12\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x11\0\0\0\x01\x03\0\0rust-regex-automata-dfa-sparse\0\0\
+  \xff\xfe\0\0\x02\0\0\0\0\0\0\0\x02\0\0\0\x05\0\0\0\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02A\0\0\0\x01\0\0\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x02\
+  \0\x01\x01\0\x002\0\0\0\0\0\0\0\0\x02\0\0\x02\0\0\x12\0\0\0\x12\0\0\0\0\x02\0\0\0\0\0\0\0\0\0\0\0\0\0\x03\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\
+  \x01\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x06\0\0\0\xff\xff\xff\xff\xff\xff\xff\xff#\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0\x12\0\0\0\x09\0\0\0\x12\0\0\0\x12\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x12\0\0\0\x01\x03\0\0rust-regex-automata-dfa\
+  -sparse\0\0\xff\xfe\0\0\x02\0\0\0\0\0\0\0\x02\0\0\0\x05\0\0\0\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\x01\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02A\0\0\0\x01\0\0\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\
+  \0\0\0\0\0\0\0\0\x02\0\x01\x01\0\x002\0\0\0\0\0\0\0\0\x02\0\0\x02\0\0\x12\0\0\0\x12\0\0\0\0\x02\0\0\0\0\0\0\0\0\0\0\0\0\0\x03\0\0\x04\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\
+  \x01\x01\x01\x01\x01\0\0\0\0\x01\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x06\0\0\0\xff\xff\xff\xff\xff\xff\xff\xff#\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0\x12\0\0\0\x09\0\0\0\x12\0\0\0\x12\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x13\0\0\0\x01\x03\0\0rust-regex-aut\
+  omata-dfa-sparse\0\0\xff\xfe\0\0\x02\0\0\0\0\0\0\0\x02\0\0\0\x05\0\0\0\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02A\0\0\0\x01\0\0\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\
+  \x01\x80\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x02\0\x01\x01\0\x002\0\0\0\0\0\0\0\0\x02\0\0\x02\0\0\x12\0\0\0\x12\0\0\0\0\x02\0\0\0\0\0\0\0\0\0\0\0\0\0\x03\0\0\
+  \x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\
+  \x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\x01\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x06\0\0\0\xff\xff\xff\xff\xff\xff\xff\xff#\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0\x12\0\0\0\x09\0\0\0\x12\0\0\0\x12\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x14\0\0\0\x01\x03\0\0rust-\
+  regex-automata-dfa-sparse\0\0\xff\xfe\0\0\x02\0\0\0\0\0\0\0\x02\0\0\0\x05\0\0\0\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02A\0\0\0\x01\0\0\0\0\0\0\0\0\
+  \x01\0\0\0\0\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x02\0\x01\x01\0\x002\0\0\0\0\0\0\0\0\x02\0\0\x02\0\0\x12\0\0\0\x12\0\0\0\0\x02\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\x03\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\x01\x01\x01\
+  \x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\x01\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x06\0\0\0\
+  \xff\xff\xff\xff\xff\xff\xff\xff#\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0\x12\0\0\0\
+  \x09\0\0\0\x12\0\0\0\x12\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x15\0\0\0\
+  \x01\x03\0\0rust-regex-automata-dfa-sparse\0\0\xff\xfe\0\0\x02\0\0\0\0\0\0\0\x02\0\0\0\x05\0\0\0\x01\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02A\0\0\0\
+  \x01\0\0\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x02\0\x01\x01\0\x002\0\0\0\0\0\0\0\0\x02\0\0\x02\0\0\x12\0\0\0\x12\0\0\0\
+  \0\x02\0\0\0\0\0\0\0\0\0\0\0\0\0\x03\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\
+  \0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\x01\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\x06\0\0\0\xff\xff\xff\xff\xff\xff\xff\xff#\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\
+  \0#\0\0\0\x12\0\0\0\x09\0\0\0\x12\0\0\0\x12\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\x16\0\0\0\x01\x03\0\0rust-regex-automata-dfa-sparse\0\0\xff\xfe\0\0\x02\0\0\0\0\0\0\0\x02\0\0\0\x05\0\0\
+  \0\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02A\0\0\0\x01\0\0\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x02\0\x01\x01\0\x002\0\0\0\0\0\0\0\0\x02\0\0\x02\0\
+  \0\x12\0\0\0\x12\0\0\0\0\x02\0\0\0\0\0\0\0\0\0\0\0\0\0\x03\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x01\
+  \x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\x01\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\
+  \x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\x06\0\0\0\xff\xff\xff\xff\xff\xff\xff\xff#\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0#\0\0\0#\0\0\0#\0\
+  \0\0#\0\0\0#\0\0\0#\0\0\0\x12\0\0\0\x09\0\0\0\x12\0\0\0\x12\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\x17\0\0\0\x01\x03\0\0rust-regex-automata-dfa-sparse\0\0\xff\xfe\0\0\x02\0\0\0\0\0\
+  \0\0\x02\0\0\0\x05\0\0\0\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02A\0\0\0\x01\0\0\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x02\0\x01\x01\0\x002\0\0\0\0\
+  \0\0\0\0\x02\0\0\x02\0\0\x12\0\0\0\x12\0\0\0\0\x02\0\0\0\0\0\0\0\0\0\0\0\0\0\x03\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\x01\0\x01\x01\x01\x01\x01\x01\x01\x01\
+  \x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x06\0\0\0\xff\xff\xff\xff\xff\xff\xff\xff#\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0#\
+  \0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0\x12\0\0\0\x09\0\0\0\x12\0\0\0\x12\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x18\0\0\0\x01\x09\0\0rust-regex-automata-dfa-sparse\0\0\xff\
+  \xfe\0\0\x02\0\0\0\0\0\0\0\x02\0\0\0)\0\0\0\x01\0\0\0\0\0\0\0\0\0\0\x01\x02\x03\x03\x03\x03\x03\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x05\x06\x06\x06\x06\x06\x06\
+  \x06\x06\x06\x06\x06\x06\x06\x07\x08\x08\x08\x08\x08\x08\x08\x08\x08\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x0a\x0b\x0b\x0b\x0b\x0c\x0d\x0d\x0e\x0f\x10\x11\x11\
+  \x11\x11\x11\x11\x11\x12\x13\x13\x13\x14\x15\x16\x17\x18\x19\x1a\x1b\x1b\x1c\x1d\x1e\x1f\x1f\x20!\x22#$%&'(()*++,,-./01123456789:;<==>?@ABC\
+  DEFGHIJKLMNOPQRSTUVWXYYZZ[[[[[[[[[[[[[[[[[[[[[[[\x5c]^___`abccccccc\
+  cdeefghijjjklllllllllllA\x06\0\0\x01\0\0\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x02\0\
+  \x05\x05\0\0\xa8\x05\0\0\0\0\0\0\0\x02\0\0l\0\0\x12\0\0\0\x12\0\0\0\0\x0b\0\x05\x05\x0a\x0a\x0c\x0c\x0e\x0e\x10\x10\x12\x12\x14\x14\x16\x16\x17\x17\x18\x18\0\0\xa8\x05\0\0\xa8\x05\0\0\xa8\x05\0\0\
+  \xa8\x05\0\0\xa8\x05\0\0\xa8\x05\0\0\xa8\x05\0\0\xa8\x05\0\0-\x05\0\0\xa8\x05\0\0\0\0\0\0\0\x02\0\x20Y\0\0\xa8\x05\0\0\0\0\0\0\0\x02\0\x20Y\0\0\xa8\x05\0\0\0\0\0\0\0\x02\
+  \0\x20Y\0\0\xa8\x05\0\0\0\0\0\0\0\x02\0\x20Y\0\0\xa8\x05\0\0\0\0\0\0\0\x1b\0<?@@AABBCCDDEEFFGGHHIIJJKKLLMMNNO\
+  OPPQQRRSSTTUUVVWWXY\0\0\x86\0\0\0\xd3\x04\0\0\x86\0\0\0\xd3\x04\0\0\x86\0\0\0\xd3\x04\0\0\x86\0\0\0\xd3\x04\0\0\x86\0\0\0\xd3\x04\0\0\x86\0\0\
+  \0\xd3\x04\0\0\x86\0\0\0\xd3\x04\0\0\x86\0\0\0\xd3\x04\0\0\x86\0\0\0\xd3\x04\0\0\x86\0\0\0\xd3\x04\0\0\x86\0\0\0\xb5\x04\0\0\x86\0\0\0\xb5\x04\0\0\x95\0\0\0\x86\0\0\0\0\0\0\
+  \0\0\x13\0\x20\x20!!\x22\x22#:;;<<=?@@AABBCDEEFGHHIIJKLLMY\0\0\x86\0\0\0\xb3\0\0\0\xb5\x04\0\0\x86\0\0\0\x95\0\0\0\xb5\x04\
+  \0\0\x86\0\0\0\x0f\x05\0\0\x86\0\0\0\xb5\x04\0\0\x86\0\0\0\x1e\x05\0\0\x86\0\0\0\xb5\x04\0\0\xa4\0\0\0\x86\0\0\0\x1e\x05\0\0\x86\0\0\0\0\0\0\0\0\x02\0\x20Y\0\0\x86\0\0\
+  \0\0\0\0\0\0\x0d\0\x203445=>>??@ABBCCDDEIJJKY\0\0\x86\0\0\0\x95\0\0\0\x86\0\0\0\xb5\x04\0\0\xb3\0\0\0\x86\0\0\0\0\x05\0\0\x86\0\
+  \0\0\xb5\x04\0\0\x86\0\0\0\xa4\0\0\0\x86\0\0\0\0\0\0\0\0\x02\0\x20;\0\0\x86\0\0\0\0\0\0\0\0\x04\0\x20VWWXY\0\0\x86\0\0\0\xb5\x04\0\0\x86\0\0\0\0\0\0\0\
+  \0\x0b\0--../122378899::;;<Y\0\0\xc9\x02\0\0\xf6\x02\0\0\xdc\x01\0\0\xbf\x03\0\0\xdc\x01\0\0\x0a\x04\0\0%\x04\0\0@\x04\0\0\x8b\x04\0\0\xdc\x01\0\
+  \0\0\0\0\0\0\x02\0\x20Y\0\0\xdc\x01\0\0\0\0\0\0\0\x02\0\x20,\0\0\xdc\x01\0\0\0\0\0\0\0\x07\0\x20.//0NOOPPQY\0\0\x86\0\0\0\x95\0\0\0\x86\0\0\0\
+  \xa4\0\0\0\xb3\0\0\0\x86\0\0\0\0\0\0\0\0!\0\x20\x20!!\x22\x22##$$%&''()**+-..//0014556677889=>>??@@A\
+  IJJKKLLMOPPQQRWXXYY\0\0\x86\0\0\0\xd3\x04\0\0\x86\0\0\0\xa4\0\0\0\xe2\x04\0\0\x86\0\0\0\xb5\x04\0\0\x86\0\0\0\xa4\0\0\0\x86\0\0\0\xb5\x04\0\
+  \0\x86\0\0\0\xb5\x04\0\0\x86\0\0\0\xb5\x04\0\0\x86\0\0\0\xf1\x04\0\0\xa4\0\0\0\x86\0\0\0\x95\0\0\0\x86\0\0\0\xb5\x04\0\0\x86\0\0\0\xa4\0\0\0\x86\0\0\0\xb5\x04\0\0\x86\0\0\
+  \0\xb5\x04\0\0\x95\0\0\0\x86\0\0\0\xb5\x04\0\0\x86\0\0\0\0\0\0\0\0\x0c\0\x20#$$%CDDEEFFGGHHIOPPQY\0\0\x86\0\0\0\xa4\0\0\0\x86\0\0\0\
+  \x95\0\0\0\x86\0\0\0\xb3\0\0\0\x86\0\0\0\xb5\x04\0\0\x86\0\0\0\xa4\0\0\0\x86\0\0\0\0\0\0\0\0\x04\0\x20MNNOY\0\0\x86\0\0\0\xa4\0\0\0\x86\0\0\0\0\0\0\0\0\
+  \x04\0\x20:;;<Y\0\0\x86\0\0\0\xc4\x04\0\0\x86\0\0\0\0\0\0\0\0\x0c\0\x20$%%&)**+/0012334?@@AY\0\0\x86\0\0\0\xb3\0\0\0\x86\0\0\
+  \0\xa4\0\0\0\x86\0\0\0\xa4\0\0\0\x86\0\0\0\xa6\x04\0\0\x86\0\0\0\xb5\x04\0\0\x86\0\0\0\0\0\0\0\0\x04\0\x20IJJKY\0\0\x86\0\0\0\xa4\0\0\0\x86\0\0\0\0\0\0\0\
+  \0\x02\0\x20Y\0\0\xa8\x05\0\0\0\0\0\0\0\x02\0\x20Y\0\0\xa8\x05\0\0\0\0\0\0\0\x02\0\x20Y\0\0\xa8\x05\0\0\0\0\0\0\0\x02\0\x20Y\0\0\xa8\x05\0\0\0\0\0\0\0\x02\0\x20\
+  Y\0\0\xa8\x05\0\0\0\0\0\0\0\x02\0\x20Y\0\0\xa8\x05\0\0\0\0\0\0\0\x02\0\x20Y\0\0\xa8\x05\0\0\0\0\0\0\0\x02\0\x20Y\0\0\xa8\x05\0\0\0\0\0\0\0\x02\0\x20Y\0\0\xa8\
+  \x05\0\0\0\0\0\0\0\x02\0\x1c\x1c\0\0<\x05\0\0\0\0\0\0\0\x04\0\x07\x07\x08\x08\x0c\x10\0\0W\x05\0\0x\x05\0\0x\x05\0\0\0\0\0\0\0\x05\0\x07\x08\x0c\x10\x1a\x1a\x1e\x1e\0\0x\x05\
+  \0\0x\x05\0\0\x93\x05\0\0\xa8\x05\0\0\0\0\0\0\0\x04\0\x07\x08\x0c\x10\x1e\x1e\0\0x\x05\0\0x\x05\0\0\xa8\x05\0\0\0\0\0\0\0\x03\0\x07\x08\x0c\x10\0\0x\x05\0\0x\x05\0\0\0\0\
+  \0\0\0\x19\0\0\0\x02\x02\x04\x04\x05\x05\x06\x09\x0a\x0a\x0b\x1f[[\x5c\x5c]]^^__``aabbccddeeffgghhiijjkk\0\0\xa8\x05\0\0\xa8\x05\0\0\xa8\
+  \x05\0\x002\0\0\0\xa8\x05\0\0A\0\0\0\xa8\x05\0\0\x86\0\0\0\x95\0\0\0\x86\0\0\0\xa4\0\0\0\x86\0\0\0\xb3\0\0\0\xc2\0\0\0g\x01\0\0\xdc\x01\0\0\xeb\x01\0\0\xdc\x01\0\0<\
+  \x02\0\0\xdc\x01\0\0K\x02\0\0f\x02\0\0\xab\x02\0\0\xba\x02\0\0\0\0\0\0\0\x02\0\0\0\0\0\0\0\0\0\0\0\0\0\x03\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\x01\
+  \0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x06\0\0\0\xff\xff\xff\xff\xff\xff\xff\xff#\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0\x12\0\0\0\x09\0\0\0\x12\0\0\0\x12\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x19\0\0\0\x84\x04\0\0rust-regex-automata-dfa-\
+  sparse\0\0\xff\xfe\0\0\x02\0\0\0\0\0\0\0\x02\0\0\0\x12\0\0\0\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\x01\x02\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\
+  \x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x03\x04\x05\x05\x05\x05\x05\x05\x05\x05\x05\x05\x05\x05\x05\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\
+  \x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x08\x09\x09\x0a\x0a\x0a\x0a\x0a\x0a\x0a\x0a\x0a\x0a\x0a\x0a\x0a\x0a\x0a\x0a\x0a\x0a\x0a\x0a\x0a\x0a\x0a\x0a\x0a\x0a\x0a\x0a\x0a\x0a\
+  \x0b\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0c\x0d\x0e\x0e\x0f\x10\x10\x10\x11\x12\x12\x12\x12\x12\x12\x12\x12\x12\x12\x12\xc4\x01\0\0\x01\0\0\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\
+  \0\0\0\0\0\0\0\x02\0\x04\x04\0\0\xb5\x01\0\0\0\0\0\0\0\x02\0\x02\x02\0\0A\0\0\0\0\0\0\0\0\x02\0\x01\x01\0\0P\0\0\0\0\0\0\0\0\x0c\0\0\0\x01\x01\x02\x05\x0a\x0a\x0b\x0b\
+  \x0c\x0c\x0d\x0d\x0e\x0e\x0f\x0f\x10\x10\x11\x11\0\0P\0\0\0\x9b\0\0\0P\0\0\0\xec\0\0\0\xfb\0\0\0\x0a\x01\0\0\x19\x01\0\0\x0a\x01\0\0(\x01\0\x007\x01\0\0F\x01\0\0\0\0\0\0\0\x0d\
+  \0\0\0\x01\x01\x02\x02\x03\x05\x0a\x0a\x0b\x0b\x0c\x0c\x0d\x0d\x0e\x0e\x0f\x0f\x10\x10\x11\x11\0\0P\0\0\0\x9b\0\0\0U\x01\0\0P\0\0\0\xec\0\0\0\xfb\0\0\0\x0a\x01\0\0\x19\x01\0\0\x0a\x01\0\0(\
+  \x01\0\x007\x01\0\0F\x01\0\0\0\0\0\0\0\x02\0\x06\x08\0\0P\0\0\0\0\0\0\0\0\x02\0\x08\x08\0\0\xec\0\0\0\0\0\0\0\0\x02\0\x06\x08\0\0\xec\0\0\0\0\0\0\0\0\x02\0\x06\
+  \x07\0\0\xec\0\0\0\0\0\0\0\0\x02\0\x07\x08\0\0\x0a\x01\0\0\0\0\0\0\0\x02\0\x06\x08\0\0\x0a\x01\0\0\0\0\0\0\0\x02\0\x06\x06\0\0\x0a\x01\0\0\0\0\0\0\0\x0d\0\0\0\x01\x01\x02\
+  \x02\x03\x05\x0a\x0a\x0b\x0b\x0c\x0c\x0d\x0d\x0e\x0e\x0f\x0f\x10\x10\x11\x11\0\0P\0\0\0\x9b\0\0\0\xa6\x01\0\0P\0\0\0\xec\0\0\0\xfb\0\0\0\x0a\x01\0\0\x19\x01\0\0\x0a\x01\0\0(\x01\0\x007\x01\0\
+  \0F\x01\0\0\0\0\0\0\0\x02\0\0\x12\0\0\x12\0\0\0\x12\0\0\0\0\x02\0\x02\x02\0\x002\0\0\0\0\0\0\0\0\x02\0\0\0\0\0\0\0\0\0\0\0\0\0\x03\0\0\x04\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\
+  \x01\x01\x01\x01\x01\x01\x01\0\0\0\0\x01\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x06\0\0\0\xff\xff\xff\xff\xff\xff\xff\xff#\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0\x12\0\0\0\x09\0\0\0\x12\0\0\0\x12\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x1a\0\0\0l\x03\0\0rust-regex-a\
+  utomata-dfa-sparse\0\0\xff\xfe\0\0\x02\0\0\0\0\0\0\0\x02\0\0\0\x06\0\0\0\x01\0\0\0\0\0\0\0\0\0\0\0\0\x01\x01\x02\x03\x03\x04\x04\x04\x04\x04\x04\
+  \x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x05\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\
+  \x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\
+  \x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\
+  \x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\xac\0\0\0\x01\0\0\0\0\0\0\0\0\x01\0\0\0\0\0\0\
+  \0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x08\x80\0\0\x01\x01\x02\x02\x03\x03\x04\x04\x05\x05\x06\x06\0\0\x12\0\0\0#\0\0\0\x12\0\0\0#\0\0\0\x12\0\0\0#\0\0\0\x12\0\0\
+  \0\x12\0\0\0\x01\0\0\0\0\0\0\0\0\x04\0\x01\x01\x03\x03\x05\x05\0\0y\0\0\0y\0\0\0y\0\0\0\0\0\0\0\0\x08\0\0\0\x01\x01\x02\x02\x03\x03\x04\x04\x05\x05\x06\x06\0\0\x12\0\0\0#\
+  \0\0\0\x12\0\0\0#\0\0\0\x12\0\0\0#\0\0\0\x12\0\0\0\x12\0\0\0\0\x02\0\0\0\0\0\0\0\0\0\0\0\0\0\x03\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\x01\
+  \0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x06\0\0\0\xff\xff\xff\xff\xff\xff\xff\xff^\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0^\0\0\0^\0\0\0^\0\0\0^\0\0\0^\0\0\0^\0\0\0#\0\0\0\x09\0\0\0\x12\0\0\0#\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x1b\0\0\0\xc4\x03\0\0rust-regex-automata-dfa-\
+  sparse\0\0\xff\xfe\0\0\x02\0\0\0\0\0\0\0\x02\0\0\0\x0e\0\0\0\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\
+  \x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x02\x03\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\
+  \x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x05\x05\x05\x05\x05\x05\x05\x05\x05\x05\x05\x05\x05\x05\x05\x05\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\
+  \x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x08\x08\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\
+  \x0a\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0c\x0d\x0d\x0e\x0f\x0f\x0f\x10\x11\x11\x11\x11\x11\x11\x11\x11\x11\x11\x11\x04\x01\0\0\x01\0\0\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x01\x80\0\0\0\0\0\0\x01\0\
+  \0\0\0\0\0\0\0\x02\0\x03\x03\0\0\xf5\0\0\0\0\0\0\0\0\x0c\0\0\0\x01\x01\x02\x04\x09\x09\x0a\x0a\x0b\x0b\x0c\x0c\x0d\x0d\x0e\x0e\x0f\x0f\x10\x10\0\x002\0\0\0}\0\0\x002\0\0\0\x8c\0\0\0\
+  \x9b\0\0\0\xaa\0\0\0\xb9\0\0\0\xaa\0\0\0\xc8\0\0\0\xd7\0\0\0\xe6\0\0\0\0\0\0\0\0\x02\0\0\x11\0\0\x12\0\0\0\x12\0\0\0\0\x02\0\x05\x07\0\x002\0\0\0\0\0\0\0\0\x02\
+  \0\x07\x07\0\0\x8c\0\0\0\0\0\0\0\0\x02\0\x05\x07\0\0\x8c\0\0\0\0\0\0\0\0\x02\0\x05\x06\0\0\x8c\0\0\0\0\0\0\0\0\x02\0\x06\x07\0\0\xaa\0\0\0\0\0\0\0\0\x02\0\x05\x07\0\
+  \0\xaa\0\0\0\0\0\0\0\0\x02\0\x05\x05\0\0\xaa\0\0\0\0\0\0\0\0\x02\0\x03\x03\0\x002\0\0\0\0\0\0\0\0\x02\0\0\0\0\0\0\0\0\0\0\0\0\0\x03\0\0\x04\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\
+  \x01\x01\x01\x01\x01\x01\x01\0\0\0\0\x01\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x06\0\0\0\xff\xff\xff\xff\xff\xff\xff\xff#\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0#\0\0\0\x12\0\0\0\x09\0\0\0\x12\0\0\0\x12\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x1c\0\0\0\x1b\x04\0\0rust-regex-a\
+  utomata-dfa-sparse\0\0\xff\xfe\0\0\x02\0\0\0\0\0\0\0\x02\0\0\0\x0f\0\0\0\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\x02\x02\x02\x02\x03\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\
+  \x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x04\x05\x05\x05\x05\x05\x05\x05\x05\x05\x05\x05\x05\x05\x05\x05\x05\x06\x06\x06\x06\
+  \x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x06\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x07\x08\x08\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\
+  \x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x09\x0a\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0b\x0c\x0d\x0d\x0e\x0f\x0f\x0f\x10\x11\x11\x11\x11\x11\x11\x11\x11\x11\x11\x11[\x01\0\0\x01\0\0\0\0\0\0\0\0\x01\0\0\0\0\0\0\
+  \0\0\x01\x80\0\0\0\0\0\0\x01\0\0\0\0\0\0\0\0\x02\0\x03\x03\0\0L\x01\0\0\0\0\0\0\0\x0c\0\0\0\x01\x01\x02\x04\x09\x09\x0a\x0a\x0b\x0b\x0c\x0c\x0d\x0d\x0e\x0e\x0f\x0f\x10\x10\0\x002\0\0\0\
+  }\0\0\x002\0\0\0\xd4\0\0\0\xe3\0\0\0\xf2\0\0\0\x01\x01\0\0\xf2\0\0\0\x10\x01\0\0\x1f\x01\0\0.\x01\0\0\0\0\0\0\0\x0e\0\0\0\x01\x01\x02\x02\x03\x03\x04\x04\x09\x09\x0a\x0a\x0b\x0b\x0c\
+  \x0c\x0d\x0d\x0e\x0e\x0f\x0f\x10\x10\0\x002\0\0\0}\0\0\x002\0\0\0=\x01\0\x002\0\0\0\xd4\0\0\0\xe3\0\0\0\xf2\0\0\0\x01\x01\0\0\xf2\0\0\0\x10\x01\0\0\x1f\x01\0\0.\x01\0\0\0\
+  \0\0\0\0\x02\0\x05\x07\0\x002\0\0\0\0\0\0\0\0\x02\0\x07\x07\0\0\xd4\0\0\0\0\0\0\0\0\x02\0\x05\x07\0\0\xd4\0\0\0\0\0\0\0\0\x02\0\x05\x06\0\0\xd4\0\0\0\0\0\0\0\0\
+  \x02\0\x06\x07\0\0\xf2\0\0\0\0\0\0\0\0\x02\0\x05\x07\0\0\xf2\0\0\0\0\0\0\0\0\x02\0\x05\x05\0\0\xf2\0\0\0\0\0\0\0\0\x02\0\0\x11\0\0\x12\0\0\0\x12\0\0\0\0\x02\0\x01\x01\
+  \0\x002\0\0\0\0\0\0\0\0\x02\0\0\0\0\0\0\0\0\0\0\0\0\0\x03\0\0\x04\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x01\
+  \x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\x01\0\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\
+  \x01\x01\x01\x01\x01\x01\x01\x01\x01\x01\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x06\0\0\0\xff\xff\xff\xff\xff\xff\xff\xff#\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0#\0\0\0#\0\0\0#\
+  \0\0\0#\0\0\0#\0\0\0#\0\0\0\x12\0\0\0\x09\0\0\0\x12\0\0\0\x12\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\
+  \0\0\0\0\0\0\0\0\0\0\0\0\0\0\0";
+
+// 直接从 LEXER_DFA_TABLE 中恢复自动机, 不需要在运行时编译正则表达式
+// 恢复失败时 (如大端序的平台, 或者 chiru 的版本不同) 再根据词法规则重新构造
+static LEXER_DFA: Lazy<LexerDfa> = Lazy::new(|| {
+  LexerDfa::from_bytes(LEXER_DFA_TABLE).or_else(|_| LexerDfa::new(&LEXER_RULE_LIST)).unwrap()
+});
+
+
+
 #[allow(unused)]
 impl<'a> ChiruLexer<'a> {
   pub const _START: usize = 0;
   pub const _STOP: usize = 1;
 
+  // 词法模式
+  pub const DEFAULT_MODE: usize = 0;
+
   // 从这里开始使用模板
   
   pub const GRAMMAR: usize = 2;
-  pub const RULE_REF: usize = 3;
-  pub const TOKEN_REF: usize = 4;
-  pub const COLON: usize = 5;
-  pub const SEMI: usize = 6;
-  pub const COMMA: usize = 7;
-  pub const OR: usize = 8;
-  pub const EPSILON: usize = 9;
-  pub const STAR: usize = 10;
-  pub const PLUS: usize = 11;
-  pub const QUESTION: usize = 12;
-  pub const LPAREN: usize = 13;
-  pub const RPAREN: usize = 14;
-  pub const AT: usize = 15;
-  pub const SHARP: usize = 16;
-  pub const LBRACKET: usize = 17;
-  pub const RBRACKET: usize = 18;
-  pub const STRING_LITERAL: usize = 19;
-  pub const REGULAR_LITERAL: usize = 20;
-  pub const WHITE_SPACE: usize = 21;
-  pub const LINE_COMMENT: usize = 22;
-  pub const BLOCK_COMMENT: usize = 23;
+  pub const LEXER: usize = 3;
+  pub const PARSER: usize = 4;
+  pub const IMPORT: usize = 5;
+  pub const MODE: usize = 6;
+  pub const FRAGMENT: usize = 7;
+  pub const EPSILON: usize = 8;
+  pub const RULE_REF: usize = 9;
+  pub const TOKEN_REF: usize = 10;
+  pub const COLON: usize = 11;
+  pub const ASSIGN: usize = 12;
+  pub const PLUS_ASSIGN: usize = 13;
+  pub const SEMI: usize = 14;
+  pub const COMMA: usize = 15;
+  pub const OR: usize = 16;
+  pub const STAR: usize = 17;
+  pub const PLUS: usize = 18;
+  pub const QUESTION: usize = 19;
+  pub const LPAREN: usize = 20;
+  pub const RPAREN: usize = 21;
+  pub const AT: usize = 22;
+  pub const SHARP: usize = 23;
+  pub const LBRACKET: usize = 24;
+  pub const RBRACKET: usize = 25;
+  pub const STRING_LITERAL: usize = 26;
+  pub const REGULAR_LITERAL: usize = 27;
+  pub const WHITE_SPACE: usize = 28;
+  pub const LINE_COMMENT: usize = 29;
+  pub const BLOCK_COMMENT: usize = 30;
 
 
   pub fn new(input: &'a str) -> Self {
@@ -236,8 +921,8 @@ impl<'a> ChiruLexer<'a> {
 
 
 impl Lexer for ChiruLexer<'_> {
-  fn iter(&self) -> TokenIter {
-    TokenIter::new(self.input, &LEXER_RULE_LIST, &self.error_listeners)
+  fn iter(&self) -> TokenIter<'_> {
+    TokenIter::new(self.input, &LEXER_RULE_LIST, &LEXER_DFA, &self.error_listeners)
   }
 }
 
@@ -1,5 +1,5 @@
 
-// generated from .\src\tool\syntaxis\chiru.chiru by chiru 0.7.0
+// generated from src/tool/syntaxis/chiru.chiru by chiru 0.7.0
  
 pub mod chiru_lexer;
 pub mod chiru_parser;